@@ -9,7 +9,7 @@ use b::simulation_world::{
         components::{ChunkBlocksComponent, ChunkCoord},
         thread_buffer_pool::acquire_buffer,
         types::ChunkLod,
-        ChunkDataOption, NeighborLODs, PaddedChunk,
+        ChunkDataOption, ChunkMesher, NeighborLODs, PaddedChunk,
     },
     terrain::{
         BasicBiomeGenerator, BiomeGenerator, BiomeMapComponent, BiomeResultBuilder,
@@ -171,7 +171,40 @@ fn bench_chunk_meshing(c: &mut Criterion) {
             let buffer = acquire_buffer();
             let dense_padded_chunk =
                 PaddedChunk::new(&dense_chunks, ChunkLod(0), dense_neighbor_lods, buffer);
-            build_chunk_mesh("bench_chunk_dense", &dense_padded_chunk, &block_registry)
+            build_chunk_mesh(
+                "bench_chunk_dense",
+                &dense_padded_chunk,
+                &block_registry,
+                ChunkMesher::Dense,
+            )
+        })
+    });
+
+    group.bench_function("binary meshing", |b| {
+        b.iter(|| {
+            let buffer = acquire_buffer();
+            let dense_padded_chunk =
+                PaddedChunk::new(&dense_chunks, ChunkLod(0), dense_neighbor_lods, buffer);
+            build_chunk_mesh(
+                "bench_chunk_binary",
+                &dense_padded_chunk,
+                &block_registry,
+                ChunkMesher::Binary,
+            )
+        })
+    });
+
+    group.bench_function("greedy meshing", |b| {
+        b.iter(|| {
+            let buffer = acquire_buffer();
+            let dense_padded_chunk =
+                PaddedChunk::new(&dense_chunks, ChunkLod(0), dense_neighbor_lods, buffer);
+            build_chunk_mesh(
+                "bench_chunk_greedy",
+                &dense_padded_chunk,
+                &block_registry,
+                ChunkMesher::Greedy,
+            )
         })
     });
 
@@ -201,7 +234,12 @@ fn bench_chunk_meshing(c: &mut Criterion) {
             let buffer = acquire_buffer();
             let hull_padded_chunk =
                 PaddedChunk::new(&hull_chunks, ChunkLod(0), hull_neighbor_lods, buffer);
-            build_chunk_mesh("bench_chunk_hull", &hull_padded_chunk, &block_registry)
+            build_chunk_mesh(
+                "bench_chunk_hull",
+                &hull_padded_chunk,
+                &block_registry,
+                ChunkMesher::Dense,
+            )
         })
     });
 }
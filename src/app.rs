@@ -4,6 +4,7 @@ use crate::{
         config,
         cross_world_communication::{SimToRenderReceiver, SimToRenderSender},
         frame_sync::FrameSync,
+        state_machine::{AppState, CurrentState},
     },
     prelude::*,
     render_world::{
@@ -13,7 +14,7 @@ use crate::{
     simulation_world::{
         input::{
             messages::{RawDeviceMessage, RawWindowMessage},
-            resources::DesiredCursorState,
+            resources::{DesiredCursorState, InputRecorder, InputReplay},
         },
         SimulationSchedule, SimulationWorldInterface,
     },
@@ -22,6 +23,7 @@ use crossbeam::channel::unbounded;
 use futures_lite::future::block_on;
 use std::{
     error::Error,
+    path::PathBuf,
     sync::{Arc, Mutex},
     thread::{self, JoinHandle},
 };
@@ -32,6 +34,42 @@ use winit::{
     window::{Window, WindowId},
 };
 
+/// Where the input of a session comes from, and whether it gets recorded.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum InputSession {
+    /// Plain live input from winit.
+    #[default]
+    Live,
+    /// Live input, recorded to the file when the window closes.
+    Record(PathBuf),
+    /// Input played back from a recording, live input takes over once it ends.
+    Replay(PathBuf),
+}
+
+impl InputSession {
+    /// Parses `--record <file>` or `--replay <file>` from command line arguments.
+    ///
+    /// Unrelated arguments are ignored.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut session = Self::Live;
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--record" => {
+                    session = Self::Record(args.next().ok_or("--record needs a value")?.into());
+                }
+                "--replay" => {
+                    session = Self::Replay(args.next().ok_or("--replay needs a value")?.into());
+                }
+                _ => {}
+            }
+        }
+
+        Ok(session)
+    }
+}
+
 /// The main application container, responsible for orchestrating OS
 /// events as well as the creation and scheduling of the ECS worlds.
 pub struct App {
@@ -47,6 +85,8 @@ pub struct App {
     render_world: Option<Arc<Mutex<RenderWorldInterface>>>,
     /// A loading tracker necessary to orchestrate async tasks between both worlds.
     loading_tracker: LoadingTracker,
+    /// Where input comes from this session.
+    input_session: InputSession,
 
     // World parallelization
     frame_sync: FrameSync,
@@ -54,12 +94,13 @@ pub struct App {
 }
 
 impl App {
-    fn new() -> Self {
+    fn new(input_session: InputSession) -> Self {
         Self {
             window: None,
             simulation_world: None,
             render_world: None,
             loading_tracker: LoadingTracker::default(),
+            input_session,
             frame_sync: FrameSync::new(),
             render_thread: None,
         }
@@ -67,9 +108,14 @@ impl App {
 
     /// Simple utility method to spin up an event loop and run a default app
     pub fn create_and_run() -> Result<(), Box<dyn Error>> {
+        Self::create_and_run_with(InputSession::Live)
+    }
+
+    /// Like `create_and_run`, but recording or replaying input.
+    pub fn create_and_run_with(input_session: InputSession) -> Result<(), Box<dyn Error>> {
         let event_loop = EventLoop::new()?;
 
-        let mut app = App::new();
+        let mut app = App::new(input_session);
 
         event_loop.run_app(&mut app)?;
         Ok(())
@@ -114,6 +160,18 @@ impl ApplicationHandler for App {
             simulation_world.add_resource(app_config.clone());
             render_world.add_resource(app_config);
 
+            // set up input recording or replay
+            match &self.input_session {
+                InputSession::Live => {}
+                InputSession::Record(path) => {
+                    simulation_world.add_resource(InputRecorder::new(path));
+                }
+                InputSession::Replay(path) => match InputReplay::load(path) {
+                    Ok(replay) => simulation_world.add_resource(replay),
+                    Err(e) => error!("Failed to load input replay {}: {}", path.display(), e),
+                },
+            }
+
             // add loading trackers
             simulation_world.add_resource(self.loading_tracker.clone());
             render_world.add_resource(self.loading_tracker.clone());
@@ -214,6 +272,7 @@ impl ApplicationHandler for App {
             match event {
                 WindowEvent::CloseRequested => {
                     info!("Window close requested, exiting app event loop.");
+                    save_input_recording(&simulation_world.lock().unwrap());
                     event_loop.exit();
                 }
                 WindowEvent::RedrawRequested => {
@@ -243,6 +302,19 @@ impl ApplicationHandler for App {
                         }
                         self.frame_sync.finish_simulation();
 
+                        // the simulation asked to quit (eg from the main menu)
+                        let shutting_down = simulation_world
+                            .lock()
+                            .unwrap()
+                            .get_resource::<CurrentState<AppState>>()
+                            .is_some_and(|state| state.val == AppState::ShuttingDown);
+                        if shutting_down {
+                            info!("Simulation is shutting down, exiting app event loop.");
+                            save_input_recording(&simulation_world.lock().unwrap());
+                            event_loop.exit();
+                            return;
+                        }
+
                         // request the next frame
                         if let Some(window) = &self.window {
                             window.request_redraw();
@@ -258,3 +330,13 @@ impl ApplicationHandler for App {
         }
     }
 }
+
+/// Writes the input recording to its file, if the session is being recorded.
+fn save_input_recording(simulation_world: &SimulationWorldInterface) {
+    if let Some(recorder) = simulation_world.get_resource::<InputRecorder>() {
+        match recorder.save() {
+            Ok(()) => info!("Saved {} recorded input frames.", recorder.recording.len()),
+            Err(e) => error!("Failed to save input recording: {}", e),
+        }
+    }
+}
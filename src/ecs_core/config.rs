@@ -1,18 +1,210 @@
+use crate::simulation_world::chunk::RENDER_DISTANCE;
 use bevy_ecs::prelude::Resource;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+use tracing::warn;
 
-#[derive(Debug, Deserialize, Resource, Clone)]
+/// Where the app's settings are read from and written back to.
+pub const CONFIG_PATH: &str = "config.ron";
+
+/// The field of view the camera starts at, in degrees.
+pub const DEFAULT_FOV: f32 = 45.0;
+
+// INFO: ---------------
+//         error
+// ---------------------
+
+#[derive(Debug)]
+pub enum ConfigError {
+    IoError(io::Error),
+    ParseError(ron::error::SpannedError),
+    SerializeError(ron::Error),
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(err: io::Error) -> Self {
+        ConfigError::IoError(err)
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::IoError(err) => write!(f, "Config IO error: {err}"),
+            ConfigError::ParseError(err) => write!(f, "Invalid config: {err}"),
+            ConfigError::SerializeError(err) => write!(f, "Failed to write config: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+// INFO: ------------------
+//         settings
+// ------------------------
+
+/// A single user facing setting in `AppConfig`, used to adjust it from the settings screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setting {
+    RenderDistance,
+    Fov,
+    MouseSensitivity,
+    Vsync,
+    UiScale,
+    Shadows,
+}
+
+impl Setting {
+    pub const ALL: [Setting; 6] = [
+        Setting::RenderDistance,
+        Setting::Fov,
+        Setting::MouseSensitivity,
+        Setting::Vsync,
+        Setting::UiScale,
+        Setting::Shadows,
+    ];
+
+    /// Whether the setting is an on/off toggle rather than a value that steps up and down.
+    pub fn is_toggle(&self) -> bool {
+        matches!(self, Setting::Vsync | Setting::Shadows)
+    }
+}
+
+// INFO: ----------------
+//         config
+// ----------------------
+
+/// The app's settings, loaded from `config.ron`.
+///
+/// Any field missing from the file takes its default, so older config files keep working.
+#[derive(Debug, Serialize, Deserialize, Resource, Clone, PartialEq)]
+#[serde(default)]
 pub struct AppConfig {
     pub texture_pack: String,
+    /// The distance in chunks to mesh around the camera.
+    pub render_distance: i32,
+    /// The camera's field of view in degrees, the widest it can zoom out to.
+    pub fov: f32,
+    /// A multiplier on mouse look.
+    pub mouse_sensitivity: f32,
+    /// Whether presenting waits for the display's refresh.
+    pub vsync: bool,
+    /// A multiplier on the size of every UI element.
+    pub ui_scale: f32,
+    pub shadows: bool,
+    /// Where changes made at runtime are written back to, if anywhere.
+    #[serde(skip)]
+    save_path: Option<PathBuf>,
 }
 
-pub fn load_config() -> AppConfig {
-    let config_path = "config.ron";
-    let config_file = std::fs::read_to_string(config_path)
-        .unwrap_or_else(|_| panic!("Failed to read config file at {}", config_path));
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            texture_pack: "rhinestone".to_string(),
+            render_distance: RENDER_DISTANCE,
+            fov: DEFAULT_FOV,
+            mouse_sensitivity: 1.0,
+            vsync: false,
+            ui_scale: 1.0,
+            shadows: true,
+            save_path: None,
+        }
+    }
+}
+
+impl AppConfig {
+    const RENDER_DISTANCE_RANGE: (i32, i32) = (2, 32);
+    const FOV_RANGE: (f32, f32) = (30.0, 110.0);
+    const MOUSE_SENSITIVITY_RANGE: (f32, f32) = (0.1, 5.0);
+    const UI_SCALE_RANGE: (f32, f32) = (0.5, 2.0);
+
+    pub fn from_ron_str(ron_string: &str) -> Result<Self, ConfigError> {
+        let config: AppConfig = ron::from_str(ron_string).map_err(ConfigError::ParseError)?;
+        Ok(config.clamped())
+    }
+
+    pub fn to_ron_string(&self) -> Result<String, ConfigError> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(ConfigError::SerializeError)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        Self::from_ron_str(&fs::read_to_string(path)?)
+    }
+
+    /// Writes the config to `path`, replacing any existing file atomically.
+    pub fn save(&self, path: &Path) -> Result<(), ConfigError> {
+        let tmp_path = path.with_extension("ron.tmp");
+        fs::write(&tmp_path, self.to_ron_string()?)?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Sets where changes made at runtime are written back to.
+    pub fn with_save_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.save_path = Some(path.into());
+        self
+    }
 
-    let config: AppConfig = ron::from_str(&config_file)
-        .unwrap_or_else(|e| panic!("Failed to parse config file: {}", e));
+    pub fn save_path(&self) -> Option<&Path> {
+        self.save_path.as_deref()
+    }
+
+    /// Moves `setting` by `steps` increments, or flips it if it is a toggle.
+    pub fn adjust(&mut self, setting: Setting, steps: i32) {
+        match setting {
+            Setting::RenderDistance => self.render_distance += steps,
+            Setting::Fov => self.fov += 5.0 * steps as f32,
+            Setting::MouseSensitivity => self.mouse_sensitivity += 0.1 * steps as f32,
+            Setting::UiScale => self.ui_scale += 0.25 * steps as f32,
+            Setting::Vsync => self.vsync = !self.vsync,
+            Setting::Shadows => self.shadows = !self.shadows,
+        }
+        *self = self.clone().clamped();
+    }
+
+    /// The config with every value pulled back into its valid range.
+    pub fn clamped(mut self) -> Self {
+        let clamp = |value: f32, (min, max): (f32, f32)| {
+            if value.is_finite() {
+                value.clamp(min, max)
+            } else {
+                min
+            }
+        };
+
+        let (min, max) = Self::RENDER_DISTANCE_RANGE;
+        self.render_distance = self.render_distance.clamp(min, max);
+        self.fov = clamp(self.fov, Self::FOV_RANGE);
+        self.mouse_sensitivity = clamp(self.mouse_sensitivity, Self::MOUSE_SENSITIVITY_RANGE);
+        self.ui_scale = clamp(self.ui_scale, Self::UI_SCALE_RANGE);
+
+        // keep stepped values tidy after repeated float additions
+        self.mouse_sensitivity = (self.mouse_sensitivity * 10.0).round() / 10.0;
+        self.ui_scale = (self.ui_scale * 4.0).round() / 4.0;
+        self
+    }
+}
+
+/// Loads `config.ron`, falling back to the defaults with a warning if it can't be read.
+pub fn load_config() -> AppConfig {
+    load_config_from(Path::new(CONFIG_PATH))
+}
 
-    config
+/// Loads the config at `path`, falling back to the defaults with a warning if it can't be read.
+///
+/// Either way, changes made at runtime are written back to `path`.
+pub fn load_config_from(path: &Path) -> AppConfig {
+    let config = AppConfig::load(path).unwrap_or_else(|e| {
+        warn!(
+            "Failed to load config from {}, using the defaults: {}",
+            path.display(),
+            e
+        );
+        AppConfig::default()
+    });
+    config.with_save_path(path)
 }
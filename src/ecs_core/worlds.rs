@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::simulation_world::console::{ConsoleCommand, ConsoleCommandRegistry};
 use bevy_ecs::{
     prelude::*,
     schedule::{Schedule, ScheduleLabel},
@@ -107,6 +108,14 @@ impl EcsBuilder {
         self
     }
 
+    /// Registers a command that can be run from the console or the headless runner
+    pub fn add_console_command(&mut self, command: ConsoleCommand) -> &mut Self {
+        self.world
+            .get_resource_or_init::<ConsoleCommandRegistry>()
+            .register(command);
+        self
+    }
+
     /// Gets the current builder entry for a schedule or creates it if it doesn't exist
     pub fn schedule_entry(&mut self, label: impl ScheduleLabel + Clone) -> &mut Schedule {
         self.schedules.entry(label)
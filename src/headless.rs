@@ -0,0 +1,546 @@
+use crate::{
+    ecs_core::{
+        async_loading::LoadingTracker,
+        config,
+        cross_world_communication::{SimToRenderReceiver, SimToRenderSender},
+        state_machine::{AppState, CurrentState},
+    },
+    prelude::*,
+    render_world::textures::load_voxel_texture_assets,
+    simulation_world::{
+        chunk::{ActiveChunkMesher, ChunkMesher, ChunkState, ChunkStateManager},
+        console::{run_console_command, ConsoleResult},
+        input::InputReplay,
+        mesh_export::{ChunkRange, LoadedChunkMeshes, MeshExportError, MeshExportSummary},
+        player::{ActiveCamera, CameraComponent},
+        time::{simulation_tick::SimulationTick, FrameClock},
+        world_save::{WorldSave, WorldSaveError},
+        SimulationSchedule, SimulationWorldInterface,
+    },
+};
+use bevy_ecs::system::SystemState;
+use crossbeam::channel::unbounded;
+use std::{
+    error::Error,
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// How long startup loading may take before a headless run gives up.
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How long to wait for in-flight chunk work after the scripted ticks.
+const SETTLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Default speed of the scripted camera in blocks per second.
+const DEFAULT_CAMERA_SPEED: f32 = 20.0;
+
+/// Default radius of the circular camera path in blocks.
+const DEFAULT_CIRCLE_RADIUS: f32 = 64.0;
+
+// INFO: ---------------------------
+//         Run configuration
+// ---------------------------------
+
+/// A scripted path for the camera to follow during a headless run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CameraPath {
+    /// Stays at the spawn point.
+    Stationary,
+    /// Flies in a straight line along +X.
+    Line { speed: f32 },
+    /// Circles around the spawn point.
+    Circle { radius: f32, speed: f32 },
+}
+
+impl CameraPath {
+    /// Offset from the spawn point after `seconds` of simulated time.
+    pub fn offset_at(&self, seconds: f32) -> Vec3 {
+        match *self {
+            CameraPath::Stationary => Vec3::ZERO,
+            CameraPath::Line { speed } => Vec3::X * speed * seconds,
+            CameraPath::Circle { radius, speed } => {
+                let angle = speed * seconds / radius;
+                Vec3::new(angle.cos() - 1.0, 0.0, angle.sin()) * radius
+            }
+        }
+    }
+}
+
+impl FromStr for CameraPath {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "still" | "stationary" => Ok(CameraPath::Stationary),
+            "line" => Ok(CameraPath::Line {
+                speed: DEFAULT_CAMERA_SPEED,
+            }),
+            "circle" => Ok(CameraPath::Circle {
+                radius: DEFAULT_CIRCLE_RADIUS,
+                speed: DEFAULT_CAMERA_SPEED,
+            }),
+            other => Err(format!(
+                "Unknown camera path '{other}' (expected still, line or circle)"
+            )),
+        }
+    }
+}
+
+/// Settings for a single headless run.
+#[derive(Debug, Clone)]
+pub struct HeadlessConfig {
+    /// How many simulation ticks to run after startup finishes.
+    pub ticks: u64,
+    /// The path the camera follows while ticking.
+    pub camera_path: CameraPath,
+    /// Whether to keep ticking (with the camera held still) until no chunks are
+    /// left generating or meshing.
+    ///
+    /// Chunk tasks run on worker threads in wall time, so a short run can end
+    /// long before the pipeline catches up with the camera.
+    pub settle: bool,
+    /// A world save (as written by `b-worldgen`) to load chunks from.
+    pub world: Option<PathBuf>,
+    /// A `.obj` or `.glb` file to export the loaded chunk meshes to at the end of the run.
+    pub export_mesh: Option<PathBuf>,
+    /// Which mesher builds chunk meshes.
+    pub mesher: ChunkMesher,
+    /// An input recording to play back instead of following `camera_path`.
+    ///
+    /// The run lasts as long as the recording, using its frame times instead of `ticks`.
+    pub replay: Option<PathBuf>,
+    /// Console commands (like `/tp 0 80 0`) to run once startup finishes, in order.
+    pub commands: Vec<String>,
+}
+
+impl Default for HeadlessConfig {
+    fn default() -> Self {
+        Self {
+            ticks: 600,
+            camera_path: CameraPath::Stationary,
+            settle: true,
+            world: None,
+            export_mesh: None,
+            mesher: ChunkMesher::default(),
+            replay: None,
+            commands: Vec::new(),
+        }
+    }
+}
+
+impl HeadlessConfig {
+    /// Parses `--ticks <n>`, `--path <still|line|circle>`, `--world <dir>`,
+    /// `--export-mesh <file>`, `--mesher <dense|binary|greedy>`, `--replay <file>`,
+    /// `--command <line>` (repeatable) and `--no-settle` from command line arguments.
+    ///
+    /// Unrelated arguments are ignored.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut config = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--ticks" => {
+                    let value = args.next().ok_or("--ticks needs a value")?;
+                    config.ticks = value
+                        .parse()
+                        .map_err(|e| format!("Invalid tick count '{value}': {e}"))?;
+                }
+                "--path" => {
+                    config.camera_path = args.next().ok_or("--path needs a value")?.parse()?;
+                }
+                "--world" => {
+                    config.world = Some(args.next().ok_or("--world needs a value")?.into());
+                }
+                "--export-mesh" => {
+                    config.export_mesh =
+                        Some(args.next().ok_or("--export-mesh needs a value")?.into());
+                }
+                "--mesher" => {
+                    config.mesher = args.next().ok_or("--mesher needs a value")?.parse()?;
+                }
+                "--replay" => {
+                    config.replay = Some(args.next().ok_or("--replay needs a value")?.into());
+                }
+                "--command" => {
+                    config
+                        .commands
+                        .push(args.next().ok_or("--command needs a value")?);
+                }
+                "--no-settle" => config.settle = false,
+                _ => {}
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+// INFO: ------------------
+//         Reporting
+// ------------------------
+
+/// How many chunks are in each loading state.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChunkCounts {
+    pub needs_generating: usize,
+    pub generating: usize,
+    pub data_ready: usize,
+    pub wants_meshing: usize,
+    pub meshing: usize,
+    pub loaded: usize,
+    pub loaded_empty: usize,
+}
+
+impl ChunkCounts {
+    pub fn from_manager(chunk_manager: &ChunkStateManager) -> Self {
+        let mut counts = Self::default();
+        for state in chunk_manager.chunk_states.values() {
+            match state {
+                ChunkState::NeedsGenerating { .. } => counts.needs_generating += 1,
+                ChunkState::Generating { .. } => counts.generating += 1,
+                ChunkState::DataReady { .. } => counts.data_ready += 1,
+                ChunkState::WantsMeshing { .. } => counts.wants_meshing += 1,
+                ChunkState::Meshing { .. } => counts.meshing += 1,
+                ChunkState::Loaded { entity: Some(_) } => counts.loaded += 1,
+                ChunkState::Loaded { entity: None } => counts.loaded_empty += 1,
+            }
+        }
+        counts
+    }
+
+    /// Chunks that are queued for or in the middle of generation or meshing.
+    pub fn pending(&self) -> usize {
+        self.needs_generating + self.generating + self.wants_meshing + self.meshing
+    }
+
+    pub fn total(&self) -> usize {
+        self.needs_generating
+            + self.generating
+            + self.data_ready
+            + self.wants_meshing
+            + self.meshing
+            + self.loaded
+            + self.loaded_empty
+    }
+}
+
+/// The results of a headless run.
+#[derive(Debug, Clone)]
+pub struct HeadlessReport {
+    /// Time spent waiting for startup loading to finish.
+    pub startup_time: Duration,
+    /// The number of simulation ticks that were run.
+    pub ticks: u64,
+    /// Wall time spent running the ticks.
+    pub tick_time: Duration,
+    /// Wall time of the slowest tick.
+    pub slowest_tick: Duration,
+    /// Extra ticks and wall time spent waiting for chunk work to finish, if settling.
+    pub settle: Option<(u64, Duration)>,
+    /// Chunk states at the end of the run.
+    pub chunks: ChunkCounts,
+    /// Where the loaded meshes were exported to and what was in them, if exporting.
+    pub mesh_export: Option<(PathBuf, MeshExportSummary)>,
+}
+
+impl fmt::Display for HeadlessReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let average = match self.ticks {
+            0 => Duration::ZERO,
+            ticks => self.tick_time.div_f64(ticks as f64),
+        };
+        let c = &self.chunks;
+
+        writeln!(f, "startup:       {:.2?}", self.startup_time)?;
+        writeln!(
+            f,
+            "ticks:         {} in {:.2?} (avg {:.2?}, slowest {:.2?})",
+            self.ticks, self.tick_time, average, self.slowest_tick
+        )?;
+        if let Some((ticks, time)) = self.settle {
+            writeln!(f, "settled:       after {ticks} more ticks ({time:.2?})")?;
+        }
+        writeln!(f, "chunks:        {} tracked", c.total())?;
+        writeln!(
+            f,
+            "  loaded:      {} ({} empty)",
+            c.loaded + c.loaded_empty,
+            c.loaded_empty
+        )?;
+        writeln!(
+            f,
+            "  generating:  {} ({} queued, {} data only)",
+            c.generating, c.needs_generating, c.data_ready
+        )?;
+        write!(
+            f,
+            "  meshing:     {} ({} queued)",
+            c.meshing, c.wants_meshing
+        )?;
+        if let Some((path, summary)) = &self.mesh_export {
+            write!(
+                f,
+                "\nexported:      {} faces from {} chunks to {}",
+                summary.faces,
+                summary.chunks,
+                path.display()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// INFO: ---------------------
+//         Headless app
+// ---------------------------
+
+/// Runs the simulation world without a window, GPU or render world.
+///
+/// Every frame advances the simulation by exactly one fixed tick, which keeps
+/// runs comparable between machines regardless of how fast they are.
+pub struct HeadlessApp {
+    pub simulation_world: SimulationWorldInterface,
+    /// Mesh deletions would normally go to the render world, here they are just dropped.
+    mesh_deletions: SimToRenderReceiver,
+    camera_start: Vec3,
+}
+
+impl HeadlessApp {
+    /// Builds the simulation world from the on-disk assets and runs its startup schedule.
+    pub fn new() -> Result<Self, Box<dyn Error>> {
+        let app_config = config::load_config();
+
+        // textures are only needed for their IDs, the images themselves are dropped
+        let (_, texture_registry) = load_voxel_texture_assets(&app_config)?;
+
+        let mut simulation_world = SimulationWorldInterface::new_headless(texture_registry);
+        simulation_world.add_resource(app_config);
+
+        Ok(Self::from_simulation_world(simulation_world))
+    }
+
+    /// Wraps an already built headless simulation world and runs its startup schedule.
+    pub fn from_simulation_world(mut simulation_world: SimulationWorldInterface) -> Self {
+        // there is no renderer to wait on
+        let loading_tracker = LoadingTracker::default();
+        loading_tracker.set_renderer_ready(true);
+        simulation_world.add_resource(loading_tracker);
+
+        let (sender, receiver) = unbounded();
+        simulation_world.add_resource(SimToRenderSender(sender));
+
+        info!("Running headless startup systems...");
+        simulation_world.run_schedule(SimulationSchedule::Startup);
+
+        // one fixed tick per frame
+        let tick_duration = simulation_world
+            .world
+            .resource::<SimulationTick>()
+            .tick_duration;
+        simulation_world
+            .world
+            .resource_mut::<FrameClock>()
+            .fixed_step = Some(tick_duration);
+
+        let camera_start = Self::camera_position(&mut simulation_world);
+
+        Self {
+            simulation_world,
+            mesh_deletions: SimToRenderReceiver(receiver),
+            camera_start,
+        }
+    }
+
+    /// Runs a full headless session as described by `config`.
+    pub fn run(config: &HeadlessConfig) -> Result<HeadlessReport, Box<dyn Error>> {
+        let mut app = Self::new()?;
+        app.simulation_world
+            .add_resource(ActiveChunkMesher(config.mesher));
+        if let Some(world) = &config.world {
+            app.load_world_save(world)?;
+        }
+        let replay = match &config.replay {
+            Some(path) => Some(InputReplay::load(path)?),
+            None => None,
+        };
+        let startup_time = app.wait_until_running(STARTUP_TIMEOUT)?;
+        info!("Headless startup finished in {:.2?}", startup_time);
+
+        for line in &config.commands {
+            let output = app.run_command(line)?;
+            if !output.is_empty() {
+                info!("{line}: {output}");
+            }
+        }
+        // scripted paths start from wherever the commands left the camera
+        app.camera_start = Self::camera_position(&mut app.simulation_world);
+
+        let mut ticks = 0;
+        let mut tick_time = Duration::ZERO;
+        let mut slowest_tick = Duration::ZERO;
+        let mut time_step = |app: &mut Self| {
+            let start = Instant::now();
+            app.step();
+            let elapsed = start.elapsed();
+
+            ticks += 1;
+            tick_time += elapsed;
+            slowest_tick = slowest_tick.max(elapsed);
+        };
+
+        match replay {
+            Some(replay) => {
+                info!("Replaying {} recorded frames...", replay.remaining());
+                app.simulation_world.add_resource(replay);
+                while app.is_replaying() {
+                    time_step(&mut app);
+                }
+            }
+            None => {
+                for _ in 0..config.ticks {
+                    app.follow_camera_path(config.camera_path);
+                    time_step(&mut app);
+                }
+            }
+        }
+
+        let settle = if config.settle {
+            Some(app.settle(SETTLE_TIMEOUT)?)
+        } else {
+            None
+        };
+
+        let mesh_export = match &config.export_mesh {
+            Some(path) => Some((path.clone(), app.export_meshes(path, None)?)),
+            None => None,
+        };
+
+        Ok(HeadlessReport {
+            startup_time,
+            ticks,
+            tick_time,
+            slowest_tick,
+            settle,
+            chunks: app.chunk_counts(),
+            mesh_export,
+        })
+    }
+
+    /// Loads chunks from a world save instead of generating them.
+    pub fn load_world_save(&mut self, dir: &Path) -> Result<(), WorldSaveError> {
+        WorldSave::load_into(dir, &mut self.simulation_world.world)
+    }
+
+    /// Writes the meshes of the loaded chunks in `range` (or all of them) to a `.obj` or `.glb`.
+    pub fn export_meshes(
+        &mut self,
+        path: &Path,
+        range: Option<ChunkRange>,
+    ) -> Result<MeshExportSummary, MeshExportError> {
+        let world = &mut self.simulation_world.world;
+        let mut state = SystemState::<LoadedChunkMeshes>::new(world);
+        state.get(world).export(path, range)
+    }
+
+    /// Keeps running frames until startup loading is done and the app is running.
+    pub fn wait_until_running(&mut self, timeout: Duration) -> Result<Duration, String> {
+        let start = Instant::now();
+
+        while !self.is_running() {
+            if start.elapsed() > timeout {
+                return Err(format!("Startup did not finish within {timeout:?}"));
+            }
+
+            self.step();
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        Ok(start.elapsed())
+    }
+
+    /// Keeps ticking until no chunk is left generating or meshing.
+    ///
+    /// Returns how many ticks and how much wall time that took.
+    pub fn settle(&mut self, timeout: Duration) -> Result<(u64, Duration), String> {
+        let start = Instant::now();
+        let mut ticks = 0;
+
+        while self.chunk_counts().pending() > 0 {
+            if start.elapsed() > timeout {
+                return Err(format!(
+                    "Chunks were still pending after {timeout:?}: {:?}",
+                    self.chunk_counts()
+                ));
+            }
+
+            self.step();
+            ticks += 1;
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        Ok((ticks, start.elapsed()))
+    }
+
+    /// Runs a single frame of the main schedule (one fixed tick once running).
+    pub fn step(&mut self) {
+        self.simulation_world.run_schedule(SimulationSchedule::Main);
+        self.simulation_world.clear_trackers();
+
+        for _ in self.mesh_deletions.0.try_iter() {}
+    }
+
+    /// Runs a console command against the simulation world right away.
+    pub fn run_command(&mut self, line: &str) -> ConsoleResult {
+        run_console_command(&mut self.simulation_world.world, line)
+    }
+
+    /// Moves the camera to where `path` puts it at the current tick.
+    pub fn follow_camera_path(&mut self, path: CameraPath) {
+        let sim_tick = self.simulation_world.world.resource::<SimulationTick>();
+        let seconds = sim_tick.tick as f32 * sim_tick.tick_duration.as_secs_f32();
+        self.set_camera_position(self.camera_start + path.offset_at(seconds));
+    }
+
+    /// Teleports the active camera.
+    pub fn set_camera_position(&mut self, position: Vec3) {
+        let world = &mut self.simulation_world.world;
+        let camera_entity = world.resource::<ActiveCamera>().0;
+        if let Some(mut camera) = world.get_mut::<CameraComponent>(camera_entity) {
+            camera.position = position;
+        }
+    }
+
+    /// Whether an `InputReplay` is still playing.
+    pub fn is_replaying(&self) -> bool {
+        self.simulation_world
+            .get_resource::<InputReplay>()
+            .is_some()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.simulation_world
+            .get_resource::<CurrentState<AppState>>()
+            .is_some_and(|state| state.val == AppState::Running)
+    }
+
+    pub fn chunk_counts(&self) -> ChunkCounts {
+        self.simulation_world
+            .get_resource::<ChunkStateManager>()
+            .map(ChunkCounts::from_manager)
+            .unwrap_or_default()
+    }
+
+    fn camera_position(simulation_world: &mut SimulationWorldInterface) -> Vec3 {
+        let world = &mut simulation_world.world;
+        let camera_entity = world.resource::<ActiveCamera>().0;
+        world
+            .get::<CameraComponent>(camera_entity)
+            .map(|camera| camera.position)
+            .unwrap_or_default()
+    }
+}
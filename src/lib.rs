@@ -1,8 +1,12 @@
 pub mod app;
 pub mod ecs_core;
+pub mod headless;
+pub mod map_render;
 pub mod prelude;
 pub mod render_world;
 pub mod simulation_world;
+#[cfg(feature = "test-support")]
+pub mod test_support;
 pub mod utils;
 
 pub use prelude::*;
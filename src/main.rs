@@ -1,4 +1,5 @@
 use b::app;
+use b::headless::{HeadlessApp, HeadlessConfig};
 use b::prelude::*;
 use b::utils;
 
@@ -7,9 +8,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     utils::logger::attach_logger();
 
     info!("Logger attached...");
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--headless") {
+        let config = HeadlessConfig::from_args(args)?;
+        info!("Running headless for {} ticks...", config.ticks);
+
+        let report = HeadlessApp::run(&config)?;
+        println!("{report}");
+        return Ok(());
+    }
+
     info!("Running app...");
 
-    if let Err(e) = app::App::create_and_run() {
+    let input_session = app::InputSession::from_args(args)?;
+    if let Err(e) = app::App::create_and_run_with(input_session) {
         error!("App error: {}", e);
     } else {
         info!("App runner finished without errors!");
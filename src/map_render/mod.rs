@@ -0,0 +1,9 @@
+//! Renders top-down images of the world on the CPU, for reviewing terrain and biome layouts.
+
+pub mod palette;
+pub mod render;
+pub mod surface;
+
+pub use palette::BlockColorPalette;
+pub use render::{MapLayer, DEFAULT_OVERLAY_ALPHA};
+pub use surface::{MapArea, MapChunkSource, SurfaceColumn, SurfaceMap};
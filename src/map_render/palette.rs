@@ -0,0 +1,62 @@
+use crate::simulation_world::block::{BlockId, BlockRegistryResource, AIR_BLOCK_ID};
+use image::RgbaImage;
+
+/// The color of every block as seen from above.
+pub struct BlockColorPalette {
+    /// RGB color indexed by block ID.
+    colors: Vec<[u8; 3]>,
+}
+
+impl BlockColorPalette {
+    /// Builds the palette from the average color of each block's top texture.
+    ///
+    /// `texture_images` are indexed by `TextureId`, as loaded by `load_voxel_texture_assets`.
+    pub fn from_textures(
+        block_registry: &BlockRegistryResource,
+        texture_images: &[RgbaImage],
+    ) -> Self {
+        let colors = block_registry
+            .get_texture_lut()
+            .iter()
+            .enumerate()
+            .map(|(id, faces)| {
+                if id as BlockId == AIR_BLOCK_ID {
+                    return [0, 0, 0];
+                }
+                texture_images
+                    .get(faces[0] as usize)
+                    .map(average_color)
+                    .unwrap_or([255, 0, 255])
+            })
+            .collect();
+
+        Self { colors }
+    }
+
+    /// The color of a block, magenta if the block is unknown.
+    pub fn color(&self, block_id: BlockId) -> [u8; 3] {
+        self.colors
+            .get(block_id as usize)
+            .copied()
+            .unwrap_or([255, 0, 255])
+    }
+}
+
+/// The alpha weighted average color of an image.
+fn average_color(image: &RgbaImage) -> [u8; 3] {
+    let mut sum = [0u64; 3];
+    let mut weight = 0u64;
+
+    for pixel in image.pixels() {
+        let alpha = pixel[3] as u64;
+        for (channel, total) in sum.iter_mut().enumerate() {
+            *total += pixel[channel] as u64 * alpha;
+        }
+        weight += alpha;
+    }
+
+    if weight == 0 {
+        return [0, 0, 0];
+    }
+    sum.map(|total| (total / weight) as u8)
+}
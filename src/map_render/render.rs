@@ -0,0 +1,190 @@
+use crate::map_render::{BlockColorPalette, SurfaceColumn, SurfaceMap};
+use crate::simulation_world::{
+    biome::BiomeId,
+    chunk::{CHUNK_SIDE_LENGTH, WORLD_MAX_Y_CHUNK, WORLD_MIN_Y_CHUNK},
+};
+use image::{Rgba, RgbaImage};
+use std::{fmt, str::FromStr};
+
+/// How strongly overlays cover the surface colors by default.
+pub const DEFAULT_OVERLAY_ALPHA: f32 = 0.6;
+
+/// Color of columns without any blocks.
+const VOID_COLOR: [u8; 3] = [0, 0, 0];
+
+/// What is drawn on top of the shaded surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapLayer {
+    /// Just the shaded surface blocks.
+    Surface,
+    /// A distinct color per biome ID.
+    Biome,
+    Temperature,
+    Precipitation,
+    Continentalness,
+    Erosion,
+    Weirdness,
+}
+
+impl MapLayer {
+    pub const ALL: [MapLayer; 7] = [
+        MapLayer::Surface,
+        MapLayer::Biome,
+        MapLayer::Temperature,
+        MapLayer::Precipitation,
+        MapLayer::Continentalness,
+        MapLayer::Erosion,
+        MapLayer::Weirdness,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MapLayer::Surface => "surface",
+            MapLayer::Biome => "biome",
+            MapLayer::Temperature => "temperature",
+            MapLayer::Precipitation => "precipitation",
+            MapLayer::Continentalness => "continentalness",
+            MapLayer::Erosion => "erosion",
+            MapLayer::Weirdness => "weirdness",
+        }
+    }
+
+    /// The overlay color of a column, `None` for the plain surface.
+    fn overlay_color(&self, column: &SurfaceColumn) -> Option<[u8; 3]> {
+        let climate = &column.climate;
+        match self {
+            MapLayer::Surface => None,
+            MapLayer::Biome => Some(biome_color(column.biome)),
+            MapLayer::Temperature => Some(diverging_color(climate.temperature)),
+            MapLayer::Precipitation => Some(diverging_color(climate.precipitation)),
+            MapLayer::Continentalness => Some(diverging_color(climate.continentalness)),
+            MapLayer::Erosion => Some(diverging_color(climate.erosion)),
+            MapLayer::Weirdness => Some(diverging_color(climate.weirdness)),
+        }
+    }
+}
+
+impl fmt::Display for MapLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for MapLayer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MapLayer::ALL
+            .into_iter()
+            .find(|layer| layer.name() == s)
+            .ok_or_else(|| {
+                let names: Vec<_> = MapLayer::ALL.iter().map(|l| l.name()).collect();
+                format!(
+                    "Unknown map layer '{s}' (expected one of: {})",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+// INFO: -----------------
+//         rendering
+// -----------------------
+
+impl SurfaceMap {
+    /// Renders the map with one pixel per block column, +X to the right and +Z down.
+    ///
+    /// Surface colors are shaded by height and slope, overlays are blended on top with
+    /// `overlay_alpha`.
+    pub fn render(
+        &self,
+        layer: MapLayer,
+        palette: &BlockColorPalette,
+        overlay_alpha: f32,
+    ) -> RgbaImage {
+        let (width, depth) = (self.area.width(), self.area.depth());
+
+        RgbaImage::from_fn(width, depth, |x, z| {
+            let column = self.column(x, z);
+            let Some(height) = column.height else {
+                return opaque(VOID_COLOR);
+            };
+
+            // compare against the north-west neighbor for a simple hillshade
+            let neighbor_height = (x > 0 && z > 0)
+                .then(|| self.column(x - 1, z - 1).height)
+                .flatten()
+                .unwrap_or(height);
+
+            let shade = height_shade(height) + slope_shade(height - neighbor_height);
+            let surface = palette.color(column.block).map(|c| scale(c, shade));
+
+            match layer.overlay_color(column) {
+                Some(overlay) => opaque(blend(surface, overlay, overlay_alpha)),
+                None => opaque(surface),
+            }
+        })
+    }
+}
+
+/// Brightness from absolute height, darker towards the bottom of the world.
+fn height_shade(height: i32) -> f32 {
+    let bottom = (WORLD_MIN_Y_CHUNK * CHUNK_SIDE_LENGTH as i32) as f32;
+    let top = ((WORLD_MAX_Y_CHUNK + 1) * CHUNK_SIDE_LENGTH as i32) as f32;
+    let t = ((height as f32 - bottom) / (top - bottom)).clamp(0.0, 1.0);
+    0.6 + 0.5 * t
+}
+
+/// Brightness change for slopes facing towards (brighter) or away from the light.
+fn slope_shade(height_difference: i32) -> f32 {
+    height_difference.clamp(-4, 4) as f32 * 0.05
+}
+
+fn scale(channel: u8, factor: f32) -> u8 {
+    (channel as f32 * factor).round().clamp(0.0, 255.0) as u8
+}
+
+fn blend(base: [u8; 3], overlay: [u8; 3], alpha: f32) -> [u8; 3] {
+    let alpha = alpha.clamp(0.0, 1.0);
+    std::array::from_fn(|i| {
+        (base[i] as f32 * (1.0 - alpha) + overlay[i] as f32 * alpha).round() as u8
+    })
+}
+
+fn opaque([r, g, b]: [u8; 3]) -> Rgba<u8> {
+    Rgba([r, g, b, 255])
+}
+
+/// A stable, well spread color per biome ID (golden ratio hue steps).
+fn biome_color(biome: BiomeId) -> [u8; 3] {
+    let hue = (biome as f32 * 0.618_034).fract();
+    hsv_to_rgb(hue, 0.65, 0.95)
+}
+
+/// Maps a noise value in `[-1, 1]` to blue (low), white (zero) and red (high).
+fn diverging_color(value: f32) -> [u8; 3] {
+    let t = value.clamp(-1.0, 1.0);
+    let fade = |amount: f32| (255.0 * (1.0 - amount)).round() as u8;
+
+    if t < 0.0 {
+        [fade(-t), fade(-t * 0.6), 255]
+    } else {
+        [255, fade(t * 0.6), fade(t)]
+    }
+}
+
+fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> [u8; 3] {
+    let sector = hue * 6.0;
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = value - chroma;
+    [r, g, b].map(|c| ((c + m) * 255.0).round() as u8)
+}
@@ -0,0 +1,207 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    biome::{BiomeId, BiomeRegistryResource},
+    block::{BlockId, BlockRegistryResource, AIR_BLOCK_ID},
+    chunk::{
+        ChunkCoord, ChunkLod, ChunkView, GeneratedChunkComponentBundle, CHUNK_SIDE_LENGTH,
+        WORLD_MAX_Y_CHUNK, WORLD_MIN_Y_CHUNK,
+    },
+    terrain::{climate::ClimateData, TerrainPipeline},
+    world_save::WorldSave,
+};
+use rayon::prelude::*;
+use std::sync::Arc;
+
+/// A rectangle of block columns, with both corners inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapArea {
+    /// The smallest block (x, z) of the area.
+    pub min: IVec2,
+    /// The largest block (x, z) of the area.
+    pub max: IVec2,
+}
+
+impl MapArea {
+    /// The area between two corners, in any order.
+    pub fn from_corners(a: IVec2, b: IVec2) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        (self.max.x - self.min.x + 1) as u32
+    }
+
+    pub fn depth(&self) -> u32 {
+        (self.max.y - self.min.y + 1) as u32
+    }
+
+    /// The (x, z) positions of every chunk column overlapping the area.
+    fn chunk_columns(&self) -> Vec<IVec2> {
+        let size = CHUNK_SIDE_LENGTH as i32;
+        let min = self.min.div_euclid(IVec2::splat(size));
+        let max = self.max.div_euclid(IVec2::splat(size));
+
+        (min.x..=max.x)
+            .flat_map(|x| (min.y..=max.y).map(move |z| IVec2::new(x, z)))
+            .collect()
+    }
+}
+
+/// Where the map gets its chunks from.
+///
+/// Chunks missing from the world save (or all chunks, without one) are generated.
+#[derive(Clone)]
+pub struct MapChunkSource {
+    pub pipeline: TerrainPipeline,
+    pub block_registry: BlockRegistryResource,
+    pub biome_registry: BiomeRegistryResource,
+    pub world_save: Option<Arc<WorldSave>>,
+}
+
+impl MapChunkSource {
+    /// Loads or generates a chunk, returning `None` if it is empty.
+    pub fn load_chunk(&self, pos: IVec3) -> Option<GeneratedChunkComponentBundle> {
+        let saved = self.world_save.as_ref().and_then(|save| {
+            save.read_chunk(pos).unwrap_or_else(|e| {
+                warn!("Failed to read chunk {} from the world save: {}", pos, e);
+                None
+            })
+        });
+
+        match saved {
+            Some(saved) => Some(saved.into_bundle(ChunkLod(0))),
+            None => self.pipeline.generate_chunk(
+                ChunkCoord { pos },
+                ChunkLod(0),
+                &self.block_registry,
+                &self.biome_registry,
+            ),
+        }
+        .filter(|bundle| bundle.chunk_blocks.is_some())
+    }
+}
+
+/// What the map knows about a single block column.
+#[derive(Debug, Clone, Copy)]
+pub struct SurfaceColumn {
+    /// The world Y of the highest non-air block, `None` if the column is all air.
+    pub height: Option<i32>,
+    /// The highest non-air block.
+    pub block: BlockId,
+    /// The biome at the surface block.
+    pub biome: BiomeId,
+    pub climate: ClimateData,
+}
+
+/// The surface of every column in a `MapArea`.
+pub struct SurfaceMap {
+    pub area: MapArea,
+    /// Columns in row-major order, with rows along +X.
+    pub columns: Vec<SurfaceColumn>,
+}
+
+impl SurfaceMap {
+    /// Finds the surface of every column in `area`, loading chunk columns in parallel.
+    ///
+    /// Chunks are loaded top-down and only until every column has found its surface.
+    pub fn sample(area: MapArea, source: &MapChunkSource) -> Self {
+        let sampled: Vec<(IVec2, Vec<SurfaceColumn>)> = area
+            .chunk_columns()
+            .into_par_iter()
+            .map(|chunk_xz| (chunk_xz, sample_chunk_column(chunk_xz, source)))
+            .collect();
+
+        let size = CHUNK_SIDE_LENGTH as i32;
+        let width = area.width() as usize;
+        let mut columns = vec![
+            SurfaceColumn {
+                height: None,
+                block: AIR_BLOCK_ID,
+                biome: 0,
+                climate: ClimateData::default(),
+            };
+            width * area.depth() as usize
+        ];
+
+        for (chunk_xz, chunk_columns) in sampled {
+            for (local_index, column) in chunk_columns.into_iter().enumerate() {
+                let local = IVec2::new(local_index as i32 % size, local_index as i32 / size);
+                let world = chunk_xz * size + local;
+                if world.cmplt(area.min).any() || world.cmpgt(area.max).any() {
+                    continue;
+                }
+
+                let offset = world - area.min;
+                columns[offset.y as usize * width + offset.x as usize] = column;
+            }
+        }
+
+        Self { area, columns }
+    }
+
+    /// The column at a block (x, z) position relative to the area's minimum corner.
+    pub fn column(&self, x: u32, z: u32) -> &SurfaceColumn {
+        &self.columns[z as usize * self.area.width() as usize + x as usize]
+    }
+}
+
+/// Samples all columns of a single chunk column, indexed by `z * CHUNK_SIDE_LENGTH + x`.
+fn sample_chunk_column(chunk_xz: IVec2, source: &MapChunkSource) -> Vec<SurfaceColumn> {
+    let size = CHUNK_SIDE_LENGTH;
+    let climate_map = source.pipeline.climate.generate(ChunkCoord {
+        pos: IVec3::new(chunk_xz.x, 0, chunk_xz.y),
+    });
+
+    let mut columns: Vec<SurfaceColumn> = (0..size * size)
+        .map(|i| SurfaceColumn {
+            height: None,
+            block: AIR_BLOCK_ID,
+            biome: 0,
+            climate: climate_map.get_data_unchecked(i % size, i / size),
+        })
+        .collect();
+    let mut unresolved = size * size;
+
+    for chunk_y in (WORLD_MIN_Y_CHUNK..=WORLD_MAX_Y_CHUNK).rev() {
+        let pos = IVec3::new(chunk_xz.x, chunk_y, chunk_xz.y);
+        let Some(bundle) = source.load_chunk(pos) else {
+            continue;
+        };
+        let Some(chunk_blocks) = bundle.chunk_blocks else {
+            continue;
+        };
+
+        let view = chunk_blocks.get_view();
+        for (i, column) in columns.iter_mut().enumerate() {
+            if column.height.is_some() {
+                continue;
+            }
+            let (x, z) = (i % size, i / size);
+
+            let top = match view {
+                ChunkView::Uniform(AIR_BLOCK_ID) => None,
+                ChunkView::Uniform(block_id) => Some((size - 1, block_id)),
+                ChunkView::Dense(view) => (0..size)
+                    .rev()
+                    .map(|y| (y, view.get_data(x, y, z)))
+                    .find(|&(_, block_id)| block_id != AIR_BLOCK_ID),
+            };
+
+            if let Some((y, block_id)) = top {
+                column.height = Some(chunk_y * size as i32 + y as i32);
+                column.block = block_id;
+                column.biome = bundle.biome_map.get_data_unchecked(x, y, z);
+                unresolved -= 1;
+            }
+        }
+
+        if unresolved == 0 {
+            break;
+        }
+    }
+
+    columns
+}
@@ -14,7 +14,7 @@ use self::time_extractor::RenderTimeExtractor;
 use crate::{
     ecs_core::{
         state_machine::{AppState, GameState},
-        EcsBuilder, Plugin,
+        AppConfig, EcsBuilder, Plugin,
     },
     render_world::scheduling::RenderSchedule,
     simulation_world::{
@@ -44,6 +44,8 @@ impl Plugin for SimulationExtractionPlugin {
                         .run_if(simulation_world_resource_changed::<WindowSizeResource>),
                     // shared sim clone
                     clone_resource_system::<AssetStorageResource<MeshAsset>>,
+                    (clone_resource_system::<AppConfig>)
+                        .run_if(simulation_world_resource_changed::<AppConfig>),
                     // shared sim state extracting
                     extract_state_system::<GameState>,
                     extract_state_system::<AppState>,
@@ -4,14 +4,14 @@ pub mod resources;
 
 use bevy_ecs::prelude::*;
 pub use graphics_context::GraphicsContext;
-pub use reconfigure_surface::reconfigure_wgpu_surface_system;
+pub use reconfigure_surface::{apply_present_mode_system, reconfigure_wgpu_surface_system};
 
 // INFO: ---------------------------
 //         Plugin definition
 // ---------------------------------
 
 use crate::{
-    ecs_core::{EcsBuilder, Plugin},
+    ecs_core::{AppConfig, EcsBuilder, Plugin},
     render_world::{
         global_extract::RenderWindowSizeResource,
         graphics_context::resources::{
@@ -60,11 +60,12 @@ impl Plugin for GraphicsContextPlugin {
         //         Queue
         // ---------------------
 
-        builder.schedule_entry(RenderSchedule::Main).add_systems(
+        builder.schedule_entry(RenderSchedule::Main).add_systems((
             // this isn't in a set because it doesnt really matter when it runs due to the fact
             // that RenderWindowSizeResource gets updated from extraction, which runs before main.
             reconfigure_wgpu_surface_system
                 .run_if(resource_changed_or_removed::<RenderWindowSizeResource>),
-        );
+            apply_present_mode_system.run_if(resource_exists_and_changed::<AppConfig>),
+        ));
     }
 }
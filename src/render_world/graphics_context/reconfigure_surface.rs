@@ -1,7 +1,8 @@
+use crate::ecs_core::AppConfig;
 use crate::prelude::*;
 use crate::render_world::global_extract::RenderWindowSizeResource;
 use crate::render_world::graphics_context::resources::{
-    RenderDevice, RenderSurface, RenderSurfaceConfig,
+    RenderAdapter, RenderDevice, RenderSurface, RenderSurfaceConfig,
 };
 use bevy_ecs::prelude::*;
 
@@ -30,3 +31,34 @@ pub fn reconfigure_wgpu_surface_system(
         surface.configure(&device, &config);
     }
 }
+
+/// A system that picks the surface's present mode from the vsync setting and reconfigures
+/// the wgpu surface if it changed.
+#[instrument(skip_all)]
+pub fn apply_present_mode_system(
+    // Input
+    app_config: Res<AppConfig>,
+    adapter: Res<RenderAdapter>,
+
+    // Output
+    surface: Res<RenderSurface>,
+    device: Res<RenderDevice>,
+    mut config: ResMut<RenderSurfaceConfig>,
+) {
+    let present_mode = if app_config.vsync {
+        wgpu::PresentMode::AutoVsync
+    } else {
+        let supported = surface.get_capabilities(&adapter).present_modes;
+        if supported.contains(&wgpu::PresentMode::Immediate) {
+            wgpu::PresentMode::Immediate
+        } else {
+            wgpu::PresentMode::AutoNoVsync
+        }
+    };
+
+    if config.present_mode != present_mode {
+        info!("Switching the present mode to {:?}", present_mode);
+        config.present_mode = present_mode;
+        surface.configure(&device, &config);
+    }
+}
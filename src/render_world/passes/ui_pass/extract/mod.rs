@@ -1,6 +1,6 @@
 pub mod ui_events;
 
 pub use ui_events::{
-    extract_ui_events_system, ExtractedUiEvent, ExtractedUiEvents, RenderableUiElement,
-    UiElementKind,
+    extract_ui_events_system, ExtractedUiEvent, ExtractedUiEvents, PanelImage, PanelShape,
+    RenderableUiElement, UiElementKind,
 };
@@ -1,17 +1,42 @@
+use crate::ecs_core::AppConfig;
 use crate::prelude::*;
 use crate::render_world::global_extract::utils::run_extract_schedule::SimulationWorld;
-use crate::simulation_world::user_interface::components::{CalculatedLayout, Node, UiBackground};
+use crate::simulation_world::asset_management::asset_storage::AssetId;
+use crate::simulation_world::user_interface::components::{
+    CalculatedLayout, NineSlice, Node, Style, UiBackground, UvRect,
+};
 use crate::simulation_world::user_interface::{
     components::TextAlign, components::UiText, layout::compute_depth::UiDepth,
 };
 use bevy_ecs::prelude::*;
 
+/// The image a panel is drawn with, if it isn't a solid color.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PanelImage {
+    pub id: AssetId,
+    pub uv_rect: UvRect,
+    pub nine_slice: Option<NineSlice>,
+}
+
+/// The border and corners a panel is drawn with, in screen pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PanelShape {
+    /// The border's width on the left, right, top and bottom.
+    pub border: [f32; 4],
+    pub border_color: [f32; 4],
+    /// The radius of the top left, top right, bottom right and bottom left corners.
+    pub corner_radius: [f32; 4],
+}
+
 #[derive(Clone, Debug)]
 pub enum UiElementKind {
     Panel {
         position: Vec2,
         size: Vec2,
+        /// The solid color, or the tint of the image.
         color: [f32; 4],
+        image: Option<PanelImage>,
+        shape: PanelShape,
     },
     Text {
         content: String,
@@ -72,27 +97,67 @@ pub fn extract_ui_events_system(
             .push(ExtractedUiEvent::Remove(entity));
     }
 
-    // changed panel events
-    let mut panel_query = simulation_world
+    // changed panel events, corners are styled unscaled so they are scaled up here
+    let ui_scale = simulation_world
         .val
-        .query_filtered::<(Entity, &CalculatedLayout, &UiBackground, &UiDepth), Or<(
-            Changed<CalculatedLayout>,
-            Changed<UiBackground>,
-            Changed<UiDepth>,
-            Added<Node>,
-        )>>();
+        .get_resource::<AppConfig>()
+        .map_or(1.0, |config| config.ui_scale);
+    let mut panel_query = simulation_world.val.query_filtered::<(
+        Entity,
+        &CalculatedLayout,
+        &UiBackground,
+        &UiDepth,
+        Option<&Style>,
+    ), Or<(
+        Changed<CalculatedLayout>,
+        Changed<UiBackground>,
+        Changed<UiDepth>,
+        Changed<Style>,
+        Added<Node>,
+    )>>();
+
+    for (entity, layout, background, depth, style) in panel_query.iter(&simulation_world.val) {
+        let shape = style.map_or(PanelShape::default(), |style| {
+            let radius = style.corner_radius;
+            PanelShape {
+                border: [
+                    layout.border.left,
+                    layout.border.right,
+                    layout.border.top,
+                    layout.border.bottom,
+                ],
+                border_color: style.border_color,
+                corner_radius: [
+                    radius.top_left,
+                    radius.top_right,
+                    radius.bottom_right,
+                    radius.bottom_left,
+                ]
+                .map(|radius| radius * ui_scale),
+            }
+        });
 
-    for (entity, layout, background, depth) in panel_query.iter(&simulation_world.val) {
         let renderable_element = RenderableUiElement {
             entity_key: entity,
             sort_key: depth.0,
             kind: UiElementKind::Panel {
                 position: layout.position,
                 size: layout.size,
-                color: match background {
-                    UiBackground::SolidColor { color } => *color,
-                    UiBackground::Image { color } => *color,
+                color: background.color(),
+                image: match background {
+                    UiBackground::SolidColor { .. } => None,
+                    UiBackground::Image {
+                        image,
+                        uv_rect,
+                        nine_slice,
+                        ..
+                    } => Some(PanelImage {
+                        id: image.id(),
+                        uv_rect: *uv_rect,
+                        nine_slice: *nine_slice,
+                    }),
                 },
+                shape,
             },
         };
 
@@ -113,7 +178,7 @@ pub fn extract_ui_events_system(
             .push(ExtractedUiEvent::Remove(entity));
     }
 
-    // changed text events
+    // changed text events, text is laid out unscaled so it is scaled up here
     let mut text_query = simulation_world
         .val
         .query_filtered::<(Entity, &CalculatedLayout, &UiText, &UiDepth), Or<(
@@ -131,7 +196,7 @@ pub fn extract_ui_events_system(
                 content: text.content.clone(),
                 position: layout.position,
                 bounds: layout.size,
-                font_size: text.font_size,
+                font_size: text.font_size * ui_scale,
                 color: text.color,
                 align: match text.align {
                     TextAlign::Start => glyphon::cosmic_text::Align::Left,
@@ -2,6 +2,7 @@ pub mod material_binding;
 pub mod object_binding;
 pub mod screen_quad;
 pub mod setup_glyphon;
+pub mod texture_binding;
 pub mod ui_pipeline;
 pub mod view_binding;
 
@@ -9,5 +10,6 @@ pub use material_binding::*;
 pub use object_binding::*;
 pub use screen_quad::*;
 pub use setup_glyphon::*;
+pub use texture_binding::*;
 pub use ui_pipeline::*;
 pub use view_binding::*;
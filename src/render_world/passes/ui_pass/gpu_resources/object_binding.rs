@@ -10,6 +10,14 @@ use bytemuck::{Pod, Zeroable};
 #[derive(Clone, Copy, Pod, Zeroable)]
 pub struct UiObjectData {
     pub model_matrix: [f32; 16],
+    /// The uv of the quad's top left corner followed by the uv size it spans.
+    pub uv_rect: [f32; 4],
+    /// The screen position of the whole panel's top left corner followed by its size, which
+    /// the border and corners are drawn against when the panel is split into several quads.
+    pub panel_rect: [f32; 4],
+    pub border: [f32; 4],
+    pub border_color: [f32; 4],
+    pub corner_radius: [f32; 4],
 }
 
 // INFO: --------------------
@@ -26,10 +34,11 @@ impl FromWorld for UiObjectBindGroupLayout {
         let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             label: Some("UI Object Bind Group Layout"),
             entries: &[
-                // object uniform (model matrix defined above)
+                // object uniform (model matrix defined above), the fragment stage reads
+                // the panel's border and corners
                 wgpu::BindGroupLayoutEntry {
                     binding: 0,
-                    visibility: wgpu::ShaderStages::VERTEX,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
                     ty: wgpu::BindingType::Buffer {
                         ty: wgpu::BufferBindingType::Storage { read_only: true },
                         has_dynamic_offset: false,
@@ -53,6 +62,8 @@ pub struct UiObjectBuffer {
     pub buffer: wgpu::Buffer,
     pub bind_group: wgpu::BindGroup,
     pub objects: Vec<UiObjectData>,
+    /// How many objects fit in `buffer`.
+    capacity: usize,
 }
 
 impl FromWorld for UiObjectBuffer {
@@ -61,26 +72,59 @@ impl FromWorld for UiObjectBuffer {
         let layout = world.resource::<UiObjectBindGroupLayout>();
 
         let initial_capacity = 128;
-        let object_buffer = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("UI Object Buffer"),
-            size: (initial_capacity as u64) * std::mem::size_of::<UiObjectData>() as u64,
-            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
-            mapped_at_creation: false,
-        });
-
-        let object_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("UI Object Bind Group"),
-            layout: &layout.0,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: object_buffer.as_entire_binding(),
-            }],
-        });
+        let (buffer, bind_group) = create_object_buffer(device, layout, initial_capacity);
 
         Self {
-            buffer: object_buffer,
-            bind_group: object_bind_group,
+            buffer,
+            bind_group,
             objects: Vec::with_capacity(initial_capacity),
+            capacity: initial_capacity,
+        }
+    }
+}
+
+impl UiObjectBuffer {
+    /// Grows the GPU buffer, if needed, so every object in `objects` fits.
+    ///
+    /// Nine-slice panels take nine objects each, so the count isn't bounded by the
+    /// number of UI nodes alone.
+    pub fn reserve_gpu_capacity(
+        &mut self,
+        device: &RenderDevice,
+        layout: &UiObjectBindGroupLayout,
+    ) {
+        if self.objects.len() <= self.capacity {
+            return;
         }
+
+        let capacity = self.objects.len().next_power_of_two();
+        let (buffer, bind_group) = create_object_buffer(device, layout, capacity);
+        self.buffer = buffer;
+        self.bind_group = bind_group;
+        self.capacity = capacity;
     }
 }
+
+fn create_object_buffer(
+    device: &RenderDevice,
+    layout: &UiObjectBindGroupLayout,
+    capacity: usize,
+) -> (wgpu::Buffer, wgpu::BindGroup) {
+    let object_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("UI Object Buffer"),
+        size: (capacity as u64) * std::mem::size_of::<UiObjectData>() as u64,
+        usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+
+    let object_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("UI Object Bind Group"),
+        layout: &layout.0,
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: object_buffer.as_entire_binding(),
+        }],
+    });
+
+    (object_buffer, object_bind_group)
+}
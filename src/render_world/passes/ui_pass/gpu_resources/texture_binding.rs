@@ -0,0 +1,193 @@
+use crate::render_world::graphics_context::resources::{RenderDevice, RenderQueue};
+use crate::simulation_world::asset_management::{asset_storage::AssetId, ImageAsset};
+use bevy_ecs::prelude::*;
+use glam::UVec2;
+use std::collections::HashMap;
+
+// INFO: --------------------
+//         gpu layout
+// --------------------------
+
+#[derive(Resource)]
+pub struct UiTextureBindGroupLayout(pub wgpu::BindGroupLayout);
+
+impl FromWorld for UiTextureBindGroupLayout {
+    fn from_world(world: &mut World) -> Self {
+        let device = world.resource::<RenderDevice>();
+
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("UI Texture Bind Group Layout"),
+            entries: &[
+                // the panel's image
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                // and how it is sampled
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        Self(layout)
+    }
+}
+
+// INFO: ----------------------
+//         gpu textures
+// ----------------------------
+
+/// An image uploaded for the UI, bound and ready to draw panels with.
+pub struct UiGpuTexture {
+    pub size: UVec2,
+    pub bind_group: wgpu::BindGroup,
+    _texture: wgpu::Texture,
+}
+
+/// Every image the UI has drawn so far, uploaded once by asset id.
+///
+/// Solid color panels are drawn with a single white pixel so every panel goes through
+/// the same pipeline.
+#[derive(Resource)]
+pub struct UiTextures {
+    sampler: wgpu::Sampler,
+    white: UiGpuTexture,
+    textures: HashMap<AssetId, UiGpuTexture>,
+}
+
+impl FromWorld for UiTextures {
+    fn from_world(world: &mut World) -> Self {
+        let device = world.resource::<RenderDevice>();
+        let queue = world.resource::<RenderQueue>();
+        let layout = world.resource::<UiTextureBindGroupLayout>();
+
+        // nearest keeps pixel art borders crisp when they are scaled up
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("UI Texture Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let white = ImageAsset {
+            name: "ui_white".to_string(),
+            size: UVec2::ONE,
+            pixels: vec![255; 4],
+        };
+        let white = upload_texture(device, queue, &layout.0, &sampler, &white);
+
+        Self {
+            sampler,
+            white,
+            textures: HashMap::new(),
+        }
+    }
+}
+
+impl UiTextures {
+    /// The texture for an image, or the white pixel for solid colors and images that
+    /// haven't been uploaded.
+    pub fn get(&self, id: Option<AssetId>) -> &UiGpuTexture {
+        id.and_then(|id| self.textures.get(&id))
+            .unwrap_or(&self.white)
+    }
+
+    pub fn contains(&self, id: AssetId) -> bool {
+        self.textures.contains_key(&id)
+    }
+
+    /// The size in pixels of an uploaded image.
+    pub fn size(&self, id: AssetId) -> Option<UVec2> {
+        self.textures.get(&id).map(|texture| texture.size)
+    }
+
+    pub fn upload(
+        &mut self,
+        device: &RenderDevice,
+        queue: &RenderQueue,
+        layout: &UiTextureBindGroupLayout,
+        id: AssetId,
+        image: &ImageAsset,
+    ) {
+        let texture = upload_texture(device, queue, &layout.0, &self.sampler, image);
+        self.textures.insert(id, texture);
+    }
+}
+
+fn upload_texture(
+    device: &RenderDevice,
+    queue: &RenderQueue,
+    layout: &wgpu::BindGroupLayout,
+    sampler: &wgpu::Sampler,
+    image: &ImageAsset,
+) -> UiGpuTexture {
+    let size = wgpu::Extent3d {
+        width: image.size.x,
+        height: image.size.y,
+        depth_or_array_layers: 1,
+    };
+
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some(&image.name),
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8UnormSrgb,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+
+    queue.write_texture(
+        wgpu::TexelCopyTextureInfo {
+            texture: &texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        &image.pixels,
+        wgpu::TexelCopyBufferLayout {
+            offset: 0,
+            // each row has `width * 4` bytes for RGBA8
+            bytes_per_row: Some(4 * image.size.x),
+            rows_per_image: Some(image.size.y),
+        },
+        size,
+    );
+
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("UI Texture Bind Group"),
+        layout,
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: wgpu::BindingResource::Sampler(sampler),
+            },
+        ],
+    });
+
+    UiGpuTexture {
+        size: image.size,
+        bind_group,
+        _texture: texture,
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
         graphics_context::resources::{RenderDevice, RenderSurfaceConfig},
         passes::ui_pass::gpu_resources::{
             material_binding::UiMaterialBindGroupLayout, object_binding::UiObjectBindGroupLayout,
-            view_binding::UiViewBindGroupLayout,
+            texture_binding::UiTextureBindGroupLayout, view_binding::UiViewBindGroupLayout,
         },
     },
 };
@@ -25,6 +25,7 @@ impl FromWorld for UiPipeline {
         let view_layout = world.resource::<UiViewBindGroupLayout>();
         let material_layout = world.resource::<UiMaterialBindGroupLayout>();
         let object_layout = world.resource::<UiObjectBindGroupLayout>();
+        let texture_layout = world.resource::<UiTextureBindGroupLayout>();
 
         const UI_VERTEX_BUFFER_LAYOUT: wgpu::VertexBufferLayout = wgpu::VertexBufferLayout {
             array_stride: (2 * std::mem::size_of::<f32>()) as wgpu::BufferAddress, // only 2d points for ui
@@ -41,7 +42,12 @@ impl FromWorld for UiPipeline {
 
         let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("UI Pipeline Layout"),
-            bind_group_layouts: &[&view_layout.0, &material_layout.0, &object_layout.0],
+            bind_group_layouts: &[
+                &view_layout.0,
+                &material_layout.0,
+                &object_layout.0,
+                &texture_layout.0,
+            ],
             push_constant_ranges: &[],
         });
 
@@ -7,7 +7,7 @@ pub mod render;
 use gpu_resources::{
     view_binding::{UiViewBindGroupLayout, UiViewBuffer},
     ScreenQuadResource, UiMaterialBindGroupLayout, UiMaterialBuffer, UiObjectBindGroupLayout,
-    UiObjectBuffer, UiPipeline,
+    UiObjectBuffer, UiPipeline, UiTextureBindGroupLayout, UiTextures,
 };
 pub use render::UiRenderPassNode;
 
@@ -18,7 +18,7 @@ pub use render::UiRenderPassNode;
 use crate::{
     ecs_core::{EcsBuilder, Plugin},
     render_world::{
-        global_extract::resources::RenderWindowSizeResource,
+        global_extract::{clone_resource_system, resources::RenderWindowSizeResource},
         passes::ui_pass::{
             extract::ExtractedUiEvents,
             prepare::UiChanges,
@@ -28,6 +28,7 @@ use crate::{
         },
         scheduling::{RenderSchedule, RenderSet},
     },
+    simulation_world::asset_management::{AssetStorageResource, ImageAsset},
 };
 use bevy_ecs::prelude::*;
 
@@ -50,6 +51,9 @@ impl Plugin for UiRenderPassPlugin {
             // ui object uniform
             .init_resource::<UiObjectBindGroupLayout>()
             .init_resource::<UiObjectBuffer>()
+            // ui textures
+            .init_resource::<UiTextureBindGroupLayout>()
+            .init_resource::<UiTextures>()
             // pipeline
             .init_resource::<UiPipeline>();
 
@@ -68,7 +72,10 @@ impl Plugin for UiRenderPassPlugin {
             .add_resource(ExtractedUiEvents::default())
             // systems
             .schedule_entry(RenderSchedule::Extract)
-            .add_systems(extract::extract_ui_events_system);
+            .add_systems((
+                extract::extract_ui_events_system,
+                clone_resource_system::<AssetStorageResource<ImageAsset>>,
+            ));
 
         // INFO: -----------------
         //         prepare
@@ -90,7 +97,11 @@ impl Plugin for UiRenderPassPlugin {
                         prepare::prepare_glyphon_view_system,
                     )
                         .run_if(resource_changed::<RenderWindowSizeResource>),
-                    (prepare::process_ui_events_system,).chain(),
+                    (
+                        prepare::process_ui_events_system,
+                        prepare::prepare_ui_textures_system,
+                    )
+                        .chain(),
                 )
                     .in_set(RenderSet::Prepare),
             );
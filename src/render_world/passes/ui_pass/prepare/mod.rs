@@ -1,7 +1,9 @@
 mod prepare_glyphon_view;
+mod prepare_ui_textures;
 mod process_ui_events;
 mod update_view_data;
 
 pub use prepare_glyphon_view::prepare_glyphon_view_system;
+pub use prepare_ui_textures::prepare_ui_textures_system;
 pub use process_ui_events::{process_ui_events_system, UiChanges};
 pub use update_view_data::update_ui_view_data_system;
@@ -0,0 +1,52 @@
+use crate::prelude::*;
+use crate::render_world::{
+    graphics_context::resources::{RenderDevice, RenderQueue},
+    passes::ui_pass::{
+        extract::UiElementKind,
+        gpu_resources::{UiTextureBindGroupLayout, UiTextures},
+        prepare::UiChanges,
+        queue::UiElementCache,
+    },
+};
+use crate::simulation_world::asset_management::{AssetStorageResource, Handle, ImageAsset};
+use bevy_ecs::prelude::*;
+
+/// A system that uploads the images of image backed panels the first time they show up.
+///
+/// Uploading changes how many quads a nine-slice panel is drawn with, so it counts as a
+/// panel change for batching.
+#[instrument(skip_all)]
+pub fn prepare_ui_textures_system(
+    // Input
+    element_cache: Res<UiElementCache>,
+    images: Res<AssetStorageResource<ImageAsset>>,
+    device: Res<RenderDevice>,
+    queue: Res<RenderQueue>,
+    layout: Res<UiTextureBindGroupLayout>,
+
+    // Output
+    mut textures: ResMut<UiTextures>,
+    mut ui_changes: ResMut<UiChanges>,
+) {
+    for element in element_cache.elements.values() {
+        let UiElementKind::Panel {
+            image: Some(image), ..
+        } = &element.kind
+        else {
+            continue;
+        };
+        if textures.contains(image.id) {
+            continue;
+        }
+
+        let uploaded = images.with(Handle::new(image.id), |asset| {
+            debug!(target: "ui_efficiency", "Uploading UI image '{}'...", asset.name);
+            textures.upload(&device, &queue, &layout, image.id, asset);
+        });
+
+        match uploaded {
+            Some(()) => ui_changes.panel_content_change_occured = true,
+            None => warn!("UI image {} isn't in the image storage.", image.id),
+        }
+    }
+}
@@ -3,25 +3,34 @@ use std::collections::HashMap;
 use crate::{
     prelude::*,
     render_world::{
-        graphics_context::resources::RenderQueue,
+        graphics_context::resources::{RenderDevice, RenderQueue},
         passes::ui_pass::{
-            extract::{RenderableUiElement, UiElementKind},
-            gpu_resources::{UiMaterialBuffer, UiMaterialData, UiObjectBuffer, UiObjectData},
+            extract::{PanelImage, RenderableUiElement, UiElementKind},
+            gpu_resources::{
+                UiMaterialBuffer, UiMaterialData, UiObjectBindGroupLayout, UiObjectBuffer,
+                UiTextures,
+            },
             prepare::UiChanges,
+            queue::panel_quads::{panel_quads, UiQuad},
         },
     },
+    simulation_world::{
+        asset_management::asset_storage::AssetId, user_interface::components::UvRect,
+    },
 };
-use bevy_ecs::prelude::*;
+use bevy_ecs::{prelude::*, system::SystemParam};
 use derive_more::{Deref, DerefMut};
 
 // INFO: -------------------
 //         Resources
 // -------------------------
 
-/// A batch of panels sharing the same material
+/// A batch of panel quads sharing the same material and texture
 #[derive(Clone, Copy, Debug)]
 pub struct PanelBatch {
     pub material_index: u32,
+    /// The image the quads sample, or `None` for solid colors.
+    pub texture: Option<AssetId>,
     pub first_instance: u32,
     pub instance_count: u32,
 }
@@ -65,6 +74,17 @@ pub struct UiElementCache {
 #[derive(Resource, Default, Deref, DerefMut, PartialEq)]
 pub struct IsGlyphonDirty(pub bool);
 
+/// The GPU side of panel drawing that batching writes to.
+#[derive(SystemParam)]
+pub struct UiPanelGpuResources<'w> {
+    device: Res<'w, RenderDevice>,
+    queue: Res<'w, RenderQueue>,
+    object_layout: Res<'w, UiObjectBindGroupLayout>,
+    textures: Res<'w, UiTextures>,
+    material_buffer: ResMut<'w, UiMaterialBuffer>,
+    object_buffer: ResMut<'w, UiObjectBuffer>,
+}
+
 // INFO: -----------------
 //         Systems
 // -----------------------
@@ -72,16 +92,23 @@ pub struct IsGlyphonDirty(pub bool);
 #[instrument(skip_all)]
 pub fn rebuild_ui_batches_system(
     // Inputs
-    queue: Res<RenderQueue>,
     element_cache: Res<UiElementCache>,
     mut sort_buffer: ResMut<UiElementSortBufferResource>,
     ui_changes: Res<UiChanges>,
 
     // Outputs
-    mut material_buffer: ResMut<UiMaterialBuffer>,
-    mut object_buffer: ResMut<UiObjectBuffer>,
+    mut gpu: UiPanelGpuResources,
     mut prepared_batches: ResMut<PreparedUiBatches>,
 ) {
+    let UiPanelGpuResources {
+        device,
+        queue,
+        object_layout,
+        textures,
+        material_buffer,
+        object_buffer,
+    } = &mut gpu;
+
     if ui_changes.structural_change_occured || ui_changes.panel_content_change_occured {
         debug!(
             target: "ui_efficiency",
@@ -102,6 +129,8 @@ pub fn rebuild_ui_batches_system(
                 color,
                 position,
                 size,
+                image,
+                shape,
             } = &element.kind
             {
                 // add new materials if they haven't been seen yet
@@ -114,14 +143,15 @@ pub fn rebuild_ui_batches_system(
                     new_material_index
                 });
 
-                // add the panel's transform to the object buffer
-                let model_matrix = Mat4::from_translation(position.extend(0.0))
-                    * Mat4::from_scale(size.extend(1.0));
-                object_buffer.objects.push(UiObjectData {
-                    model_matrix: model_matrix.to_cols_array(),
-                });
+                // add the panel's quads to the object buffer
+                for quad in quads_for_panel(*position, *size, image, textures) {
+                    object_buffer
+                        .objects
+                        .push(quad.object_data(*position, *size, shape));
+                }
             }
         }
+        object_buffer.reserve_gpu_capacity(device, object_layout);
 
         // write to the GPU buffers
         for (i, material) in material_buffer.materials.iter().enumerate() {
@@ -154,37 +184,46 @@ pub fn rebuild_ui_batches_system(
         .collect();
 
     let mut current_panel_batch: Option<PanelBatch> = None;
-    let mut current_panel_material_color: Option<[u32; 4]> = None;
+    let mut current_panel_key: Option<([u32; 4], Option<AssetId>)> = None;
     let mut current_text_batch: Option<TextBatch> = None;
     let mut object_index_counter = 0;
 
     for item in sort_buffer.drain(..) {
         match &item.kind {
-            UiElementKind::Panel { color, .. } => {
+            UiElementKind::Panel {
+                color,
+                position,
+                size,
+                image,
+                ..
+            } => {
                 flush_text_batch(current_text_batch.take(), &mut prepared_batches.batches);
 
-                let color_key = color.map(|f| f.to_bits());
-                if current_panel_material_color == Some(color_key) && current_panel_batch.is_some()
-                {
-                    current_panel_batch.as_mut().unwrap().instance_count += 1;
+                // must match the quads pushed to the object buffer above
+                let quad_count = quads_for_panel(*position, *size, image, textures).len() as u32;
+                let texture = image.map(|image| image.id);
+                let key = (color.map(|f| f.to_bits()), texture);
+                if current_panel_key == Some(key) && current_panel_batch.is_some() {
+                    current_panel_batch.as_mut().unwrap().instance_count += quad_count;
                 } else {
                     flush_panel_batch(current_panel_batch.take(), &mut prepared_batches.batches);
 
                     let material_index = *material_map
-                        .get(&color_key)
+                        .get(&key.0)
                         .expect("Material should exist in map");
                     current_panel_batch = Some(PanelBatch {
                         material_index,
+                        texture,
                         first_instance: object_index_counter,
-                        instance_count: 1,
+                        instance_count: quad_count,
                     });
-                    current_panel_material_color = Some(color_key);
+                    current_panel_key = Some(key);
                 }
-                object_index_counter += 1;
+                object_index_counter += quad_count;
             }
             UiElementKind::Text { .. } => {
                 flush_panel_batch(current_panel_batch.take(), &mut prepared_batches.batches);
-                current_panel_material_color = None;
+                current_panel_key = None;
 
                 if current_text_batch.is_none() {
                     current_text_batch = Some(TextBatch::default());
@@ -202,6 +241,25 @@ pub fn rebuild_ui_batches_system(
     flush_text_batch(current_text_batch.take(), &mut prepared_batches.batches);
 }
 
+/// The quads a panel is drawn with, nine-sliced if its image is already uploaded.
+fn quads_for_panel(
+    position: Vec2,
+    size: Vec2,
+    image: &Option<PanelImage>,
+    textures: &UiTextures,
+) -> Vec<UiQuad> {
+    match image {
+        None => panel_quads(position, size, UvRect::FULL, None, None),
+        Some(image) => panel_quads(
+            position,
+            size,
+            image.uv_rect,
+            image.nine_slice,
+            textures.size(image.id).map(|size| size.as_vec2()),
+        ),
+    }
+}
+
 /// Flushes a panel batch into the list of render batches if it exists.
 fn flush_panel_batch(batch: Option<PanelBatch>, batches: &mut Vec<UiRenderBatch>) {
     if let Some(batch) = batch {
@@ -1,8 +1,10 @@
 mod batch_ui_elements;
+pub mod panel_quads;
 mod preprocess_glyphon_text;
 
 pub use batch_ui_elements::{
     rebuild_ui_batches_system, IsGlyphonDirty, PreparedUiBatches, TextBatch, UiElementCache,
     UiElementSortBufferResource, UiRenderBatch,
 };
+pub use panel_quads::{panel_quads, UiQuad};
 pub use preprocess_glyphon_text::{mark_glyphon_dirty_system, preprocess_glyphon_text_system};
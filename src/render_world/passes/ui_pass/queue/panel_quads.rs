@@ -0,0 +1,114 @@
+use crate::prelude::*;
+use crate::render_world::passes::ui_pass::{extract::PanelShape, gpu_resources::UiObjectData};
+use crate::simulation_world::user_interface::components::{NineSlice, UvRect};
+
+/// A single textured quad of a panel, in screen space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UiQuad {
+    pub position: Vec2,
+    pub size: Vec2,
+    pub uv_rect: UvRect,
+}
+
+impl UiQuad {
+    /// The quad's object data, drawn as part of the panel at `panel_position` and
+    /// `panel_size` with its border and corners.
+    pub fn object_data(
+        &self,
+        panel_position: Vec2,
+        panel_size: Vec2,
+        shape: &PanelShape,
+    ) -> UiObjectData {
+        let model_matrix = Mat4::from_translation(self.position.extend(0.0))
+            * Mat4::from_scale(self.size.extend(1.0));
+        let uv_size = self.uv_rect.size();
+
+        UiObjectData {
+            model_matrix: model_matrix.to_cols_array(),
+            uv_rect: [self.uv_rect.min.x, self.uv_rect.min.y, uv_size.x, uv_size.y],
+            panel_rect: [
+                panel_position.x,
+                panel_position.y,
+                panel_size.x,
+                panel_size.y,
+            ],
+            border: shape.border,
+            border_color: shape.border_color,
+            corner_radius: shape.corner_radius,
+        }
+    }
+}
+
+/// The quads a panel is drawn with.
+///
+/// Plain panels and stretched images are a single quad, nine-slice images are nine so
+/// their borders keep their size in pixels. Without a `texture_size` the image isn't
+/// uploaded yet and there is nothing to measure the borders against, so it stretches.
+pub fn panel_quads(
+    position: Vec2,
+    size: Vec2,
+    uv_rect: UvRect,
+    nine_slice: Option<NineSlice>,
+    texture_size: Option<Vec2>,
+) -> Vec<UiQuad> {
+    let (Some(slice), Some(texture_size)) = (nine_slice, texture_size) else {
+        return vec![UiQuad {
+            position,
+            size,
+            uv_rect,
+        }];
+    };
+
+    // borders shrink together when the panel is too small to fit them
+    let fit = |start: f32, end: f32, available: f32| {
+        let total = start + end;
+        if total > available && total > 0.0 {
+            let scale = available / total;
+            (start * scale, end * scale)
+        } else {
+            (start, end)
+        }
+    };
+    let (left, right) = fit(slice.left, slice.right, size.x);
+    let (top, bottom) = fit(slice.top, slice.bottom, size.y);
+
+    let xs = [
+        position.x,
+        position.x + left,
+        position.x + size.x - right,
+        position.x + size.x,
+    ];
+    let ys = [
+        position.y,
+        position.y + top,
+        position.y + size.y - bottom,
+        position.y + size.y,
+    ];
+    let us = [
+        uv_rect.min.x,
+        uv_rect.min.x + slice.left / texture_size.x,
+        uv_rect.max.x - slice.right / texture_size.x,
+        uv_rect.max.x,
+    ];
+    let vs = [
+        uv_rect.min.y,
+        uv_rect.min.y + slice.top / texture_size.y,
+        uv_rect.max.y - slice.bottom / texture_size.y,
+        uv_rect.max.y,
+    ];
+
+    let mut quads = Vec::with_capacity(9);
+    for row in 0..3 {
+        for column in 0..3 {
+            quads.push(UiQuad {
+                position: vec2(xs[column], ys[row]),
+                size: vec2(xs[column + 1] - xs[column], ys[row + 1] - ys[row]),
+                uv_rect: UvRect {
+                    min: vec2(us[column], vs[row]),
+                    max: vec2(us[column + 1], vs[row + 1]),
+                },
+            });
+        }
+    }
+    quads
+}
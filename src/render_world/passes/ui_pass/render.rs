@@ -5,7 +5,7 @@ use crate::{
         ui_pass::{
             gpu_resources::{
                 GlyphonAtlasResource, GlyphonRendererResource, GlyphonViewportResource,
-                UiMaterialBuffer, UiObjectBuffer, UiPipeline,
+                UiMaterialBuffer, UiObjectBuffer, UiPipeline, UiTextures,
             },
             queue::{PreparedUiBatches, UiRenderBatch},
         },
@@ -29,6 +29,7 @@ impl RenderNode for UiRenderPassNode {
         let view_bind_group = world.get_resource::<UiViewBuffer>().unwrap();
         let material_buffer = world.get_resource::<UiMaterialBuffer>().unwrap();
         let object_buffer = world.get_resource::<UiObjectBuffer>().unwrap();
+        let textures = world.get_resource::<UiTextures>().unwrap();
 
         let text_atlas = world.get_resource::<GlyphonAtlasResource>().unwrap();
         let glyphon_viewport = world.get_resource::<GlyphonViewportResource>().unwrap();
@@ -76,6 +77,11 @@ impl RenderNode for UiRenderPassNode {
 
                     let material_offset = panel_batch.material_index * material_buffer.stride;
                     render_pass.set_bind_group(1, &material_buffer.bind_group, &[material_offset]);
+                    render_pass.set_bind_group(
+                        3,
+                        &textures.get(panel_batch.texture).bind_group,
+                        &[],
+                    );
                     render_pass.draw_indexed(
                         0..quad.index_count,
                         0,
@@ -1,6 +1,6 @@
 use crate::prelude::*;
 use crate::render_world::graphics_context::resources::RenderDevice;
-use crate::render_world::types::PackedFace;
+use crate::render_world::types::{FaceExtent, PackedFace};
 use bevy_ecs::prelude::*;
 use offset_allocator::{Allocation, Allocator};
 
@@ -13,16 +13,35 @@ const INITIAL_PHYSICAL_SIZE: u64 = 1024 * 1024; // (1 MB)
 /// The max number of chunks for storing metadata
 const MAX_CHUNKS: u64 = 10_000;
 
+/// The `extent_index` of a mesh whose faces all cover a single block.
+pub const NO_EXTENTS: u32 = u32::MAX;
+
 // INFO: --------------------
 //         data types
 // --------------------------
 
 /// A representation of raw chunk data used in the chunk metadata storage buffer.
+///
+/// Padded to 32 bytes to match the WGSL struct's array stride.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct ChunkRenderData {
     pub world_pos: [f32; 3],
     pub start_index: u32,
+    /// Word index of the face extents, or `NO_EXTENTS` when the mesh wasn't greedy merged.
+    pub extent_index: u32,
+    pub _padding: [u32; 3],
+}
+
+impl ChunkRenderData {
+    pub fn new(world_pos: [f32; 3], start_index: u32, extent_index: u32) -> Self {
+        Self {
+            world_pos,
+            start_index,
+            extent_index,
+            _padding: [0; 3],
+        }
+    }
 }
 
 /// A voxel mesh handle holding the GPU allocation and index.
@@ -34,6 +53,8 @@ pub struct VoxelMesh {
     pub slot_index: u32,
     /// Number of faces to draw
     pub face_count: u32,
+    /// Word index of the face extents in the geometry buffer, or `NO_EXTENTS`
+    pub extent_index: u32,
 }
 
 // INFO: --------------------------
@@ -255,20 +276,27 @@ impl ChunkStorageManager {
     }
 
     /// Upload a chunk to the GPU buffer.
+    ///
+    /// Faces take 4 bytes each. Greedy meshes also pass one `FaceExtent` per face, which
+    /// are stored right after the faces two to a word (6 bytes a face in total).
     pub fn allocate_chunk(
         &mut self,
         device: &RenderDevice,
         queue: &wgpu::Queue,
         layout: &ChunkStorageBindGroupLayout,
         faces: &[PackedFace],
+        extents: &[FaceExtent],
         world_pos: [f32; 3],
     ) -> Option<VoxelMesh> {
         if faces.is_empty() {
             return None;
         }
+        debug_assert!(extents.is_empty() || extents.len() == faces.len());
 
-        // allocate faces (variable in size)
-        let size_bytes = (faces.len() * std::mem::size_of::<PackedFace>()) as u32;
+        // allocate faces and extents (variable in size), keeping the extents word aligned
+        let face_bytes = (faces.len() * std::mem::size_of::<PackedFace>()) as u32;
+        let extent_bytes = std::mem::size_of_val(extents).next_multiple_of(4) as u32;
+        let size_bytes = face_bytes + extent_bytes;
 
         // check if allocation offset is outside physical address space and resize if necessary
         let geometry_allocation = self.geometry_allocator.allocate(size_bytes)?;
@@ -291,12 +319,22 @@ impl ChunkStorageManager {
         );
 
         let start_index = geometry_allocation.offset / 4;
-
-        let meta_data = ChunkRenderData {
-            world_pos,
-            start_index,
+        let extent_index = if extents.is_empty() {
+            NO_EXTENTS
+        } else {
+            // pad odd counts so the write stays a multiple of 4 bytes
+            let mut packed = extents.to_vec();
+            packed.resize(packed.len().next_multiple_of(2), FaceExtent::UNIT);
+            queue.write_buffer(
+                &self.face_buffer,
+                (geometry_allocation.offset + face_bytes) as u64,
+                bytemuck::cast_slice(&packed),
+            );
+            start_index + faces.len() as u32
         };
 
+        let meta_data = ChunkRenderData::new(world_pos, start_index, extent_index);
+
         let meta_offset = slot_index as u64 * std::mem::size_of::<ChunkRenderData>() as u64;
         queue.write_buffer(
             &self.meta_buffer,
@@ -308,9 +346,28 @@ impl ChunkStorageManager {
             geometry_allocation,
             slot_index,
             face_count: faces.len() as u32,
+            extent_index,
         })
     }
 
+    /// Moves an already uploaded mesh to a new world position.
+    ///
+    /// Only the per-slot metadata is rewritten, the face data stays where it is.
+    pub fn update_world_pos(&self, queue: &wgpu::Queue, mesh: &VoxelMesh, world_pos: [f32; 3]) {
+        let meta_data = ChunkRenderData::new(
+            world_pos,
+            mesh.geometry_allocation.offset / 4,
+            mesh.extent_index,
+        );
+
+        let meta_offset = mesh.slot_index as u64 * std::mem::size_of::<ChunkRenderData>() as u64;
+        queue.write_buffer(
+            &self.meta_buffer,
+            meta_offset,
+            bytemuck::bytes_of(&meta_data),
+        );
+    }
+
     /// Frees all resources associated with the chunk handle.
     pub fn free_chunk(&mut self, mesh: VoxelMesh) {
         self.geometry_allocator.free(mesh.geometry_allocation);
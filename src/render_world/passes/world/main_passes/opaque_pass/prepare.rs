@@ -24,7 +24,7 @@ pub fn prepare_opaque_meshes_system(
     chunk_storage_layout: Res<ChunkStorageBindGroupLayout>,
     cpu_mesh_assets: Res<AssetStorageResource<MeshAsset>>,
     mut chunk_memory_manager: ResMut<ChunkStorageManager>,
-    meshes_to_prepare: Query<(&OpaqueRenderMeshComponent, &RenderTransformComponent)>,
+    meshes_to_prepare: Query<(&OpaqueRenderMeshComponent, Ref<RenderTransformComponent>)>,
 
     // Output (storage insertion)
     mut gpu_mesh_storage: ResMut<RenderMeshStorageResource>,
@@ -44,6 +44,7 @@ pub fn prepare_opaque_meshes_system(
                     &queue,
                     &chunk_storage_layout,
                     &mesh_asset.faces,
+                    &mesh_asset.extents,
                     world_pos,
                 ) {
                     debug!(
@@ -62,6 +63,12 @@ pub fn prepare_opaque_meshes_system(
                     handle.id()
                 );
             }
+        } else if transform.is_changed() {
+            // meshes that move after upload (eg falling blocks) need their position refreshed
+            if let Some(gpu_mesh) = gpu_mesh_storage.meshes.get(&handle.id()) {
+                let world_pos = transform.transform.w_axis.truncate().to_array();
+                chunk_memory_manager.update_world_pos(&queue, gpu_mesh, world_pos);
+            }
         }
     }
 }
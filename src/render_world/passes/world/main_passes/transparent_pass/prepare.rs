@@ -25,7 +25,10 @@ pub fn prepare_transparent_meshes_system(
     chunk_storage_layout: Res<ChunkStorageBindGroupLayout>,
     cpu_mesh_assets: Res<AssetStorageResource<MeshAsset>>,
     mut chunk_memory_manager: ResMut<ChunkStorageManager>,
-    meshes_to_prepare: Query<(&TransparentRenderMeshComponent, &RenderTransformComponent)>,
+    meshes_to_prepare: Query<(
+        &TransparentRenderMeshComponent,
+        Ref<RenderTransformComponent>,
+    )>,
 
     // Output (storage insertion)
     mut gpu_mesh_storage: ResMut<RenderMeshStorageResource>,
@@ -45,6 +48,7 @@ pub fn prepare_transparent_meshes_system(
                     &queue,
                     &chunk_storage_layout,
                     &mesh_asset.faces,
+                    &mesh_asset.extents,
                     world_pos,
                 ) {
                     debug!(
@@ -63,6 +67,12 @@ pub fn prepare_transparent_meshes_system(
                     handle.id()
                 );
             }
+        } else if transform.is_changed() {
+            // meshes that move after upload (eg falling blocks) need their position refreshed
+            if let Some(gpu_mesh) = gpu_mesh_storage.meshes.get(&handle.id()) {
+                let world_pos = transform.transform.w_axis.truncate().to_array();
+                chunk_memory_manager.update_world_pos(&queue, gpu_mesh, world_pos);
+            }
         }
     }
 }
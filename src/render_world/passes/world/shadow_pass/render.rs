@@ -1,3 +1,4 @@
+use crate::ecs_core::AppConfig;
 use crate::prelude::*;
 use crate::render_world::passes::world::gpu_resources::world_uniforms::ChunkStorageManager;
 use crate::render_world::{
@@ -74,6 +75,14 @@ impl RenderNode for ShadowRenderPassNode {
                     occlusion_query_set: None,
                 });
 
+        // with shadows turned off the map is only cleared, so nothing is ever in shadow
+        if world
+            .get_resource::<AppConfig>()
+            .is_some_and(|config| !config.shadows)
+        {
+            return;
+        }
+
         // INFO: -------------------------------------------
         //         shadow pipeline: iterate and draw
         // -------------------------------------------------
@@ -1,5 +1,7 @@
 mod packed_face;
+mod unpacked_face;
 mod wireframe_vertex;
 
-pub use packed_face::{upload_voxel_mesh, PackedFace};
+pub use packed_face::{upload_voxel_mesh, FaceExtent, PackedFace};
+pub use unpacked_face::{FaceVertex, UnpackedFace, QUAD_TRIANGLES};
 pub use wireframe_vertex::WireframeVertex;
@@ -5,17 +5,75 @@ use crate::{
             gpu_resources::world_uniforms::ChunkStorageBindGroupLayout, ChunkStorageManager,
             VoxelMesh,
         },
+        types::UnpackedFace,
     },
     simulation_world::chunk::meshing::common::{AoLevel, FaceSide},
 };
+use glam::UVec3;
 use wgpu::Queue;
 
 /// A struct representing a single voxel face in the world
+///
+/// A face covers a single block, the greedy mesher stores the size of its merged
+/// faces separately (see `FaceExtent`) so every other mesh stays at 4 bytes a face.
 #[repr(transparent)]
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, bytemuck::Pod, bytemuck::Zeroable, Hash,
 )]
-pub struct PackedFace(pub u32);
+pub struct PackedFace {
+    /// Position, AO (or surface drop), normal and texture bits.
+    pub data: u32,
+}
+
+/// The width and height of a greedy merged face in blocks, each stored minus one.
+///
+/// Only meshes with merged faces carry these, two to a word on the GPU.
+#[repr(transparent)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    bytemuck::Pod,
+    bytemuck::Zeroable,
+    Hash,
+)]
+pub struct FaceExtent(pub u16);
+
+impl FaceExtent {
+    const MASK: u16 = 0b11111; // 5 bits (1-32, stored minus one)
+    const HEIGHT_SHIFT: u16 = 5;
+
+    /// A face covering a single block.
+    pub const UNIT: FaceExtent = FaceExtent(0);
+
+    /// The largest width or height a face can have.
+    pub const MAX: u32 = Self::MASK as u32 + 1;
+
+    /// Which axes `width` and `height` run along depends on the normal, see
+    /// `FaceSide::extent_axes`.
+    #[inline(always)]
+    pub fn new(width: u32, height: u32) -> Self {
+        Self(
+            ((width - 1) as u16 & Self::MASK)
+                | (((height - 1) as u16 & Self::MASK) << Self::HEIGHT_SHIFT),
+        )
+    }
+
+    #[inline(always)]
+    pub fn width(self) -> u32 {
+        (self.0 & Self::MASK) as u32 + 1
+    }
+
+    #[inline(always)]
+    pub fn height(self) -> u32 {
+        ((self.0 >> Self::HEIGHT_SHIFT) & Self::MASK) as u32 + 1
+    }
+}
 
 impl PackedFace {
     // mask sizes for each category
@@ -23,6 +81,7 @@ impl PackedFace {
     const AO_MASK: u32 = 0b11; // 2 bits (0-3)
     const NORM_MASK: u32 = 0b111; // 3 bits (0-7)
     const TEX_MASK: u32 = 0b111111; // 6 bits (0-63)
+    const DROP_MASK: u32 = 0b111; // 3 bits (0-7)
 
     // shift distances
     const Y_SHIFT: u32 = 5;
@@ -60,18 +119,95 @@ impl PackedFace {
         // 6 tid bits 😳
         packed |= (texture_id & Self::TEX_MASK) << Self::TEX_SHIFT;
 
-        PackedFace(packed)
+        PackedFace { data: packed }
+    }
+
+    /// Packs a transparent face into a `PackedFace`.
+    ///
+    /// The transparent pass doesn't shade with AO, so the AO bits are instead used to
+    /// store how far the top edge of the face sits below a full block (in eighths).
+    /// This is what lets fluids render at partial heights.
+    #[inline(always)]
+    pub fn new_transparent(
+        x: u32,
+        y: u32,
+        z: u32,
+        normal: FaceSide,
+        surface_drop: u8,
+        texture_id: u32,
+    ) -> Self {
+        let mut packed = 0u32;
+
+        packed |= x & Self::POS_MASK;
+        packed |= (y & Self::POS_MASK) << Self::Y_SHIFT;
+        packed |= (z & Self::POS_MASK) << Self::Z_SHIFT;
+
+        // 3 surface drop bits in place of AO
+        packed |= (surface_drop as u32 & Self::DROP_MASK) << Self::AO_SHIFT;
+
+        packed |= (normal as u32 & Self::NORM_MASK) << Self::NORM_SHIFT;
+        packed |= (texture_id & Self::TEX_MASK) << Self::TEX_SHIFT;
+
+        PackedFace { data: packed }
+    }
+
+    /// Moves the face to another block position, keeping everything else.
+    #[inline(always)]
+    pub fn with_position(mut self, x: u32, y: u32, z: u32) -> Self {
+        let position_bits =
+            Self::POS_MASK | (Self::POS_MASK << Self::Y_SHIFT) | (Self::POS_MASK << Self::Z_SHIFT);
+        self.data = (self.data & !position_bits)
+            | (x & Self::POS_MASK)
+            | ((y & Self::POS_MASK) << Self::Y_SHIFT)
+            | ((z & Self::POS_MASK) << Self::Z_SHIFT);
+        self
+    }
+
+    /// Unpacks a single block face on the CPU, the same way `face_unpacking.wesl` does on
+    /// the GPU.
+    ///
+    /// Both the AO levels and the surface drop are read from the same bits, so only
+    /// the one matching how the face was packed is meaningful.
+    #[inline(always)]
+    pub fn unpack(self) -> UnpackedFace {
+        self.unpack_with_extent(FaceExtent::UNIT)
+    }
+
+    /// Unpacks a face that covers `extent` blocks.
+    #[inline(always)]
+    pub fn unpack_with_extent(self, extent: FaceExtent) -> UnpackedFace {
+        let packed = self.data;
+        let ao = |corner: u32| {
+            AoLevel::from(((packed >> (Self::AO_SHIFT + corner * 2)) & Self::AO_MASK) as u8)
+        };
+
+        UnpackedFace {
+            position: UVec3::new(
+                packed & Self::POS_MASK,
+                (packed >> Self::Y_SHIFT) & Self::POS_MASK,
+                (packed >> Self::Z_SHIFT) & Self::POS_MASK,
+            ),
+            normal: FaceSide::from(((packed >> Self::NORM_SHIFT) & Self::NORM_MASK) as u8),
+            ao_levels: [ao(0), ao(1), ao(2), ao(3)],
+            surface_drop: ((packed >> Self::AO_SHIFT) & Self::DROP_MASK) as u8,
+            texture_id: (packed >> Self::TEX_SHIFT) & Self::TEX_MASK,
+            width: extent.width(),
+            height: extent.height(),
+        }
     }
 }
 
 /// Uploads a voxel mesh to the SSBO and returns its handle.
+///
+/// `extents` is either empty or holds the size of every face.
 pub fn upload_voxel_mesh(
     manager: &mut ChunkStorageManager,
     device: &RenderDevice,
     queue: &Queue,
     layout: &ChunkStorageBindGroupLayout,
     faces: &[PackedFace],
+    extents: &[FaceExtent],
     world_pos: [f32; 3],
 ) -> Option<VoxelMesh> {
-    manager.allocate_chunk(device, queue, layout, faces, world_pos)
+    manager.allocate_chunk(device, queue, layout, faces, extents, world_pos)
 }
@@ -0,0 +1,122 @@
+use crate::prelude::*;
+use crate::{
+    render_world::textures::registry::TextureId,
+    simulation_world::chunk::meshing::common::{AoLevel, FaceSide},
+};
+use glam::UVec3;
+
+// INFO: ---------------------------------------
+//         lookup tables (vertex_pulling.wesl)
+// ---------------------------------------------
+
+/// The relative position of the 8 vertices of a voxel.
+const CUBE_VERTICES: [Vec3; 8] = [
+    vec3(0.0, 0.0, 0.0),
+    vec3(1.0, 0.0, 0.0),
+    vec3(1.0, 1.0, 0.0),
+    vec3(0.0, 1.0, 0.0),
+    vec3(0.0, 0.0, 1.0),
+    vec3(1.0, 0.0, 1.0),
+    vec3(1.0, 1.0, 1.0),
+    vec3(0.0, 1.0, 1.0),
+];
+
+/// The 4 unique cube vertices of each face, in the order the shader's AO corners use.
+///
+/// These are the distinct entries of `CUBE_INDICES` in the shader, which draws each
+/// face as the corners `0, 1, 2, 2, 3, 0`.
+const FACE_CORNERS: [[usize; 4]; 6] = [
+    [7, 6, 2, 3], // top
+    [0, 1, 5, 4], // bottom
+    [5, 1, 2, 6], // right
+    [0, 4, 7, 3], // left
+    [4, 5, 6, 7], // front
+    [1, 0, 3, 2], // back
+];
+
+/// The UVs of each face corner, with V pointing down the texture.
+const CORNER_UVS: [Vec2; 4] = [
+    vec2(0.0, 1.0),
+    vec2(1.0, 1.0),
+    vec2(1.0, 0.0),
+    vec2(0.0, 0.0),
+];
+
+/// Maps the integer AO level (0-3) to a brightness.
+const AO_VALUES: [f32; 4] = [1.0, 0.8, 0.5, 0.2];
+
+/// The corners of the two counter-clockwise triangles making up a face.
+pub const QUAD_TRIANGLES: [usize; 6] = [0, 1, 2, 2, 3, 0];
+
+// INFO: -----------------------
+//         unpacked types
+// -----------------------------
+
+/// A `PackedFace` unpacked back into its parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnpackedFace {
+    /// The block position of the face within its chunk.
+    pub position: UVec3,
+    pub normal: FaceSide,
+    /// Ambient occlusion of each corner, only set on opaque faces.
+    pub ao_levels: [AoLevel; 4],
+    /// How far the top edge sits below a full block in eighths, only set on transparent faces.
+    pub surface_drop: u8,
+    pub texture_id: TextureId,
+    /// Blocks covered along the normal's width axis.
+    pub width: u32,
+    /// Blocks covered along the normal's height axis.
+    pub height: u32,
+}
+
+/// A single corner of a face, as the vertex shader would output it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FaceVertex {
+    /// Position relative to the chunk origin.
+    pub position: Vec3,
+    pub normal: Vec3,
+    /// UV within the face's texture layer, with V pointing down the texture.
+    ///
+    /// Runs past 1 on faces covering several blocks, where the texture repeats.
+    pub uv: Vec2,
+    /// Brightness from ambient occlusion, `1.0` being unoccluded.
+    pub ao: f32,
+}
+
+impl UnpackedFace {
+    /// The 4 corners of the face, to be drawn as `QUAD_TRIANGLES`.
+    ///
+    /// Transparent faces have their top edge lowered by the surface drop instead of
+    /// being shaded with AO. The time based wobble of the transparent pass is left out.
+    pub fn corners(&self, transparent: bool) -> [FaceVertex; 4] {
+        let side = self.normal as usize;
+        let normal = IVec3::from_array(self.normal.to_vec3()).as_vec3();
+        let block_pos = self.position.as_vec3();
+        let extent = Vec2::new(self.width as f32, self.height as f32);
+        let (width_axis, height_axis) = self.normal.extent_axes();
+        let stretch = Vec3::ONE
+            + width_axis.as_vec3() * (extent.x - 1.0)
+            + height_axis.as_vec3() * (extent.y - 1.0);
+
+        std::array::from_fn(|corner| {
+            let offset = CUBE_VERTICES[FACE_CORNERS[side][corner]] * stretch;
+            let mut position = block_pos + offset;
+
+            let ao = if transparent {
+                if offset.y > 0.5 {
+                    position.y -= self.surface_drop as f32 / 8.0;
+                }
+                1.0
+            } else {
+                AO_VALUES[self.ao_levels[corner] as usize]
+            };
+
+            FaceVertex {
+                position,
+                normal,
+                uv: CORNER_UVS[corner] * extent,
+                ao,
+            }
+        })
+    }
+}
@@ -60,10 +60,8 @@ impl Plugin for AppLifecyclePlugin {
                     .in_set(SimulationSet::PreUpdate),
             );
 
-        // initial startup loading state should take us from loading
-        // to running/playing once they finish.
-        builder
-            .add_resource(OnLoadComplete::new(AppState::Running))
-            .add_resource(OnLoadComplete::new(GameState::Playing));
+        // initial startup loading state should take us from loading to running once it
+        // finishes, where the game state goes depends on the client/headless split
+        builder.add_resource(OnLoadComplete::new(AppState::Running));
     }
 }
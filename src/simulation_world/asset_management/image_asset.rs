@@ -0,0 +1,37 @@
+use crate::simulation_world::asset_management::Asset;
+use glam::UVec2;
+use image::RgbaImage;
+use std::path::Path;
+
+/// An RGBA8 image on the CPU, like the ones image backed UI nodes draw.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImageAsset {
+    pub name: String,
+    pub size: UVec2,
+    /// Tightly packed RGBA8 pixels, row by row from the top.
+    pub pixels: Vec<u8>,
+}
+
+impl Asset for ImageAsset {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl ImageAsset {
+    pub fn from_rgba(name: impl Into<String>, image: RgbaImage) -> Self {
+        Self {
+            name: name.into(),
+            size: UVec2::new(image.width(), image.height()),
+            pixels: image.into_raw(),
+        }
+    }
+
+    /// Loads a PNG, named after its file stem.
+    pub fn load(path: &Path) -> Result<Self, image::ImageError> {
+        let name = path
+            .file_stem()
+            .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned());
+        Ok(Self::from_rgba(name, image::open(path)?.into_rgba8()))
+    }
+}
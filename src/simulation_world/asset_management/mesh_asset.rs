@@ -1,11 +1,13 @@
 use crate::ecs_core::SimToRenderSender;
 use crate::prelude::*;
 use crate::{
-    render_world::types::PackedFace,
+    render_world::types::{FaceExtent, PackedFace},
     simulation_world::{
         asset_management::AssetStorageResource,
         asset_management::{Asset, Handle},
-        chunk::{OpaqueMeshComponent, TransparentMeshComponent},
+        chunk::{
+            thread_buffer_pool::release_face_buffer, OpaqueMeshComponent, TransparentMeshComponent,
+        },
     },
 };
 use bevy_ecs::prelude::*;
@@ -20,6 +22,9 @@ use std::collections::{hash_map::Entry, HashMap};
 pub struct MeshAsset {
     pub name: String,
     pub faces: Vec<PackedFace>,
+    /// The size of each face, left empty unless the mesh was greedy merged so plain
+    /// meshes stay at 4 bytes a face.
+    pub extents: Vec<FaceExtent>,
 }
 impl Asset for MeshAsset {
     fn name(&self) -> &str {
@@ -27,6 +32,13 @@ impl Asset for MeshAsset {
     }
 }
 
+impl Drop for MeshAsset {
+    /// Hands the face buffer back to the pool, so freed chunk meshes feed new ones.
+    fn drop(&mut self) {
+        release_face_buffer(std::mem::take(&mut self.faces));
+    }
+}
+
 /// Tracks the last-known mesh handle for each entity (Opaque).
 #[derive(Resource, Default, Debug)]
 pub struct OpaqueMeshShadow {
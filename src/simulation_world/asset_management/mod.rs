@@ -1,7 +1,9 @@
 pub mod asset_storage;
+pub mod image_asset;
 pub mod mesh_asset;
 
 pub use asset_storage::{Asset, AssetStorageResource, Handle};
+pub use image_asset::ImageAsset;
 pub use mesh_asset::{delete_stale_mesh_assets, MeshAsset, MeshDeletionRequest};
 
 // INFO: ---------------------------------
@@ -26,6 +28,9 @@ pub struct AssetManagementPlugin;
 
 impl Plugin for AssetManagementPlugin {
     fn build(&self, builder: &mut EcsBuilder) {
+        // the image asset storage
+        builder.add_resource(AssetStorageResource::<ImageAsset>::default());
+
         // the mesh asset storage
         builder
             .add_resource(AssetStorageResource::<MeshAsset>::default())
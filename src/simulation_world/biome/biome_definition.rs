@@ -17,6 +17,8 @@ pub struct BiomeDefinition {
     pub name: String,
     pub tint_colors: BiomeTintColors,
     pub terrain: TerrainParameters,
+    /// Rough temperature of the biome from -1.0 (frozen) to 1.0 (scorching).
+    pub temperature: f32,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -47,6 +49,9 @@ mod raw {
         pub(super) tint_colors: Option<RawTintColors>,
 
         pub(super) terrain: RawTerrainParameters,
+
+        #[serde(default)]
+        pub(super) temperature: f32,
     }
 
     #[derive(Deserialize, Debug, Default)]
@@ -77,6 +82,7 @@ mod raw {
                     .tint_colors
                     .map_or_else(BiomeTintColors::default, |raw| raw.into()),
                 terrain: raw_def.terrain.into(),
+                temperature: raw_def.temperature.clamp(-1.0, 1.0),
             }
         }
     }
@@ -7,7 +7,7 @@ use std::{collections::HashMap, fs, path::Path, sync::Arc};
 
 pub type BiomeId = u8;
 
-#[derive(Resource, Default, Clone)]
+#[derive(Resource, Clone)]
 pub struct BiomeRegistryResource {
     /// Stores definitions indexed by BiomeId enum variant.
     definitions: Arc<Vec<BiomeDefinition>>,
@@ -39,6 +39,15 @@ impl BiomeRegistryResource {
         self.name_to_id.get(&name.to_lowercase()).copied()
     }
 
+    /// Returns the name of every registered biome, indexed by biome ID.
+    pub fn biome_names(&self) -> Vec<String> {
+        let mut names = vec![String::new(); self.definitions.len()];
+        for (name, &id) in self.name_to_id.iter() {
+            names[id as usize] = name.clone();
+        }
+        names
+    }
+
     /// Gets the definition for a given biome name (filename).
     ///
     /// Defaults to ID 0's definition if not found.
@@ -55,61 +64,28 @@ impl BiomeRegistryResource {
     }
 }
 
-// INFO: ------------------------------
-//         System to load files
-// ------------------------------------
-
-/// A system that is built to run once at startup. It scans the biome directory and
-/// loads all definitions found into the `BiomeRegistryResource` for global access.
-#[instrument(skip_all)]
-pub fn initialize_biome_registry_system(mut commands: Commands) {
-    let registry = load_biome_defs_from_disk();
-    commands.insert_resource(registry);
+impl FromWorld for BiomeRegistryResource {
+    fn from_world(_world: &mut World) -> Self {
+        load_biome_defs_from_disk()
+    }
 }
 
+// INFO: -----------------------------
+//         Loading definitions
+// -----------------------------------
+
+/// The biome every registry has as ID 0, used as the fallback for unknown names.
+const DEFAULT_BIOME_NAME: &str = "ocean";
+
 /// A util that scans the biome asset directory and loads all valid biome definitions
 /// found into a `BiomeRegistryResource` struct.
 #[instrument(skip_all)]
 pub fn load_biome_defs_from_disk() -> BiomeRegistryResource {
-    info!("Loading biome definitions...");
+    info!("Loading biome definitions from disk...");
 
-    let mut biome_definitions: Vec<BiomeDefinition> = Vec::new();
-    let mut name_to_id: HashMap<String, BiomeId> = HashMap::new();
     let biome_dir = Path::new("assets/biomes");
+    let mut sources: Vec<(String, String)> = Vec::new();
 
-    // helper closure for local registration (identical to block loading)
-    let mut register = |name: String, definition: BiomeDefinition| -> BiomeId {
-        let id = biome_definitions.len() as BiomeId;
-        biome_definitions.push(definition);
-        name_to_id.insert(name.to_lowercase(), id);
-        id
-    };
-
-    // load the default biome
-    let default_biome_name = "ocean";
-    let default_ron_path = biome_dir.join(format!("{}.ron", default_biome_name));
-    let default_definition = match fs::read_to_string(&default_ron_path)
-        .map_err(|e| e.to_string())
-        .and_then(|ron_string| load_biome_from_str(&ron_string).map_err(|e| e.to_string()))
-    {
-        Ok(def) => def,
-        Err(e) => {
-            panic!(
-                "CRITICAL: Failed to load or parse default biome file ({:?}): {}. Cannot proceed.",
-                default_ron_path, e
-            );
-        }
-    };
-    let default_id = register(default_biome_name.to_string(), default_definition);
-    if default_id != 0 {
-        panic!(
-            "Default biome '{}' did not get ID 0! Check loading logic.",
-            default_biome_name
-        );
-    }
-    info!("Registered default biome '{}' as ID 0", default_biome_name);
-
-    // now parse the rest of the biomes
     if biome_dir.is_dir() {
         for entry in fs::read_dir(biome_dir).unwrap_or_else(|e| {
             panic!("Failed to read biome directory {:?}: {}", biome_dir, e);
@@ -140,30 +116,9 @@ pub fn load_biome_defs_from_disk() -> BiomeRegistryResource {
                     }
                 };
 
-                // skip default or _ files
-                if name == default_biome_name {
-                    continue;
-                } else if name.starts_with("_") {
-                    continue;
-                }
-
-                let ron_string = match fs::read_to_string(&path) {
-                    Ok(s) => s,
-                    Err(e) => {
-                        error!("Failed to read biome file {:?}: {}", path, e);
-                        continue;
-                    }
-                };
-
-                // construct concrete biome definition object
-                match load_biome_from_str(&ron_string) {
-                    Ok(definition) => {
-                        let runtime_id = register(name.clone(), definition);
-                        info!("Loaded biome '{}' (runtime id={})", name, runtime_id);
-                    }
-                    Err(e) => {
-                        error!("Failed to parse biome file {:?}: {}", path, e);
-                    }
+                match fs::read_to_string(&path) {
+                    Ok(ron_string) => sources.push((name, ron_string)),
+                    Err(e) => error!("Failed to read biome file {:?}: {}", path, e),
                 }
             }
         }
@@ -174,14 +129,79 @@ pub fn load_biome_defs_from_disk() -> BiomeRegistryResource {
         );
     }
 
-    let registry = BiomeRegistryResource {
-        definitions: Arc::new(biome_definitions),
-        name_to_id: Arc::new(name_to_id),
-    };
+    // directory order is platform dependent, sort so biome IDs are the same everywhere
+    sources.sort();
+
+    let registry = load_biome_defs_from_sources(sources);
 
     if registry.definitions.len() <= 1 {
         warn!("Only the default biome was loaded. Check 'assets/biomes/' directory for other biome files.");
     }
 
-    return registry;
+    registry
+}
+
+/// Builds a `BiomeRegistryResource` from `(name, ron)` pairs, as if each were a file in
+/// `assets/biomes`.
+///
+/// Panics if the default "ocean" biome is missing or fails to parse, since ID 0 has to exist.
+pub fn load_biome_defs_from_sources(
+    sources: impl IntoIterator<Item = (String, String)>,
+) -> BiomeRegistryResource {
+    let mut sources: Vec<(String, String)> = sources.into_iter().collect();
+
+    let mut biome_definitions: Vec<BiomeDefinition> = Vec::new();
+    let mut name_to_id: HashMap<String, BiomeId> = HashMap::new();
+
+    // helper closure for local registration (identical to block loading)
+    let mut register = |name: String, definition: BiomeDefinition| -> BiomeId {
+        let id = biome_definitions.len() as BiomeId;
+        biome_definitions.push(definition);
+        name_to_id.insert(name.to_lowercase(), id);
+        id
+    };
+
+    // load the default biome
+    let default_index = sources
+        .iter()
+        .position(|(name, _)| name == DEFAULT_BIOME_NAME)
+        .unwrap_or_else(|| {
+            panic!(
+                "CRITICAL: Default biome '{}' is missing. Cannot proceed.",
+                DEFAULT_BIOME_NAME
+            )
+        });
+    let (default_name, default_ron) = sources.remove(default_index);
+    let default_definition = load_biome_from_str(&default_ron).unwrap_or_else(|e| {
+        panic!(
+            "CRITICAL: Failed to parse default biome '{}': {}. Cannot proceed.",
+            default_name, e
+        )
+    });
+    register(default_name, default_definition);
+    info!("Registered default biome '{}' as ID 0", DEFAULT_BIOME_NAME);
+
+    // now parse the rest of the biomes
+    for (name, ron_string) in sources {
+        // skip _ files
+        if name.starts_with("_") {
+            continue;
+        }
+
+        // construct concrete biome definition object
+        match load_biome_from_str(&ron_string) {
+            Ok(definition) => {
+                let runtime_id = register(name.clone(), definition);
+                info!("Loaded biome '{}' (runtime id={})", name, runtime_id);
+            }
+            Err(e) => {
+                error!("Failed to parse biome '{}': {}", name, e);
+            }
+        }
+    }
+
+    BiomeRegistryResource {
+        definitions: Arc::new(biome_definitions),
+        name_to_id: Arc::new(name_to_id),
+    }
 }
@@ -0,0 +1,48 @@
+use crate::simulation_world::block::BlockId;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// How a block reacts to random and scheduled ticks, as written in the block RON files.
+///
+/// Blocks are referenced by name and resolved into a `BlockBehavior` once every
+/// block has been registered.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum BlockBehaviorConfig {
+    /// Slowly spreads onto nearby `target` blocks that have air above them, and
+    /// turns back into `target` when covered by an opaque block (eg grass on dirt).
+    SpreadOnto { target: String },
+    /// Turns into `into` when random ticked in a biome at or above `min_temperature`.
+    Melt { into: String, min_temperature: f32 },
+}
+
+/// The runtime version of a `BlockBehaviorConfig` with all names resolved to IDs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlockBehavior {
+    SpreadOnto { target: BlockId },
+    Melt { into: BlockId, min_temperature: f32 },
+}
+
+impl BlockBehaviorConfig {
+    /// Resolves block names into IDs, returning the name that failed if one is unknown.
+    pub fn resolve(&self, name_to_id: &HashMap<String, BlockId>) -> Result<BlockBehavior, String> {
+        let lookup = |name: &String| {
+            name_to_id
+                .get(&name.to_lowercase())
+                .copied()
+                .ok_or_else(|| name.clone())
+        };
+
+        Ok(match self {
+            BlockBehaviorConfig::SpreadOnto { target } => BlockBehavior::SpreadOnto {
+                target: lookup(target)?,
+            },
+            BlockBehaviorConfig::Melt {
+                into,
+                min_temperature,
+            } => BlockBehavior::Melt {
+                into: lookup(into)?,
+                min_temperature: *min_temperature,
+            },
+        })
+    }
+}
@@ -1,4 +1,7 @@
-use crate::render_world::textures::registry::TextureId;
+use crate::{
+    render_world::textures::registry::TextureId,
+    simulation_world::block::{BlockBehaviorConfig, BlockId},
+};
 use serde::Deserialize;
 
 /// Loads a block definition from string and returns two hot/cold split structs
@@ -20,6 +23,73 @@ pub struct BlockRenderData<T = TextureId> {
 #[derive(Debug, Clone)]
 pub struct BlockDescription {
     pub display_name: String,
+    pub fluid: Option<FluidProperties>,
+    pub behavior: Option<BlockBehaviorConfig>,
+    /// Whether the block falls (as an entity) when there is nothing underneath it.
+    pub has_gravity: bool,
+}
+
+/// Configuration for blocks that flow like a fluid (eg water).
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct FluidProperties {
+    /// How many blocks the fluid can flow horizontally away from a source.
+    #[serde(default = "FluidProperties::default_flow_distance")]
+    pub flow_distance: u8,
+    /// How many simulation ticks pass between a change and the fluid reacting to it.
+    #[serde(default = "FluidProperties::default_tick_delay")]
+    pub tick_delay: u32,
+}
+
+impl FluidProperties {
+    /// Flow levels are packed into 3 bits when meshing, so 7 is the hard cap.
+    pub const MAX_FLOW_DISTANCE: u8 = 7;
+
+    fn default_flow_distance() -> u8 {
+        Self::MAX_FLOW_DISTANCE
+    }
+
+    fn default_tick_delay() -> u32 {
+        4
+    }
+}
+
+/// Hot fluid data for a single registered fluid variant (a source or one of its flow levels).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FluidState {
+    /// The block ID of the source variant of this fluid.
+    pub source_id: BlockId,
+    /// Horizontal distance from the nearest source, `0` being the source itself.
+    pub distance: u8,
+    /// The flow config shared by every variant of this fluid.
+    pub properties: FluidProperties,
+}
+
+impl FluidState {
+    #[inline(always)]
+    pub fn is_source(&self) -> bool {
+        self.distance == 0
+    }
+
+    /// Whether two fluid variants belong to the same fluid.
+    #[inline(always)]
+    pub fn is_same_fluid(&self, other: &FluidState) -> bool {
+        self.source_id == other.source_id
+    }
+
+    /// How far the top surface sits below a full block, in eighths of a block.
+    ///
+    /// Sources render full height, flowing variants get shallower the further they travel.
+    #[inline(always)]
+    pub fn surface_drop(&self) -> u8 {
+        if self.is_source() {
+            return 0;
+        }
+
+        // spread the remaining 7 eighths evenly over the flow distance
+        let span = (self.properties.flow_distance.max(2) - 1) as u32;
+        (1 + ((self.distance as u32 - 1) * 6) / span) as u8
+    }
 }
 
 // INFO: ------------------
@@ -79,6 +149,12 @@ pub mod raw {
         pub(super) display_name: String,
         pub(super) textures: TextureConfig,
         pub(super) is_transparent: bool,
+        #[serde(default)]
+        pub(super) fluid: Option<super::FluidProperties>,
+        #[serde(default)]
+        pub(super) behavior: Option<BlockBehaviorConfig>,
+        #[serde(default)]
+        pub(super) has_gravity: bool,
     }
 
     impl BlockProperties {
@@ -93,6 +169,14 @@ pub mod raw {
 
             let description = super::BlockDescription {
                 display_name: self.display_name,
+                fluid: self.fluid.map(|mut fluid| {
+                    fluid.flow_distance = fluid
+                        .flow_distance
+                        .clamp(1, super::FluidProperties::MAX_FLOW_DISTANCE);
+                    fluid
+                }),
+                behavior: self.behavior,
+                has_gravity: self.has_gravity,
             };
 
             (render_data, description)
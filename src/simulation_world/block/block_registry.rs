@@ -2,7 +2,8 @@ use crate::{
     prelude::*,
     render_world::textures::{registry::TextureId, TextureRegistryResource},
     simulation_world::block::{
-        load_block_from_str, BlockDescription, BlockFaceTextures, BlockRenderData,
+        load_block_from_str, BlockBehavior, BlockDescription, BlockFaceTextures, BlockRenderData,
+        FluidState,
     },
 };
 use bevy_ecs::prelude::*;
@@ -27,6 +28,15 @@ pub struct BlockRegistryResource {
     /// to optimize super hot loops (meshing).
     /// Layout: [Top, Bottom, Left, Right, Front, Back]
     texture_lut: Arc<Vec<[TextureId; 6]>>,
+    /// Direct access to the fluid variant (if any) of each block
+    /// to optimize super hot loops (meshing, fluid ticking).
+    fluid_lut: Arc<Vec<Option<FluidState>>>,
+    /// Maps a fluid source ID to the IDs of all its variants, indexed by flow distance.
+    fluid_variants: Arc<HashMap<BlockId, Vec<BlockId>>>,
+    /// The resolved tick behavior (if any) of each block.
+    behavior_lut: Arc<Vec<Option<BlockBehavior>>>,
+    /// Whether each block is affected by gravity.
+    gravity_lut: Arc<Vec<bool>>,
 
     /// All loaded block descriptors from disc.
     descriptions: Arc<Vec<BlockDescription>>,
@@ -61,6 +71,15 @@ impl BlockRegistryResource {
         self.name_to_id.get(&name.to_lowercase()).copied()
     }
 
+    /// Returns the name of every registered block, indexed by block ID.
+    pub fn block_names(&self) -> Vec<String> {
+        let mut names = vec![String::new(); self.descriptions.len()];
+        for (name, &id) in self.name_to_id.iter() {
+            names[id as usize] = name.clone();
+        }
+        names
+    }
+
     /// Returns a slice of booleans representing the transparency state of all blocks.
     /// Index is BlockId.
     ///
@@ -78,6 +97,41 @@ impl BlockRegistryResource {
     pub fn get_texture_lut(&self) -> &[[TextureId; 6]] {
         &self.texture_lut
     }
+
+    /// Returns a slice of the fluid state of all blocks (`None` for non-fluids).
+    /// Index is BlockId.
+    #[inline(always)]
+    pub fn get_fluid_lut(&self) -> &[Option<FluidState>] {
+        &self.fluid_lut
+    }
+
+    /// Gets the fluid state of a block, if it is a fluid.
+    #[inline(always)]
+    pub fn get_fluid(&self, id: BlockId) -> Option<FluidState> {
+        self.fluid_lut.get(id as usize).copied().flatten()
+    }
+
+    /// Gets the tick behavior of a block, if it has one.
+    #[inline(always)]
+    pub fn get_behavior(&self, id: BlockId) -> Option<BlockBehavior> {
+        self.behavior_lut.get(id as usize).copied().flatten()
+    }
+
+    /// Whether a block falls when there is nothing underneath it.
+    #[inline(always)]
+    pub fn has_gravity(&self, id: BlockId) -> bool {
+        self.gravity_lut.get(id as usize).copied().unwrap_or(false)
+    }
+
+    /// Gets the ID of the variant of a fluid at a given flow distance from its source.
+    ///
+    /// Distance `0` is the source itself. Returns `None` past the fluid's flow distance.
+    pub fn get_fluid_variant(&self, source_id: BlockId, distance: u8) -> Option<BlockId> {
+        self.fluid_variants
+            .get(&source_id)
+            .and_then(|variants| variants.get(distance as usize))
+            .copied()
+    }
 }
 
 fn register_block(
@@ -142,15 +196,70 @@ impl FromWorld for BlockRegistryResource {
         info!("Loading block definitions from disk...");
 
         let texture_registry = world.get_resource::<TextureRegistryResource>().unwrap();
+        let block_dir = Path::new("assets/blocks");
+
+        let mut sources: Vec<(String, String)> = Vec::new();
 
+        if block_dir.is_dir() {
+            let entries = fs::read_dir(block_dir).unwrap_or_else(|e| {
+                panic!("Failed to read block directory {:?}: {}", block_dir, e);
+            });
+
+            for entry in entries {
+                let entry = match entry {
+                    Ok(e) => e,
+                    Err(e) => {
+                        warn!("Failed to read entry in block directory: {}", e);
+                        continue;
+                    }
+                };
+                let path = entry.path();
+
+                // ignore non-ron files
+                if path.is_file() && path.extension().map_or(false, |s| s == "ron") {
+                    let name = match path.file_stem().and_then(|s| s.to_str()) {
+                        Some(name_str) => name_str.to_string(),
+                        None => {
+                            warn!("Skipping invalid filename: {:?}", path.file_name());
+                            continue;
+                        }
+                    };
+
+                    match fs::read_to_string(&path) {
+                        Ok(ron_string) => sources.push((name, ron_string)),
+                        Err(e) => error!("Failed to read {:?}: {}", path, e),
+                    }
+                }
+            }
+        } else {
+            warn!(
+                "Block directory not found at: {:?}. Only 'Air' loaded.",
+                block_dir
+            );
+        }
+
+        // directory order is platform dependent, sort so block IDs are the same everywhere
+        sources.sort();
+
+        Self::from_ron_sources(sources, texture_registry)
+    }
+}
+
+impl BlockRegistryResource {
+    /// Builds the registry from `(name, ron)` pairs, as if each were a file in `assets/blocks`.
+    ///
+    /// The name is what the block is looked up by (the file stem on disk). Air is always
+    /// registered as ID 0 and a block named "stone" takes the reserved ID 1.
+    pub fn from_ron_sources(
+        sources: impl IntoIterator<Item = (String, String)>,
+        texture_registry: &TextureRegistryResource,
+    ) -> Self {
         let mut render_data_vec: Vec<BlockRenderData<TextureId>> = Vec::new();
         let mut descriptions_vec: Vec<BlockDescription> = Vec::new();
         // This vector stores the hot path [u16; 6] arrays
         let mut texture_lut_vec: Vec<[TextureId; 6]> = Vec::new();
         let mut name_to_id: HashMap<String, BlockId> = HashMap::new();
 
-        let block_dir = Path::new("assets/blocks");
-
         // INFO: ---------------------------------------
         //          manual air block registration (ID 0)
         // ---------------------------------------------
@@ -169,6 +278,9 @@ impl FromWorld for BlockRegistryResource {
 
         let air_desc = BlockDescription {
             display_name: "Air".to_string(),
+            fluid: None,
+            behavior: None,
+            has_gravity: false,
         };
 
         let air_id = register_block(
@@ -207,6 +319,9 @@ impl FromWorld for BlockRegistryResource {
 
         let placeholder_desc = BlockDescription {
             display_name: "Stone (Placeholder)".to_string(),
+            fluid: None,
+            behavior: None,
+            has_gravity: false,
         };
 
         render_data_vec.push(placeholder_render_data_ids);
@@ -214,100 +329,95 @@ impl FromWorld for BlockRegistryResource {
         texture_lut_vec.push([missing_texture_id; 6]);
 
         // INFO: ------------------------------------------
-        //         parse remaining blocks from disc
+        //         parse remaining block sources
         // ------------------------------------------------
 
         let mut stone_was_loaded = false;
+        let mut fluid_variants: HashMap<BlockId, Vec<BlockId>> = HashMap::new();
+
+        for (name, ron_string) in sources {
+            // skip reserved names
+            if name == "air" {
+                error!("Skipping 'air.ron' (Reserved).");
+                continue;
+            } else if name.starts_with('_') {
+                continue;
+            }
 
-        if block_dir.is_dir() {
-            let entries = fs::read_dir(block_dir).unwrap_or_else(|e| {
-                panic!("Failed to read block directory {:?}: {}", block_dir, e);
-            });
-
-            for entry in entries {
-                let entry = match entry {
-                    Ok(e) => e,
-                    Err(e) => {
-                        warn!("Failed to read entry in block directory: {}", e);
-                        continue;
-                    }
-                };
-                let path = entry.path();
-
-                // ignore non-ron files
-                if path.is_file() && path.extension().map_or(false, |s| s == "ron") {
-                    let name = match path.file_stem().and_then(|s| s.to_str()) {
-                        Some(name_str) => name_str.to_string(),
-                        None => {
-                            warn!("Skipping invalid filename: {:?}", path.file_name());
-                            continue;
-                        }
-                    };
-
-                    // skip reserved names
-                    if name == "air" {
-                        error!("Skipping 'air.ron' (Reserved).");
-                        continue;
-                    } else if name.starts_with('_') {
-                        continue;
-                    }
-
-                    // read & register
-                    let ron_string = match fs::read_to_string(&path) {
-                        Ok(s) => s,
-                        Err(e) => {
-                            error!("Failed to read {:?}: {}", path, e);
-                            continue;
-                        }
-                    };
-
-                    match load_block_from_str(&ron_string) {
-                        Ok((render_props, desc_props)) => {
-                            if name == "stone" {
-                                // overwrite first slot
-                                register_block(
-                                    name.clone(),
-                                    render_props,
-                                    desc_props,
-                                    Some(SOLID_BLOCK_ID),
-                                    texture_registry,
-                                    &mut render_data_vec,
-                                    &mut descriptions_vec,
-                                    &mut texture_lut_vec,
-                                    &mut name_to_id,
-                                );
-                                stone_was_loaded = true;
-                                info!(
-                                    "Loaded 'stone' and assigned to reserved ID {}",
-                                    SOLID_BLOCK_ID
-                                );
-                            } else {
-                                // otherwise append
-                                let id = register_block(
-                                    name.clone(),
-                                    render_props,
-                                    desc_props,
+            match load_block_from_str(&ron_string) {
+                Ok((render_props, desc_props)) => {
+                    if name == "stone" {
+                        // overwrite first slot
+                        register_block(
+                            name.clone(),
+                            render_props,
+                            desc_props,
+                            Some(SOLID_BLOCK_ID),
+                            texture_registry,
+                            &mut render_data_vec,
+                            &mut descriptions_vec,
+                            &mut texture_lut_vec,
+                            &mut name_to_id,
+                        );
+                        stone_was_loaded = true;
+                        info!(
+                            "Loaded 'stone' and assigned to reserved ID {}",
+                            SOLID_BLOCK_ID
+                        );
+                    } else {
+                        // otherwise append
+                        let fluid = desc_props.fluid;
+                        let id = register_block(
+                            name.clone(),
+                            render_props.clone(),
+                            desc_props.clone(),
+                            None,
+                            texture_registry,
+                            &mut render_data_vec,
+                            &mut descriptions_vec,
+                            &mut texture_lut_vec,
+                            &mut name_to_id,
+                        );
+                        info!("Loaded block '{}' (id={})", name, id);
+
+                        // fluids get an extra block registered for each flow level
+                        if let Some(fluid) = fluid {
+                            let mut variants = vec![id];
+                            for distance in 1..=fluid.flow_distance {
+                                let flowing_desc = BlockDescription {
+                                    display_name: format!(
+                                        "{} (Flowing {})",
+                                        desc_props.display_name, distance
+                                    ),
+                                    fluid: Some(fluid),
+                                    behavior: None,
+                                    has_gravity: false,
+                                };
+
+                                variants.push(register_block(
+                                    format!("{name}_flowing_{distance}"),
+                                    render_props.clone(),
+                                    flowing_desc,
                                     None,
                                     texture_registry,
                                     &mut render_data_vec,
                                     &mut descriptions_vec,
                                     &mut texture_lut_vec,
                                     &mut name_to_id,
-                                );
-                                info!("Loaded block '{}' (id={})", name, id);
+                                ));
                             }
-                        }
-                        Err(e) => {
-                            error!("Failed to parse {:?}: {}", path, e);
+                            info!(
+                                "Registered {} flowing variants for fluid '{}'",
+                                fluid.flow_distance, name
+                            );
+                            fluid_variants.insert(id, variants);
                         }
                     }
                 }
+                Err(e) => {
+                    error!("Failed to parse block '{}': {}", name, e);
+                }
             }
-        } else {
-            warn!(
-                "Block directory not found at: {:?}. Only 'Air' loaded.",
-                block_dir
-            );
         }
 
         if !stone_was_loaded {
@@ -319,10 +429,46 @@ impl FromWorld for BlockRegistryResource {
         let transparency_lut: Vec<bool> =
             render_data_vec.iter().map(|d| d.is_transparent).collect();
 
+        let mut fluid_lut: Vec<Option<FluidState>> = vec![None; render_data_vec.len()];
+        for (&source_id, variants) in fluid_variants.iter() {
+            let properties = descriptions_vec[source_id as usize].fluid.unwrap();
+            for (distance, &variant_id) in variants.iter().enumerate() {
+                fluid_lut[variant_id as usize] = Some(FluidState {
+                    source_id,
+                    distance: distance as u8,
+                    properties,
+                });
+            }
+        }
+
+        // behaviors reference other blocks by name so resolve them once all are registered
+        let behavior_lut: Vec<Option<BlockBehavior>> = descriptions_vec
+            .iter()
+            .map(|desc| {
+                let config = desc.behavior.as_ref()?;
+                match config.resolve(&name_to_id) {
+                    Ok(behavior) => Some(behavior),
+                    Err(missing) => {
+                        error!(
+                            "Block '{}' has a behavior referencing unknown block '{}'. Ignoring it.",
+                            desc.display_name, missing
+                        );
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        let gravity_lut: Vec<bool> = descriptions_vec.iter().map(|d| d.has_gravity).collect();
+
         Self {
             render_data: Arc::new(render_data_vec),
             transparency_lut: Arc::new(transparency_lut),
             texture_lut: Arc::new(texture_lut_vec),
+            fluid_lut: Arc::new(fluid_lut),
+            fluid_variants: Arc::new(fluid_variants),
+            behavior_lut: Arc::new(behavior_lut),
+            gravity_lut: Arc::new(gravity_lut),
             descriptions: Arc::new(descriptions_vec),
             name_to_id: Arc::new(name_to_id),
         }
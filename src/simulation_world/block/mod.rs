@@ -1,9 +1,12 @@
+pub mod block_behavior;
 pub mod block_definition;
 pub mod block_registry;
 pub mod targeted_block;
 
+pub use block_behavior::{BlockBehavior, BlockBehaviorConfig};
 pub use block_definition::{
-    load_block_from_str, BlockDescription, BlockFaceTextures, BlockRenderData,
+    load_block_from_str, BlockDescription, BlockFaceTextures, BlockRenderData, FluidProperties,
+    FluidState,
 };
 pub use block_registry::{BlockId, BlockRegistryResource, AIR_BLOCK_ID, SOLID_BLOCK_ID};
 pub use targeted_block::TargetedBlock;
@@ -0,0 +1,55 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    block::{BlockBehavior, BlockId, AIR_BLOCK_ID},
+    block_updates::BlockTickContext,
+};
+use rand::{Rng, RngCore};
+
+/// Runs a block's behavior at a position, for both random and scheduled ticks.
+pub fn run_block_behavior(
+    world_pos: IVec3,
+    block: BlockId,
+    behavior: BlockBehavior,
+    ctx: &mut BlockTickContext,
+    rng: &mut impl RngCore,
+) {
+    match behavior {
+        BlockBehavior::SpreadOnto { target } => spread_onto(world_pos, block, target, ctx, rng),
+        BlockBehavior::Melt {
+            into,
+            min_temperature,
+        } => {
+            if ctx.biome_temperature(world_pos) >= min_temperature {
+                ctx.voxels.set_block(world_pos, into);
+            }
+        }
+    }
+}
+
+/// Covered blocks decay back into `target`, uncovered ones spread to a random nearby `target`.
+fn spread_onto(
+    world_pos: IVec3,
+    block: BlockId,
+    target: BlockId,
+    ctx: &mut BlockTickContext,
+    rng: &mut impl RngCore,
+) {
+    if !ctx.is_transparent(world_pos + IVec3::Y) {
+        ctx.voxels.set_block(world_pos, target);
+        return;
+    }
+
+    // pick from the 3x5x3 area around the block, spreading mostly downhill
+    let offset = IVec3::new(
+        rng.random_range(-1..=1),
+        rng.random_range(-3..=1),
+        rng.random_range(-1..=1),
+    );
+    let candidate = world_pos + offset;
+
+    if ctx.voxels.get_block(candidate) == Some(target)
+        && ctx.voxels.get_block(candidate + IVec3::Y) == Some(AIR_BLOCK_ID)
+    {
+        ctx.voxels.set_block(candidate, block);
+    }
+}
@@ -0,0 +1,40 @@
+pub mod behaviors;
+pub mod random_ticks;
+pub mod scheduled_ticks;
+pub mod tick_context;
+pub mod tick_queue;
+
+pub use behaviors::run_block_behavior;
+pub use random_ticks::{random_block_ticks_system, RandomTickSpeed};
+pub use scheduled_ticks::{
+    run_scheduled_block_ticks_system, schedule_neighbor_block_ticks_system, ScheduledBlockTicks,
+};
+pub use tick_context::BlockTickContext;
+pub use tick_queue::TickQueue;
+
+// INFO: -----------------------------
+//         Block updates plugin
+// -----------------------------------
+
+use crate::prelude::*;
+use bevy_ecs::prelude::*;
+
+pub struct BlockUpdatePlugin;
+
+impl Plugin for BlockUpdatePlugin {
+    fn build(&self, builder: &mut EcsBuilder) {
+        builder
+            .init_resource::<ScheduledBlockTicks>()
+            .init_resource::<RandomTickSpeed>()
+            .schedule_entry(SimulationSchedule::FixedUpdate)
+            .add_systems(
+                (
+                    schedule_neighbor_block_ticks_system,
+                    run_scheduled_block_ticks_system,
+                    random_block_ticks_system,
+                )
+                    .chain()
+                    .in_set(FixedUpdateSet::MainLogic),
+            );
+    }
+}
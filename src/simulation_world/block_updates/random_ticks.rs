@@ -0,0 +1,72 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    block_updates::{run_block_behavior, BlockTickContext},
+    chunk::{ChunkState, ChunkStateManager, CHUNK_SIDE_LENGTH},
+};
+use bevy_ecs::prelude::*;
+use rand::Rng;
+
+/// How many random voxels are picked from every loaded chunk each simulation tick.
+#[derive(Resource, Debug, Clone, Copy, Deref, DerefMut)]
+pub struct RandomTickSpeed(pub u32);
+
+impl Default for RandomTickSpeed {
+    fn default() -> Self {
+        Self(24)
+    }
+}
+
+/// Picks `RandomTickSpeed` random voxels in every loaded chunk and runs their behaviors.
+#[instrument(skip_all)]
+pub fn random_block_ticks_system(
+    // Input
+    random_tick_speed: Res<RandomTickSpeed>,
+    chunk_manager: Res<ChunkStateManager>,
+
+    // Output
+    mut ctx: BlockTickContext,
+) {
+    if random_tick_speed.0 == 0 {
+        return;
+    }
+
+    let loaded_chunks: Vec<IVec3> = chunk_manager
+        .chunk_states
+        .iter()
+        .filter_map(|(coord, state)| match state {
+            ChunkState::Loaded { entity: Some(_) } => Some(*coord),
+            _ => None,
+        })
+        .collect();
+
+    let mut rng = rand::rng();
+    let side = CHUNK_SIDE_LENGTH as i32;
+
+    for chunk_coord in loaded_chunks {
+        // whole chunks of a block without behavior can be skipped without sampling
+        if let Some(uniform_block) = ctx.voxels.chunk_uniform_block(chunk_coord) {
+            if ctx.block_registry.get_behavior(uniform_block).is_none() {
+                continue;
+            }
+        }
+
+        let chunk_origin = chunk_coord * side;
+
+        for _ in 0..random_tick_speed.0 {
+            let pos = chunk_origin
+                + IVec3::new(
+                    rng.random_range(0..side),
+                    rng.random_range(0..side),
+                    rng.random_range(0..side),
+                );
+
+            let Some(block) = ctx.voxels.get_block(pos) else {
+                break; // chunk isn't accessible (eg lower detail)
+            };
+
+            if let Some(behavior) = ctx.block_registry.get_behavior(block) {
+                run_block_behavior(pos, block, behavior, &mut ctx, &mut rng);
+            }
+        }
+    }
+}
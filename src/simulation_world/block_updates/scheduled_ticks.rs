@@ -0,0 +1,103 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    block::{BlockId, BlockRegistryResource},
+    block_updates::{run_block_behavior, BlockTickContext, TickQueue},
+    chunk::{BlockChangedEvent, VoxelReader},
+    falling_block::try_start_falling,
+    time::simulation_tick::SimulationTick,
+};
+use bevy_ecs::prelude::*;
+
+/// Max number of scheduled block ticks to run in a single simulation tick.
+///
+/// Anything past this stays queued and is picked up on the following ticks.
+const MAX_SCHEDULED_TICKS_PER_TICK: usize = 1024;
+
+/// How many ticks a gravity-affected block waits after a neighbor changes before
+/// checking whether it should fall.
+const GRAVITY_CHECK_DELAY: u32 = 2;
+
+/// The 6 direct neighbors of a voxel plus the voxel itself.
+const UPDATE_OFFSETS: [IVec3; 7] = [
+    IVec3::ZERO,
+    IVec3::Y,
+    IVec3::NEG_Y,
+    IVec3::X,
+    IVec3::NEG_X,
+    IVec3::Z,
+    IVec3::NEG_Z,
+];
+
+/// Block updates that should happen a set number of ticks in the future.
+///
+/// Each update remembers the block it was scheduled for and is skipped if
+/// that block has since been replaced.
+#[derive(Resource, Default, Debug, Deref, DerefMut)]
+pub struct ScheduledBlockTicks(pub TickQueue<BlockId>);
+
+impl ScheduledBlockTicks {
+    /// Schedules `block` at `world_pos` to update `delay` ticks after `current_tick`.
+    pub fn schedule_in(&mut self, world_pos: IVec3, block: BlockId, current_tick: u64, delay: u32) {
+        self.0
+            .schedule(world_pos, block, current_tick + delay.max(1) as u64);
+    }
+}
+
+/// Schedules ticks for blocks that react to one of their neighbors changing.
+///
+/// For now that is only gravity-affected blocks, which check for support.
+#[instrument(skip_all)]
+pub fn schedule_neighbor_block_ticks_system(
+    // Input
+    mut events: MessageReader<BlockChangedEvent>,
+    voxels: VoxelReader,
+    block_registry: Res<BlockRegistryResource>,
+    sim_tick: Res<SimulationTick>,
+
+    // Output
+    mut scheduled_ticks: ResMut<ScheduledBlockTicks>,
+) {
+    for event in events.read() {
+        for offset in UPDATE_OFFSETS {
+            let pos = event.world_pos + offset;
+            let Some(block) = voxels.get_block(pos) else {
+                continue;
+            };
+
+            if block_registry.has_gravity(block) {
+                scheduled_ticks.schedule_in(pos, block, sim_tick.tick, GRAVITY_CHECK_DELAY);
+            }
+        }
+    }
+}
+
+/// Runs every scheduled block tick that is due.
+#[instrument(skip_all)]
+pub fn run_scheduled_block_ticks_system(
+    // Input
+    sim_tick: Res<SimulationTick>,
+
+    // Output
+    mut scheduled_ticks: ResMut<ScheduledBlockTicks>,
+    mut ctx: BlockTickContext,
+) {
+    let due = scheduled_ticks.pop_due(sim_tick.tick, MAX_SCHEDULED_TICKS_PER_TICK);
+    if due.is_empty() {
+        return;
+    }
+
+    let mut rng = rand::rng();
+
+    for (pos, scheduled_block) in due {
+        // the block may have been replaced while the tick was waiting
+        if ctx.voxels.get_block(pos) != Some(scheduled_block) {
+            continue;
+        }
+
+        if ctx.block_registry.has_gravity(scheduled_block) {
+            try_start_falling(pos, scheduled_block, &mut ctx);
+        } else if let Some(behavior) = ctx.block_registry.get_behavior(scheduled_block) {
+            run_block_behavior(pos, scheduled_block, behavior, &mut ctx, &mut rng);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    asset_management::{AssetStorageResource, MeshAsset},
+    biome::BiomeRegistryResource,
+    block::BlockRegistryResource,
+    chunk::{ChunkCoord, ChunkStateManager, WorldVoxels, CHUNK_SIDE_LENGTH},
+    terrain::BiomeMapComponent,
+};
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemParam;
+
+/// Everything a block behavior needs to look at and change the world around it.
+#[derive(SystemParam)]
+pub struct BlockTickContext<'w, 's> {
+    pub voxels: WorldVoxels<'w, 's>,
+    pub block_registry: Res<'w, BlockRegistryResource>,
+    /// For spawning entities out of blocks (eg falling blocks).
+    pub commands: Commands<'w, 's>,
+    pub mesh_assets: Res<'w, AssetStorageResource<MeshAsset>>,
+    biome_registry: Res<'w, BiomeRegistryResource>,
+    chunk_manager: Res<'w, ChunkStateManager>,
+    biome_maps: Query<'w, 's, &'static BiomeMapComponent>,
+}
+
+impl<'w, 's> BlockTickContext<'w, 's> {
+    /// Gets the temperature of the biome at a world position.
+    ///
+    /// Falls back to temperate (0.0) when the biome can't be found.
+    pub fn biome_temperature(&self, world_pos: IVec3) -> f32 {
+        let (chunk_coord, local_pos) = ChunkCoord::world_to_chunk_and_local_pos(world_pos);
+
+        let biome_id = self
+            .chunk_manager
+            .get_entity(chunk_coord)
+            .and_then(|entity| self.biome_maps.get(entity).ok())
+            .filter(|biome_map| biome_map.size() == CHUNK_SIDE_LENGTH)
+            .map(|biome_map| {
+                biome_map.get_data_unchecked(
+                    local_pos.x as usize,
+                    local_pos.y as usize,
+                    local_pos.z as usize,
+                )
+            });
+
+        match biome_id {
+            Some(id) => self.biome_registry.get(id).temperature,
+            None => 0.0,
+        }
+    }
+
+    /// Whether the block at a world position is see-through (missing chunks count as solid).
+    pub fn is_transparent(&self, world_pos: IVec3) -> bool {
+        self.voxels
+            .get_block(world_pos)
+            .is_some_and(|id| self.block_registry.get_render_data(id).is_transparent)
+    }
+}
@@ -0,0 +1,85 @@
+use crate::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+
+/// A queue of voxel positions waiting for an update, keyed by the tick they are due.
+///
+/// Each position can only be queued once at a time, later requests for a position
+/// that is already waiting are ignored. `T` is any extra data carried with the update.
+#[derive(Debug)]
+pub struct TickQueue<T> {
+    /// Positions bucketed by the `SimulationTick` they should update on.
+    scheduled: BTreeMap<u64, Vec<(IVec3, T)>>,
+    /// Every position currently somewhere in `scheduled`, to avoid duplicate updates.
+    pending: HashSet<IVec3>,
+}
+
+impl<T> Default for TickQueue<T> {
+    fn default() -> Self {
+        Self {
+            scheduled: BTreeMap::new(),
+            pending: HashSet::new(),
+        }
+    }
+}
+
+impl<T> TickQueue<T> {
+    /// Schedules an update at `world_pos` on tick `due_tick`.
+    ///
+    /// Positions that are already scheduled are ignored.
+    pub fn schedule(&mut self, world_pos: IVec3, data: T, due_tick: u64) {
+        if self.pending.insert(world_pos) {
+            self.scheduled
+                .entry(due_tick)
+                .or_default()
+                .push((world_pos, data));
+        }
+    }
+
+    /// Removes and returns up to `budget` updates that are due on or before `current_tick`.
+    pub fn pop_due(&mut self, current_tick: u64, budget: usize) -> Vec<(IVec3, T)> {
+        let mut due = Vec::new();
+
+        while due.len() < budget {
+            let Some(mut entry) = self.scheduled.first_entry() else {
+                break;
+            };
+            if *entry.key() > current_tick {
+                break;
+            }
+
+            let bucket = entry.get_mut();
+            let take = (budget - due.len()).min(bucket.len());
+            due.extend(bucket.drain(..take));
+
+            if bucket.is_empty() {
+                entry.remove();
+            }
+        }
+
+        for (pos, _) in due.iter() {
+            self.pending.remove(pos);
+        }
+
+        due
+    }
+
+    /// Whether an update is currently waiting at `world_pos`.
+    pub fn is_scheduled(&self, world_pos: IVec3) -> bool {
+        self.pending.contains(&world_pos)
+    }
+
+    /// Returns the number of positions waiting to update.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Drops every waiting update.
+    pub fn clear(&mut self) {
+        self.scheduled.clear();
+        self.pending.clear();
+    }
+}
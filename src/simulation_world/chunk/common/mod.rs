@@ -1,7 +1,12 @@
 pub mod chunk_scaling;
 pub mod padded_chunk_view;
 pub mod thread_buffer_pool;
+pub mod voxel_access;
 
 pub use chunk_scaling::{downsample_chunk, upsample_chunk};
 pub use padded_chunk_view::{ChunkDataOption, NeighborLODs, PaddedChunk};
 pub use thread_buffer_pool::TOTAL_BUFFER_SIZE;
+pub use voxel_access::{
+    for_each_chunk_touching_voxel, mark_chunk_dirty, BlockChangedEvent, VoxelBatch, VoxelReader,
+    WorldVoxels,
+};
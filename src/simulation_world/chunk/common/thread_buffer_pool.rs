@@ -1,12 +1,21 @@
 use super::padded_chunk_view::PADDED_SIZE;
 use crate::prelude::*;
+use crate::render_world::types::PackedFace;
 use crate::simulation_world::block::BlockId;
-use std::cell::RefCell;
+use crossbeam::queue::ArrayQueue;
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        LazyLock,
+    },
+};
 
 pub const TOTAL_BUFFER_SIZE: usize = PADDED_SIZE * PADDED_SIZE * PADDED_SIZE;
 
 thread_local! {
     pub static BLOCKID_BUFFER_POOL: RefCell<Vec<Vec<BlockId>>> = RefCell::new(Vec::new());
+    pub static FACE_SCRATCH_POOL: RefCell<Vec<Vec<PackedFace>>> = const { RefCell::new(Vec::new()) };
 }
 
 /// Get a buffer from the thread buffer pool
@@ -37,3 +46,202 @@ pub fn release_buffer(vec: Vec<BlockId>) {
         pool.borrow_mut().push(vec);
     })
 }
+
+// INFO: ----------------------------
+//         face scratch buffers
+// ----------------------------------
+
+/// Scratch buffers each thread keeps around, a mesher holds at most two at once.
+const MAX_SCRATCH_BUFFERS: usize = 4;
+
+/// Get a scratch buffer for collecting faces while meshing.
+///
+/// Scratch buffers grow to fit the biggest mesh the thread has built and never leave
+/// it, the finished faces get copied into a pooled buffer (see `acquire_face_buffer`).
+pub fn acquire_face_scratch() -> Vec<PackedFace> {
+    FACE_SCRATCH_POOL.with(|pool| match pool.borrow_mut().pop() {
+        Some(mut vec) => {
+            vec.clear();
+            vec
+        }
+        None => Vec::with_capacity(1 << 12),
+    })
+}
+
+/// Release a scratch buffer back to the thread's pool.
+pub fn release_face_scratch(vec: Vec<PackedFace>) {
+    FACE_SCRATCH_POOL.with(|pool| {
+        let mut pool = pool.borrow_mut();
+        if pool.len() < MAX_SCRATCH_BUFFERS {
+            pool.push(vec);
+        }
+    })
+}
+
+// INFO: ----------------------------
+//         pooled face buffers
+// ----------------------------------
+
+/// The smallest size class holds `1 << MIN_FACE_CLASS_SHIFT` faces.
+const MIN_FACE_CLASS_SHIFT: u32 = 6;
+/// Size classes go up to 131072 faces, more than a chunk can ever have.
+const FACE_SIZE_CLASSES: usize = 12;
+/// The most idle buffers any size class keeps, small classes hit this first.
+const MAX_IDLE_PER_CLASS: usize = 512;
+/// Bytes a single size class may keep idle, so big classes keep only a few buffers.
+const MAX_IDLE_BYTES_PER_CLASS: usize = 16 << 20;
+/// Bytes the whole pool keeps idle at the default render distance.
+pub const DEFAULT_FACE_POOL_IDLE_BYTES: usize = 64 << 20;
+
+/// Face buffers shared between all threads, bucketed by power of two capacity.
+///
+/// Meshes are built on worker threads but freed on the main thread, so unlike the
+/// scratch buffers these can't live in a thread local. Idle buffers are capped both per
+/// class and in total bytes (see `set_face_buffer_pool_budget`), extra returned buffers
+/// are freed.
+struct FaceBufferPool {
+    classes: [ArrayQueue<Vec<PackedFace>>; FACE_SIZE_CLASSES],
+    allocated: AtomicUsize,
+    reused: AtomicUsize,
+    idle_bytes: AtomicUsize,
+    idle_budget: AtomicUsize,
+}
+
+static FACE_BUFFER_POOL: LazyLock<FaceBufferPool> = LazyLock::new(|| FaceBufferPool {
+    classes: std::array::from_fn(|class| ArrayQueue::new(max_idle_for_class(class))),
+    allocated: AtomicUsize::new(0),
+    reused: AtomicUsize::new(0),
+    idle_bytes: AtomicUsize::new(0),
+    idle_budget: AtomicUsize::new(DEFAULT_FACE_POOL_IDLE_BYTES),
+});
+
+/// A snapshot of how the pooled face buffers are being used.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FaceBufferPoolStats {
+    /// Buffers allocated because their size class had none idle.
+    pub allocated: usize,
+    /// Buffers handed out again after being returned.
+    pub reused: usize,
+    /// Buffers waiting in the pool right now.
+    pub idle: usize,
+    /// Bytes held by the idle buffers.
+    pub idle_bytes: usize,
+}
+
+/// The capacity of a size class.
+#[inline]
+fn face_class_capacity(class: usize) -> usize {
+    1 << (class as u32 + MIN_FACE_CLASS_SHIFT)
+}
+
+/// The bytes one buffer of a size class holds.
+#[inline]
+fn face_class_bytes(class: usize) -> usize {
+    face_class_capacity(class) * size_of::<PackedFace>()
+}
+
+/// How many idle buffers a size class keeps.
+fn max_idle_for_class(class: usize) -> usize {
+    (MAX_IDLE_BYTES_PER_CLASS / face_class_bytes(class)).clamp(2, MAX_IDLE_PER_CLASS)
+}
+
+/// The smallest size class that fits `len` faces, if any does.
+#[inline]
+fn face_class_for_len(len: usize) -> Option<usize> {
+    let shift = len
+        .next_power_of_two()
+        .trailing_zeros()
+        .max(MIN_FACE_CLASS_SHIFT);
+    let class = (shift - MIN_FACE_CLASS_SHIFT) as usize;
+    (class < FACE_SIZE_CLASSES).then_some(class)
+}
+
+/// Get an empty face buffer that can hold at least `len` faces without growing.
+pub fn acquire_face_buffer(len: usize) -> Vec<PackedFace> {
+    let Some(class) = face_class_for_len(len) else {
+        return Vec::with_capacity(len);
+    };
+
+    let pool = &*FACE_BUFFER_POOL;
+    match pool.classes[class].pop() {
+        Some(vec) => {
+            pool.idle_bytes
+                .fetch_sub(face_class_bytes(class), Ordering::Relaxed);
+            pool.reused.fetch_add(1, Ordering::Relaxed);
+            vec
+        }
+        None => {
+            pool.allocated.fetch_add(1, Ordering::Relaxed);
+            Vec::with_capacity(face_class_capacity(class))
+        }
+    }
+}
+
+/// Release a face buffer back to the shared pool.
+///
+/// Buffers that did not come from `acquire_face_buffer` (or grew since) are just freed.
+pub fn release_face_buffer(mut vec: Vec<PackedFace>) {
+    let capacity = vec.capacity();
+    let Some(class) = face_class_for_len(capacity) else {
+        return;
+    };
+    if face_class_capacity(class) != capacity {
+        return;
+    }
+
+    let pool = &*FACE_BUFFER_POOL;
+    let bytes = face_class_bytes(class);
+    let idle_bytes = pool.idle_bytes.fetch_add(bytes, Ordering::Relaxed) + bytes;
+    if idle_bytes > pool.idle_budget.load(Ordering::Relaxed) {
+        pool.idle_bytes.fetch_sub(bytes, Ordering::Relaxed);
+        return;
+    }
+
+    vec.clear();
+    // a full class drops the buffer
+    if pool.classes[class].push(vec).is_err() {
+        pool.idle_bytes.fetch_sub(bytes, Ordering::Relaxed);
+    }
+}
+
+/// Sets how many bytes of idle buffers the pool keeps, freeing any above it.
+///
+/// Largest buffers are freed first, they are the least likely to be reused.
+pub fn set_face_buffer_pool_budget(max_idle_bytes: usize) {
+    let pool = &*FACE_BUFFER_POOL;
+    pool.idle_budget.store(max_idle_bytes, Ordering::Relaxed);
+
+    for class in (0..FACE_SIZE_CLASSES).rev() {
+        while pool.idle_bytes.load(Ordering::Relaxed) > max_idle_bytes {
+            if pool.classes[class].pop().is_none() {
+                break;
+            }
+            pool.idle_bytes
+                .fetch_sub(face_class_bytes(class), Ordering::Relaxed);
+        }
+    }
+}
+
+/// Copies faces into a pooled buffer of the right size class.
+pub fn pooled_face_buffer(faces: &[PackedFace]) -> Vec<PackedFace> {
+    let mut vec = acquire_face_buffer(faces.len());
+    vec.extend_from_slice(faces);
+    vec
+}
+
+/// Current usage of the pooled face buffers.
+pub fn face_buffer_pool_stats() -> FaceBufferPoolStats {
+    let pool = &*FACE_BUFFER_POOL;
+
+    let mut stats = FaceBufferPoolStats {
+        allocated: pool.allocated.load(Ordering::Relaxed),
+        reused: pool.reused.load(Ordering::Relaxed),
+        ..Default::default()
+    };
+    for (class, queue) in pool.classes.iter().enumerate() {
+        stats.idle += queue.len();
+        stats.idle_bytes += queue.len() * face_class_bytes(class);
+    }
+
+    stats
+}
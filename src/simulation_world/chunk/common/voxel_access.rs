@@ -0,0 +1,234 @@
+use crate::prelude::*;
+use crate::simulation_world::block::BlockId;
+use crate::simulation_world::chunk::{
+    ChunkBlocksComponent, ChunkCoord, ChunkMeshDirty, ChunkState, ChunkStateManager, ChunkView,
+    CHUNK_SIDE_LENGTH,
+};
+use bevy_ecs::message::Messages;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemParam;
+use std::collections::HashSet;
+
+/// An event that is sent whenever a single voxel in the world is changed.
+#[derive(Message, Clone, Debug)]
+pub struct BlockChangedEvent {
+    /// The world position of the changed voxel.
+    pub world_pos: IVec3,
+    /// The block that was there before the change.
+    pub old_block: BlockId,
+    /// The block that is there now.
+    pub new_block: BlockId,
+}
+
+/// A system param for reading and writing single voxels by world position.
+///
+/// Only full detail chunks that hold block data can be accessed. Every write
+/// marks the owning chunk (and any neighbor touching the voxel) as `ChunkMeshDirty`
+/// and fires a `BlockChangedEvent`.
+#[derive(SystemParam)]
+pub struct WorldVoxels<'w, 's> {
+    chunk_manager: Res<'w, ChunkStateManager>,
+    chunks: Query<'w, 's, &'static mut ChunkBlocksComponent>,
+    changed_events: MessageWriter<'w, BlockChangedEvent>,
+    commands: Commands<'w, 's>,
+}
+
+impl<'w, 's> WorldVoxels<'w, 's> {
+    /// Gets the block at a world position, if its chunk is available.
+    pub fn get_block(&self, world_pos: IVec3) -> Option<BlockId> {
+        let (chunk_coord, local_pos) = ChunkCoord::world_to_chunk_and_local_pos(world_pos);
+        let entity = self.chunk_manager.get_entity(chunk_coord)?;
+        block_in_chunk(self.chunks.get(entity).ok()?, local_pos)
+    }
+
+    /// Returns the block a chunk is filled with if it is uniform.
+    ///
+    /// Useful for skipping whole chunks (eg all air) without sampling voxels.
+    pub fn chunk_uniform_block(&self, chunk_coord: IVec3) -> Option<BlockId> {
+        let entity = self.chunk_manager.get_entity(chunk_coord)?;
+        self.chunks.get(entity).ok()?.is_uniform()
+    }
+
+    /// Sets the block at a world position, returning the block that was replaced.
+    ///
+    /// Returns `None` (and changes nothing) if the chunk is not available.
+    pub fn set_block(&mut self, world_pos: IVec3, block_id: BlockId) -> Option<BlockId> {
+        let old_block = self.write_block(world_pos, block_id)?;
+
+        if old_block != block_id {
+            for_each_chunk_touching_voxel(world_pos, |chunk_coord| {
+                mark_chunk_dirty(chunk_coord, &self.chunk_manager, &mut self.commands);
+            });
+        }
+
+        Some(old_block)
+    }
+
+    /// Sets the block at a world position WITHOUT marking any chunk as dirty.
+    ///
+    /// Intended for batched edits that collect the touched chunks themselves and
+    /// dirty each of them once afterwards with `mark_chunk_dirty`.
+    pub fn set_block_deferred(&mut self, world_pos: IVec3, block_id: BlockId) -> Option<BlockId> {
+        self.write_block(world_pos, block_id)
+    }
+
+    /// Marks a chunk as dirty so it gets re-meshed.
+    pub fn mark_chunk_dirty(&mut self, chunk_coord: IVec3) {
+        mark_chunk_dirty(chunk_coord, &self.chunk_manager, &mut self.commands);
+    }
+
+    /// Starts a batch of writes that dirties every touched chunk only once.
+    ///
+    /// Call `VoxelBatch::finish` when done, otherwise no chunk gets re-meshed.
+    pub fn batch(&mut self) -> VoxelBatch<'_, 'w, 's> {
+        VoxelBatch {
+            voxels: self,
+            touched_chunks: HashSet::new(),
+            changed_count: 0,
+        }
+    }
+
+    fn write_block(&mut self, world_pos: IVec3, block_id: BlockId) -> Option<BlockId> {
+        let old_block = self.get_block(world_pos)?;
+        if old_block == block_id {
+            return Some(old_block);
+        }
+
+        let (chunk_coord, local_pos) = ChunkCoord::world_to_chunk_and_local_pos(world_pos);
+        let entity = self.chunk_manager.get_entity(chunk_coord)?;
+        let mut chunk_blocks = self.chunks.get_mut(entity).ok()?;
+
+        let mut writer = chunk_blocks.get_writer();
+        writer.set_data(
+            local_pos.x as usize,
+            local_pos.y as usize,
+            local_pos.z as usize,
+            block_id,
+        );
+
+        self.changed_events.write(BlockChangedEvent {
+            world_pos,
+            old_block,
+            new_block: block_id,
+        });
+
+        Some(old_block)
+    }
+}
+
+/// A set of voxel writes whose chunks are marked dirty together at the end.
+///
+/// Large edits (eg filling a region) would otherwise insert `ChunkMeshDirty`
+/// on the same chunk once per voxel.
+pub struct VoxelBatch<'a, 'w, 's> {
+    voxels: &'a mut WorldVoxels<'w, 's>,
+    touched_chunks: HashSet<IVec3>,
+    changed_count: usize,
+}
+
+impl<'a, 'w, 's> VoxelBatch<'a, 'w, 's> {
+    /// Gets the block at a world position, if its chunk is available.
+    pub fn get_block(&self, world_pos: IVec3) -> Option<BlockId> {
+        self.voxels.get_block(world_pos)
+    }
+
+    /// Sets the block at a world position, returning the block that was replaced.
+    pub fn set_block(&mut self, world_pos: IVec3, block_id: BlockId) -> Option<BlockId> {
+        let old_block = self.voxels.set_block_deferred(world_pos, block_id)?;
+
+        if old_block != block_id {
+            self.changed_count += 1;
+            for_each_chunk_touching_voxel(world_pos, |chunk_coord| {
+                self.touched_chunks.insert(chunk_coord);
+            });
+        }
+
+        Some(old_block)
+    }
+
+    /// Marks every chunk touched by the batch as dirty, returning how many voxels changed.
+    pub fn finish(self) -> usize {
+        for chunk_coord in self.touched_chunks {
+            self.voxels.mark_chunk_dirty(chunk_coord);
+        }
+        self.changed_count
+    }
+}
+
+/// A read-only counterpart to `WorldVoxels` for systems that only look at voxels.
+#[derive(SystemParam)]
+pub struct VoxelReader<'w, 's> {
+    chunk_manager: Res<'w, ChunkStateManager>,
+    chunks: Query<'w, 's, &'static ChunkBlocksComponent>,
+}
+
+impl<'w, 's> VoxelReader<'w, 's> {
+    /// Gets the block at a world position, if its chunk is available.
+    pub fn get_block(&self, world_pos: IVec3) -> Option<BlockId> {
+        let (chunk_coord, local_pos) = ChunkCoord::world_to_chunk_and_local_pos(world_pos);
+        let entity = self.chunk_manager.get_entity(chunk_coord)?;
+        block_in_chunk(self.chunks.get(entity).ok()?, local_pos)
+    }
+}
+
+/// Reads a voxel from a chunk, only if the chunk is at full detail.
+#[inline(always)]
+fn block_in_chunk(chunk_blocks: &ChunkBlocksComponent, local_pos: IVec3) -> Option<BlockId> {
+    if chunk_blocks.size() != CHUNK_SIDE_LENGTH {
+        return None;
+    }
+
+    Some(match chunk_blocks.get_view() {
+        ChunkView::Uniform(block_id) => block_id,
+        ChunkView::Dense(volume_view) => volume_view.get_data(
+            local_pos.x as usize,
+            local_pos.y as usize,
+            local_pos.z as usize,
+        ),
+    })
+}
+
+/// Calls `f` with the coordinate of every chunk whose mesh depends on a voxel.
+///
+/// That is the chunk owning the voxel plus any neighbor sharing the edge it sits on.
+pub fn for_each_chunk_touching_voxel(world_pos: IVec3, mut f: impl FnMut(IVec3)) {
+    let (chunk_coord, local_pos) = ChunkCoord::world_to_chunk_and_local_pos(world_pos);
+    let max_idx = (CHUNK_SIDE_LENGTH - 1) as i32;
+
+    f(chunk_coord);
+
+    for axis in [IVec3::X, IVec3::Y, IVec3::Z] {
+        let local = local_pos.dot(axis);
+        if local == 0 {
+            f(chunk_coord - axis);
+        } else if local == max_idx {
+            f(chunk_coord + axis);
+        }
+    }
+}
+
+/// Marks a chunk as `ChunkMeshDirty` if it has a mesh (or one in progress).
+///
+/// Chunks that are still generating or waiting outside render distance are left
+/// alone, they will mesh with the updated data once they get there.
+pub fn mark_chunk_dirty(
+    chunk_coord: IVec3,
+    chunk_manager: &ChunkStateManager,
+    commands: &mut Commands,
+) {
+    if let Some(
+        ChunkState::Loaded {
+            entity: Some(entity),
+        }
+        | ChunkState::WantsMeshing { entity }
+        | ChunkState::Meshing { entity },
+    ) = chunk_manager.get_state(chunk_coord)
+    {
+        commands.entity(entity).insert(ChunkMeshDirty);
+    }
+}
+
+/// Swaps the `BlockChangedEvent` buffers once per tick so old events get dropped.
+pub fn update_block_changed_events_system(mut events: ResMut<Messages<BlockChangedEvent>>) {
+    events.update();
+}
@@ -0,0 +1,357 @@
+use super::{common::*, OpaqueMeshData, TransparentMeshData};
+use crate::prelude::*;
+use crate::simulation_world::{
+    block::{block_registry::AIR_BLOCK_ID, BlockId, BlockRegistryResource, FluidState},
+    chunk::{
+        common::padded_chunk_view::PADDED_SIZE, thread_buffer_pool::acquire_face_scratch,
+        PaddedChunk, CHUNK_SIDE_LENGTH,
+    },
+};
+
+/// One bit per padded voxel, stored as columns along each axis.
+///
+/// `axes[0][y][z]` holds x bits, `axes[1][x][z]` holds y bits and `axes[2][x][y]` holds
+/// z bits, all in padded coordinates. The padded side length (34) fits in a u64.
+struct OccupancyColumns {
+    axes: Box<[[[u64; PADDED_SIZE]; PADDED_SIZE]; 3]>,
+}
+
+impl OccupancyColumns {
+    fn new() -> Self {
+        Self {
+            axes: Box::new([[[0; PADDED_SIZE]; PADDED_SIZE]; 3]),
+        }
+    }
+
+    /// Marks a voxel given in padded coordinates.
+    #[inline(always)]
+    fn set(&mut self, px: usize, py: usize, pz: usize) {
+        self.axes[0][py][pz] |= 1 << px;
+        self.axes[1][px][pz] |= 1 << py;
+        self.axes[2][px][py] |= 1 << pz;
+    }
+}
+
+/// Voxels of transparent blocks that cull faces between each other.
+struct TransparentGroup {
+    kind: TransparentKind,
+    occupancy: OccupancyColumns,
+    /// Fluid voxels without the same fluid above them, only built for fluids.
+    surface: Option<OccupancyColumns>,
+}
+
+#[derive(Clone, Copy)]
+enum TransparentKind {
+    /// A single transparent block, culled against itself.
+    Block(BlockId),
+    /// Every variant of a fluid.
+    Fluid,
+}
+
+/// Mesher that culls faces with bitwise operations on occupancy columns.
+///
+/// Produces the same faces as the dense mesher (in a different order) but skips the
+/// per-voxel neighbor lookups for everything except fluid faces, which need the
+/// neighbor's surface height.
+#[instrument(skip_all)]
+pub fn build_binary_mesh(
+    name: &str,
+    padded_chunk: &PaddedChunk,
+    block_registry: &BlockRegistryResource,
+) -> (Option<OpaqueMeshData>, Option<TransparentMeshData>) {
+    let mut opaque_faces = acquire_face_scratch();
+    let mut transparent_faces = acquire_face_scratch();
+
+    let ctx = MesherContext {
+        padded_chunk,
+        block_registry,
+        center_lod: padded_chunk.center_lod(),
+        neighbor_lods: padded_chunk.neighbor_lods(),
+        chunk_size: padded_chunk.get_size(),
+        scale: (CHUNK_SIDE_LENGTH / padded_chunk.get_size()) as f32,
+    };
+
+    let transparency_lut = block_registry.get_transparency_lut();
+    let texture_lut = block_registry.get_texture_lut();
+    let fluid_lut = block_registry.get_fluid_lut();
+
+    let size = ctx.chunk_size;
+
+    // INFO: ------------------------------
+    //         build occupancy columns
+    // ------------------------------------
+
+    let group_lut = build_group_lut(transparency_lut, fluid_lut);
+    let mut groups: Vec<Option<TransparentGroup>> = Vec::new();
+    groups.resize_with(group_lut.len(), || None);
+
+    let mut solid = OccupancyColumns::new();
+    for px in 0..size + 2 {
+        for pz in 0..size + 2 {
+            for py in 0..size + 2 {
+                let id = padded_chunk.get_block(px as i32 - 1, py as i32 - 1, pz as i32 - 1);
+                if id == AIR_BLOCK_ID {
+                    continue;
+                }
+
+                if !transparency_lut[id as usize] {
+                    solid.set(px, py, pz);
+                }
+
+                if let Some(group_index) = group_lut[id as usize] {
+                    groups[group_index]
+                        .get_or_insert_with(|| TransparentGroup {
+                            kind: match fluid_lut[id as usize] {
+                                Some(_) => TransparentKind::Fluid,
+                                None => TransparentKind::Block(id),
+                            },
+                            occupancy: OccupancyColumns::new(),
+                            surface: None,
+                        })
+                        .occupancy
+                        .set(px, py, pz);
+                }
+            }
+        }
+    }
+
+    let mut groups: Vec<TransparentGroup> = groups.into_iter().flatten().collect();
+    for group in &mut groups {
+        if let TransparentKind::Fluid = group.kind {
+            group.surface = Some(build_fluid_surface(&group.occupancy));
+        }
+    }
+
+    // INFO: ---------------------
+    //         cull faces
+    // ---------------------------
+
+    let interior = ((1u64 << size) - 1) << 1;
+
+    for &face_side in &FaceSide::ALL {
+        let face_i = face_side as usize;
+        let (axis, positive) = face_axis(face_side);
+        // moves each voxel's neighbor on this side onto the voxel's own bit
+        let neighbor = |column: u64| if positive { column >> 1 } else { column << 1 };
+
+        for i in 1..=size {
+            for j in 1..=size {
+                let solid_column = solid.axes[axis][i][j];
+                let neighbor_solid = neighbor(solid_column);
+
+                // opaque blocks facing anything transparent
+                let mut bits = solid_column & !neighbor_solid & interior;
+                while bits != 0 {
+                    let pos = column_position(axis, i, j, bits.trailing_zeros() as usize);
+                    bits &= bits - 1;
+
+                    let block_id = padded_chunk.get_block(pos.x, pos.y, pos.z);
+                    // fluids that happen to be opaque are meshed with their group
+                    if fluid_lut[block_id as usize].is_some() {
+                        continue;
+                    }
+
+                    let tex_id = texture_lut[block_id as usize][face_i];
+                    let ao = calculate_ao_levels_for_face(
+                        pos,
+                        face_side,
+                        padded_chunk,
+                        transparency_lut,
+                    );
+                    ctx.push_face(face_side, pos, tex_id, ao, &mut opaque_faces);
+                }
+
+                for group in &groups {
+                    let column = group.occupancy.axes[axis][i][j];
+                    if column == 0 {
+                        continue;
+                    }
+                    let neighbor_same = neighbor(column);
+
+                    match group.kind {
+                        // transparent blocks facing transparent blocks of another type
+                        TransparentKind::Block(block_id) => {
+                            let mut bits = column & !neighbor_solid & !neighbor_same & interior;
+                            let tex_id = texture_lut[block_id as usize][face_i];
+                            while bits != 0 {
+                                let pos =
+                                    column_position(axis, i, j, bits.trailing_zeros() as usize);
+                                bits &= bits - 1;
+
+                                ctx.push_transparent_face(
+                                    face_side,
+                                    pos,
+                                    tex_id,
+                                    0,
+                                    &mut transparent_faces,
+                                );
+                            }
+                        }
+                        // fluids only get candidates here, their surface heights decide the rest
+                        TransparentKind::Fluid => {
+                            let candidates = match face_side {
+                                FaceSide::Top => column & !neighbor_same,
+                                FaceSide::Bottom => column & !neighbor_same & !neighbor_solid,
+                                _ => {
+                                    let surface = group.surface.as_ref().unwrap();
+                                    let neighbor_surface = neighbor(surface.axes[axis][i][j]);
+                                    column
+                                        & ((neighbor_same & neighbor_surface)
+                                            | (!neighbor_same & !neighbor_solid))
+                                }
+                            };
+
+                            let mut bits = candidates & interior;
+                            while bits != 0 {
+                                let pos =
+                                    column_position(axis, i, j, bits.trailing_zeros() as usize);
+                                bits &= bits - 1;
+
+                                let block_id = padded_chunk.get_block(pos.x, pos.y, pos.z);
+                                let fluid = fluid_lut[block_id as usize].unwrap();
+                                if let Some(drop) = fluid_face_drop(
+                                    pos,
+                                    face_side,
+                                    block_id,
+                                    fluid,
+                                    padded_chunk,
+                                    transparency_lut,
+                                    fluid_lut,
+                                ) {
+                                    let tex_id = texture_lut[block_id as usize][face_i];
+                                    ctx.push_transparent_face(
+                                        face_side,
+                                        pos,
+                                        tex_id,
+                                        drop,
+                                        &mut transparent_faces,
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    build_mesh_assets(name, opaque_faces, transparent_faces)
+}
+
+/// Maps each block ID to the transparent group it culls with, if any.
+///
+/// Every transparent block gets its own group, and all variants of a fluid share one.
+fn build_group_lut(
+    transparency_lut: &[bool],
+    fluid_lut: &[Option<FluidState>],
+) -> Vec<Option<usize>> {
+    let mut group_lut = vec![None; transparency_lut.len()];
+    let mut fluid_groups: Vec<(BlockId, usize)> = Vec::new();
+    let mut next_group = 0;
+
+    for id in 0..transparency_lut.len() {
+        if id == AIR_BLOCK_ID as usize {
+            continue;
+        }
+
+        group_lut[id] = match fluid_lut[id] {
+            Some(fluid) => match fluid_groups
+                .iter()
+                .find(|(source_id, _)| *source_id == fluid.source_id)
+            {
+                Some(&(_, group)) => Some(group),
+                None => {
+                    fluid_groups.push((fluid.source_id, next_group));
+                    next_group += 1;
+                    Some(next_group - 1)
+                }
+            },
+            None if transparency_lut[id] => {
+                next_group += 1;
+                Some(next_group - 1)
+            }
+            None => None,
+        };
+    }
+
+    group_lut
+}
+
+/// Finds the fluid voxels that do not have the same fluid above them.
+///
+/// Only these can have a lowered surface, so only these can show side faces to the
+/// same fluid.
+fn build_fluid_surface(occupancy: &OccupancyColumns) -> OccupancyColumns {
+    let mut surface = OccupancyColumns::new();
+
+    for px in 0..PADDED_SIZE {
+        for pz in 0..PADDED_SIZE {
+            let column = occupancy.axes[1][px][pz];
+            let mut bits = column & !(column >> 1);
+            while bits != 0 {
+                surface.set(px, bits.trailing_zeros() as usize, pz);
+                bits &= bits - 1;
+            }
+        }
+    }
+
+    surface
+}
+
+/// The surface drop of a fluid face if it should be rendered, same as the dense mesher.
+#[inline(always)]
+fn fluid_face_drop(
+    pos: IVec3,
+    face_side: FaceSide,
+    block_id: BlockId,
+    fluid: FluidState,
+    padded_chunk: &PaddedChunk,
+    transparency_lut: &[bool],
+    fluid_lut: &[Option<FluidState>],
+) -> Option<u8> {
+    let drop = fluid_surface_drop(pos, fluid, padded_chunk, fluid_lut);
+
+    let neighbor_pos = pos + NEIGHBOR_OFFSETS[face_side as usize];
+    let neighbor_id = padded_chunk.get_block(neighbor_pos.x, neighbor_pos.y, neighbor_pos.z);
+    let neighbor_fluid = fluid_lut[neighbor_id as usize].map(|n| {
+        (
+            n,
+            fluid_surface_drop(neighbor_pos, n, padded_chunk, fluid_lut),
+        )
+    });
+
+    should_render_fluid_face(
+        face_side,
+        block_id,
+        fluid,
+        drop,
+        neighbor_id,
+        transparency_lut[neighbor_id as usize],
+        neighbor_fluid,
+    )
+    .then_some(drop)
+}
+
+/// The column axis a face side looks along, and whether it looks towards positive.
+#[inline(always)]
+fn face_axis(face_side: FaceSide) -> (usize, bool) {
+    match face_side {
+        FaceSide::Right => (0, true),
+        FaceSide::Left => (0, false),
+        FaceSide::Top => (1, true),
+        FaceSide::Bottom => (1, false),
+        FaceSide::Front => (2, true),
+        FaceSide::Back => (2, false),
+    }
+}
+
+/// Converts a padded column index and bit back to a chunk-local block position.
+#[inline(always)]
+fn column_position(axis: usize, i: usize, j: usize, bit: usize) -> IVec3 {
+    let (i, j, bit) = (i as i32 - 1, j as i32 - 1, bit as i32 - 1);
+    match axis {
+        0 => IVec3::new(bit, i, j),
+        1 => IVec3::new(i, bit, j),
+        _ => IVec3::new(i, j, bit),
+    }
+}
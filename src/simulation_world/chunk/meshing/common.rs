@@ -1,8 +1,12 @@
 use super::{OpaqueMeshData, TransparentMeshData};
 use crate::render_world::types::PackedFace;
 use crate::simulation_world::{
-    block::{BlockId, BlockRegistryResource},
-    chunk::{types::ChunkLod, NeighborLODs, PaddedChunk},
+    block::{BlockId, BlockRegistryResource, FluidState},
+    chunk::{
+        thread_buffer_pool::{pooled_face_buffer, release_face_scratch},
+        types::ChunkLod,
+        NeighborLODs, PaddedChunk,
+    },
 };
 use crate::{prelude::*, render_world::textures::registry::TextureId};
 
@@ -114,6 +118,20 @@ pub enum FaceSide {
     Back = 5,   // -Z
 }
 
+impl From<u8> for FaceSide {
+    #[inline(always)]
+    fn from(v: u8) -> Self {
+        match v {
+            0 => FaceSide::Top,
+            1 => FaceSide::Bottom,
+            2 => FaceSide::Right,
+            3 => FaceSide::Left,
+            4 => FaceSide::Front,
+            _ => FaceSide::Back,
+        }
+    }
+}
+
 impl FaceSide {
     /// All sides of a cube to be iterated over
     pub const ALL: [FaceSide; 6] = [
@@ -125,6 +143,18 @@ impl FaceSide {
         FaceSide::Back,
     ];
 
+    /// The axes a face on this side stretches along with its width and height.
+    ///
+    /// Matches `EXTENT_WIDTH_AXES` and `EXTENT_HEIGHT_AXES` in the vertex shader.
+    #[inline]
+    pub fn extent_axes(&self) -> (IVec3, IVec3) {
+        match self {
+            FaceSide::Top | FaceSide::Bottom => (IVec3::X, IVec3::Z),
+            FaceSide::Right | FaceSide::Left => (IVec3::Z, IVec3::Y),
+            FaceSide::Front | FaceSide::Back => (IVec3::X, IVec3::Y),
+        }
+    }
+
     #[inline]
     pub fn to_vec3(&self) -> [i32; 3] {
         match self {
@@ -157,6 +187,50 @@ pub fn should_render_face(
     }
 }
 
+/// Determine if a face of a fluid block should be rendered.
+///
+/// Faces between two variants of the same fluid are only rendered on the sides,
+/// and only where the neighbor's surface sits lower. A lowered top surface is
+/// always visible since there is a gap above it.
+#[inline(always)]
+pub fn should_render_fluid_face(
+    face_side: FaceSide,
+    current_id: BlockId,
+    current: FluidState,
+    current_drop: u8,
+    neighbor_id: BlockId,
+    neighbor_transparent: bool,
+    neighbor: Option<(FluidState, u8)>,
+) -> bool {
+    match neighbor {
+        Some((neighbor_fluid, neighbor_drop)) if neighbor_fluid.is_same_fluid(&current) => {
+            match face_side {
+                FaceSide::Top | FaceSide::Bottom => false,
+                _ => neighbor_drop > current_drop,
+            }
+        }
+        _ if face_side == FaceSide::Top && current_drop > 0 => true,
+        _ => should_render_face(current_id, true, neighbor_id, neighbor_transparent),
+    }
+}
+
+/// Gets how far below a full block the surface of a fluid voxel sits (in eighths).
+///
+/// Fluid with more of the same fluid on top of it is always full height.
+#[inline(always)]
+pub fn fluid_surface_drop(
+    pos: IVec3,
+    fluid: FluidState,
+    padded_chunk: &PaddedChunk,
+    fluid_lut: &[Option<FluidState>],
+) -> u8 {
+    let above_id = padded_chunk.get_block(pos.x, pos.y + 1, pos.z);
+    match fluid_lut[above_id as usize] {
+        Some(above) if above.is_same_fluid(&fluid) => 0,
+        _ => fluid.surface_drop(),
+    }
+}
+
 /// Get the AO value (0-3) for a single vertex.
 #[inline(always)]
 pub fn get_ao(
@@ -231,7 +305,10 @@ pub fn calculate_ao_levels_for_face(
     ]
 }
 
-/// Builds the mesh assets given chunk vertices
+/// Builds the mesh assets given chunk faces.
+///
+/// The faces are copied into pooled buffers and the scratch buffers they were
+/// collected in go back to the thread's pool.
 #[instrument(skip_all)]
 pub fn build_mesh_assets(
     name: &str,
@@ -241,7 +318,8 @@ pub fn build_mesh_assets(
     let opaque = if !opaque_faces.is_empty() {
         Some(OpaqueMeshData {
             name: name.to_string(),
-            faces: opaque_faces,
+            faces: pooled_face_buffer(&opaque_faces),
+            extents: Vec::new(),
         })
     } else {
         None
@@ -249,11 +327,16 @@ pub fn build_mesh_assets(
     let trans = if !transparent_faces.is_empty() {
         Some(TransparentMeshData {
             name: format!("{}_trans", name),
-            faces: transparent_faces,
+            faces: pooled_face_buffer(&transparent_faces),
+            extents: Vec::new(),
         })
     } else {
         None
     };
+
+    release_face_scratch(opaque_faces);
+    release_face_scratch(transparent_faces);
+
     (opaque, trans)
 }
 
@@ -287,4 +370,25 @@ impl<'a> MesherContext<'a> {
 
         out_faces.push(face);
     }
+
+    #[inline(always)]
+    pub fn push_transparent_face(
+        &self,
+        face_side: FaceSide,
+        block_pos: IVec3,
+        tex_id: TextureId,
+        surface_drop: u8,
+        out_faces: &mut Vec<PackedFace>,
+    ) {
+        let face = PackedFace::new_transparent(
+            block_pos.x as u32,
+            block_pos.y as u32,
+            block_pos.z as u32,
+            face_side,
+            surface_drop,
+            tex_id,
+        );
+
+        out_faces.push(face);
+    }
 }
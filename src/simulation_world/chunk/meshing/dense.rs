@@ -2,7 +2,7 @@ use super::{common::*, OpaqueMeshData, TransparentMeshData};
 use crate::prelude::*;
 use crate::simulation_world::{
     block::{block_registry::AIR_BLOCK_ID, BlockRegistryResource},
-    chunk::{PaddedChunk, CHUNK_SIDE_LENGTH},
+    chunk::{thread_buffer_pool::acquire_face_scratch, PaddedChunk, CHUNK_SIDE_LENGTH},
 };
 
 /// Standard mesher for dense, mixed-block chunks.
@@ -12,10 +12,8 @@ pub fn build_dense_mesh(
     padded_chunk: &PaddedChunk,
     block_registry: &BlockRegistryResource,
 ) -> (Option<OpaqueMeshData>, Option<TransparentMeshData>) {
-    // TODO: using a buffer pool is probably better than this alloc guesswork
-    // even though we ultimately need a personal copy of the data at the end
-    let mut opaque_faces = Vec::with_capacity(20_000);
-    let mut transparent_faces = Vec::with_capacity(5_000);
+    let mut opaque_faces = acquire_face_scratch();
+    let mut transparent_faces = acquire_face_scratch();
 
     let ctx = MesherContext {
         padded_chunk,
@@ -28,6 +26,7 @@ pub fn build_dense_mesh(
 
     let transparency_lut = block_registry.get_transparency_lut();
     let texture_lut = block_registry.get_texture_lut();
+    let fluid_lut = block_registry.get_fluid_lut();
 
     let size = ctx.chunk_size;
 
@@ -43,11 +42,43 @@ pub fn build_dense_mesh(
 
                 let is_current_transparent = transparency_lut[current_block_id as usize];
 
-                let faces = if is_current_transparent {
-                    &mut transparent_faces
-                } else {
-                    &mut opaque_faces
-                };
+                // fluids have their own face rules and partial heights
+                if let Some(fluid) = fluid_lut[current_block_id as usize] {
+                    let drop = fluid_surface_drop(pos, fluid, padded_chunk, fluid_lut);
+
+                    for &face_side in &FaceSide::ALL {
+                        let face_i = face_side as usize;
+                        let neighbor_pos = pos + NEIGHBOR_OFFSETS[face_i];
+                        let neighbor_id =
+                            padded_chunk.get_block(neighbor_pos.x, neighbor_pos.y, neighbor_pos.z);
+                        let neighbor_fluid = fluid_lut[neighbor_id as usize].map(|n| {
+                            (
+                                n,
+                                fluid_surface_drop(neighbor_pos, n, padded_chunk, fluid_lut),
+                            )
+                        });
+
+                        if should_render_fluid_face(
+                            face_side,
+                            current_block_id,
+                            fluid,
+                            drop,
+                            neighbor_id,
+                            transparency_lut[neighbor_id as usize],
+                            neighbor_fluid,
+                        ) {
+                            let tex_id = texture_lut[current_block_id as usize][face_i];
+                            ctx.push_transparent_face(
+                                face_side,
+                                pos,
+                                tex_id,
+                                drop,
+                                &mut transparent_faces,
+                            );
+                        }
+                    }
+                    continue;
+                }
 
                 // iterate each face checking and generating face verts
                 for &face_side in &FaceSide::ALL {
@@ -68,14 +99,24 @@ pub fn build_dense_mesh(
                     ) {
                         let tex_id = texture_lut[current_block_id as usize][face_i];
 
-                        let ao = calculate_ao_levels_for_face(
-                            pos,
-                            face_side,
-                            padded_chunk,
-                            transparency_lut,
-                        );
+                        if is_current_transparent {
+                            ctx.push_transparent_face(
+                                face_side,
+                                pos,
+                                tex_id,
+                                0,
+                                &mut transparent_faces,
+                            );
+                        } else {
+                            let ao = calculate_ao_levels_for_face(
+                                pos,
+                                face_side,
+                                padded_chunk,
+                                transparency_lut,
+                            );
 
-                        ctx.push_face(face_side, pos, tex_id, ao, faces);
+                            ctx.push_face(face_side, pos, tex_id, ao, &mut opaque_faces);
+                        }
                     }
                 }
             }
@@ -0,0 +1,132 @@
+use super::common::FaceSide;
+use crate::prelude::*;
+use crate::render_world::types::{FaceExtent, PackedFace};
+use crate::simulation_world::chunk::{thread_buffer_pool::acquire_face_scratch, CHUNK_SIDE_LENGTH};
+use std::cell::RefCell;
+
+const SIZE: usize = CHUNK_SIDE_LENGTH;
+
+thread_local! {
+    /// Every block position of a side, holding the index of its face plus one (0 is empty).
+    ///
+    /// Merging consumes every slot it fills, so the grid is all zeros between calls.
+    static MERGE_GRID: RefCell<Vec<u32>> = RefCell::new(vec![0; SIZE * SIZE * SIZE]);
+}
+
+/// Merges coplanar neighboring faces that look the same into larger quads.
+///
+/// Opaque faces are merged when they share a texture and all 4 of their AO corners
+/// are equal, so shading matches the unmerged mesh exactly. Transparent faces are
+/// merged when they share a texture and surface drop, but faces with a lowered
+/// top edge are never merged vertically, since only their top row would be lowered.
+///
+/// The merged faces come back in a face scratch buffer, alongside the extent of each
+/// one (`FaceExtent::UNIT` for faces that weren't merged).
+#[instrument(skip_all)]
+pub fn merge_coplanar_faces(
+    faces: &[PackedFace],
+    transparent: bool,
+) -> (Vec<PackedFace>, Vec<FaceExtent>) {
+    MERGE_GRID.with(|grid| merge_with_grid(faces, transparent, &mut grid.borrow_mut()))
+}
+
+fn merge_with_grid(
+    faces: &[PackedFace],
+    transparent: bool,
+    grid: &mut [u32],
+) -> (Vec<PackedFace>, Vec<FaceExtent>) {
+    let mut merged = acquire_face_scratch();
+    let mut extents = Vec::with_capacity(faces.len());
+
+    for side in FaceSide::ALL {
+        let normal_axis = IVec3::from_array(side.to_vec3()).abs();
+        let (width_axis, height_axis) = side.extent_axes();
+
+        // plane coordinates of a face as (layer, u, v)
+        let to_plane = |face: &PackedFace| {
+            let pos = face.unpack().position.as_ivec3();
+            (
+                pos.dot(normal_axis) as usize,
+                pos.dot(width_axis) as usize,
+                pos.dot(height_axis) as usize,
+            )
+        };
+        let index = |layer: usize, u: usize, v: usize| (layer * SIZE + v) * SIZE + u;
+
+        // INFO: -------------------------------
+        //         fill the side's grid
+        // -------------------------------------
+
+        let mut any_faces = false;
+        for (face_index, face) in faces.iter().enumerate() {
+            let unpacked = face.unpack();
+            if unpacked.normal != side {
+                continue;
+            }
+
+            let uniform_ao = unpacked
+                .ao_levels
+                .iter()
+                .all(|&ao| ao == unpacked.ao_levels[0]);
+            if !transparent && !uniform_ao {
+                merged.push(*face);
+                extents.push(FaceExtent::UNIT);
+                continue;
+            }
+
+            let (layer, u, v) = to_plane(face);
+            grid[index(layer, u, v)] = face_index as u32 + 1;
+            any_faces = true;
+        }
+        if !any_faces {
+            continue;
+        }
+
+        // INFO: -------------------------
+        //         merge each layer
+        // -------------------------------
+
+        // faces merge when everything but their position matches
+        let key = |slot: u32| faces[slot as usize - 1].with_position(0, 0, 0);
+
+        for layer in 0..SIZE {
+            for v in 0..SIZE {
+                for u in 0..SIZE {
+                    let slot = grid[index(layer, u, v)];
+                    if slot == 0 {
+                        continue;
+                    }
+                    let face = faces[slot as usize - 1];
+                    let face_key = key(slot);
+                    let matches = |slot: u32| slot != 0 && key(slot) == face_key;
+
+                    let mut width = 1;
+                    while u + width < SIZE && matches(grid[index(layer, u + width, v)]) {
+                        width += 1;
+                    }
+
+                    let can_grow_vertically =
+                        !(transparent && height_axis == IVec3::Y && face.unpack().surface_drop > 0);
+                    let mut height = 1;
+                    while can_grow_vertically
+                        && v + height < SIZE
+                        && (u..u + width).all(|u| matches(grid[index(layer, u, v + height)]))
+                    {
+                        height += 1;
+                    }
+
+                    for v in v..v + height {
+                        grid[index(layer, u, v)..index(layer, u + width, v)].fill(0);
+                    }
+
+                    let (layer, u, v) = (layer as i32, u as i32, v as i32);
+                    let pos = normal_axis * layer + width_axis * u + height_axis * v;
+                    merged.push(face.with_position(pos.x as u32, pos.y as u32, pos.z as u32));
+                    extents.push(FaceExtent::new(width as u32, height as u32));
+                }
+            }
+        }
+    }
+
+    (merged, extents)
+}
@@ -2,7 +2,7 @@ use super::{common::*, OpaqueMeshData, TransparentMeshData};
 use crate::prelude::*;
 use crate::simulation_world::{
     block::{block_registry::BlockId, BlockRegistryResource},
-    chunk::{PaddedChunk, CHUNK_SIDE_LENGTH},
+    chunk::{thread_buffer_pool::acquire_face_scratch, PaddedChunk, CHUNK_SIDE_LENGTH},
 };
 
 /// Optimized mesher for uniform solid chunks.
@@ -15,7 +15,7 @@ pub fn build_hull_mesh(
     block_registry: &BlockRegistryResource,
     block_id: BlockId,
 ) -> (Option<OpaqueMeshData>, Option<TransparentMeshData>) {
-    let mut faces = Vec::with_capacity(4096);
+    let mut faces = acquire_face_scratch();
 
     let ctx = MesherContext {
         padded_chunk,
@@ -29,7 +29,10 @@ pub fn build_hull_mesh(
     let transparency_lut = block_registry.get_transparency_lut();
     let texture_lut = block_registry.get_texture_lut();
 
+    let fluid_lut = block_registry.get_fluid_lut();
+
     let is_trans = transparency_lut[block_id as usize];
+    let fluid = fluid_lut[block_id as usize];
     let size = ctx.chunk_size;
 
     macro_rules! mesh_plane {
@@ -56,15 +59,51 @@ pub fn build_hull_mesh(
 
                         let is_neighbor_trans = transparency_lut[neighbor_id as usize];
 
-                        if should_render_face(block_id, is_trans, neighbor_id, is_neighbor_trans) {
-                            let ao = calculate_ao_levels_for_face(
-                                pos,
-                                FaceSide::ALL[$face_idx],
-                                ctx.padded_chunk,
-                                transparency_lut,
-                            );
+                        let face_side = FaceSide::ALL[$face_idx];
+
+                        if let Some(fluid) = fluid {
+                            let drop = fluid_surface_drop(pos, fluid, ctx.padded_chunk, fluid_lut);
+                            let neighbor_fluid = fluid_lut[neighbor_id as usize].map(|n| {
+                                (
+                                    n,
+                                    fluid_surface_drop(
+                                        neighbor_pos,
+                                        n,
+                                        ctx.padded_chunk,
+                                        fluid_lut,
+                                    ),
+                                )
+                            });
+
+                            if should_render_fluid_face(
+                                face_side,
+                                block_id,
+                                fluid,
+                                drop,
+                                neighbor_id,
+                                is_neighbor_trans,
+                                neighbor_fluid,
+                            ) {
+                                ctx.push_transparent_face(face_side, pos, tex_id, drop, &mut faces);
+                            }
+                        } else if should_render_face(
+                            block_id,
+                            is_trans,
+                            neighbor_id,
+                            is_neighbor_trans,
+                        ) {
+                            if is_trans {
+                                ctx.push_transparent_face(face_side, pos, tex_id, 0, &mut faces);
+                            } else {
+                                let ao = calculate_ao_levels_for_face(
+                                    pos,
+                                    face_side,
+                                    ctx.padded_chunk,
+                                    transparency_lut,
+                                );
 
-                            ctx.push_face(FaceSide::ALL[$face_idx], pos, tex_id, ao, &mut faces);
+                                ctx.push_face(face_side, pos, tex_id, ao, &mut faces);
+                            }
                         }
                     }
                 }
@@ -86,9 +125,9 @@ pub fn build_hull_mesh(
     mesh_plane!(5, 0..size, 0..size, |x, y| IVec3::new(x as i32, y as i32, 0));
 
     let (opaque_faces, transparent_faces) = if is_trans {
-        (Vec::new(), faces)
+        (acquire_face_scratch(), faces)
     } else {
-        (faces, Vec::new())
+        (faces, acquire_face_scratch())
     };
 
     build_mesh_assets(name, opaque_faces, transparent_faces)
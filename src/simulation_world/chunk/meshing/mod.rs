@@ -1,6 +1,9 @@
+pub mod binary;
 pub mod common;
 pub mod dense;
+pub mod greedy;
 pub mod hull;
+pub mod single_block;
 
 // INFO: --------------------------------
 //         public mesh entrypoint
@@ -13,19 +16,102 @@ use crate::simulation_world::{
         block_registry::{BlockId, AIR_BLOCK_ID},
         BlockRegistryResource,
     },
-    chunk::PaddedChunk,
+    chunk::{
+        thread_buffer_pool::{pooled_face_buffer, release_face_buffer, release_face_scratch},
+        PaddedChunk,
+    },
 };
+use bevy_ecs::prelude::Resource;
 
 // convenience mesh types
 pub type OpaqueMeshData = MeshAsset;
 pub type TransparentMeshData = MeshAsset;
 
+/// The ways a chunk can be meshed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChunkMesher {
+    /// One face per visible voxel face.
+    #[default]
+    Dense,
+    /// The same faces as `Dense`, culled with bitwise operations on occupancy columns.
+    Binary,
+    /// Like `Binary`, with coplanar faces that look the same merged into larger quads.
+    Greedy,
+}
+
+impl ChunkMesher {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChunkMesher::Dense => "dense",
+            ChunkMesher::Binary => "binary",
+            ChunkMesher::Greedy => "greedy",
+        }
+    }
+
+    /// The next mesher, for cycling through them.
+    pub fn next(&self) -> Self {
+        match self {
+            ChunkMesher::Dense => ChunkMesher::Binary,
+            ChunkMesher::Binary => ChunkMesher::Greedy,
+            ChunkMesher::Greedy => ChunkMesher::Dense,
+        }
+    }
+}
+
+impl std::str::FromStr for ChunkMesher {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dense" => Ok(ChunkMesher::Dense),
+            "binary" => Ok(ChunkMesher::Binary),
+            "greedy" => Ok(ChunkMesher::Greedy),
+            other => Err(format!(
+                "Unknown mesher '{other}' (expected dense, binary or greedy)"
+            )),
+        }
+    }
+}
+
+/// The mesher used for every new chunk mesh.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActiveChunkMesher(pub ChunkMesher);
+
 /// Main chunk meshing entry point: Build a mesh for a single chunk.
 #[instrument(skip_all, fields(chunk = %name))]
 pub fn build_chunk_mesh(
     name: &str,
     padded_chunk: &PaddedChunk,
     block_registry: &BlockRegistryResource,
+    mesher: ChunkMesher,
+) -> (Option<OpaqueMeshData>, Option<TransparentMeshData>) {
+    let (opaque, transparent) = build_voxel_faces(name, padded_chunk, block_registry, mesher);
+
+    match mesher {
+        ChunkMesher::Dense | ChunkMesher::Binary => (opaque, transparent),
+        ChunkMesher::Greedy => (
+            opaque.map(|mesh| merge_mesh_faces(mesh, false)),
+            transparent.map(|mesh| merge_mesh_faces(mesh, true)),
+        ),
+    }
+}
+
+/// Swaps a mesh's faces for their greedy merged version, keeping both buffers pooled.
+fn merge_mesh_faces(mut mesh: MeshAsset, transparent: bool) -> MeshAsset {
+    let (merged, extents) = greedy::merge_coplanar_faces(&mesh.faces, transparent);
+    let faces = std::mem::replace(&mut mesh.faces, pooled_face_buffer(&merged));
+    release_face_buffer(faces);
+    release_face_scratch(merged);
+    mesh.extents = extents;
+    mesh
+}
+
+/// Builds one face per visible voxel face, with the hull mesher for uniform chunks.
+fn build_voxel_faces(
+    name: &str,
+    padded_chunk: &PaddedChunk,
+    block_registry: &BlockRegistryResource,
+    mesher: ChunkMesher,
 ) -> (Option<OpaqueMeshData>, Option<TransparentMeshData>) {
     match padded_chunk.get_center_uniform_block() {
         Some(block_id) => {
@@ -38,7 +124,12 @@ pub fn build_chunk_mesh(
             hull::build_hull_mesh(name, padded_chunk, block_registry, block_id)
         }
         // otherwise do a full dense mesh
-        None => dense::build_dense_mesh(name, padded_chunk, block_registry),
+        None => match mesher {
+            ChunkMesher::Dense => dense::build_dense_mesh(name, padded_chunk, block_registry),
+            ChunkMesher::Binary | ChunkMesher::Greedy => {
+                binary::build_binary_mesh(name, padded_chunk, block_registry)
+            }
+        },
     }
 }
 
@@ -0,0 +1,43 @@
+use super::{common::*, OpaqueMeshData, TransparentMeshData};
+use crate::prelude::*;
+use crate::render_world::types::PackedFace;
+use crate::simulation_world::{
+    block::{block_registry::BlockId, BlockRegistryResource},
+    chunk::thread_buffer_pool::acquire_face_scratch,
+};
+
+/// Builds a mesh for a single free-standing block sitting at the mesh origin.
+///
+/// Used for blocks that live outside of a chunk (eg falling blocks), so every
+/// face is emitted and nothing is occluded.
+#[instrument(skip_all)]
+pub fn build_single_block_mesh(
+    name: &str,
+    block_id: BlockId,
+    block_registry: &BlockRegistryResource,
+) -> (Option<OpaqueMeshData>, Option<TransparentMeshData>) {
+    let mut opaque_faces = acquire_face_scratch();
+    let mut transparent_faces = acquire_face_scratch();
+
+    let textures = block_registry.get_texture_lut()[block_id as usize];
+    let is_transparent = block_registry.get_render_data(block_id).is_transparent;
+
+    for &face_side in &FaceSide::ALL {
+        let tex_id = textures[face_side as usize];
+
+        if is_transparent {
+            transparent_faces.push(PackedFace::new_transparent(0, 0, 0, face_side, 0, tex_id));
+        } else {
+            opaque_faces.push(PackedFace::new(
+                0,
+                0,
+                0,
+                face_side,
+                [AoLevel::None; 4],
+                tex_id,
+            ));
+        }
+    }
+
+    build_mesh_assets(name, opaque_faces, transparent_faces)
+}
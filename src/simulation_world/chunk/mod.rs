@@ -17,20 +17,38 @@ pub use types::*;
 // ------------------------------------
 
 use crate::{
-    ecs_core::{EcsBuilder, Plugin},
-    simulation_world::{player::ActiveCamera, scheduling::FixedUpdateSet, SimulationSchedule},
+    ecs_core::{
+        state_machine::{in_state, GameState},
+        AppConfig, EcsBuilder, Plugin,
+    },
+    simulation_world::{
+        input::{types::SimulationAction, ActionStateResource},
+        player::ActiveCamera,
+        scheduling::FixedUpdateSet,
+        SimulationSchedule,
+    },
     SimulationSet,
 };
-use bevy_ecs::prelude::*;
+use bevy_ecs::{message::Messages, prelude::*};
 
 pub struct ChunkLoadingPlugin;
 
 impl Plugin for ChunkLoadingPlugin {
     fn build(&self, builder: &mut EcsBuilder) {
-        builder.add_resource(ChunkStateManager::default());
+        builder
+            .add_resource(ChunkStateManager::default())
+            .init_resource::<RenderDistance>()
+            .init_resource::<ActiveChunkMesher>()
+            .init_resource::<Messages<BlockChangedEvent>>();
 
         builder
             .schedule_entry(SimulationSchedule::Main)
+            .add_systems(
+                apply_render_distance_setting_system
+                    .run_if(resource_exists_and_changed::<AppConfig>)
+                    .before(manage_distance_based_chunk_loading_targets_system)
+                    .in_set(SimulationSet::PreUpdate),
+            )
             .add_systems(
                 (manage_distance_based_chunk_loading_targets_system)
                     .run_if(
@@ -38,11 +56,27 @@ impl Plugin for ChunkLoadingPlugin {
                             q.get(camera.0).is_ok()
                         },
                     )
+                    .run_if(not(in_state(GameState::Paused)))
                     .in_set(SimulationSet::PreUpdate),
+            )
+            .add_systems(
+                cycle_active_chunk_mesher_system
+                    .run_if(|action_state: Res<ActionStateResource>| {
+                        action_state.just_happened(SimulationAction::CycleChunkMesher)
+                    })
+                    .in_set(SimulationSet::Update),
+            )
+            .add_systems(
+                update_face_pool_budget_system
+                    .run_if(resource_changed::<RenderDistance>)
+                    .in_set(SimulationSet::Update),
             );
 
         builder
             .schedule_entry(SimulationSchedule::FixedUpdate)
+            .add_systems(
+                voxel_access::update_block_changed_events_system.in_set(FixedUpdateSet::PreUpdate),
+            )
             .add_systems(
                 (
                     handle_dirty_chunks_system,
@@ -51,6 +85,8 @@ impl Plugin for ChunkLoadingPlugin {
                     start_pending_meshing_tasks_system,
                     poll_chunk_meshing_tasks,
                 )
+                    // chained so each step sees the components inserted by the one before it
+                    .chain()
                     .in_set(FixedUpdateSet::MainLogic),
             );
     }
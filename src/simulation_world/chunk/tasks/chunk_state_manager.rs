@@ -15,7 +15,7 @@ pub enum ChunkState {
     ///
     /// This exists so that chunks can remain stagnant and hold data without
     /// being queued for meshing. Important for the "generation buffer" ring
-    /// that extends past the mesh render distance (`LOAD_DISTANCE` const).
+    /// that extends past the mesh render distance (`RenderDistance::load_distance`).
     DataReady { entity: Entity },
     /// Entity is awaiting a mesh slot
     WantsMeshing { entity: Entity },
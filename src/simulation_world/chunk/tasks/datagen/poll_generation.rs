@@ -1,6 +1,6 @@
 use crate::prelude::*;
 use crate::simulation_world::chunk::{
-    CheckForMeshing, ChunkGenerationTaskComponent, ChunkState, WantsMeshing, RENDER_DISTANCE,
+    CheckForMeshing, ChunkGenerationTaskComponent, ChunkState, RenderDistance, WantsMeshing,
     WORLD_MAX_Y_CHUNK, WORLD_MIN_Y_CHUNK,
 };
 use crate::simulation_world::chunk::{ChunkCoord, ChunkStateManager};
@@ -9,15 +9,19 @@ use bevy_ecs::prelude::*;
 use crossbeam::channel::TryRecvError;
 
 /// Assesses whether a chunk coordinate is within the meshing radius of the camera.
-pub fn chunk_is_in_mesh_radius(camera_chunk_pos: IVec3, chunk_coord: IVec3) -> bool {
+pub fn chunk_is_in_mesh_radius(
+    camera_chunk_pos: IVec3,
+    chunk_coord: IVec3,
+    render_distance: RenderDistance,
+) -> bool {
     let dx = chunk_coord.x - camera_chunk_pos.x;
     let dy = chunk_coord.y;
     let dz = chunk_coord.z - camera_chunk_pos.z;
 
-    dx.abs() <= RENDER_DISTANCE
+    dx.abs() <= render_distance.0
         && dy >= WORLD_MIN_Y_CHUNK
         && dy <= WORLD_MAX_Y_CHUNK
-        && dz.abs() <= RENDER_DISTANCE
+        && dz.abs() <= render_distance.0
 }
 
 /// Polls chunk generation tasks, adds generated components, and marks chunks as
@@ -28,6 +32,7 @@ pub fn poll_chunk_generation_tasks(
     mut tasks_query: Query<(Entity, &mut ChunkGenerationTaskComponent, &ChunkCoord)>,
     active_camera: Res<ActiveCamera>, // to gauge if chunk is in meshing range
     camera_query: Query<&ChunkCoord>,
+    render_distance: Res<RenderDistance>,
 
     // Output
     mut commands: Commands,
@@ -45,7 +50,8 @@ pub fn poll_chunk_generation_tasks(
                         if let Some(chunk_blocks) = gen_bundle.chunk_blocks {
                             let mut is_in_mesh_radius = false;
                             if let Ok(cam_pos) = camera_chunk_pos {
-                                is_in_mesh_radius = chunk_is_in_mesh_radius(cam_pos, coord.pos);
+                                is_in_mesh_radius =
+                                    chunk_is_in_mesh_radius(cam_pos, coord.pos, *render_distance);
                             }
 
                             if is_in_mesh_radius {
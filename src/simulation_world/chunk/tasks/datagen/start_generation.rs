@@ -1,18 +1,12 @@
 use crate::prelude::*;
-use crate::simulation_world::chunk::{
-    ChunkBlocksComponent, ChunkGenerationTaskComponent, ChunkState, NeedsGenerating,
-};
-use crate::simulation_world::terrain::generators::{
-    biome::BiomeResultBuilder, painting::PaintResultBuilder, shaping::ShapeResultBuilder,
-};
-use crate::simulation_world::terrain::{generators::shaping::ChunkUniformity, BiomeMapComponent};
+use crate::simulation_world::chunk::{ChunkGenerationTaskComponent, ChunkState, NeedsGenerating};
+use crate::simulation_world::terrain::generators::shaping::ChunkUniformity;
 use crate::simulation_world::{
     biome::BiomeRegistryResource,
     block::BlockRegistryResource,
-    chunk::{components::GeneratedChunkComponentBundle, ChunkCoord, ChunkStateManager},
-    terrain::{
-        ActiveBiomeGenerator, ActiveClimateGenerator, ActiveTerrainGenerator, ActiveTerrainPainter,
-    },
+    chunk::{ChunkCoord, ChunkLod, ChunkStateManager},
+    terrain::{ActiveTerrainPipeline, TerrainPipeline},
+    world_save::{SavedChunk, WorldSaveResource},
 };
 use bevy_ecs::prelude::*;
 use crossbeam::channel::unbounded;
@@ -31,10 +25,8 @@ pub fn start_pending_generation_tasks_system(
     mut chunk_manager: ResMut<ChunkStateManager>,
     block_registry: Res<BlockRegistryResource>,
     biome_registry: Res<BiomeRegistryResource>,
-    biome_generator: Res<ActiveBiomeGenerator>,
-    terrain_generator: Res<ActiveTerrainGenerator>,
-    terrain_painter: Res<ActiveTerrainPainter>,
-    climate_generator: Res<ActiveClimateGenerator>,
+    active_pipeline: ActiveTerrainPipeline,
+    world_save: Option<Res<WorldSaveResource>>,
 ) {
     for (entity, needs_generating, coord) in pending_chunks_query.iter_mut() {
         // check for cancellation
@@ -55,9 +47,52 @@ pub fn start_pending_generation_tasks_system(
         }
 
         let lod = needs_generating.lod;
+        let pipeline = active_pipeline.get();
+
+        // saved chunks are read on a worker thread, generating any the save is missing
+        if let Some(world_save) = world_save.as_ref() {
+            let (sender, receiver) = unbounded();
+
+            let world_save = world_save.0.clone();
+            let blocks_clone = block_registry.clone();
+            let biomes_clone = biome_registry.clone();
+            let coord_clone = coord.clone();
+
+            rayon::spawn(move || {
+                // saves only hold full detail chunks
+                let saved = match world_save.read_chunk(coord_clone.pos) {
+                    Ok(saved) => saved.filter(|_| lod == ChunkLod(0)),
+                    Err(e) => {
+                        warn!(
+                            target: "chunk_loading",
+                            "Failed to load chunk {} from the world save: {}. Regenerating it.",
+                            coord_clone, e
+                        );
+                        None
+                    }
+                };
+
+                let bundle = match saved {
+                    Some(saved) => saved.into_bundle(lod),
+                    None => pipeline
+                        .generate_chunk(coord_clone.clone(), lod, &blocks_clone, &biomes_clone)
+                        .unwrap_or_else(|| SavedChunk::Empty.into_bundle(lod)),
+                };
+
+                let _ = sender.send(bundle);
+            });
+
+            commands
+                .entity(entity)
+                .insert(ChunkGenerationTaskComponent { receiver })
+                .remove::<NeedsGenerating>();
+
+            chunk_manager.mark_as_generating(coord.pos, entity);
+            continue;
+        }
 
         // check if the chunk is empty according to the terrain generator
-        match terrain_generator.0.determine_chunk_uniformity(coord.pos) {
+        match pipeline.shaper.determine_chunk_uniformity(coord.pos) {
             ChunkUniformity::Empty => {
                 trace!(
                     target: "chunk_loading",
@@ -69,17 +104,9 @@ pub fn start_pending_generation_tasks_system(
                 continue;
             }
             ChunkUniformity::Solid => {
-                let chunk_blocks = ChunkBlocksComponent::new_uniform_solid(lod);
-
-                let bundle = GeneratedChunkComponentBundle {
-                    chunk_blocks: Some(chunk_blocks),
-                    chunk_metadata: None,
-                    biome_map: BiomeMapComponent::new_empty(lod),
-                };
-
                 // instant completed task
                 let (sender, receiver) = unbounded();
-                let _ = sender.send(bundle);
+                let _ = sender.send(TerrainPipeline::solid_chunk(lod));
 
                 commands
                     .entity(entity)
@@ -97,43 +124,15 @@ pub fn start_pending_generation_tasks_system(
 
         let blocks_clone = block_registry.clone();
         let biomes_clone = biome_registry.clone();
-        let terrain_gen = terrain_generator.0.clone();
-        let biome_gen = biome_generator.0.clone();
-        let terrain_paint = terrain_painter.0.clone();
-        let climate_gen = climate_generator.0.clone();
         let coord_clone = coord.clone();
 
         rayon::spawn(move || {
-            // for testing, override the chunk lod
-            // let mut lod = ChunkLod(0);
-            // if coord_clone.z >= 5 && (coord_clone.x == 0 || coord_clone.x == 1) {
-            //     lod = ChunkLod(3);
-            // }
-
-            // INFO: biome gen
-            let climate_map = climate_gen.generate(coord_clone.clone());
-            let biome_map = BiomeMapComponent::new_empty(lod);
-            let biome_builder = BiomeResultBuilder::new(biome_map, coord_clone.clone());
-            let biome_map = biome_gen
-                .generate_biome_chunk(biome_builder, &climate_map, &biomes_clone)
-                .finish();
-
-            // INFO: shaping
-            let chunk_blocks = ChunkBlocksComponent::new_uniform_empty(lod);
-            let shaper = ShapeResultBuilder::new(chunk_blocks, coord_clone.clone());
-            let shaped_chunk_blocks = terrain_gen
-                .shape_terrain_chunk(&climate_map, shaper)
-                .finish();
-
-            // INFO: painting
-            let painter_builder = PaintResultBuilder::new(
-                shaped_chunk_blocks,
+            let bundle = pipeline.generate_mixed_chunk(
                 coord_clone.clone(),
-                blocks_clone.clone(),
+                lod,
+                &blocks_clone,
+                &biomes_clone,
             );
-            let (painted_chunk_blocks, chunk_metadata) = terrain_paint
-                .paint_terrain_chunk(painter_builder, &biome_map, &blocks_clone, &biomes_clone)
-                .finish();
 
             trace!(
                 target: "chunk_loading",
@@ -141,11 +140,6 @@ pub fn start_pending_generation_tasks_system(
                 coord_clone
             );
 
-            let bundle = GeneratedChunkComponentBundle {
-                chunk_blocks: Some(painted_chunk_blocks),
-                chunk_metadata: Some(chunk_metadata),
-                biome_map,
-            };
             let _ = sender.send(bundle);
         });
 
@@ -1,9 +1,9 @@
 use crate::prelude::*;
 use crate::simulation_world::chunk::{
     CheckForMeshing, ChunkCoord, ChunkLod, ChunkState, ChunkStateManager, NeedsGenerating,
-    WantsMeshing, RENDER_DISTANCE,
+    RenderDistance, WantsMeshing,
 };
-use crate::simulation_world::chunk::{LOAD_DISTANCE, WORLD_MAX_Y_CHUNK, WORLD_MIN_Y_CHUNK};
+use crate::simulation_world::chunk::{WORLD_MAX_Y_CHUNK, WORLD_MIN_Y_CHUNK};
 use crate::simulation_world::player::ActiveCamera;
 use bevy_ecs::prelude::*;
 use glam::IVec3;
@@ -17,24 +17,27 @@ pub fn manage_distance_based_chunk_loading_targets_system(
     // Input
     active_camera: Res<ActiveCamera>,
     camera_query: Query<&ChunkCoord>,
+    render_distance: Res<RenderDistance>,
 
     // Output
     mut chunk_manager: ResMut<ChunkStateManager>, // for marking loaded/unloaded
     mut commands: Commands,                       // for spawning chunk entities
 ) {
     let camera_chunk_pos = camera_query.get(active_camera.0).unwrap().pos;
+    let mesh_distance = render_distance.0;
+    let load_distance = render_distance.load_distance();
 
     // desired chunks based on camera location for loading or meshing
     let mut desired_load_chunks = HashSet::new();
     let mut desired_mesh_chunks = HashSet::new();
 
     for y in WORLD_MIN_Y_CHUNK..=WORLD_MAX_Y_CHUNK {
-        for z in -LOAD_DISTANCE..=LOAD_DISTANCE {
-            for x in -LOAD_DISTANCE..=LOAD_DISTANCE {
+        for z in -load_distance..=load_distance {
+            for x in -load_distance..=load_distance {
                 let coord = IVec3::new(camera_chunk_pos.x + x, y, camera_chunk_pos.z + z);
                 desired_load_chunks.insert(coord);
             }
-            for x in -RENDER_DISTANCE..=RENDER_DISTANCE {
+            for x in -mesh_distance..=mesh_distance {
                 let coord = IVec3::new(camera_chunk_pos.x + x, y, camera_chunk_pos.z + z);
                 desired_mesh_chunks.insert(coord);
             }
@@ -117,3 +120,28 @@ pub fn manage_distance_based_chunk_loading_targets_system(
         }
     }
 }
+
+/// Unloads every chunk, so they all get loaded again around the camera (eg after the
+/// generator or world save changed).
+#[instrument(skip_all)]
+pub fn unload_all_chunks(world: &mut World) {
+    let entities: Vec<Entity> = world
+        .resource_mut::<ChunkStateManager>()
+        .chunk_states
+        .drain()
+        .filter_map(|(_, state)| state.entity())
+        .collect();
+    info!("Unloading all {} chunks...", entities.len());
+
+    for entity in entities {
+        if let Ok(entity) = world.get_entity_mut(entity) {
+            entity.despawn();
+        }
+    }
+
+    // loading targets are only picked when the camera changes chunk
+    let camera_entity = world.resource::<ActiveCamera>().0;
+    if let Some(mut chunk_coord) = world.get_mut::<ChunkCoord>(camera_entity) {
+        chunk_coord.set_changed();
+    }
+}
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use crate::simulation_world::chunk::{
+    ActiveChunkMesher, ChunkMeshDirty, OpaqueMeshComponent, TransparentMeshComponent,
+};
+use bevy_ecs::prelude::*;
+
+/// Chunks with at least one mesh.
+type WithChunkMesh = Or<(With<OpaqueMeshComponent>, With<TransparentMeshComponent>)>;
+
+/// Switches to the other chunk mesher and remeshes every meshed chunk with it.
+#[instrument(skip_all)]
+pub fn cycle_active_chunk_mesher_system(
+    // Input
+    meshed_chunks: Query<Entity, WithChunkMesh>,
+
+    // Output
+    mut active_mesher: ResMut<ActiveChunkMesher>,
+    mut commands: Commands,
+) {
+    active_mesher.0 = active_mesher.0.next();
+
+    let mut remeshed = 0;
+    for entity in meshed_chunks.iter() {
+        commands.entity(entity).insert(ChunkMeshDirty);
+        remeshed += 1;
+    }
+
+    info!(
+        target: "chunk_loading",
+        "Switched to the {} mesher, remeshing {} chunks",
+        active_mesher.0.name(),
+        remeshed
+    );
+}
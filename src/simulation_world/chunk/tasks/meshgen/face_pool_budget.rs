@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use crate::simulation_world::chunk::{
+    thread_buffer_pool::{set_face_buffer_pool_budget, DEFAULT_FACE_POOL_IDLE_BYTES},
+    RenderDistance, RENDER_DISTANCE,
+};
+use bevy_ecs::prelude::*;
+
+/// Scales the idle face buffer budget with the number of meshed chunk columns.
+///
+/// Dropping the render distance frees the idle buffers the smaller area won't need.
+#[instrument(skip_all)]
+pub fn update_face_pool_budget_system(
+    // Input
+    render_distance: Res<RenderDistance>,
+) {
+    let columns = |distance: i32| (2 * distance.max(0) as usize + 1).pow(2);
+    let budget =
+        DEFAULT_FACE_POOL_IDLE_BYTES * columns(render_distance.0) / columns(RENDER_DISTANCE);
+
+    debug!(
+        target: "memory",
+        "Face buffer pool budget set to {:.1} MiB for render distance {}",
+        budget as f64 / (1024.0 * 1024.0),
+        render_distance.0
+    );
+    set_face_buffer_pool_budget(budget);
+}
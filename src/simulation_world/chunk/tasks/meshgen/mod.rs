@@ -1,10 +1,14 @@
 #[path = "components.rs"]
 pub mod meshtask_components;
 
+pub mod cycle_mesher;
+pub mod face_pool_budget;
 pub mod poll_meshing;
 pub mod start_meshing;
 
 pub use meshtask_components::{CheckForMeshing, ChunkMeshingTaskComponent, WantsMeshing};
 
+pub use cycle_mesher::cycle_active_chunk_mesher_system;
+pub use face_pool_budget::update_face_pool_budget_system;
 pub use poll_meshing::poll_chunk_meshing_tasks;
 pub use start_meshing::{handle_dirty_chunks_system, start_pending_meshing_tasks_system};
@@ -79,7 +79,7 @@ pub fn poll_chunk_meshing_tasks(
                                 commands
                                     .entity(entity)
                                     .remove::<ChunkMeshingTaskComponent>();
-                                chunk_manager.mark_as_loaded(coord.pos, entity);
+                                finish_meshing(&mut chunk_manager, current_state, coord, entity);
                                 continue; // continue to avoid adding transform component
                             }
                         }
@@ -98,7 +98,7 @@ pub fn poll_chunk_meshing_tasks(
                             })
                             .remove::<ChunkMeshingTaskComponent>();
 
-                        chunk_manager.mark_as_loaded(coord.pos, entity);
+                        finish_meshing(&mut chunk_manager, current_state, coord, entity);
                     }
                     Some(_) => {
                         error!(
@@ -140,3 +140,19 @@ pub fn poll_chunk_meshing_tasks(
         }
     }
 }
+
+/// Marks a chunk as loaded once its mesh is applied, unless it was marked dirty
+/// again while meshing, in which case it stays queued for another pass.
+fn finish_meshing(
+    chunk_manager: &mut ChunkStateManager,
+    state_at_poll: Option<ChunkState>,
+    coord: &ChunkCoord,
+    entity: Entity,
+) {
+    match state_at_poll {
+        Some(ChunkState::WantsMeshing { .. }) => {
+            chunk_manager.mark_as_needs_meshing(coord.pos, entity)
+        }
+        _ => chunk_manager.mark_as_loaded(coord.pos, entity),
+    }
+}
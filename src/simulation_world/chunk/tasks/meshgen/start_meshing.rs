@@ -10,7 +10,9 @@ use crate::simulation_world::chunk::{
 };
 use crate::simulation_world::{
     block::BlockRegistryResource,
-    chunk::{build_chunk_mesh, ChunkBlocksComponent, ChunkCoord, ChunkStateManager},
+    chunk::{
+        build_chunk_mesh, ActiveChunkMesher, ChunkBlocksComponent, ChunkCoord, ChunkStateManager,
+    },
 };
 use bevy_ecs::prelude::*;
 use crossbeam::channel::unbounded;
@@ -58,6 +60,7 @@ pub fn start_pending_meshing_tasks_system(
     mut commands: Commands,
     mut chunk_manager: ResMut<ChunkStateManager>,
     block_registry: Res<BlockRegistryResource>,
+    active_mesher: Res<ActiveChunkMesher>,
 ) {
     'chunk_loop: for (entity, chunk_comp, chunk_coord) in pending_chunks_query.iter_mut() {
         // check for cancellation
@@ -155,6 +158,7 @@ pub fn start_pending_meshing_tasks_system(
 
         let block_registry_clone = block_registry.clone();
         let coord_clone = chunk_coord.clone();
+        let mesher = active_mesher.0;
 
         trace!(target: "chunk_loading", "Starting meshing task for {}.", chunk_coord.pos);
 
@@ -168,6 +172,7 @@ pub fn start_pending_meshing_tasks_system(
                 &coord_clone.to_string(),
                 &padded_view,
                 &block_registry_clone,
+                mesher,
             );
 
             let used_buffer = padded_view.take_buffer();
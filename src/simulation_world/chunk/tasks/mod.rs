@@ -3,9 +3,13 @@ pub mod meshgen;
 
 pub mod chunk_state_manager;
 pub mod manage_load_targets;
+pub mod render_distance;
 
 pub use datagen::*;
 pub use meshgen::*;
 
 pub use chunk_state_manager::{ChunkState, ChunkStateManager};
-pub use manage_load_targets::manage_distance_based_chunk_loading_targets_system;
+pub use manage_load_targets::{
+    manage_distance_based_chunk_loading_targets_system, unload_all_chunks,
+};
+pub use render_distance::{apply_render_distance_setting_system, RenderDistance};
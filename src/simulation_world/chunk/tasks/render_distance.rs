@@ -0,0 +1,48 @@
+use crate::ecs_core::AppConfig;
+use crate::prelude::*;
+use crate::simulation_world::{
+    chunk::{ChunkCoord, LOAD_DISTANCE, RENDER_DISTANCE},
+    player::ActiveCamera,
+};
+use bevy_ecs::prelude::*;
+
+/// The distance in the xz plane, in chunks, to mesh around the camera.
+///
+/// Chunks are loaded (but not meshed) one ring further out than this.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderDistance(pub i32);
+
+impl Default for RenderDistance {
+    fn default() -> Self {
+        Self(RENDER_DISTANCE)
+    }
+}
+
+impl RenderDistance {
+    /// The distance in the xz plane, in chunks, to keep chunk data loaded around the camera.
+    pub fn load_distance(&self) -> i32 {
+        self.0 + (LOAD_DISTANCE - RENDER_DISTANCE)
+    }
+}
+
+/// Takes the render distance from the settings whenever they change.
+///
+/// Also marks the active camera's chunk as changed so the load targets are recomputed
+/// right away rather than once the camera crosses into another chunk.
+#[instrument(skip_all)]
+pub fn apply_render_distance_setting_system(
+    // Input
+    config: Res<AppConfig>,
+    active_camera: Res<ActiveCamera>,
+
+    // Output (render distance and a nudged camera chunk)
+    mut render_distance: ResMut<RenderDistance>,
+    mut chunk_coords: Query<&mut ChunkCoord>,
+) {
+    if render_distance.set_if_neq(RenderDistance(config.render_distance)) {
+        info!("Render distance set to {} chunks.", config.render_distance);
+        if let Ok(mut coord) = chunk_coords.get_mut(active_camera.0) {
+            coord.set_changed();
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::prelude::*;
+use bevy_ecs::prelude::*;
+use std::{collections::BTreeMap, fmt, str::FromStr, sync::Arc};
+
+/// What a command prints back on success.
+pub type ConsoleResult = Result<String, ConsoleError>;
+
+/// The body of a console command. Commands get the whole world, so they can do anything
+/// a system could without having to be scheduled.
+pub type ConsoleCommandFn = dyn Fn(&mut World, &ConsoleArgs) -> ConsoleResult + Send + Sync;
+
+// INFO: ----------------
+//         errors
+// ----------------------
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConsoleError {
+    /// Nothing but whitespace was entered.
+    Empty,
+    UnknownCommand(String),
+    /// An argument is missing or doesn't parse, raised by `ConsoleArgs`.
+    InvalidArgument(String),
+    /// Like `InvalidArgument`, along with how the command should be called.
+    Usage {
+        reason: String,
+        usage: String,
+    },
+    /// The command ran but couldn't do what was asked.
+    Failed(String),
+}
+
+impl fmt::Display for ConsoleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConsoleError::Empty => write!(f, "No command entered"),
+            ConsoleError::UnknownCommand(name) => {
+                write!(f, "Unknown command '{name}', try /help")
+            }
+            ConsoleError::InvalidArgument(reason) => write!(f, "{reason}"),
+            ConsoleError::Usage { reason, usage } => write!(f, "{reason} (usage: {usage})"),
+            ConsoleError::Failed(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ConsoleError {}
+
+// INFO: -------------------
+//         arguments
+// -------------------------
+
+/// The whitespace separated words after a command's name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConsoleArgs {
+    words: Vec<String>,
+}
+
+impl ConsoleArgs {
+    pub fn new(words: Vec<String>) -> Self {
+        Self { words }
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.words.get(index).map(String::as_str)
+    }
+
+    /// The word at `index`, which has to be there.
+    pub fn str(&self, index: usize) -> Result<&str, ConsoleError> {
+        self.get(index)
+            .ok_or_else(|| ConsoleError::InvalidArgument(format!("Missing argument {}", index + 1)))
+    }
+
+    /// The word at `index` parsed as a `T`.
+    pub fn parse<T: FromStr>(&self, index: usize) -> Result<T, ConsoleError> {
+        let word = self.str(index)?;
+        word.parse().map_err(|_| {
+            ConsoleError::InvalidArgument(format!(
+                "'{word}' is not a valid {}",
+                std::any::type_name::<T>()
+            ))
+        })
+    }
+
+    /// Three words starting at `index` as a position.
+    pub fn vec3(&self, index: usize) -> Result<Vec3, ConsoleError> {
+        Ok(Vec3::new(
+            self.parse(index)?,
+            self.parse(index + 1)?,
+            self.parse(index + 2)?,
+        ))
+    }
+
+    /// Three words starting at `index` as a block position.
+    pub fn ivec3(&self, index: usize) -> Result<IVec3, ConsoleError> {
+        Ok(IVec3::new(
+            self.parse(index)?,
+            self.parse(index + 1)?,
+            self.parse(index + 2)?,
+        ))
+    }
+}
+
+// INFO: ------------------
+//         registry
+// ------------------------
+
+/// A named command that can be typed into the console or passed to the headless runner.
+#[derive(Clone)]
+pub struct ConsoleCommand {
+    pub name: &'static str,
+    /// The arguments it takes, e.g. `<x> <y> <z>`.
+    pub usage: &'static str,
+    pub description: &'static str,
+    run: Arc<ConsoleCommandFn>,
+}
+
+impl ConsoleCommand {
+    pub fn new(
+        name: &'static str,
+        usage: &'static str,
+        description: &'static str,
+        run: impl Fn(&mut World, &ConsoleArgs) -> ConsoleResult + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name,
+            usage,
+            description,
+            run: Arc::new(run),
+        }
+    }
+
+    /// How to call the command, e.g. `/tp <x> <y> <z>`.
+    pub fn usage_line(&self) -> String {
+        if self.usage.is_empty() {
+            format!("/{}", self.name)
+        } else {
+            format!("/{} {}", self.name, self.usage)
+        }
+    }
+}
+
+/// Every command plugins registered through `EcsBuilder::add_console_command`.
+#[derive(Resource, Default)]
+pub struct ConsoleCommandRegistry {
+    commands: BTreeMap<&'static str, ConsoleCommand>,
+}
+
+impl ConsoleCommandRegistry {
+    /// Adds a command, replacing any other one with the same name.
+    pub fn register(&mut self, command: ConsoleCommand) {
+        if self.commands.contains_key(command.name) {
+            warn!("Console command /{} registered twice", command.name);
+        }
+        self.commands.insert(command.name, command);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ConsoleCommand> {
+        self.commands.get(name)
+    }
+
+    /// Every command, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = &ConsoleCommand> {
+        self.commands.values()
+    }
+}
+
+// INFO: -----------------
+//         running
+// -----------------------
+
+/// Parses and runs a single command line like `/tp 0 80 0`, the leading slash is optional.
+pub fn run_console_command(world: &mut World, line: &str) -> ConsoleResult {
+    let line = line.trim();
+    let mut words = line.strip_prefix('/').unwrap_or(line).split_whitespace();
+    let name = words.next().ok_or(ConsoleError::Empty)?;
+    let args = ConsoleArgs::new(words.map(str::to_string).collect());
+
+    let command = world
+        .get_resource::<ConsoleCommandRegistry>()
+        .and_then(|registry| registry.get(name))
+        .cloned()
+        .ok_or_else(|| ConsoleError::UnknownCommand(name.to_string()))?;
+
+    (command.run)(world, &args).map_err(|err| match err {
+        ConsoleError::InvalidArgument(reason) => ConsoleError::Usage {
+            reason,
+            usage: command.usage_line(),
+        },
+        err => err,
+    })
+}
@@ -0,0 +1,66 @@
+use crate::simulation_world::console::command::ConsoleResult;
+use bevy_ecs::prelude::*;
+use std::collections::VecDeque;
+
+/// How many lines the console keeps before dropping the oldest.
+pub const CONSOLE_LOG_CAPACITY: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsoleLineKind {
+    /// A command as it was entered.
+    Input,
+    Output,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsoleLine {
+    pub kind: ConsoleLineKind,
+    pub text: String,
+}
+
+/// Everything entered into the console and what came back, oldest first.
+#[derive(Resource, Debug, Default)]
+pub struct ConsoleLog {
+    lines: VecDeque<ConsoleLine>,
+}
+
+impl ConsoleLog {
+    pub fn push(&mut self, kind: ConsoleLineKind, text: impl Into<String>) {
+        // multi-line output gets a line each so the screen can lay them out
+        for text in text.into().lines() {
+            if self.lines.len() == CONSOLE_LOG_CAPACITY {
+                self.lines.pop_front();
+            }
+            self.lines.push_back(ConsoleLine {
+                kind,
+                text: text.to_string(),
+            });
+        }
+    }
+
+    /// Logs a command along with its result.
+    pub fn push_result(&mut self, line: &str, result: &ConsoleResult) {
+        self.push(ConsoleLineKind::Input, line.trim());
+        match result {
+            Ok(output) if output.is_empty() => {}
+            Ok(output) => self.push(ConsoleLineKind::Output, output.as_str()),
+            Err(err) => self.push(ConsoleLineKind::Error, err.to_string()),
+        }
+    }
+
+    /// The last `count` lines, oldest first.
+    pub fn tail(&self, count: usize) -> impl Iterator<Item = &ConsoleLine> {
+        self.lines
+            .iter()
+            .skip(self.lines.len().saturating_sub(count))
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
@@ -0,0 +1,60 @@
+pub mod command;
+pub mod log;
+pub mod run_commands;
+
+pub use command::{
+    run_console_command, ConsoleArgs, ConsoleCommand, ConsoleCommandRegistry, ConsoleError,
+    ConsoleResult,
+};
+pub use log::{ConsoleLine, ConsoleLineKind, ConsoleLog};
+pub use run_commands::{run_console_commands_system, ConsoleCommandMessage};
+
+// INFO: ------------------------
+//         console plugin
+// ------------------------------
+
+use crate::prelude::*;
+use bevy_ecs::{message::Messages, prelude::*};
+
+/// Runs console commands sent as `ConsoleCommandMessage`s.
+///
+/// The commands themselves are registered by the plugins that own what they touch,
+/// through `EcsBuilder::add_console_command`.
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, builder: &mut EcsBuilder) {
+        builder
+            .init_resource::<ConsoleCommandRegistry>()
+            .init_resource::<ConsoleLog>()
+            .init_resource::<Messages<ConsoleCommandMessage>>();
+
+        builder.add_console_command(ConsoleCommand::new(
+            "help",
+            "[command]",
+            "Lists every command, or explains one",
+            help_command,
+        ));
+
+        builder
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(run_console_commands_system.in_set(SimulationSet::PreUpdate));
+    }
+}
+
+fn help_command(world: &mut World, args: &ConsoleArgs) -> ConsoleResult {
+    let registry = world.resource::<ConsoleCommandRegistry>();
+    let describe =
+        |command: &ConsoleCommand| format!("{}  {}", command.usage_line(), command.description);
+
+    match args.get(0) {
+        Some(name) => {
+            let name = name.strip_prefix('/').unwrap_or(name);
+            registry
+                .get(name)
+                .map(describe)
+                .ok_or_else(|| ConsoleError::UnknownCommand(name.to_string()))
+        }
+        None => Ok(registry.iter().map(describe).collect::<Vec<_>>().join("\n")),
+    }
+}
@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use crate::simulation_world::console::{command::run_console_command, log::ConsoleLog};
+use bevy_ecs::{message::Messages, prelude::*};
+
+/// A command line to run at the start of the next frame.
+#[derive(Message, Debug, Clone)]
+pub struct ConsoleCommandMessage {
+    pub line: String,
+}
+
+/// A system that runs every pending `ConsoleCommandMessage` and logs the results.
+///
+/// Exclusive, since commands get the whole world.
+#[instrument(skip_all)]
+pub fn run_console_commands_system(world: &mut World) {
+    let lines: Vec<String> = world
+        .resource_mut::<Messages<ConsoleCommandMessage>>()
+        .drain()
+        .map(|message| message.line)
+        .collect();
+
+    for line in lines {
+        let result = run_console_command(world, &line);
+        match &result {
+            Ok(_) => info!(target: "console", "{}", line.trim()),
+            Err(err) => warn!(target: "console", "{}: {}", line.trim(), err),
+        }
+        world
+            .resource_mut::<ConsoleLog>()
+            .push_result(&line, &result);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::prelude::*;
+use crate::simulation_world::block::BlockId;
+use bevy_ecs::prelude::*;
+use std::collections::VecDeque;
+
+/// Default memory budget of the journal, shared between the undo and redo stacks.
+const DEFAULT_JOURNAL_BUDGET_BYTES: usize = 32 * 1024 * 1024;
+
+/// A single recorded voxel change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoxelEdit {
+    pub world_pos: IVec3,
+    pub old_block: BlockId,
+    pub new_block: BlockId,
+}
+
+/// All voxel changes made by one user action (eg a click or a bulk edit).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EditTransaction {
+    edits: Vec<VoxelEdit>,
+}
+
+impl EditTransaction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a change, ignoring ones that didn't actually change anything.
+    pub fn record(&mut self, world_pos: IVec3, old_block: BlockId, new_block: BlockId) {
+        if old_block != new_block {
+            self.edits.push(VoxelEdit {
+                world_pos,
+                old_block,
+                new_block,
+            });
+        }
+    }
+
+    pub fn edits(&self) -> &[VoxelEdit] {
+        &self.edits
+    }
+
+    pub fn len(&self) -> usize {
+        self.edits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// The transaction that reverts this one (old and new swapped, in reverse order).
+    pub fn inverted(&self) -> Self {
+        Self {
+            edits: self
+                .edits
+                .iter()
+                .rev()
+                .map(|edit| VoxelEdit {
+                    world_pos: edit.world_pos,
+                    old_block: edit.new_block,
+                    new_block: edit.old_block,
+                })
+                .collect(),
+        }
+    }
+
+    fn size_bytes(&self) -> usize {
+        self.edits.len() * std::mem::size_of::<VoxelEdit>()
+    }
+}
+
+/// The undo/redo history of user voxel edits.
+///
+/// Once the recorded edits go over the memory budget the oldest transactions
+/// are forgotten.
+#[derive(Resource, Debug)]
+pub struct EditJournal {
+    undo_stack: VecDeque<EditTransaction>,
+    redo_stack: Vec<EditTransaction>,
+    used_bytes: usize,
+    budget_bytes: usize,
+}
+
+impl Default for EditJournal {
+    fn default() -> Self {
+        Self::with_budget(DEFAULT_JOURNAL_BUDGET_BYTES)
+    }
+}
+
+impl EditJournal {
+    pub fn with_budget(budget_bytes: usize) -> Self {
+        Self {
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            used_bytes: 0,
+            budget_bytes,
+        }
+    }
+
+    /// Records a new transaction, clearing anything that could be redone.
+    pub fn commit(&mut self, transaction: EditTransaction) {
+        if transaction.is_empty() {
+            return;
+        }
+
+        for redo in self.redo_stack.drain(..) {
+            self.used_bytes -= redo.size_bytes();
+        }
+
+        if transaction.size_bytes() > self.budget_bytes {
+            // older history would no longer line up with the world, so drop it all
+            warn!(
+                target: "edit_history",
+                "Edit of {} blocks is too large to undo, clearing the edit history.",
+                transaction.len()
+            );
+            self.clear();
+            return;
+        }
+
+        self.push_undo(transaction);
+    }
+
+    /// Takes the most recent transaction to undo, if there is one.
+    ///
+    /// It should be applied inverted and handed back with `push_redo`.
+    pub fn pop_undo(&mut self) -> Option<EditTransaction> {
+        let transaction = self.undo_stack.pop_back()?;
+        self.used_bytes -= transaction.size_bytes();
+        Some(transaction)
+    }
+
+    /// Takes the most recently undone transaction to redo, if there is one.
+    ///
+    /// It should be re-applied and handed back with `push_undo`.
+    pub fn pop_redo(&mut self) -> Option<EditTransaction> {
+        let transaction = self.redo_stack.pop()?;
+        self.used_bytes -= transaction.size_bytes();
+        Some(transaction)
+    }
+
+    /// Puts a transaction on the undo stack without touching the redo stack.
+    pub fn push_undo(&mut self, transaction: EditTransaction) {
+        self.used_bytes += transaction.size_bytes();
+        self.undo_stack.push_back(transaction);
+        self.enforce_budget();
+    }
+
+    /// Puts an undone transaction on the redo stack.
+    pub fn push_redo(&mut self, transaction: EditTransaction) {
+        self.used_bytes += transaction.size_bytes();
+        self.redo_stack.push(transaction);
+        self.enforce_budget();
+    }
+
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.used_bytes = 0;
+    }
+
+    pub fn undo_len(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    pub fn redo_len(&self) -> usize {
+        self.redo_stack.len()
+    }
+
+    /// Forgets the oldest undo steps until the journal fits its budget.
+    fn enforce_budget(&mut self) {
+        while self.used_bytes > self.budget_bytes {
+            let Some(oldest) = self.undo_stack.pop_front() else {
+                break;
+            };
+            self.used_bytes -= oldest.size_bytes();
+            trace!(target: "edit_history", "Forgot undo step of {} blocks", oldest.len());
+        }
+    }
+}
@@ -0,0 +1,25 @@
+pub mod edit_journal;
+pub mod undo_redo;
+
+pub use edit_journal::{EditJournal, EditTransaction, VoxelEdit};
+pub use undo_redo::{apply_transaction, handle_edit_history_events_system, EditHistoryEvent};
+
+// INFO: ----------------------------
+//         Edit history plugin
+// ----------------------------------
+
+use crate::prelude::*;
+use bevy_ecs::message::Messages;
+use bevy_ecs::prelude::*;
+
+pub struct EditHistoryPlugin;
+
+impl Plugin for EditHistoryPlugin {
+    fn build(&self, builder: &mut EcsBuilder) {
+        builder
+            .init_resource::<EditJournal>()
+            .init_resource::<Messages<EditHistoryEvent>>()
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(handle_edit_history_events_system.in_set(SimulationSet::Update));
+    }
+}
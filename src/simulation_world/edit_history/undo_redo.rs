@@ -0,0 +1,72 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    chunk::WorldVoxels,
+    edit_history::{EditJournal, EditTransaction},
+};
+use bevy_ecs::prelude::*;
+
+/// An event that is sent to step through the edit history.
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditHistoryEvent {
+    Undo,
+    Redo,
+}
+
+/// A system that handles the `EditHistoryEvent`.
+#[instrument(skip_all)]
+pub fn handle_edit_history_events_system(
+    // Input
+    mut events: MessageReader<EditHistoryEvent>,
+
+    // Output
+    mut journal: ResMut<EditJournal>,
+    mut voxels: WorldVoxels,
+) {
+    for event in events.read() {
+        match event {
+            EditHistoryEvent::Undo => {
+                let Some(transaction) = journal.pop_undo() else {
+                    debug!(target: "edit_history", "Nothing to undo.");
+                    continue;
+                };
+                apply_transaction(&transaction.inverted(), &mut voxels);
+                journal.push_redo(transaction);
+            }
+            EditHistoryEvent::Redo => {
+                let Some(transaction) = journal.pop_redo() else {
+                    debug!(target: "edit_history", "Nothing to redo.");
+                    continue;
+                };
+                apply_transaction(&transaction, &mut voxels);
+                journal.push_undo(transaction);
+            }
+        }
+    }
+}
+
+/// Writes every change of a transaction into the world, dirtying each touched chunk once.
+///
+/// A change is only applied where the voxel still holds its `old_block`. Voxels that
+/// something else (fluids, falling blocks, block ticks) changed since are left alone.
+pub fn apply_transaction(transaction: &EditTransaction, voxels: &mut WorldVoxels) {
+    let mut batch = voxels.batch();
+    let mut skipped = 0;
+    for edit in transaction.edits() {
+        if batch.get_block(edit.world_pos) != Some(edit.old_block) {
+            skipped += 1;
+            continue;
+        }
+        batch.set_block(edit.world_pos, edit.new_block);
+    }
+    let changed = batch.finish();
+
+    if skipped > 0 {
+        warn!(
+            target: "edit_history",
+            "Skipped {} of {} recorded changes, the world changed there since.",
+            skipped,
+            transaction.len()
+        );
+    }
+    debug!(target: "edit_history", "Applied {} of {} recorded changes", changed, transaction.len());
+}
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use crate::simulation_world::block::BlockId;
+use bevy_ecs::prelude::*;
+
+/// A block that came loose from the world and is falling as an entity.
+///
+/// The position is stepped every simulation tick, while the entity's
+/// `TransformComponent` is interpolated between ticks for smooth rendering.
+#[derive(Component, Debug, Clone)]
+pub struct FallingBlockComponent {
+    /// The block that gets placed back into the world on landing.
+    pub block: BlockId,
+    /// Downwards speed in blocks per second.
+    pub velocity: f32,
+    /// Position of the block's minimum corner as of the latest tick.
+    pub position: Vec3,
+    /// Position of the block's minimum corner as of the tick before.
+    pub previous_position: Vec3,
+}
+
+impl FallingBlockComponent {
+    pub fn new(block: BlockId, position: Vec3) -> Self {
+        Self {
+            block,
+            velocity: 0.0,
+            position,
+            previous_position: position,
+        }
+    }
+}
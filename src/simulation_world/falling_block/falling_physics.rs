@@ -0,0 +1,128 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    block::BlockRegistryResource,
+    chunk::{TransformComponent, WorldVoxels, CHUNK_SIDE_LENGTH, WORLD_MIN_Y_CHUNK},
+    falling_block::{can_fall_into, FallingBlockComponent},
+    time::{simulation_tick::SimulationTick, FrameClock},
+};
+use bevy_ecs::prelude::*;
+
+/// Downwards acceleration of falling blocks in blocks per second squared.
+const GRAVITY: f32 = 32.0;
+
+/// The max speed a falling block can reach in blocks per second.
+const TERMINAL_VELOCITY: f32 = 40.0;
+
+/// Lowest y coordinate that can hold a block.
+const WORLD_MIN_Y: i32 = WORLD_MIN_Y_CHUNK * CHUNK_SIDE_LENGTH as i32;
+
+/// Steps every falling block forward by one tick and places the ones that land.
+///
+/// Every block boundary crossed during the tick is checked, so fast blocks can't
+/// tunnel through thin floors. A block that reaches an unloaded chunk waits in
+/// place until it loads.
+#[instrument(skip_all)]
+pub fn update_falling_blocks_system(
+    // Input
+    block_registry: Res<BlockRegistryResource>,
+    sim_tick: Res<SimulationTick>,
+
+    // Output
+    mut falling_blocks: Query<(Entity, &mut FallingBlockComponent)>,
+    mut voxels: WorldVoxels,
+    mut commands: Commands,
+) {
+    let dt = sim_tick.tick_duration.as_secs_f32();
+
+    for (entity, mut falling) in falling_blocks.iter_mut() {
+        falling.previous_position = falling.position;
+        falling.velocity = (falling.velocity + GRAVITY * dt).min(TERMINAL_VELOCITY);
+
+        let start_y = falling.position.y;
+        let target_y = start_y - falling.velocity * dt;
+        let (x, z) = (
+            falling.position.x.floor() as i32,
+            falling.position.z.floor() as i32,
+        );
+
+        // walk the block boundaries the bottom face passes this tick, top to bottom
+        let mut resting_y = None;
+        for boundary in ((target_y.ceil() as i32)..=(start_y.floor() as i32)).rev() {
+            let below_pos = IVec3::new(x, boundary - 1, z);
+
+            match voxels.get_block(below_pos) {
+                Some(below) if can_fall_into(below, &block_registry) => continue,
+                Some(_) => {
+                    land(
+                        entity,
+                        &falling,
+                        IVec3::new(x, boundary, z),
+                        &mut voxels,
+                        &mut commands,
+                        &block_registry,
+                    );
+                    resting_y = Some(boundary);
+                    break;
+                }
+                None if below_pos.y < WORLD_MIN_Y => {
+                    trace!(target: "falling_block", "Falling block fell out of the world at {}", below_pos);
+                    commands.entity(entity).despawn();
+                    resting_y = Some(boundary);
+                    break;
+                }
+                None => {
+                    // unloaded below, hover until the chunk is ready
+                    falling.velocity = 0.0;
+                    resting_y = Some(boundary);
+                    break;
+                }
+            }
+        }
+
+        falling.position.y = resting_y.map_or(target_y, |y| y as f32);
+    }
+}
+
+/// Places a falling block back into the world and removes its entity.
+///
+/// If something took its spot in the meantime the block is lost.
+fn land(
+    entity: Entity,
+    falling: &FallingBlockComponent,
+    landing_pos: IVec3,
+    voxels: &mut WorldVoxels,
+    commands: &mut Commands,
+    block_registry: &BlockRegistryResource,
+) {
+    match voxels.get_block(landing_pos) {
+        Some(current) if can_fall_into(current, block_registry) => {
+            voxels.set_block(landing_pos, falling.block);
+            trace!(target: "falling_block", "Block {} landed at {}", falling.block, landing_pos);
+        }
+        _ => {
+            debug!(target: "falling_block", "Block {} couldn't land at {}, dropping it", falling.block, landing_pos);
+        }
+    }
+
+    commands.entity(entity).despawn();
+}
+
+/// Smooths the rendered position of falling blocks between simulation ticks.
+#[instrument(skip_all)]
+pub fn interpolate_falling_blocks_system(
+    // Input
+    frame_clock: Res<FrameClock>,
+
+    // Output
+    mut falling_blocks: Query<(&FallingBlockComponent, &mut TransformComponent)>,
+) {
+    for (falling, mut transform) in falling_blocks.iter_mut() {
+        let position = falling
+            .previous_position
+            .lerp(falling.position, frame_clock.alpha.clamp(0.0, 1.0));
+
+        if transform.position != position {
+            transform.position = position;
+        }
+    }
+}
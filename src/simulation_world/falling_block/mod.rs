@@ -0,0 +1,33 @@
+pub mod falling_block_component;
+pub mod falling_physics;
+pub mod start_falling;
+
+pub use falling_block_component::FallingBlockComponent;
+pub use falling_physics::{interpolate_falling_blocks_system, update_falling_blocks_system};
+pub use start_falling::{can_fall_into, spawn_falling_block, try_start_falling};
+
+// INFO: -----------------------------
+//         Falling block plugin
+// -----------------------------------
+
+use crate::ecs_core::state_machine::{utils::in_state, AppState};
+use crate::prelude::*;
+use bevy_ecs::prelude::*;
+
+pub struct FallingBlockPlugin;
+
+impl Plugin for FallingBlockPlugin {
+    fn build(&self, builder: &mut EcsBuilder) {
+        builder
+            .schedule_entry(SimulationSchedule::FixedUpdate)
+            .add_systems(update_falling_blocks_system.in_set(FixedUpdateSet::MainLogic));
+
+        builder
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(
+                interpolate_falling_blocks_system
+                    .in_set(SimulationSet::PostUpdate)
+                    .run_if(in_state(AppState::Running)),
+            );
+    }
+}
@@ -0,0 +1,75 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    asset_management::{AssetStorageResource, MeshAsset},
+    block::{BlockId, BlockRegistryResource, AIR_BLOCK_ID},
+    block_updates::BlockTickContext,
+    chunk::{
+        meshing::single_block::build_single_block_mesh, OpaqueMeshComponent, TransformComponent,
+        TransparentMeshComponent,
+    },
+    falling_block::FallingBlockComponent,
+};
+use bevy_ecs::prelude::*;
+
+/// Whether a falling block can move into (and later replace) a block.
+#[inline(always)]
+pub fn can_fall_into(block: BlockId, registry: &BlockRegistryResource) -> bool {
+    block == AIR_BLOCK_ID || registry.get_fluid(block).is_some()
+}
+
+/// Turns the block at `world_pos` into a falling entity if nothing is holding it up.
+///
+/// Blocks above an unloaded chunk stay put until it loads.
+pub fn try_start_falling(world_pos: IVec3, block: BlockId, ctx: &mut BlockTickContext) {
+    let Some(below) = ctx.voxels.get_block(world_pos - IVec3::Y) else {
+        return;
+    };
+
+    if !can_fall_into(below, &ctx.block_registry) {
+        return;
+    }
+
+    ctx.voxels.set_block(world_pos, AIR_BLOCK_ID);
+    spawn_falling_block(
+        &mut ctx.commands,
+        &ctx.mesh_assets,
+        &ctx.block_registry,
+        world_pos,
+        block,
+    );
+}
+
+/// Spawns a falling block entity with its own single block mesh at `world_pos`.
+pub fn spawn_falling_block(
+    commands: &mut Commands,
+    mesh_assets: &AssetStorageResource<MeshAsset>,
+    block_registry: &BlockRegistryResource,
+    world_pos: IVec3,
+    block: BlockId,
+) -> Entity {
+    let position = world_pos.as_vec3();
+    let name = format!(
+        "falling_block_{}_{}_{}",
+        world_pos.x, world_pos.y, world_pos.z
+    );
+    let (opaque_asset, transparent_asset) = build_single_block_mesh(&name, block, block_registry);
+
+    let mut entity = commands.spawn((
+        FallingBlockComponent::new(block, position),
+        TransformComponent {
+            position,
+            ..Default::default()
+        },
+    ));
+
+    if let Some(asset) = opaque_asset {
+        entity.insert(OpaqueMeshComponent::new(mesh_assets.add(asset)));
+    }
+    if let Some(asset) = transparent_asset {
+        entity.insert(TransparentMeshComponent::new(mesh_assets.add(asset)));
+    }
+
+    trace!(target: "falling_block", "Block {} at {} started falling", block, world_pos);
+
+    entity.id()
+}
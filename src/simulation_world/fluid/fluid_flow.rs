@@ -0,0 +1,176 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    block::{BlockId, BlockRegistryResource, FluidState, AIR_BLOCK_ID},
+    chunk::{BlockChangedEvent, VoxelReader, WorldVoxels},
+    fluid::FluidTickQueue,
+    time::simulation_tick::SimulationTick,
+};
+use bevy_ecs::prelude::*;
+
+/// Max number of voxels the fluid simulation will update in a single tick.
+///
+/// Anything past this stays queued and is picked up on the following ticks.
+const MAX_FLUID_UPDATES_PER_TICK: usize = 2048;
+
+/// Horizontal directions fluid can spread in.
+const HORIZONTAL_OFFSETS: [IVec3; 4] = [IVec3::X, IVec3::NEG_X, IVec3::Z, IVec3::NEG_Z];
+
+/// Directions a voxel can receive fluid from (above and the 4 sides).
+const INFLOW_OFFSETS: [IVec3; 5] = [IVec3::Y, IVec3::X, IVec3::NEG_X, IVec3::Z, IVec3::NEG_Z];
+
+/// Schedules fluid updates around every voxel that changed since the last tick.
+#[instrument(skip_all)]
+pub fn schedule_fluid_updates_system(
+    // Input
+    mut events: MessageReader<BlockChangedEvent>,
+    voxels: VoxelReader,
+    block_registry: Res<BlockRegistryResource>,
+    sim_tick: Res<SimulationTick>,
+
+    // Output
+    mut queue: ResMut<FluidTickQueue>,
+) {
+    for event in events.read() {
+        let candidates = std::iter::once(event.world_pos)
+            .chain(INFLOW_OFFSETS.iter().map(|&o| event.world_pos + o))
+            .chain(std::iter::once(event.world_pos - IVec3::Y));
+
+        for pos in candidates {
+            let get_block = |p: IVec3| voxels.get_block(p);
+            if let Some(fluid) = fluid_affecting(pos, &get_block, &block_registry) {
+                queue.schedule(pos, (), sim_tick.tick + fluid.properties.tick_delay as u64);
+            }
+        }
+    }
+}
+
+/// Applies all fluid updates that are due this tick.
+#[instrument(skip_all)]
+pub fn tick_fluids_system(
+    // Input
+    block_registry: Res<BlockRegistryResource>,
+    sim_tick: Res<SimulationTick>,
+
+    // Output
+    mut queue: ResMut<FluidTickQueue>,
+    mut voxels: WorldVoxels,
+) {
+    let due = queue.pop_due(sim_tick.tick, MAX_FLUID_UPDATES_PER_TICK);
+    if due.is_empty() {
+        return;
+    }
+
+    // resolve every update against the same snapshot so results don't depend on order
+    let mut changes = Vec::with_capacity(due.len());
+    for (pos, ()) in due {
+        let get_block = |p: IVec3| voxels.get_block(p);
+        if let Some(new_block) = next_fluid_block(pos, &get_block, &block_registry) {
+            changes.push((pos, new_block));
+        }
+    }
+
+    trace!(target: "fluid", "Applying {} fluid changes ({} still queued)", changes.len(), queue.len());
+
+    for (pos, new_block) in changes {
+        voxels.set_block(pos, new_block);
+    }
+}
+
+// INFO: --------------------
+//         flow rules
+// --------------------------
+
+/// Decides what a voxel should become on its next fluid update.
+///
+/// Returns `None` if the voxel should stay as it is. Sources and non-fluid blocks
+/// never change, while air and flowing fluid take on the best inflow from their
+/// neighbors (or dry up into air if there is none).
+pub fn next_fluid_block(
+    pos: IVec3,
+    get_block: &impl Fn(IVec3) -> Option<BlockId>,
+    registry: &BlockRegistryResource,
+) -> Option<BlockId> {
+    let current = get_block(pos)?;
+    let current_fluid = registry.get_fluid(current);
+
+    match current_fluid {
+        Some(fluid) if fluid.is_source() => return None,
+        None if current != AIR_BLOCK_ID => return None,
+        _ => {}
+    }
+
+    let desired = inflow(pos, get_block, registry).unwrap_or(AIR_BLOCK_ID);
+    (desired != current).then_some(desired)
+}
+
+/// Finds the fluid variant flowing into a voxel from its neighbors, if any.
+fn inflow(
+    pos: IVec3,
+    get_block: &impl Fn(IVec3) -> Option<BlockId>,
+    registry: &BlockRegistryResource,
+) -> Option<BlockId> {
+    // fluid falling from above always wins and restarts the flow distance
+    if let Some(above) = get_block(pos + IVec3::Y).and_then(|id| registry.get_fluid(id)) {
+        return registry.get_fluid_variant(above.source_id, 1);
+    }
+
+    // otherwise take the closest-to-source neighbor that is able to spread sideways
+    HORIZONTAL_OFFSETS
+        .iter()
+        .filter_map(|&offset| {
+            let neighbor_pos = pos + offset;
+            let neighbor = registry.get_fluid(get_block(neighbor_pos)?)?;
+
+            if !can_spread_sideways(neighbor_pos, &neighbor, get_block, registry) {
+                return None;
+            }
+
+            let distance = neighbor.distance + 1;
+            (distance <= neighbor.properties.flow_distance)
+                .then_some((neighbor.source_id, distance))
+        })
+        .min_by_key(|&(_, distance)| distance)
+        .and_then(|(source_id, distance)| registry.get_fluid_variant(source_id, distance))
+}
+
+/// Fluid only spreads sideways when it has something to rest on, otherwise it falls.
+fn can_spread_sideways(
+    pos: IVec3,
+    fluid: &FluidState,
+    get_block: &impl Fn(IVec3) -> Option<BlockId>,
+    registry: &BlockRegistryResource,
+) -> bool {
+    let Some(below) = get_block(pos - IVec3::Y) else {
+        return false; // unloaded, don't leak into the unknown
+    };
+
+    if below == AIR_BLOCK_ID {
+        return false;
+    }
+
+    match registry.get_fluid(below) {
+        // resting on a body of the same fluid (eg the surface of a lake)
+        Some(below_fluid) if below_fluid.is_same_fluid(fluid) => below_fluid.is_source(),
+        _ => true,
+    }
+}
+
+/// Returns the fluid that could change the voxel at `pos`, if there is one.
+///
+/// Used to decide whether an update is worth scheduling and how long it should wait.
+fn fluid_affecting(
+    pos: IVec3,
+    get_block: &impl Fn(IVec3) -> Option<BlockId>,
+    registry: &BlockRegistryResource,
+) -> Option<FluidState> {
+    let current = get_block(pos)?;
+
+    match registry.get_fluid(current) {
+        Some(fluid) if fluid.is_source() => None,
+        Some(fluid) => Some(fluid),
+        None if current == AIR_BLOCK_ID => INFLOW_OFFSETS
+            .iter()
+            .find_map(|&offset| registry.get_fluid(get_block(pos + offset)?)),
+        None => None,
+    }
+}
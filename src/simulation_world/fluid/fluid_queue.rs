@@ -0,0 +1,7 @@
+use crate::prelude::*;
+use crate::simulation_world::block_updates::TickQueue;
+use bevy_ecs::prelude::*;
+
+/// Voxel positions waiting for their fluid state to be re-evaluated.
+#[derive(Resource, Default, Debug, Deref, DerefMut)]
+pub struct FluidTickQueue(pub TickQueue<()>);
@@ -0,0 +1,27 @@
+pub mod fluid_flow;
+pub mod fluid_queue;
+
+pub use fluid_flow::{next_fluid_block, schedule_fluid_updates_system, tick_fluids_system};
+pub use fluid_queue::FluidTickQueue;
+
+// INFO: ----------------------
+//         Fluid plugin
+// ----------------------------
+
+use crate::prelude::*;
+use bevy_ecs::prelude::*;
+
+pub struct FluidPlugin;
+
+impl Plugin for FluidPlugin {
+    fn build(&self, builder: &mut EcsBuilder) {
+        builder
+            .init_resource::<FluidTickQueue>()
+            .schedule_entry(SimulationSchedule::FixedUpdate)
+            .add_systems(
+                (schedule_fluid_updates_system, tick_fluids_system)
+                    .chain()
+                    .in_set(FixedUpdateSet::MainLogic),
+            );
+    }
+}
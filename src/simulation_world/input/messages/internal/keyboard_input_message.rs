@@ -5,4 +5,6 @@ use winit::{event::ElementState, keyboard::PhysicalKey};
 pub struct KeyboardInputMessage {
     pub key_code: PhysicalKey,
     pub state: ElementState,
+    /// The text the press typed given the keyboard layout, if any.
+    pub text: Option<String>,
 }
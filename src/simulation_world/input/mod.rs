@@ -4,7 +4,8 @@ pub mod systems;
 pub mod types;
 
 pub use resources::{
-    ActionStateResource, Buttons, CursorMovement, InputActionMapResource, WindowSizeResource,
+    ActionStateResource, Buttons, CursorMovement, InputActionMapResource, InputCapture,
+    InputRecorder, InputReplay, WindowSizeResource,
 };
 pub use types::*;
 
@@ -13,27 +14,40 @@ pub use types::*;
 // -----------------------------------
 
 use crate::{
-    ecs_core::{state_machine::AppState, EcsBuilder, Plugin},
+    ecs_core::{
+        state_machine::{utils::in_state, AppState, GameState},
+        AppConfig, EcsBuilder, Plugin,
+    },
     simulation_world::{
         input::{
             messages::{
                 internal::MouseResizeMessage, KeyboardInputMessage, MouseButtonInputMessage,
                 MouseMoveMessage, MouseScrollMessage, RawDeviceMessage, RawWindowMessage,
             },
-            resources::DesiredCursorState,
+            resources::{CursorPosition, DesiredCursorState, GamepadAxes, GamepadBackendResource},
             systems::{
-                toggle_chunk_borders::ChunkBoundsToggle, toggle_chunk_borders_system,
-                toggle_cursor_system, toggle_opaque_wireframe::OpaqueWireframeMode,
+                lock_cursor_system,
+                processing::{record_input_frame_system, replay_input_frame_system},
+                release_cursor_system,
+                toggle_chunk_borders::ChunkBoundsToggle,
+                toggle_chunk_borders_system,
+                toggle_opaque_wireframe::OpaqueWireframeMode,
                 toggle_opaque_wireframe_mode_system,
             },
         },
-        scheduling::OnExit,
+        scheduling::{OnEnter, OnExit},
+        time::frame_clock::update_frame_clock_system,
         SimulationSchedule, SimulationSet,
     },
 };
 use bevy_ecs::{
     message::Messages,
-    schedule::{IntoScheduleConfigs, SystemSet},
+    schedule::{
+        common_conditions::{
+            not, resource_added, resource_changed, resource_exists, resource_exists_and_changed,
+        },
+        IntoScheduleConfigs, SystemCondition, SystemSet,
+    },
     system::Res,
 };
 use systems::processing;
@@ -43,6 +57,11 @@ use winit::{event::MouseButton, keyboard::PhysicalKey};
 pub enum InputSystemSet {
     WindowEvents,
     DeviceEvents,
+    GamepadEvents,
+    /// Actions from held buttons, after anything that consumes buttons first (like the UI).
+    Actions,
+    /// Axis values, computed once the frame clock knows this frame's time.
+    Axes,
 }
 
 pub struct InputModulePlugin;
@@ -51,13 +70,24 @@ impl Plugin for InputModulePlugin {
     fn build(&self, builder: &mut EcsBuilder) {
         // resources
         builder
-            .add_resource(InputActionMapResource::default())
+            .init_resource::<InputActionMapResource>()
             .add_resource(ActionStateResource::default());
 
         builder
             .add_resource(Buttons::<PhysicalKey>::default())
             .add_resource(Buttons::<MouseButton>::default())
-            .add_resource(CursorMovement::default());
+            .add_resource(CursorMovement::default())
+            .add_resource(CursorPosition::default())
+            .add_resource(InputCapture::default());
+
+        // gamepads (the backend is only there when one could be opened)
+        builder
+            .add_resource(Buttons::<GamepadButton>::default())
+            .add_resource(Buttons::<(GamepadAxis, AxisDirection)>::default())
+            .add_resource(GamepadAxes::default());
+        if let Some(backend) = GamepadBackendResource::detect() {
+            builder.add_resource(backend);
+        }
 
         // external events (comes from the app wrapper)
         builder
@@ -83,7 +113,17 @@ impl Plugin for InputModulePlugin {
             .add_systems(
                 processing::device_events_system
                     .in_set(InputSystemSet::DeviceEvents)
-                    .in_set(SimulationSet::Input),
+                    .in_set(SimulationSet::Input)
+                    .run_if(not(resource_exists::<InputReplay>)),
+            )
+            .add_systems(
+                processing::gamepad_events_system
+                    .in_set(InputSystemSet::GamepadEvents)
+                    .in_set(SimulationSet::Input)
+                    .run_if(
+                        resource_exists::<GamepadBackendResource>
+                            .and(not(resource_exists::<InputReplay>)),
+                    ),
             )
             .add_systems(
                 processing::handle_resize_system
@@ -92,9 +132,31 @@ impl Plugin for InputModulePlugin {
             )
             .add_systems(
                 processing::update_action_state_system
+                    .in_set(InputSystemSet::Actions)
                     .after(InputSystemSet::WindowEvents)
                     .after(InputSystemSet::DeviceEvents)
-                    .in_set(SimulationSet::Input),
+                    .after(InputSystemSet::GamepadEvents)
+                    .in_set(SimulationSet::Input)
+                    .run_if(not(resource_exists::<InputReplay>)),
+            )
+            .add_systems(
+                processing::apply_mouse_sensitivity_setting_system
+                    .run_if(resource_exists_and_changed::<AppConfig>)
+                    .before(InputSystemSet::Axes)
+                    .in_set(SimulationSet::PreUpdate),
+            )
+            .add_systems(
+                processing::update_axis_state_system
+                    .in_set(InputSystemSet::Axes)
+                    .in_set(SimulationSet::PreUpdate)
+                    .after(update_frame_clock_system)
+                    .run_if(not(resource_exists::<InputReplay>)),
+            )
+            .add_systems(
+                processing::save_keybinds_system.run_if(
+                    resource_changed::<InputActionMapResource>
+                        .and(not(resource_added::<InputActionMapResource>)),
+                ),
             );
 
         builder
@@ -105,15 +167,14 @@ impl Plugin for InputModulePlugin {
         //         keybind-based actions below
         // -------------------------------------------
 
-        // set desired cursor state on pause action
+        // the cursor is locked while playing and free in menus
         builder
             .add_resource(DesiredCursorState::default())
-            .schedule_entry(SimulationSchedule::Main)
-            .add_systems(
-                toggle_cursor_system.run_if(|action_state: Res<ActionStateResource>| {
-                    action_state.just_happened(SimulationAction::TogglePause)
-                }),
-            );
+            .schedule_entry(OnEnter(GameState::Playing))
+            .add_systems(lock_cursor_system);
+        builder
+            .schedule_entry(OnExit(GameState::Playing))
+            .add_systems(release_cursor_system);
 
         // toggle opaque wireframe mode
         builder
@@ -136,3 +197,33 @@ impl Plugin for InputModulePlugin {
             ));
     }
 }
+
+/// Records input to, or replays it from, a file when an `InputRecorder` or `InputReplay`
+/// resource is present.
+///
+/// Shared with the headless world, where a replay is the only source of input.
+pub struct InputRecordingPlugin;
+
+impl Plugin for InputRecordingPlugin {
+    fn build(&self, builder: &mut EcsBuilder) {
+        builder
+            .init_resource::<ActionStateResource>()
+            .init_resource::<CursorMovement>();
+
+        builder
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(
+                replay_input_frame_system
+                    .in_set(SimulationSet::Input)
+                    .after(InputSystemSet::WindowEvents)
+                    .run_if(resource_exists::<InputReplay>.and(in_state(AppState::Running))),
+            )
+            .add_systems(
+                record_input_frame_system
+                    .in_set(SimulationSet::PreUpdate)
+                    .after(update_frame_clock_system)
+                    .after(InputSystemSet::Axes)
+                    .run_if(resource_exists::<InputRecorder>.and(in_state(AppState::Running))),
+            );
+    }
+}
@@ -1,6 +1,8 @@
-use crate::simulation_world::input::types::simulation_action::SimulationAction;
+use crate::simulation_world::input::types::{
+    simulation_action::SimulationAction, simulation_axis::SimulationAxis,
+};
 use bevy_ecs::prelude::Resource;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /// A resource for central mapping of input to actions
 ///
@@ -16,6 +18,9 @@ pub struct ActionStateResource {
 
     /// Actions that ended or were released this frame.
     ended: HashSet<SimulationAction>,
+
+    /// The value of every axis this frame, missing ones are zero.
+    axes: HashMap<SimulationAxis, f32>,
 }
 
 impl ActionStateResource {
@@ -23,10 +28,11 @@ impl ActionStateResource {
     //        State manipulation
     // ---------------------------------
 
-    /// Clears the `just_happened` and `ended` sets.
+    /// Clears the `just_happened` and `ended` sets along with the axis values.
     pub fn clear(&mut self) {
         self.just_happened.clear();
         self.ended.clear();
+        self.axes.clear();
     }
 
     /// Marks an action as pressed.
@@ -46,6 +52,15 @@ impl ActionStateResource {
         }
     }
 
+    /// Sets the value of an axis for this frame.
+    pub fn set_axis(&mut self, axis: SimulationAxis, value: f32) {
+        if value == 0.0 {
+            self.axes.remove(&axis);
+        } else {
+            self.axes.insert(axis, value);
+        }
+    }
+
     // INFO: -----------------------
     //        State checking
     // -----------------------------
@@ -64,4 +79,26 @@ impl ActionStateResource {
     pub fn just_ended(&self, action: SimulationAction) -> bool {
         self.ended.contains(&action)
     }
+
+    pub fn iter_just_happened(&self) -> impl Iterator<Item = SimulationAction> + '_ {
+        self.just_happened.iter().copied()
+    }
+
+    pub fn iter_ongoing(&self) -> impl Iterator<Item = SimulationAction> + '_ {
+        self.ongoing.iter().copied()
+    }
+
+    pub fn iter_ended(&self) -> impl Iterator<Item = SimulationAction> + '_ {
+        self.ended.iter().copied()
+    }
+
+    /// How far the axis moved this frame, zero when nothing drives it.
+    pub fn axis(&self, axis: SimulationAxis) -> f32 {
+        self.axes.get(&axis).copied().unwrap_or(0.0)
+    }
+
+    /// Every axis with a non-zero value this frame.
+    pub fn iter_axes(&self) -> impl Iterator<Item = (SimulationAxis, f32)> + '_ {
+        self.axes.iter().map(|(&axis, &value)| (axis, value))
+    }
 }
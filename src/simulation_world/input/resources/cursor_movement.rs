@@ -1,10 +1,22 @@
 use bevy_ecs::prelude::Resource;
 use glam::{DVec2, Vec2};
 
-#[derive(Debug, Resource, Default)]
+#[derive(Debug, Resource)]
 pub struct CursorMovement {
     mouse_delta: DVec2,
     scroll_delta: Vec2,
+    /// A multiplier on the mouse delta when it drives the look axes.
+    mouse_sensitivity: f64,
+}
+
+impl Default for CursorMovement {
+    fn default() -> Self {
+        Self {
+            mouse_delta: DVec2::ZERO,
+            scroll_delta: Vec2::ZERO,
+            mouse_sensitivity: 1.0,
+        }
+    }
 }
 
 impl CursorMovement {
@@ -25,6 +37,15 @@ impl CursorMovement {
         self.mouse_delta
     }
 
+    /// The mouse delta scaled by the mouse sensitivity.
+    pub fn get_scaled_mouse_delta(&self) -> DVec2 {
+        self.mouse_delta * self.mouse_sensitivity
+    }
+
+    pub fn set_mouse_sensitivity(&mut self, sensitivity: f32) {
+        self.mouse_sensitivity = sensitivity as f64;
+    }
+
     pub fn get_scroll_delta(&self) -> Vec2 {
         self.scroll_delta
     }
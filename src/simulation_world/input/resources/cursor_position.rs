@@ -0,0 +1,9 @@
+use bevy_ecs::prelude::Resource;
+use glam::Vec2;
+
+/// Where the cursor is within the window in physical pixels, the same space the UI is
+/// laid out in.
+///
+/// `None` while the cursor is outside the window.
+#[derive(Debug, Resource, Default)]
+pub struct CursorPosition(pub Option<Vec2>);
@@ -0,0 +1,229 @@
+use crate::simulation_world::input::types::gamepad::{
+    AxisDirection, GamepadAxis, GamepadButton, GamepadStick,
+};
+use bevy_ecs::prelude::Resource;
+use glam::Vec2;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+/// Stick and trigger values below this are treated as zero, since worn sticks rarely
+/// rest exactly at the center.
+pub const DEFAULT_DEADZONE: f32 = 0.15;
+
+/// How far an axis has to be pushed before it counts as pressed for bindings.
+pub const AXIS_PRESS_THRESHOLD: f32 = 0.5;
+
+// INFO: ---------------------
+//         analog state
+// ---------------------------
+
+/// The latest raw value of every gamepad axis, read through a deadzone.
+#[derive(Debug, Resource)]
+pub struct GamepadAxes {
+    values: HashMap<GamepadAxis, f32>,
+    pub deadzone: f32,
+}
+
+impl Default for GamepadAxes {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+            deadzone: DEFAULT_DEADZONE,
+        }
+    }
+}
+
+impl GamepadAxes {
+    pub fn set(&mut self, axis: GamepadAxis, value: f32) {
+        self.values.insert(axis, value.clamp(-1.0, 1.0));
+    }
+
+    pub fn raw(&self, axis: GamepadAxis) -> f32 {
+        self.values.get(&axis).copied().unwrap_or(0.0)
+    }
+
+    /// The value of `axis` with the deadzone cut out and the rest rescaled to 0..1.
+    pub fn get(&self, axis: GamepadAxis) -> f32 {
+        let value = self.raw(axis);
+        let magnitude = rescale_past_deadzone(value.abs(), self.deadzone);
+        magnitude.copysign(value)
+    }
+
+    /// Both axes of a stick with a radial deadzone, so diagonals aren't snapped to the axes.
+    pub fn stick(&self, stick: GamepadStick) -> Vec2 {
+        let (x, y) = stick.axes();
+        let raw = Vec2::new(self.raw(x), self.raw(y));
+        let length = raw.length().min(1.0);
+        if length <= self.deadzone {
+            return Vec2::ZERO;
+        }
+        raw.normalize() * rescale_past_deadzone(length, self.deadzone)
+    }
+
+    /// Every half axis pushed past `AXIS_PRESS_THRESHOLD`.
+    pub fn pressed_directions(&self) -> impl Iterator<Item = (GamepadAxis, AxisDirection)> + '_ {
+        self.values
+            .iter()
+            .filter(|(_, value)| value.abs() >= AXIS_PRESS_THRESHOLD)
+            .filter_map(|(&axis, &value)| Some((axis, AxisDirection::of(value)?)))
+    }
+
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+fn rescale_past_deadzone(magnitude: f32, deadzone: f32) -> f32 {
+    if magnitude <= deadzone {
+        0.0
+    } else {
+        ((magnitude - deadzone) / (1.0 - deadzone)).min(1.0)
+    }
+}
+
+// INFO: ----------------
+//         backends
+// ----------------------
+
+/// A gamepad event, already translated from whatever the backend reports.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GamepadEvent {
+    ButtonPressed(GamepadButton),
+    ButtonReleased(GamepadButton),
+    AxisChanged(GamepadAxis, f32),
+    /// The pad went away, everything it held is released.
+    Disconnected,
+}
+
+/// Somewhere gamepad events come from.
+pub trait GamepadBackend: Send {
+    /// Appends every event that arrived since the last poll.
+    fn poll(&mut self, events: &mut Vec<GamepadEvent>);
+}
+
+/// The active gamepad backend. Absent when there is none, so the gamepad systems don't run.
+#[derive(Resource)]
+pub struct GamepadBackendResource(pub Mutex<Box<dyn GamepadBackend>>);
+
+impl GamepadBackendResource {
+    pub fn new(backend: impl GamepadBackend + 'static) -> Self {
+        Self(Mutex::new(Box::new(backend)))
+    }
+
+    /// The gilrs backend when built with the `gamepad` feature and it starts up.
+    pub fn detect() -> Option<Self> {
+        #[cfg(feature = "gamepad")]
+        match gilrs_backend::GilrsBackend::new() {
+            Ok(backend) => return Some(Self::new(backend)),
+            Err(e) => crate::prelude::warn!("Gamepad support unavailable: {}", e),
+        }
+        None
+    }
+}
+
+/// A backend driven by hand, for tests and scripted input.
+///
+/// Clones share the same queue, so one can be handed to the world while another
+/// pushes events into it.
+#[derive(Debug, Clone, Default)]
+pub struct MockGamepad {
+    queue: Arc<Mutex<VecDeque<GamepadEvent>>>,
+}
+
+impl MockGamepad {
+    pub fn push(&self, event: GamepadEvent) {
+        self.queue.lock().unwrap().push_back(event);
+    }
+
+    pub fn press(&self, button: GamepadButton) {
+        self.push(GamepadEvent::ButtonPressed(button));
+    }
+
+    pub fn release(&self, button: GamepadButton) {
+        self.push(GamepadEvent::ButtonReleased(button));
+    }
+
+    pub fn move_axis(&self, axis: GamepadAxis, value: f32) {
+        self.push(GamepadEvent::AxisChanged(axis, value));
+    }
+}
+
+impl GamepadBackend for MockGamepad {
+    fn poll(&mut self, events: &mut Vec<GamepadEvent>) {
+        events.extend(self.queue.lock().unwrap().drain(..));
+    }
+}
+
+#[cfg(feature = "gamepad")]
+mod gilrs_backend {
+    use super::{GamepadBackend, GamepadEvent};
+    use crate::simulation_world::input::types::gamepad::{GamepadAxis, GamepadButton};
+    use gilrs::{Axis, Button, EventType, Gilrs};
+
+    /// Reads every connected pad through gilrs, merged as if they were one.
+    pub struct GilrsBackend(Gilrs);
+
+    impl GilrsBackend {
+        pub fn new() -> Result<Self, Box<gilrs::Error>> {
+            Gilrs::new().map(Self).map_err(Box::new)
+        }
+    }
+
+    impl GamepadBackend for GilrsBackend {
+        fn poll(&mut self, events: &mut Vec<GamepadEvent>) {
+            while let Some(event) = self.0.next_event() {
+                let event = match event.event {
+                    EventType::ButtonPressed(button, _) => {
+                        to_button(button).map(GamepadEvent::ButtonPressed)
+                    }
+                    EventType::ButtonReleased(button, _) => {
+                        to_button(button).map(GamepadEvent::ButtonReleased)
+                    }
+                    EventType::AxisChanged(axis, value, _) => {
+                        to_axis(axis).map(|axis| GamepadEvent::AxisChanged(axis, value))
+                    }
+                    EventType::Disconnected => Some(GamepadEvent::Disconnected),
+                    _ => None,
+                };
+                events.extend(event);
+            }
+        }
+    }
+
+    fn to_button(button: Button) -> Option<GamepadButton> {
+        Some(match button {
+            Button::South => GamepadButton::South,
+            Button::East => GamepadButton::East,
+            Button::North => GamepadButton::North,
+            Button::West => GamepadButton::West,
+            Button::LeftTrigger => GamepadButton::LeftTrigger,
+            Button::LeftTrigger2 => GamepadButton::LeftTrigger2,
+            Button::RightTrigger => GamepadButton::RightTrigger,
+            Button::RightTrigger2 => GamepadButton::RightTrigger2,
+            Button::Select => GamepadButton::Select,
+            Button::Start => GamepadButton::Start,
+            Button::Mode => GamepadButton::Mode,
+            Button::LeftThumb => GamepadButton::LeftThumb,
+            Button::RightThumb => GamepadButton::RightThumb,
+            Button::DPadUp => GamepadButton::DPadUp,
+            Button::DPadDown => GamepadButton::DPadDown,
+            Button::DPadLeft => GamepadButton::DPadLeft,
+            Button::DPadRight => GamepadButton::DPadRight,
+            _ => return None,
+        })
+    }
+
+    fn to_axis(axis: Axis) -> Option<GamepadAxis> {
+        Some(match axis {
+            Axis::LeftStickX => GamepadAxis::LeftStickX,
+            Axis::LeftStickY => GamepadAxis::LeftStickY,
+            Axis::RightStickX => GamepadAxis::RightStickX,
+            Axis::RightStickY => GamepadAxis::RightStickY,
+            Axis::LeftZ => GamepadAxis::LeftZ,
+            Axis::RightZ => GamepadAxis::RightZ,
+            _ => return None,
+        })
+    }
+}
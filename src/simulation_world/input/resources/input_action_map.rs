@@ -1,172 +1,592 @@
-use crate::simulation_world::input::types::simulation_action::SimulationAction;
-use bevy_ecs::prelude::Resource;
-use std::collections::hash_map::{HashMap, Iter};
+use crate::prelude::*;
+use crate::simulation_world::input::{
+    resources::buttons::Buttons,
+    types::{
+        gamepad::{AxisDirection, GamepadAxis, GamepadButton, GamepadStick},
+        simulation_action::SimulationAction,
+        simulation_axis::SimulationAxis,
+    },
+};
+use bevy_ecs::prelude::{FromWorld, Resource, World};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
 use winit::{
     event::MouseButton,
     keyboard::{KeyCode, PhysicalKey},
 };
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+/// The user's key bindings, written back here whenever they change at runtime.
+pub const KEYBINDS_PATH: &str = "keybinds.ron";
+
+/// The bindings used when `keybinds.ron` doesn't exist.
+pub const DEFAULT_KEYBINDS_PATH: &str = "assets/default_keybinds.ron";
+
+const DEFAULT_KEYBINDS: &str = include_str!("../../../../assets/default_keybinds.ron");
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
 pub enum Input {
     Key(PhysicalKey),
     MouseButton(MouseButton),
+    GamepadButton(GamepadButton),
+    /// One direction of an analog axis, pressed once pushed past `AXIS_PRESS_THRESHOLD`.
+    GamepadAxis(GamepadAxis, AxisDirection),
 }
 
-/// A map from input keys to an action. Set as a resource
+// INFO: -------------------------
+//         modifier chords
+// -------------------------------
+
+/// A modifier key that has to be held for a chord, either side counts.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum Modifier {
+    Ctrl,
+    Shift,
+    Alt,
+    Super,
+}
+
+impl Modifier {
+    pub const ALL: [Modifier; 4] = [
+        Modifier::Ctrl,
+        Modifier::Shift,
+        Modifier::Alt,
+        Modifier::Super,
+    ];
+
+    /// The left and right keys of this modifier.
+    pub fn key_codes(self) -> [KeyCode; 2] {
+        match self {
+            Modifier::Ctrl => [KeyCode::ControlLeft, KeyCode::ControlRight],
+            Modifier::Shift => [KeyCode::ShiftLeft, KeyCode::ShiftRight],
+            Modifier::Alt => [KeyCode::AltLeft, KeyCode::AltRight],
+            Modifier::Super => [KeyCode::SuperLeft, KeyCode::SuperRight],
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// A set of modifiers, written as a list like `[Ctrl, Shift]` in RON.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(from = "Vec<Modifier>", into = "Vec<Modifier>")]
+pub struct Modifiers(u8);
+
+impl Modifiers {
+    pub const NONE: Modifiers = Modifiers(0);
+
+    pub fn with(self, modifier: Modifier) -> Self {
+        Self(self.0 | modifier.bit())
+    }
+
+    pub fn contains(self, modifier: Modifier) -> bool {
+        self.0 & modifier.bit() != 0
+    }
+
+    /// Whether every modifier in `self` is also in `other`.
+    pub fn is_subset_of(self, other: Modifiers) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn count(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// The modifiers currently held down on the keyboard.
+    pub fn held(keyboard: &Buttons<PhysicalKey>) -> Self {
+        Modifier::ALL
+            .into_iter()
+            .filter(|modifier| {
+                modifier
+                    .key_codes()
+                    .into_iter()
+                    .any(|code| keyboard.is_down(PhysicalKey::Code(code)))
+            })
+            .fold(Self::NONE, Self::with)
+    }
+}
+
+impl From<Vec<Modifier>> for Modifiers {
+    fn from(modifiers: Vec<Modifier>) -> Self {
+        modifiers.into_iter().fold(Self::NONE, Self::with)
+    }
+}
+
+impl From<Modifiers> for Vec<Modifier> {
+    fn from(modifiers: Modifiers) -> Self {
+        Modifier::ALL
+            .into_iter()
+            .filter(|&modifier| modifiers.contains(modifier))
+            .collect()
+    }
+}
+
+/// An input along with the modifiers that have to be held for it.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct KeyChord {
+    pub input: Input,
+    pub modifiers: Modifiers,
+}
+
+impl KeyChord {
+    pub fn new(input: Input, modifiers: Modifiers) -> Self {
+        Self { input, modifiers }
+    }
+
+    pub fn key(code: KeyCode) -> Self {
+        Self::new(Input::Key(PhysicalKey::Code(code)), Modifiers::NONE)
+    }
+
+    pub fn mouse(button: MouseButton) -> Self {
+        Self::new(Input::MouseButton(button), Modifiers::NONE)
+    }
+
+    pub fn gamepad(button: GamepadButton) -> Self {
+        Self::new(Input::GamepadButton(button), Modifiers::NONE)
+    }
+
+    pub fn with(self, modifier: Modifier) -> Self {
+        Self::new(self.input, self.modifiers.with(modifier))
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for modifier in Vec::<Modifier>::from(self.modifiers) {
+            write!(f, "{modifier:?}+")?;
+        }
+        match self.input {
+            Input::Key(PhysicalKey::Code(code)) => write!(f, "{code:?}"),
+            Input::Key(key) => write!(f, "{key:?}"),
+            Input::MouseButton(button) => write!(f, "Mouse{button:?}"),
+            Input::GamepadButton(button) => write!(f, "Pad{button:?}"),
+            Input::GamepadAxis(axis, AxisDirection::Positive) => write!(f, "Pad{axis:?}+"),
+            Input::GamepadAxis(axis, AxisDirection::Negative) => write!(f, "Pad{axis:?}-"),
+        }
+    }
+}
+
+// INFO: -----------------------
+//         axis bindings
+// -----------------------------
+
+/// Where an axis gets its value from.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum AxisSource {
+    /// 1 while `positive` is held and -1 while `negative` is, like W/S for moving forward.
+    Composite { positive: Input, negative: Input },
+    /// A single analog axis, like a trigger, through the axial deadzone.
+    GamepadAxis(GamepadAxis),
+    /// The horizontal half of a stick, through the radial deadzone.
+    StickX(GamepadStick),
+    /// The vertical half of a stick, through the radial deadzone.
+    StickY(GamepadStick),
+    /// Horizontal mouse motion, in pixels.
+    MouseX,
+    /// Vertical mouse motion, in pixels (down is positive).
+    MouseY,
+    /// Vertical scrolling, in lines.
+    ScrollY,
+}
+
+impl AxisSource {
+    /// Whether the source reports how far it moved this frame rather than how far it's
+    /// being held, in which case it isn't scaled by the frame time.
+    pub fn is_per_frame(&self) -> bool {
+        matches!(
+            self,
+            AxisSource::MouseX | AxisSource::MouseY | AxisSource::ScrollY
+        )
+    }
+}
+
+fn is_one(value: &f32) -> bool {
+    *value == 1.0
+}
+
+fn one() -> f32 {
+    1.0
+}
+
+/// One source feeding an axis, several bindings on the same axis add up.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct AxisBinding {
+    pub axis: SimulationAxis,
+    pub source: AxisSource,
+    /// Converts the source's units into the axis', e.g. degrees per pixel of mouse motion.
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub scale: f32,
+}
+
+impl AxisBinding {
+    pub fn new(axis: SimulationAxis, source: AxisSource) -> Self {
+        Self {
+            axis,
+            source,
+            scale: 1.0,
+        }
+    }
+
+    pub fn scaled(self, scale: f32) -> Self {
+        Self { scale, ..self }
+    }
+}
+
+/// The user's tuning of an axis, applied on top of every binding feeding it.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct AxisSettings {
+    #[serde(default = "one", skip_serializing_if = "is_one")]
+    pub sensitivity: f32,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub invert: bool,
+}
+
+impl Default for AxisSettings {
+    fn default() -> Self {
+        Self {
+            sensitivity: 1.0,
+            invert: false,
+        }
+    }
+}
+
+impl AxisSettings {
+    pub fn apply(&self, value: f32) -> f32 {
+        let value = value * self.sensitivity;
+        if self.invert {
+            -value
+        } else {
+            value
+        }
+    }
+}
+
+// INFO: -----------------------
+//         keybinds file
+// -----------------------------
+
+/// One entry of `keybinds.ron`, every action fires when the chord is pressed.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct KeyBinding {
+    pub input: Input,
+    #[serde(default, skip_serializing_if = "Modifiers::is_empty")]
+    pub modifiers: Modifiers,
+    pub actions: Vec<SimulationAction>,
+}
+
+impl KeyBinding {
+    pub fn chord(&self) -> KeyChord {
+        KeyChord::new(self.input, self.modifiers)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeybindsFile {
+    bindings: Vec<KeyBinding>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    axes: Vec<AxisBinding>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    axis_settings: BTreeMap<SimulationAxis, AxisSettings>,
+}
+
+/// The same chord bound by two separate entries of a keybinds file.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct KeybindConflict {
+    pub chord: KeyChord,
+    pub first: Vec<SimulationAction>,
+    pub second: Vec<SimulationAction>,
+}
+
+impl fmt::Display for KeybindConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is bound to both {:?} and {:?}",
+            self.chord, self.first, self.second
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum KeybindsError {
+    IoError(io::Error),
+    ParseError(ron::error::SpannedError),
+    SerializeError(ron::Error),
+    /// The bindings parsed but bind some chords more than once.
+    Conflicts(Vec<KeybindConflict>),
+}
+
+impl From<io::Error> for KeybindsError {
+    fn from(err: io::Error) -> Self {
+        KeybindsError::IoError(err)
+    }
+}
+
+impl fmt::Display for KeybindsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeybindsError::IoError(err) => write!(f, "Keybinds IO error: {err}"),
+            KeybindsError::ParseError(err) => write!(f, "Invalid keybinds: {err}"),
+            KeybindsError::SerializeError(err) => write!(f, "Failed to write keybinds: {err}"),
+            KeybindsError::Conflicts(conflicts) => {
+                write!(f, "Conflicting keybinds: ")?;
+                for (i, conflict) in conflicts.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{conflict}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for KeybindsError {}
+
+// INFO: ------------------
+//         resource
+// ------------------------
+
+/// A map from input chords to actions. Set as a resource
 /// which means it can be configured by systems at runtime.
+///
+/// Loaded from `keybinds.ron`, falling back to `assets/default_keybinds.ron`. Keys are
+/// physical positions, so bindings stay in place regardless of the keyboard layout.
 #[derive(Debug, Resource)]
 pub struct InputActionMapResource {
-    bindings: HashMap<Input, SimulationAction>,
+    bindings: Vec<KeyBinding>,
+    axes: Vec<AxisBinding>,
+    axis_settings: BTreeMap<SimulationAxis, AxisSettings>,
+    /// Indices into `bindings` for each input, most modifiers first.
+    by_input: HashMap<Input, Vec<usize>>,
+    /// Where runtime changes are written back to, if anywhere.
+    save_path: Option<PathBuf>,
 }
 
 impl InputActionMapResource {
-    /// Gets the game action associated with a given input, if one exists.
-    pub fn get_action(&self, input: &Input) -> Option<&SimulationAction> {
-        self.bindings.get(input)
+    /// Builds a map from bindings, rejecting chords that are bound more than once.
+    pub fn from_bindings(bindings: Vec<KeyBinding>) -> Result<Self, KeybindsError> {
+        let conflicts = find_conflicts(&bindings);
+        if !conflicts.is_empty() {
+            return Err(KeybindsError::Conflicts(conflicts));
+        }
+
+        let mut map = Self {
+            bindings,
+            axes: Vec::new(),
+            axis_settings: BTreeMap::new(),
+            by_input: HashMap::new(),
+            save_path: None,
+        };
+        map.rebuild_lookup();
+        Ok(map)
+    }
+
+    pub fn from_ron_str(ron_string: &str) -> Result<Self, KeybindsError> {
+        let file: KeybindsFile = ron::from_str(ron_string).map_err(KeybindsError::ParseError)?;
+        let mut map = Self::from_bindings(file.bindings)?;
+        map.axes = file.axes;
+        map.axis_settings = file.axis_settings;
+        Ok(map)
+    }
+
+    pub fn to_ron_string(&self) -> Result<String, KeybindsError> {
+        let file = KeybindsFile {
+            bindings: self.bindings.clone(),
+            axes: self.axes.clone(),
+            axis_settings: self.axis_settings.clone(),
+        };
+        ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default())
+            .map_err(KeybindsError::SerializeError)
+    }
+
+    /// Writes the bindings to `path`, replacing any existing file atomically.
+    pub fn save(&self, path: &Path) -> Result<(), KeybindsError> {
+        let tmp_path = path.with_extension("ron.tmp");
+        fs::write(&tmp_path, self.to_ron_string()?)?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Sets where runtime changes are written back to.
+    pub fn with_save_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.save_path = Some(path.into());
+        self
     }
 
-    /// Provides an iterator over all the currently configured input bindings.
-    pub fn iter<'a>(&'a self) -> Iter<'a, Input, SimulationAction> {
+    pub fn save_path(&self) -> Option<&Path> {
+        self.save_path.as_deref()
+    }
+
+    /// The actions of the most specific chord on `input` that `held` satisfies.
+    ///
+    /// With Ctrl held, a `Ctrl+Z` binding wins over a plain `Z` one, while a plain `W`
+    /// still fires with Shift held.
+    pub fn actions_for(&self, input: &Input, held: Modifiers) -> &[SimulationAction] {
+        self.by_input
+            .get(input)
+            .and_then(|indices| {
+                indices
+                    .iter()
+                    .map(|&i| &self.bindings[i])
+                    .find(|binding| binding.modifiers.is_subset_of(held))
+            })
+            .map_or(&[], |binding| &binding.actions)
+    }
+
+    /// Every action bound to `input` under any modifiers.
+    pub fn all_actions_for<'a>(
+        &'a self,
+        input: &Input,
+    ) -> impl Iterator<Item = SimulationAction> + 'a {
+        self.by_input
+            .get(input)
+            .into_iter()
+            .flatten()
+            .flat_map(|&i| self.bindings[i].actions.iter().copied())
+    }
+
+    /// Provides an iterator over all the currently configured bindings.
+    pub fn iter(&self) -> impl Iterator<Item = &KeyBinding> {
         self.bindings.iter()
     }
+
+    /// Adds `action` to `chord`, doing nothing if it's already bound there.
+    pub fn bind(&mut self, chord: KeyChord, action: SimulationAction) {
+        match self.bindings.iter_mut().find(|b| b.chord() == chord) {
+            Some(binding) if binding.actions.contains(&action) => return,
+            Some(binding) => binding.actions.push(action),
+            None => self.bindings.push(KeyBinding {
+                input: chord.input,
+                modifiers: chord.modifiers,
+                actions: vec![action],
+            }),
+        }
+        self.rebuild_lookup();
+    }
+
+    /// Removes `action` from `chord`, returning whether it was bound there.
+    pub fn unbind(&mut self, chord: KeyChord, action: SimulationAction) -> bool {
+        let Some(index) = self.bindings.iter().position(|b| b.chord() == chord) else {
+            return false;
+        };
+
+        let actions = &mut self.bindings[index].actions;
+        let len = actions.len();
+        actions.retain(|&a| a != action);
+        let removed = actions.len() != len;
+
+        if actions.is_empty() {
+            self.bindings.remove(index);
+        }
+        self.rebuild_lookup();
+        removed
+    }
+
+    /// Every source feeding an axis.
+    pub fn iter_axes(&self) -> impl Iterator<Item = &AxisBinding> {
+        self.axes.iter()
+    }
+
+    /// Adds another source to an axis.
+    pub fn bind_axis(&mut self, binding: AxisBinding) {
+        if !self.axes.contains(&binding) {
+            self.axes.push(binding);
+        }
+    }
+
+    /// Removes every source of `axis` that reads from `source`, returning whether any did.
+    pub fn unbind_axis(&mut self, axis: SimulationAxis, source: AxisSource) -> bool {
+        let len = self.axes.len();
+        self.axes
+            .retain(|binding| binding.axis != axis || binding.source != source);
+        self.axes.len() != len
+    }
+
+    pub fn axis_settings(&self, axis: SimulationAxis) -> AxisSettings {
+        self.axis_settings.get(&axis).copied().unwrap_or_default()
+    }
+
+    pub fn set_axis_settings(&mut self, axis: SimulationAxis, settings: AxisSettings) {
+        if settings == AxisSettings::default() {
+            self.axis_settings.remove(&axis);
+        } else {
+            self.axis_settings.insert(axis, settings);
+        }
+    }
+
+    /// The built-in bindings from `assets/default_keybinds.ron`.
+    pub fn builtin() -> Self {
+        Self::from_ron_str(DEFAULT_KEYBINDS)
+            .unwrap_or_else(|e| panic!("{DEFAULT_KEYBINDS_PATH} is invalid: {e}"))
+    }
+
+    fn rebuild_lookup(&mut self) {
+        self.by_input.clear();
+        for (i, binding) in self.bindings.iter().enumerate() {
+            self.by_input.entry(binding.input).or_default().push(i);
+        }
+        for indices in self.by_input.values_mut() {
+            indices.sort_by_key(|&i| std::cmp::Reverse(self.bindings[i].modifiers.count()));
+        }
+    }
 }
 
-impl Default for InputActionMapResource {
-    fn default() -> Self {
-        Self {
-            bindings: HashMap::from([
-                // Core player movement
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::KeyW)),
-                    SimulationAction::MoveForward,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::KeyS)),
-                    SimulationAction::MoveBackward,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::KeyA)),
-                    SimulationAction::MoveLeft,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::KeyD)),
-                    SimulationAction::MoveRight,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::ShiftLeft)),
-                    SimulationAction::MoveFaster,
-                ),
-                // Core player actions
-                (
-                    Input::MouseButton(MouseButton::Left),
-                    SimulationAction::BreakVoxel,
-                ),
-                (
-                    Input::MouseButton(MouseButton::Right),
-                    SimulationAction::PlaceVoxel,
-                ),
-                // Terrain gen
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::KeyT)),
-                    SimulationAction::CycleActiveTerrainGenerator,
-                ),
-                // Game time control
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::ArrowRight)),
-                    SimulationAction::JumpGameTimeForward,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::ArrowLeft)),
-                    SimulationAction::JumpGameTimeBackward,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::Space)),
-                    SimulationAction::PauseGameTime,
-                ),
-                // Misc
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::Escape)),
-                    SimulationAction::TogglePause,
-                ),
-                // Debug/analysis tools
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::F1)),
-                    SimulationAction::ToggleDiagnostics,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::KeyU)),
-                    SimulationAction::ToggleDiagnostics,
-                ),
-                // (
-                //     Input::Key(PhysicalKey::Code(KeyCode::Digit1)),
-                //     SimulationAction::ToggleDiagnostics,
-                // ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::F2)),
-                    SimulationAction::ToggleOpaqueWireframeMode,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::KeyO)),
-                    SimulationAction::ToggleOpaqueWireframeMode,
-                ),
-                // (
-                //     Input::Key(PhysicalKey::Code(KeyCode::Digit2)),
-                //     SimulationAction::ToggleOpaqueWireframeMode,
-                // ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::F3)),
-                    SimulationAction::ToggleChunkBorders,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::KeyB)),
-                    SimulationAction::ToggleChunkBorders,
-                ),
-                // (
-                //     Input::Key(PhysicalKey::Code(KeyCode::Digit3)),
-                //     SimulationAction::ToggleChunkBorders,
-                // ),
-                // Showcase actions
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::Digit0)),
-                    SimulationAction::Showcase0,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::Digit1)),
-                    SimulationAction::Showcase1,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::Digit2)),
-                    SimulationAction::Showcase2,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::Digit3)),
-                    SimulationAction::Showcase3,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::Digit4)),
-                    SimulationAction::Showcase4,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::Digit5)),
-                    SimulationAction::Showcase5,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::Digit6)),
-                    SimulationAction::Showcase6,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::Digit7)),
-                    SimulationAction::Showcase7,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::Digit8)),
-                    SimulationAction::Showcase8,
-                ),
-                (
-                    Input::Key(PhysicalKey::Code(KeyCode::Digit9)),
-                    SimulationAction::Showcase9,
-                ),
-            ]),
+/// Finds every chord that appears in more than one binding.
+pub fn find_conflicts(bindings: &[KeyBinding]) -> Vec<KeybindConflict> {
+    let mut seen: HashMap<KeyChord, usize> = HashMap::new();
+    let mut conflicts = Vec::new();
+
+    for (i, binding) in bindings.iter().enumerate() {
+        match seen.get(&binding.chord()) {
+            Some(&first) => conflicts.push(KeybindConflict {
+                chord: binding.chord(),
+                first: bindings[first].actions.clone(),
+                second: binding.actions.clone(),
+            }),
+            None => {
+                seen.insert(binding.chord(), i);
+            }
+        }
+    }
+
+    conflicts
+}
+
+impl FromWorld for InputActionMapResource {
+    fn from_world(_world: &mut World) -> Self {
+        match fs::read_to_string(KEYBINDS_PATH) {
+            Ok(ron_string) => match Self::from_ron_str(&ron_string) {
+                Ok(map) => map.with_save_path(KEYBINDS_PATH),
+                // not saving over the broken file so the user can still fix it
+                Err(e) => {
+                    error!(
+                        "Failed to load {}: {}. Using the default keybinds.",
+                        KEYBINDS_PATH, e
+                    );
+                    Self::builtin()
+                }
+            },
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                Self::builtin().with_save_path(KEYBINDS_PATH)
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to read {}: {}. Using the default keybinds.",
+                    KEYBINDS_PATH, e
+                );
+                Self::builtin()
+            }
         }
     }
 }
@@ -0,0 +1,12 @@
+use bevy_ecs::prelude::Resource;
+
+/// Set when the UI takes input away from the world.
+#[derive(Debug, Resource, Default)]
+pub struct InputCapture {
+    /// A UI element like a text field has the keyboard and mouse, so typing doesn't also
+    /// fire actions. While captured every action is released and every axis reads zero.
+    pub captured: bool,
+    /// The pointer is over an interactive UI node, so mouse buttons go to it instead of
+    /// firing their actions.
+    pub pointer: bool,
+}
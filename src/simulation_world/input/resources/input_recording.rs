@@ -0,0 +1,183 @@
+use crate::simulation_world::input::types::{
+    simulation_action::SimulationAction, simulation_axis::SimulationAxis,
+};
+use bevy_ecs::prelude::Resource;
+use glam::{DVec2, Vec2};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashSet,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+// INFO: ---------------------
+//         recorded data
+// ---------------------------
+
+/// Everything the input systems produced in a single running frame.
+///
+/// Actions are stored as transitions rather than raw keys, so a replay doesn't depend
+/// on the keybinds of whoever plays it back.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputFrame {
+    /// How long the frame took, fed to `FrameClock` on replay.
+    pub delta: Duration,
+    /// Actions that just happened.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pressed: Vec<SimulationAction>,
+    /// Actions that became ongoing (usually along with a press, but chords can switch mid-hold).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub held: Vec<SimulationAction>,
+    /// Actions that ended.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub released: Vec<SimulationAction>,
+    /// Every non-zero axis, already scaled by the frame time and the user's settings.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub axes: Vec<(SimulationAxis, f32)>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub mouse_delta: [f64; 2],
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub scroll_delta: [f32; 2],
+}
+
+fn is_zero<T: Default + PartialEq>(delta: &[T; 2]) -> bool {
+    delta.iter().all(|v| *v == T::default())
+}
+
+impl InputFrame {
+    pub fn mouse_delta(&self) -> DVec2 {
+        DVec2::from_array(self.mouse_delta)
+    }
+
+    pub fn scroll_delta(&self) -> Vec2 {
+        Vec2::from_array(self.scroll_delta)
+    }
+}
+
+/// A recorded input session, one `InputFrame` per running frame.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputRecording {
+    pub frames: Vec<InputFrame>,
+}
+
+#[derive(Debug)]
+pub enum InputRecordingError {
+    IoError(io::Error),
+    ParseError(ron::error::SpannedError),
+    SerializeError(ron::Error),
+}
+
+impl From<io::Error> for InputRecordingError {
+    fn from(err: io::Error) -> Self {
+        InputRecordingError::IoError(err)
+    }
+}
+
+impl fmt::Display for InputRecordingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputRecordingError::IoError(err) => write!(f, "Input recording IO error: {err}"),
+            InputRecordingError::ParseError(err) => write!(f, "Invalid input recording: {err}"),
+            InputRecordingError::SerializeError(err) => {
+                write!(f, "Failed to write input recording: {err}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputRecordingError {}
+
+impl InputRecording {
+    pub fn from_ron_str(ron_string: &str) -> Result<Self, InputRecordingError> {
+        ron::from_str(ron_string).map_err(InputRecordingError::ParseError)
+    }
+
+    pub fn to_ron_string(&self) -> Result<String, InputRecordingError> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(InputRecordingError::SerializeError)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, InputRecordingError> {
+        Self::from_ron_str(&fs::read_to_string(path)?)
+    }
+
+    /// Writes the recording to `path`, going through a temp file so a crash mid-write
+    /// doesn't leave half a recording behind.
+    pub fn save(&self, path: &Path) -> Result<(), InputRecordingError> {
+        let tmp_path = path.with_extension("ron.tmp");
+        fs::write(&tmp_path, self.to_ron_string()?)?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+// INFO: -------------------
+//         resources
+// -------------------------
+
+/// Records every running frame's input while present.
+#[derive(Debug, Resource, Default)]
+pub struct InputRecorder {
+    pub recording: InputRecording,
+    /// Where `save` writes the recording, if anywhere.
+    pub path: Option<PathBuf>,
+    /// Actions that were ongoing at the end of the last recorded frame.
+    pub(crate) ongoing: HashSet<SimulationAction>,
+}
+
+impl InputRecorder {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: Some(path.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Writes the recording so far to its path. Does nothing without one.
+    pub fn save(&self) -> Result<(), InputRecordingError> {
+        match &self.path {
+            Some(path) => self.recording.save(path),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Feeds a recording back in place of the live input while present.
+///
+/// Removes itself once the last frame has been played.
+#[derive(Debug, Resource)]
+pub struct InputReplay {
+    pub recording: InputRecording,
+    /// The next frame to play.
+    pub(crate) next_frame: usize,
+    /// The `FrameClock` fixed step to go back to once the replay is over.
+    pub(crate) resume_fixed_step: Option<Option<Duration>>,
+}
+
+impl InputReplay {
+    pub fn new(recording: InputRecording) -> Self {
+        Self {
+            recording,
+            next_frame: 0,
+            resume_fixed_step: None,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, InputRecordingError> {
+        Ok(Self::new(InputRecording::load(path)?))
+    }
+
+    /// How many frames are left to play.
+    pub fn remaining(&self) -> usize {
+        self.recording.len() - self.next_frame
+    }
+}
@@ -1,13 +1,26 @@
 pub mod action;
 pub mod buttons;
 pub mod cursor_movement;
+pub mod cursor_position;
 pub mod desired_cursor;
+pub mod gamepad;
 pub mod input_action_map;
+pub mod input_capture;
+pub mod input_recording;
 pub mod window_size;
 
 pub use action::ActionStateResource;
 pub use buttons::Buttons;
 pub use cursor_movement::CursorMovement;
+pub use cursor_position::CursorPosition;
 pub use desired_cursor::DesiredCursorState;
-pub use input_action_map::{Input, InputActionMapResource};
+pub use gamepad::{GamepadAxes, GamepadBackend, GamepadBackendResource, GamepadEvent, MockGamepad};
+pub use input_action_map::{
+    AxisBinding, AxisSettings, AxisSource, Input, InputActionMapResource, KeyBinding, KeyChord,
+    KeybindConflict, KeybindsError, Modifier, Modifiers,
+};
+pub use input_capture::InputCapture;
+pub use input_recording::{
+    InputFrame, InputRecorder, InputRecording, InputRecordingError, InputReplay,
+};
 pub use window_size::WindowSizeResource;
@@ -3,5 +3,5 @@ pub mod toggle_cursor;
 pub mod toggle_opaque_wireframe;
 
 pub use toggle_chunk_borders::toggle_chunk_borders_system;
-pub use toggle_cursor::toggle_cursor_system;
+pub use toggle_cursor::{lock_cursor_system, release_cursor_system};
 pub use toggle_opaque_wireframe::toggle_opaque_wireframe_mode_system;
@@ -1,12 +1,15 @@
 use crate::simulation_world::input::resources::DesiredCursorState;
 use bevy_ecs::system::ResMut;
+use winit::window::CursorGrabMode;
 
-pub fn toggle_cursor_system(mut desired_cursor_state: ResMut<DesiredCursorState>) {
-    desired_cursor_state.visible = !desired_cursor_state.visible;
+/// Hides and locks the cursor for looking around while playing.
+pub fn lock_cursor_system(mut desired_cursor_state: ResMut<DesiredCursorState>) {
+    desired_cursor_state.visible = false;
+    desired_cursor_state.grab_mode = CursorGrabMode::Locked;
+}
 
-    if desired_cursor_state.visible {
-        desired_cursor_state.grab_mode = winit::window::CursorGrabMode::None;
-    } else {
-        desired_cursor_state.grab_mode = winit::window::CursorGrabMode::Locked;
-    }
+/// Frees the cursor for pointing at menus.
+pub fn release_cursor_system(mut desired_cursor_state: ResMut<DesiredCursorState>) {
+    desired_cursor_state.visible = true;
+    desired_cursor_state.grab_mode = CursorGrabMode::None;
 }
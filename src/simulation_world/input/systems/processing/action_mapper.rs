@@ -1,20 +1,44 @@
 use crate::{
     prelude::*,
-    simulation_world::input::{
-        resources::{action::ActionStateResource, buttons::Buttons, input_action_map::Input},
-        InputActionMapResource,
+    simulation_world::{
+        input::{
+            resources::{
+                action::ActionStateResource,
+                buttons::Buttons,
+                input_action_map::{AxisSource, Input, Modifiers},
+                CursorMovement, GamepadAxes, InputCapture,
+            },
+            types::gamepad::{AxisDirection, GamepadAxis, GamepadButton},
+            InputActionMapResource, SimulationAction, SimulationAxis,
+        },
+        time::FrameClock,
     },
 };
-use bevy_ecs::prelude::{Res, ResMut};
+use bevy_ecs::{
+    prelude::{Res, ResMut},
+    system::SystemParam,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
 use winit::{event::MouseButton, keyboard::PhysicalKey};
 
 /// A system that translates the raw state from `Buttons` resources into abstract,
 /// stateful actions in `ActionStateResource`, using the bindings from `InputActionMapResource`.
+///
+/// Each held input fires the actions of its most specific chord given the held modifiers,
+/// so actions switch over when a modifier is pressed or released mid-hold. While the
+/// `InputCapture` is captured every ongoing action ends and nothing new fires, and while
+/// it has the pointer mouse buttons count as released.
 #[instrument(skip_all)]
 pub fn update_action_state_system(
     // Input state
+    capture: Option<Res<InputCapture>>,
     keyboard_input: Res<Buttons<PhysicalKey>>,
     mouse_input: Res<Buttons<MouseButton>>,
+    gamepad_buttons: Res<Buttons<GamepadButton>>,
+    gamepad_axes: Res<Buttons<(GamepadAxis, AxisDirection)>>,
     input_map: Res<InputActionMapResource>,
 
     // Output state
@@ -22,45 +46,152 @@ pub fn update_action_state_system(
 ) {
     action_state.clear(); // clear previous frame stale state
 
-    // INFO: ---------------------------------
-    //         Handle keyboard buttons
-    // ---------------------------------------
-
-    for key_code in keyboard_input.iter_current() {
-        if let Some(action) = input_map.get_action(&Input::Key(*key_code)) {
-            if keyboard_input.was_pressed(*key_code) {
-                action_state.press(*action);
-            }
-            action_state.hold(*action);
+    let (captured, pointer_captured) = capture.map_or((false, false), |capture| {
+        (capture.captured, capture.pointer)
+    });
+    if captured {
+        let ongoing: Vec<_> = action_state.iter_ongoing().collect();
+        for action in ongoing {
+            action_state.release(action);
         }
+        return;
     }
 
-    for key_code in keyboard_input.iter_previous() {
-        if !keyboard_input.is_down(*key_code) {
-            if let Some(action) = input_map.get_action(&Input::Key(*key_code)) {
-                action_state.release(*action);
+    let held_modifiers = Modifiers::held(&keyboard_input);
+    let mut active = HashSet::new();
+
+    // INFO: -----------------------------
+    //         Handle held inputs
+    // -----------------------------------
+
+    let held_inputs = held(&keyboard_input, Input::Key)
+        .chain(held(&mouse_input, Input::MouseButton).filter(|_| !pointer_captured))
+        .chain(held(&gamepad_buttons, Input::GamepadButton))
+        .chain(held(&gamepad_axes, |(axis, direction)| {
+            Input::GamepadAxis(axis, direction)
+        }));
+
+    for (input, just_pressed) in held_inputs {
+        for &action in input_map.actions_for(&input, held_modifiers) {
+            if just_pressed {
+                action_state.press(action);
             }
+            action_state.hold(action);
+            active.insert(action);
         }
     }
 
-    // INFO: ------------------------------
-    //         Handle mouse buttons
-    // ------------------------------------
+    // INFO: -------------------------------
+    //         Release ended actions
+    // -------------------------------------
+
+    // covers released inputs as well as held ones whose chord changed
+    let touched_inputs = touched(&keyboard_input, Input::Key)
+        .chain(touched(&mouse_input, Input::MouseButton))
+        .chain(touched(&gamepad_buttons, Input::GamepadButton))
+        .chain(touched(&gamepad_axes, |(axis, direction)| {
+            Input::GamepadAxis(axis, direction)
+        }));
+
+    let ended: HashSet<SimulationAction> = touched_inputs
+        .flat_map(|input| input_map.all_actions_for(&input).collect::<Vec<_>>())
+        .filter(|action| !active.contains(action))
+        .collect();
+
+    for action in ended {
+        action_state.release(action);
+    }
+}
+
+/// The held state of every kind of input that can be bound.
+#[derive(SystemParam)]
+pub struct AllButtons<'w> {
+    keyboard: Res<'w, Buttons<PhysicalKey>>,
+    mouse: Res<'w, Buttons<MouseButton>>,
+    gamepad_buttons: Res<'w, Buttons<GamepadButton>>,
+    gamepad_directions: Res<'w, Buttons<(GamepadAxis, AxisDirection)>>,
+}
 
-    for button in mouse_input.iter_current() {
-        if let Some(action) = input_map.get_action(&Input::MouseButton(*button)) {
-            if mouse_input.was_pressed(*button) {
-                action_state.press(*action);
+impl AllButtons<'_> {
+    pub fn is_down(&self, input: Input) -> bool {
+        match input {
+            Input::Key(key) => self.keyboard.is_down(key),
+            Input::MouseButton(button) => self.mouse.is_down(button),
+            Input::GamepadButton(button) => self.gamepad_buttons.is_down(button),
+            Input::GamepadAxis(axis, direction) => {
+                self.gamepad_directions.is_down((axis, direction))
             }
-            action_state.hold(*action);
         }
     }
+}
+
+/// A system that sums every axis binding from `InputActionMapResource` into this frame's
+/// axis values in `ActionStateResource`.
+///
+/// Runs after the frame clock is updated, so held inputs are scaled by this frame's time.
+#[instrument(skip_all)]
+pub fn update_axis_state_system(
+    // Input state
+    capture: Option<Res<InputCapture>>,
+    buttons: AllButtons,
+    gamepad_axes: Res<GamepadAxes>,
+    movement: Res<CursorMovement>,
+    clock: Res<FrameClock>,
+    input_map: Res<InputActionMapResource>,
+
+    // Output state
+    mut action_state: ResMut<ActionStateResource>,
+) {
+    if capture.is_some_and(|capture| capture.captured) {
+        return;
+    }
 
-    for button in mouse_input.iter_previous() {
-        if !mouse_input.is_down(*button) {
-            if let Some(action) = input_map.get_action(&Input::MouseButton(*button)) {
-                action_state.release(*action);
+    let is_down = |input| buttons.is_down(input);
+    let delta_secs = clock.delta.as_secs_f32();
+
+    let mut values: HashMap<SimulationAxis, f32> = HashMap::new();
+    for binding in input_map.iter_axes() {
+        let value = match binding.source {
+            AxisSource::Composite { positive, negative } => {
+                is_down(positive) as i32 as f32 - is_down(negative) as i32 as f32
             }
-        }
+            AxisSource::GamepadAxis(axis) => gamepad_axes.get(axis),
+            AxisSource::StickX(stick) => gamepad_axes.stick(stick).x,
+            AxisSource::StickY(stick) => gamepad_axes.stick(stick).y,
+            AxisSource::MouseX => movement.get_scaled_mouse_delta().x as f32,
+            AxisSource::MouseY => movement.get_scaled_mouse_delta().y as f32,
+            AxisSource::ScrollY => movement.get_scroll_delta().y,
+        };
+        let per_frame = if binding.source.is_per_frame() {
+            value
+        } else {
+            value * delta_secs
+        };
+        *values.entry(binding.axis).or_default() += per_frame * binding.scale;
+    }
+
+    for (axis, value) in values {
+        action_state.set_axis(axis, input_map.axis_settings(axis).apply(value));
     }
 }
+
+/// Every input held this frame, along with whether it was pressed this frame.
+fn held<'a, T: Copy + Eq + Hash + Send + Sync + 'static>(
+    buttons: &'a Buttons<T>,
+    to_input: impl Fn(T) -> Input + 'a,
+) -> impl Iterator<Item = (Input, bool)> + 'a {
+    buttons
+        .iter_current()
+        .map(move |&button| (to_input(button), buttons.was_pressed(button)))
+}
+
+/// Every input held this frame or the last.
+fn touched<'a, T: Copy + Eq + Hash + Send + Sync + 'static>(
+    buttons: &'a Buttons<T>,
+    to_input: impl Fn(T) -> Input + 'a,
+) -> impl Iterator<Item = Input> + 'a {
+    buttons
+        .iter_previous()
+        .chain(buttons.iter_current())
+        .map(move |&button| to_input(button))
+}
@@ -0,0 +1,58 @@
+use crate::{
+    prelude::*,
+    simulation_world::input::{
+        resources::{Buttons, GamepadAxes, GamepadBackendResource, GamepadEvent},
+        types::gamepad::{AxisDirection, GamepadAxis, GamepadButton},
+    },
+};
+use bevy_ecs::prelude::*;
+
+/// A system that polls the gamepad backend and updates the gamepad button and axis state.
+///
+/// Axes pushed past the press threshold also count as held buttons, so they can be bound
+/// to digital actions.
+#[instrument(skip_all)]
+pub fn gamepad_events_system(
+    // Input
+    backend: Res<GamepadBackendResource>,
+
+    // Output
+    mut buttons: ResMut<Buttons<GamepadButton>>,
+    mut half_axes: ResMut<Buttons<(GamepadAxis, AxisDirection)>>,
+    mut axes: ResMut<GamepadAxes>,
+    mut events: Local<Vec<GamepadEvent>>,
+) {
+    buttons.swap_previous();
+    half_axes.swap_previous();
+
+    events.clear();
+    backend.0.lock().unwrap().poll(&mut events);
+
+    for event in events.iter() {
+        match *event {
+            GamepadEvent::ButtonPressed(button) => buttons.press(button),
+            GamepadEvent::ButtonReleased(button) => buttons.release(button),
+            GamepadEvent::AxisChanged(axis, value) => axes.set(axis, value),
+            GamepadEvent::Disconnected => {
+                let held: Vec<_> = buttons.iter_current().copied().collect();
+                for button in held {
+                    buttons.release(button);
+                }
+                axes.clear();
+            }
+        }
+    }
+
+    let pressed: Vec<_> = axes.pressed_directions().collect();
+    let released: Vec<_> = half_axes
+        .iter_current()
+        .filter(|half| !pressed.contains(half))
+        .copied()
+        .collect();
+    for half in released {
+        half_axes.release(half);
+    }
+    for half in pressed {
+        half_axes.press(half);
+    }
+}
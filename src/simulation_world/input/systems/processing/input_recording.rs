@@ -0,0 +1,101 @@
+use crate::{
+    prelude::*,
+    simulation_world::{
+        input::resources::{
+            ActionStateResource, CursorMovement, InputFrame, InputRecorder, InputReplay,
+        },
+        time::FrameClock,
+    },
+};
+use bevy_ecs::prelude::*;
+
+/// A system that appends this frame's action transitions, axis values, cursor deltas and
+/// frame time to the `InputRecorder`.
+#[instrument(skip_all)]
+pub fn record_input_frame_system(
+    // Input
+    action_state: Res<ActionStateResource>,
+    movement: Res<CursorMovement>,
+    clock: Res<FrameClock>,
+
+    // Output
+    mut recorder: ResMut<InputRecorder>,
+) {
+    let sorted = |actions: &mut dyn Iterator<Item = _>| {
+        let mut actions: Vec<_> = actions.collect();
+        actions.sort_unstable();
+        actions
+    };
+
+    let held = sorted(
+        &mut action_state
+            .iter_ongoing()
+            .filter(|action| !recorder.ongoing.contains(action)),
+    );
+    recorder.ongoing = action_state.iter_ongoing().collect();
+
+    let mut axes: Vec<_> = action_state.iter_axes().collect();
+    axes.sort_unstable_by_key(|&(axis, _)| axis);
+
+    let frame = InputFrame {
+        delta: clock.delta,
+        pressed: sorted(&mut action_state.iter_just_happened()),
+        held,
+        released: sorted(&mut action_state.iter_ended()),
+        axes,
+        mouse_delta: movement.get_mouse_delta().to_array(),
+        scroll_delta: movement.get_scroll_delta().to_array(),
+    };
+    recorder.recording.frames.push(frame);
+}
+
+/// A system that plays the next recorded frame in place of the live input.
+///
+/// The frame time is fed through `FrameClock::fixed_step`, so the clock update that
+/// follows sees exactly the recorded delta.
+#[instrument(skip_all)]
+pub fn replay_input_frame_system(
+    mut commands: Commands,
+    mut replay: ResMut<InputReplay>,
+
+    // Output
+    mut action_state: ResMut<ActionStateResource>,
+    mut movement: ResMut<CursorMovement>,
+    mut clock: ResMut<FrameClock>,
+) {
+    if replay.resume_fixed_step.is_none() {
+        replay.resume_fixed_step = Some(clock.fixed_step);
+    }
+
+    action_state.clear();
+    movement.reset_deltas();
+
+    let Some(frame) = replay.recording.frames.get(replay.next_frame) else {
+        info!("Input replay finished, handing input back.");
+        let still_held: Vec<_> = action_state.iter_ongoing().collect();
+        for action in still_held {
+            action_state.release(action);
+        }
+        clock.fixed_step = replay.resume_fixed_step.flatten();
+        commands.remove_resource::<InputReplay>();
+        return;
+    };
+
+    for &action in &frame.pressed {
+        action_state.press(action);
+    }
+    for &action in &frame.held {
+        action_state.hold(action);
+    }
+    for &action in &frame.released {
+        action_state.release(action);
+    }
+    for &(axis, value) in &frame.axes {
+        action_state.set_axis(axis, value);
+    }
+    movement.adjust_mouse_delta(frame.mouse_delta());
+    movement.adjust_scroll_delta(frame.scroll_delta());
+    clock.fixed_step = Some(frame.delta);
+
+    replay.next_frame += 1;
+}
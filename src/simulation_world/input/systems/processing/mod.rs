@@ -1,9 +1,17 @@
 pub mod action_mapper;
 pub mod clear_stale_input;
 pub mod device_events;
+pub mod gamepad_events;
+pub mod input_recording;
+pub mod mouse_sensitivity;
+pub mod save_keybinds;
 pub mod window_events;
 
-pub use action_mapper::update_action_state_system;
+pub use action_mapper::{update_action_state_system, update_axis_state_system};
 pub use clear_stale_input::clear_stale_input_events_system;
 pub use device_events::device_events_system;
+pub use gamepad_events::gamepad_events_system;
+pub use input_recording::{record_input_frame_system, replay_input_frame_system};
+pub use mouse_sensitivity::apply_mouse_sensitivity_setting_system;
+pub use save_keybinds::save_keybinds_system;
 pub use window_events::{handle_resize_system, window_events_system};
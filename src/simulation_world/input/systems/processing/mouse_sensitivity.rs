@@ -0,0 +1,14 @@
+use crate::{ecs_core::AppConfig, prelude::*, simulation_world::input::CursorMovement};
+use bevy_ecs::prelude::*;
+
+/// Takes the mouse sensitivity from the settings whenever they change.
+#[instrument(skip_all)]
+pub fn apply_mouse_sensitivity_setting_system(
+    // Input
+    config: Res<AppConfig>,
+
+    // Output
+    mut movement: ResMut<CursorMovement>,
+) {
+    movement.set_mouse_sensitivity(config.mouse_sensitivity);
+}
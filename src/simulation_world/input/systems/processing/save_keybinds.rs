@@ -0,0 +1,18 @@
+use crate::{prelude::*, simulation_world::input::InputActionMapResource};
+use bevy_ecs::prelude::Res;
+
+/// Writes the key bindings back to their file after they change at runtime.
+#[instrument(skip_all)]
+pub fn save_keybinds_system(
+    // Input
+    input_map: Res<InputActionMapResource>,
+) {
+    let Some(path) = input_map.save_path() else {
+        return;
+    };
+
+    match input_map.save(path) {
+        Ok(()) => info!("Saved keybinds to {}", path.display()),
+        Err(e) => error!("Failed to save keybinds to {}: {}", path.display(), e),
+    }
+}
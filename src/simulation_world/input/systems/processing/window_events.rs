@@ -3,12 +3,13 @@ use crate::simulation_world::input::{
         internal::MouseResizeMessage, KeyboardInputMessage, MouseButtonInputMessage,
         RawWindowMessage,
     },
-    resources::{Buttons, WindowSizeResource},
+    resources::{Buttons, CursorPosition, WindowSizeResource},
 };
 use bevy_ecs::{
     message::{MessageReader, MessageWriter},
     system::ResMut,
 };
+use glam::Vec2;
 use tracing::instrument;
 use winit::{
     event::{ElementState, MouseButton, WindowEvent},
@@ -21,6 +22,7 @@ pub fn window_events_system(
     // State to modify
     mut keyboard_input: ResMut<Buttons<PhysicalKey>>,
     mut mouse_input: ResMut<Buttons<MouseButton>>,
+    mut cursor_position: ResMut<CursorPosition>,
 
     // Input from OS bridge
     mut raw_window_events: MessageReader<RawWindowMessage>,
@@ -40,6 +42,7 @@ pub fn window_events_system(
                 let semantic_event = KeyboardInputMessage {
                     key_code: event.physical_key,
                     state: event.state,
+                    text: event.text.as_ref().map(|text| text.to_string()),
                 };
 
                 match semantic_event.state {
@@ -62,6 +65,10 @@ pub fn window_events_system(
 
                 mouse_button_writer.write(semantic_event);
             }
+            WindowEvent::CursorMoved { position, .. } => {
+                cursor_position.0 = Some(Vec2::new(position.x as f32, position.y as f32));
+            }
+            WindowEvent::CursorLeft { .. } => cursor_position.0 = None,
             WindowEvent::Resized(physical_size) => {
                 resize_writer.write(MouseResizeMessage {
                     width: physical_size.width,
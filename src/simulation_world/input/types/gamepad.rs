@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+/// A gamepad button, named by position (`South` is A on Xbox and Cross on PlayStation).
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum GamepadButton {
+    // Action pad
+    South,
+    East,
+    North,
+    West,
+
+    // Bumpers and triggers
+    LeftTrigger,
+    LeftTrigger2,
+    RightTrigger,
+    RightTrigger2,
+
+    // Menu pad
+    Select,
+    Start,
+    Mode,
+
+    // Stick clicks
+    LeftThumb,
+    RightThumb,
+
+    // D-pad
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+/// An analog gamepad axis, sticks go from -1 to 1 (up and right are positive).
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+    /// Analog left trigger, on pads that report it as an axis.
+    LeftZ,
+    /// Analog right trigger, on pads that report it as an axis.
+    RightZ,
+}
+
+/// One half of an axis, so it can be bound like a button.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum AxisDirection {
+    Positive,
+    Negative,
+}
+
+impl AxisDirection {
+    /// The direction `value` points in, if any.
+    pub fn of(value: f32) -> Option<Self> {
+        if value > 0.0 {
+            Some(AxisDirection::Positive)
+        } else if value < 0.0 {
+            Some(AxisDirection::Negative)
+        } else {
+            None
+        }
+    }
+}
+
+/// One of the two sticks.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum GamepadStick {
+    Left,
+    Right,
+}
+
+impl GamepadStick {
+    pub fn axes(self) -> (GamepadAxis, GamepadAxis) {
+        match self {
+            GamepadStick::Left => (GamepadAxis::LeftStickX, GamepadAxis::LeftStickY),
+            GamepadStick::Right => (GamepadAxis::RightStickX, GamepadAxis::RightStickY),
+        }
+    }
+}
@@ -1,3 +1,7 @@
+pub mod gamepad;
 pub mod simulation_action;
+pub mod simulation_axis;
 
+pub use gamepad::{AxisDirection, GamepadAxis, GamepadButton, GamepadStick};
 pub use simulation_action::SimulationAction;
+pub use simulation_axis::SimulationAxis;
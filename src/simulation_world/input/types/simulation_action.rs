@@ -1,17 +1,30 @@
+use serde::{Deserialize, Serialize};
+
 /// Defines
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
 pub enum SimulationAction {
-    // Core player movement
-    MoveForward,
-    MoveBackward,
-    MoveLeft,
-    MoveRight,
+    // Core player movement (the direction itself is an axis, see `SimulationAxis`)
     MoveFaster,
 
     // Core player interaction
     BreakVoxel,
     PlaceVoxel,
 
+    // World edit interactions
+    SelectFirstCorner,
+    SelectSecondCorner,
+    WorldEditFill,
+    WorldEditReplace,
+    WorldEditHollow,
+    WorldEditWalls,
+    WorldEditCopy,
+    WorldEditRotate,
+    WorldEditPaste,
+
+    // Edit history
+    UndoEdit,
+    RedoEdit,
+
     // Terrain interactions
     CycleActiveTerrainGenerator,
 
@@ -24,7 +37,9 @@ pub enum SimulationAction {
     ToggleDiagnostics,
     ToggleOpaqueWireframeMode,
     ToggleChunkBorders,
+    CycleChunkMesher,
     TogglePause,
+    ToggleConsole,
 
     // Showcase actions
     Showcase1,
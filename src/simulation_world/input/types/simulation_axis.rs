@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// An analog action, read as a value rather than pressed or released.
+///
+/// The value is the amount for the current frame. Held inputs like keys and sticks are
+/// scaled by the frame time, while mouse and scroll deltas are already per frame.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum SimulationAxis {
+    // Core player movement
+    MoveForward,
+    MoveRight,
+
+    // Camera
+    LookRight,
+    LookUp,
+    ZoomIn,
+}
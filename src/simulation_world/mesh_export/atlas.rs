@@ -0,0 +1,85 @@
+use crate::prelude::*;
+use crate::render_world::textures::registry::TextureId;
+use image::{Rgba, RgbaImage};
+
+/// Color of the placeholder tile used when there are no textures to pack.
+const MISSING_COLORS: [Rgba<u8>; 2] = [Rgba([255, 0, 255, 255]), Rgba([0, 0, 0, 255])];
+
+/// The layers of the block texture array packed side by side into a single image.
+///
+/// Offline tools don't understand texture arrays, so exported meshes point into
+/// this instead.
+#[derive(Debug, Clone)]
+pub struct TextureAtlas {
+    pub image: RgbaImage,
+    tile_width: u32,
+    tile_height: u32,
+    columns: u32,
+    tile_count: u32,
+}
+
+impl TextureAtlas {
+    /// Packs texture array layers (indexed by `TextureId`) into a roughly square grid.
+    ///
+    /// All layers are expected to share the size of the first, as they do in the
+    /// texture array.
+    pub fn from_layers(layers: &[RgbaImage]) -> Self {
+        let Some(first) = layers.first() else {
+            return Self::missing();
+        };
+        let (tile_width, tile_height) = first.dimensions();
+        let tile_count = layers.len() as u32;
+        let columns = (tile_count as f32).sqrt().ceil() as u32;
+        let rows = tile_count.div_ceil(columns);
+
+        let mut image = RgbaImage::new(columns * tile_width, rows * tile_height);
+        for (index, layer) in layers.iter().enumerate() {
+            let index = index as u32;
+            let (x, y) = (
+                (index % columns) * tile_width,
+                (index / columns) * tile_height,
+            );
+            image::imageops::replace(&mut image, layer, x as i64, y as i64);
+        }
+
+        Self {
+            image,
+            tile_width,
+            tile_height,
+            columns,
+            tile_count,
+        }
+    }
+
+    /// A single checkered tile, for when the real textures aren't available.
+    pub fn missing() -> Self {
+        let image = RgbaImage::from_fn(2, 2, |x, y| MISSING_COLORS[((x + y) % 2) as usize]);
+        Self {
+            image,
+            tile_width: 2,
+            tile_height: 2,
+            columns: 1,
+            tile_count: 1,
+        }
+    }
+
+    /// Maps a UV within a texture layer to a UV within the atlas.
+    ///
+    /// Both have V pointing down the image. UVs are pulled in by half a texel so
+    /// nearest sampling never bleeds into a neighboring tile, and texture IDs past
+    /// the last layer use the first one.
+    pub fn uv(&self, texture_id: TextureId, layer_uv: Vec2) -> Vec2 {
+        let index = if texture_id < self.tile_count {
+            texture_id
+        } else {
+            0
+        };
+        let tile = Vec2::new((index % self.columns) as f32, (index / self.columns) as f32);
+        let tile_size = Vec2::new(self.tile_width as f32, self.tile_height as f32);
+        let atlas_size = Vec2::new(self.image.width() as f32, self.image.height() as f32);
+
+        let half_texel = Vec2::splat(0.5) / tile_size;
+        let inset = layer_uv.clamp(half_texel, Vec2::ONE - half_texel);
+        (tile + inset) * tile_size / atlas_size
+    }
+}
@@ -0,0 +1,33 @@
+use std::{fmt, io::Error};
+
+#[derive(Debug)]
+pub enum MeshExportError {
+    IoError(Error),
+    ImageError(image::ImageError),
+    /// The mesh can't be written in the requested format.
+    Unsupported(String),
+}
+
+impl From<Error> for MeshExportError {
+    fn from(err: Error) -> Self {
+        MeshExportError::IoError(err)
+    }
+}
+
+impl From<image::ImageError> for MeshExportError {
+    fn from(err: image::ImageError) -> Self {
+        MeshExportError::ImageError(err)
+    }
+}
+
+impl fmt::Display for MeshExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshExportError::IoError(err) => write!(f, "Mesh export IO error: {err}"),
+            MeshExportError::ImageError(err) => write!(f, "Mesh export image error: {err}"),
+            MeshExportError::Unsupported(what) => write!(f, "Unsupported mesh export: {what}"),
+        }
+    }
+}
+
+impl std::error::Error for MeshExportError {}
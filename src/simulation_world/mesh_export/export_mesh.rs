@@ -0,0 +1,97 @@
+use crate::prelude::*;
+use crate::render_world::types::{FaceExtent, PackedFace, QUAD_TRIANGLES};
+use crate::simulation_world::mesh_export::TextureAtlas;
+
+/// Plain vertex and index buffers, in the layout the exporters write.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeshBuffers {
+    pub positions: Vec<[f32; 3]>,
+    pub normals: Vec<[f32; 3]>,
+    /// Atlas UVs, with V pointing down the image.
+    pub uvs: Vec<[f32; 2]>,
+    /// AO brightness, written as a grey vertex color.
+    pub ao: Vec<f32>,
+    pub indices: Vec<u32>,
+}
+
+impl MeshBuffers {
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// The component-wise min and max of every position.
+    pub fn bounds(&self) -> (Vec3, Vec3) {
+        self.positions.iter().fold(
+            (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+            |(min, max), &p| (min.min(Vec3::from(p)), max.max(Vec3::from(p))),
+        )
+    }
+}
+
+/// Chunk meshes decoded from their packed faces into regular triangle meshes.
+///
+/// Opaque and transparent faces are kept apart so they can get separate materials.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExportMesh {
+    pub opaque: MeshBuffers,
+    pub transparent: MeshBuffers,
+}
+
+impl ExportMesh {
+    /// Decodes the faces of one chunk mesh and appends them.
+    ///
+    /// `origin` is the world position of the chunk and `scale` the size of one of
+    /// its voxels in blocks.
+    ///
+    /// `extents` is empty unless the mesh was greedy merged.
+    pub fn push_faces(
+        &mut self,
+        faces: &[PackedFace],
+        extents: &[FaceExtent],
+        transparent: bool,
+        origin: Vec3,
+        scale: f32,
+        atlas: &TextureAtlas,
+    ) {
+        let buffers = if transparent {
+            &mut self.transparent
+        } else {
+            &mut self.opaque
+        };
+
+        for (index, face) in faces.iter().enumerate() {
+            let extent = extents.get(index).copied().unwrap_or(FaceExtent::UNIT);
+            let face = face.unpack_with_extent(extent);
+            let first_index = buffers.positions.len() as u32;
+
+            for corner in face.corners(transparent) {
+                buffers
+                    .positions
+                    .push((origin + corner.position * scale).to_array());
+                buffers.normals.push(corner.normal.to_array());
+                buffers
+                    .uvs
+                    .push(atlas.uv(face.texture_id, corner.uv).to_array());
+                buffers.ao.push(corner.ao);
+            }
+
+            buffers.indices.extend(
+                QUAD_TRIANGLES
+                    .iter()
+                    .map(|&corner| first_index + corner as u32),
+            );
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.opaque.is_empty() && self.transparent.is_empty()
+    }
+
+    pub fn face_count(&self) -> usize {
+        (self.opaque.indices.len() + self.transparent.indices.len()) / QUAD_TRIANGLES.len()
+    }
+}
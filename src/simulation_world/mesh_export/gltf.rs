@@ -0,0 +1,201 @@
+use crate::simulation_world::mesh_export::{
+    ExportMesh, MeshBuffers, MeshExportError, TextureAtlas,
+};
+use std::{fmt::Write as _, fs, io::Cursor, path::Path};
+
+// glTF constants
+const GLB_MAGIC: &[u8; 4] = b"glTF";
+const GLB_VERSION: u32 = 2;
+const CHUNK_JSON: u32 = 0x4E4F534A;
+const CHUNK_BIN: u32 = 0x004E4942;
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const NEAREST: u32 = 9728;
+const CLAMP_TO_EDGE: u32 = 33071;
+
+/// Writes `mesh` as a single self contained binary glTF (`.glb`), atlas included.
+///
+/// Opaque faces use an alpha masked material and transparent faces a blended one.
+/// AO is stored as `COLOR_0`, which multiplies the base color in glTF.
+pub fn write_glb(
+    path: &Path,
+    mesh: &ExportMesh,
+    atlas: &TextureAtlas,
+) -> Result<(), MeshExportError> {
+    let mut gltf = GltfBuilder::default();
+
+    let mut primitives = Vec::new();
+    for (material, buffers) in [&mesh.opaque, &mesh.transparent].into_iter().enumerate() {
+        if !buffers.is_empty() {
+            primitives.push(gltf.push_primitive(buffers, material));
+        }
+    }
+
+    let mut png = Vec::new();
+    atlas
+        .image
+        .write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)?;
+    let image_view = gltf.push_view(&png, None);
+
+    // INFO: -------------------
+    //         json chunk
+    // -------------------------
+
+    let mut json = String::from(r#"{"asset":{"version":"2.0","generator":"b"},"#);
+    if primitives.is_empty() {
+        json.push_str(r#""scene":0,"scenes":[{}],"#);
+    } else {
+        let _ = write!(
+            json,
+            r#""scene":0,"scenes":[{{"nodes":[0]}}],"nodes":[{{"mesh":0,"name":"chunks"}}],"meshes":[{{"primitives":[{}]}}],"#,
+            primitives.join(",")
+        );
+    }
+    let _ = write!(
+        json,
+        r#""materials":[{},{}],"#,
+        material_json("opaque", r#""alphaMode":"MASK","alphaCutoff":0.5"#),
+        material_json("transparent", r#""alphaMode":"BLEND","doubleSided":true"#)
+    );
+    let _ = write!(
+        json,
+        r#""textures":[{{"sampler":0,"source":0}}],"samplers":[{{"magFilter":{NEAREST},"minFilter":{NEAREST},"wrapS":{CLAMP_TO_EDGE},"wrapT":{CLAMP_TO_EDGE}}}],"images":[{{"bufferView":{image_view},"mimeType":"image/png"}}],"#
+    );
+    let _ = write!(
+        json,
+        r#""accessors":[{}],"bufferViews":[{}],"buffers":[{{"byteLength":{}}}]}}"#,
+        gltf.accessors.join(","),
+        gltf.views.join(","),
+        gltf.bin.len()
+    );
+
+    // INFO: ------------------
+    //         glb layout
+    // ------------------------
+
+    let mut json = json.into_bytes();
+    json.resize(json.len().next_multiple_of(4), b' ');
+    let bin = gltf.bin;
+
+    let total_len = 12 + 8 + json.len() + 8 + bin.len();
+    let mut out = Vec::with_capacity(total_len);
+    out.extend(GLB_MAGIC);
+    out.extend(GLB_VERSION.to_le_bytes());
+    out.extend((total_len as u32).to_le_bytes());
+    out.extend((json.len() as u32).to_le_bytes());
+    out.extend(CHUNK_JSON.to_le_bytes());
+    out.extend(json);
+    out.extend((bin.len() as u32).to_le_bytes());
+    out.extend(CHUNK_BIN.to_le_bytes());
+    out.extend(bin);
+
+    fs::write(path, out)?;
+    Ok(())
+}
+
+fn material_json(name: &str, alpha: &str) -> String {
+    format!(
+        r#"{{"name":"{name}","pbrMetallicRoughness":{{"baseColorTexture":{{"index":0}},"metallicFactor":0.0,"roughnessFactor":1.0}},{alpha}}}"#
+    )
+}
+
+/// Accumulates the binary buffer along with the views and accessors into it.
+#[derive(Default)]
+struct GltfBuilder {
+    bin: Vec<u8>,
+    views: Vec<String>,
+    accessors: Vec<String>,
+}
+
+impl GltfBuilder {
+    /// Appends 4 byte aligned data as a new buffer view and returns its index.
+    fn push_view(&mut self, bytes: &[u8], target: Option<u32>) -> usize {
+        let offset = self.bin.len();
+        self.bin.extend_from_slice(bytes);
+        self.bin.resize(self.bin.len().next_multiple_of(4), 0);
+
+        let target = target
+            .map(|target| format!(r#","target":{target}"#))
+            .unwrap_or_default();
+        self.views.push(format!(
+            r#"{{"buffer":0,"byteOffset":{offset},"byteLength":{}{target}}}"#,
+            bytes.len()
+        ));
+        self.views.len() - 1
+    }
+
+    /// Appends an accessor over a new buffer view and returns its index.
+    fn push_accessor(
+        &mut self,
+        bytes: &[u8],
+        target: u32,
+        component_type: u32,
+        count: usize,
+        kind: &str,
+        extra: &str,
+    ) -> usize {
+        let view = self.push_view(bytes, Some(target));
+        self.accessors.push(format!(
+            r#"{{"bufferView":{view},"componentType":{component_type},"count":{count},"type":"{kind}"{extra}}}"#
+        ));
+        self.accessors.len() - 1
+    }
+
+    /// Writes the buffers of one primitive and returns its json.
+    fn push_primitive(&mut self, buffers: &MeshBuffers, material: usize) -> String {
+        let count = buffers.vertex_count();
+        let (min, max) = buffers.bounds();
+        let bounds = format!(
+            r#","min":[{},{},{}],"max":[{},{},{}]"#,
+            min.x, min.y, min.z, max.x, max.y, max.z
+        );
+        let colors: Vec<[f32; 3]> = buffers.ao.iter().map(|&ao| [ao; 3]).collect();
+
+        let position = self.push_accessor(
+            bytemuck::cast_slice(&buffers.positions),
+            ARRAY_BUFFER,
+            FLOAT,
+            count,
+            "VEC3",
+            &bounds,
+        );
+        let normal = self.push_accessor(
+            bytemuck::cast_slice(&buffers.normals),
+            ARRAY_BUFFER,
+            FLOAT,
+            count,
+            "VEC3",
+            "",
+        );
+        let uv = self.push_accessor(
+            bytemuck::cast_slice(&buffers.uvs),
+            ARRAY_BUFFER,
+            FLOAT,
+            count,
+            "VEC2",
+            "",
+        );
+        let color = self.push_accessor(
+            bytemuck::cast_slice(&colors),
+            ARRAY_BUFFER,
+            FLOAT,
+            count,
+            "VEC3",
+            "",
+        );
+        let indices = self.push_accessor(
+            bytemuck::cast_slice(&buffers.indices),
+            ELEMENT_ARRAY_BUFFER,
+            UNSIGNED_INT,
+            buffers.indices.len(),
+            "SCALAR",
+            "",
+        );
+
+        format!(
+            r#"{{"attributes":{{"POSITION":{position},"NORMAL":{normal},"TEXCOORD_0":{uv},"COLOR_0":{color}}},"indices":{indices},"material":{material}}}"#
+        )
+    }
+}
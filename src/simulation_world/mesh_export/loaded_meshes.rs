@@ -0,0 +1,173 @@
+use crate::prelude::*;
+use crate::{
+    ecs_core::AppConfig,
+    render_world::textures::load_voxel_texture_assets,
+    simulation_world::{
+        asset_management::{AssetStorageResource, MeshAsset},
+        chunk::{
+            ChunkBlocksComponent, ChunkCoord, OpaqueMeshComponent, TransformComponent,
+            TransparentMeshComponent,
+        },
+        mesh_export::{write_export_mesh, ExportMesh, MeshExportError, TextureAtlas},
+    },
+};
+use bevy_ecs::{prelude::*, system::SystemParam};
+use std::path::{Path, PathBuf};
+
+/// A box of chunk coordinates, with both corners inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkRange {
+    pub min: IVec3,
+    pub max: IVec3,
+}
+
+impl ChunkRange {
+    /// The range between two corners, in any order.
+    pub fn from_corners(a: IVec3, b: IVec3) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    pub fn contains(&self, chunk_pos: IVec3) -> bool {
+        chunk_pos.cmpge(self.min).all() && chunk_pos.cmple(self.max).all()
+    }
+}
+
+/// Writes the meshes of the loaded chunks to a file.
+///
+/// The format (`.obj` or `.glb`) follows the file extension.
+#[derive(Message, Clone, Debug, PartialEq, Eq)]
+pub struct MeshExportEvent {
+    pub path: PathBuf,
+    /// Only export chunks in this range, instead of every loaded chunk.
+    pub range: Option<ChunkRange>,
+}
+
+/// What ended up in an exported mesh.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MeshExportSummary {
+    pub chunks: usize,
+    pub faces: usize,
+}
+
+type ChunkMeshData = (
+    &'static ChunkCoord,
+    &'static TransformComponent,
+    Option<&'static ChunkBlocksComponent>,
+    Option<&'static OpaqueMeshComponent>,
+    Option<&'static TransparentMeshComponent>,
+);
+
+type WithChunkMesh = Or<(With<OpaqueMeshComponent>, With<TransparentMeshComponent>)>;
+
+/// Read access to the meshes of every loaded chunk.
+#[derive(SystemParam)]
+pub struct LoadedChunkMeshes<'w, 's> {
+    chunks: Query<'w, 's, ChunkMeshData, WithChunkMesh>,
+    meshes: Res<'w, AssetStorageResource<MeshAsset>>,
+    app_config: Option<Res<'w, AppConfig>>,
+}
+
+impl LoadedChunkMeshes<'_, '_> {
+    /// Decodes the meshes of the loaded chunks in `range` (or all of them).
+    ///
+    /// Chunks are added in coordinate order so repeated exports of the same area match.
+    pub fn build_mesh(
+        &self,
+        range: Option<ChunkRange>,
+        atlas: &TextureAtlas,
+    ) -> (ExportMesh, MeshExportSummary) {
+        let mut chunks: Vec<_> = self
+            .chunks
+            .iter()
+            .filter(|(coord, ..)| range.is_none_or(|range| range.contains(coord.pos)))
+            .collect();
+        chunks.sort_by_key(|(coord, ..)| coord.pos.to_array());
+
+        let mut mesh = ExportMesh::default();
+        for (_, transform, blocks, opaque, transparent) in &chunks {
+            let scale = blocks.map_or(1.0, |blocks| (1u32 << blocks.lod().0) as f32);
+            let handles = [
+                (opaque.map(|mesh| mesh.mesh_handle), false),
+                (transparent.map(|mesh| mesh.mesh_handle), true),
+            ];
+
+            for (handle, is_transparent) in handles {
+                let Some(handle) = handle else {
+                    continue;
+                };
+                self.meshes.with(handle, |asset| {
+                    mesh.push_faces(
+                        &asset.faces,
+                        &asset.extents,
+                        is_transparent,
+                        transform.position,
+                        scale,
+                        atlas,
+                    )
+                });
+            }
+        }
+
+        let summary = MeshExportSummary {
+            chunks: chunks.len(),
+            faces: mesh.face_count(),
+        };
+        (mesh, summary)
+    }
+
+    /// Packs the block textures of the configured texture pack into an atlas.
+    ///
+    /// Falls back to a placeholder tile when the textures can't be loaded.
+    pub fn load_atlas(&self) -> TextureAtlas {
+        let Some(app_config) = &self.app_config else {
+            return TextureAtlas::missing();
+        };
+
+        match load_voxel_texture_assets(app_config) {
+            Ok((staging, _)) => TextureAtlas::from_layers(&staging.images),
+            Err(e) => {
+                warn!(target: "mesh_export", "Exporting without textures: {:?}", e);
+                TextureAtlas::missing()
+            }
+        }
+    }
+
+    /// Builds and writes the mesh of the loaded chunks in `range` (or all of them).
+    pub fn export(
+        &self,
+        path: &Path,
+        range: Option<ChunkRange>,
+    ) -> Result<MeshExportSummary, MeshExportError> {
+        let atlas = self.load_atlas();
+        let (mesh, summary) = self.build_mesh(range, &atlas);
+        write_export_mesh(path, &mesh, &atlas)?;
+        Ok(summary)
+    }
+}
+
+/// A system that handles every pending `MeshExportEvent`.
+///
+/// Exporting decodes and writes everything on the spot, so it stalls the frame
+/// it happens on.
+#[instrument(skip_all)]
+pub fn handle_mesh_export_events_system(
+    // Input
+    mut events: MessageReader<MeshExportEvent>,
+    loaded_meshes: LoadedChunkMeshes,
+) {
+    for event in events.read() {
+        match loaded_meshes.export(&event.path, event.range) {
+            Ok(summary) => info!(
+                target: "mesh_export",
+                "Exported {} faces from {} chunks to {:?}",
+                summary.faces,
+                summary.chunks,
+                event.path
+            ),
+            Err(e) => error!(target: "mesh_export", "Failed to export {:?}: {}", event.path, e),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+pub mod atlas;
+pub mod error;
+pub mod export_mesh;
+pub mod gltf;
+pub mod loaded_meshes;
+pub mod obj;
+
+pub use atlas::TextureAtlas;
+pub use error::MeshExportError;
+pub use export_mesh::{ExportMesh, MeshBuffers};
+pub use gltf::write_glb;
+pub use loaded_meshes::{
+    handle_mesh_export_events_system, ChunkRange, LoadedChunkMeshes, MeshExportEvent,
+    MeshExportSummary,
+};
+pub use obj::{atlas_path, write_obj};
+
+use std::{fs, path::Path};
+
+/// The mesh file formats, picked by file extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeshExportFormat {
+    /// Wavefront `.obj`, with a `.mtl` and an atlas png next to it.
+    Obj,
+    /// Binary glTF `.glb`, with the atlas embedded.
+    Glb,
+}
+
+impl MeshExportFormat {
+    pub fn from_path(path: &Path) -> Result<Self, MeshExportError> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("obj") => Ok(MeshExportFormat::Obj),
+            Some("glb") => Ok(MeshExportFormat::Glb),
+            _ => Err(MeshExportError::Unsupported(format!(
+                "{path:?} is neither a .obj nor a .glb file"
+            ))),
+        }
+    }
+}
+
+/// Writes a mesh in the format matching the file extension.
+pub fn write_export_mesh(
+    path: &Path,
+    mesh: &ExportMesh,
+    atlas: &TextureAtlas,
+) -> Result<(), MeshExportError> {
+    let format = MeshExportFormat::from_path(path)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    match format {
+        MeshExportFormat::Obj => write_obj(path, mesh, atlas),
+        MeshExportFormat::Glb => write_glb(path, mesh, atlas),
+    }
+}
+
+// INFO: ---------------------------
+//         Mesh export plugin
+// ---------------------------------
+
+use crate::prelude::*;
+use bevy_ecs::message::Messages;
+use bevy_ecs::prelude::*;
+
+pub struct MeshExportPlugin;
+
+impl Plugin for MeshExportPlugin {
+    fn build(&self, builder: &mut EcsBuilder) {
+        builder
+            .init_resource::<Messages<MeshExportEvent>>()
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(handle_mesh_export_events_system.in_set(SimulationSet::Update));
+    }
+}
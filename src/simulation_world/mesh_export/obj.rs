@@ -0,0 +1,82 @@
+use crate::simulation_world::mesh_export::{
+    ExportMesh, MeshBuffers, MeshExportError, TextureAtlas,
+};
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Where the atlas image of an exported mesh is written.
+pub fn atlas_path(mesh_path: &Path) -> PathBuf {
+    let stem = mesh_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "mesh".to_string());
+    mesh_path.with_file_name(format!("{stem}_atlas.png"))
+}
+
+/// Writes `mesh` as a Wavefront OBJ, with its material library and atlas next to it.
+///
+/// AO is written as vertex colors (`v x y z r g b`), which Blender and most other
+/// tools pick up. V is flipped since OBJ UVs start at the bottom of the image.
+pub fn write_obj(
+    path: &Path,
+    mesh: &ExportMesh,
+    atlas: &TextureAtlas,
+) -> Result<(), MeshExportError> {
+    let mtl_path = path.with_extension("mtl");
+    let atlas_path = atlas_path(path);
+    let file_name = |path: &Path| {
+        path.file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned()
+    };
+
+    let mut obj = String::new();
+    let _ = writeln!(obj, "mtllib {}", file_name(&mtl_path));
+
+    let mut first_vertex = 1;
+    for (name, buffers) in [("opaque", &mesh.opaque), ("transparent", &mesh.transparent)] {
+        if buffers.is_empty() {
+            continue;
+        }
+        write_object(&mut obj, name, buffers, first_vertex);
+        first_vertex += buffers.vertex_count();
+    }
+
+    let mut mtl = String::new();
+    for (name, opacity) in [("opaque", 1.0), ("transparent", 0.8)] {
+        let _ = writeln!(mtl, "newmtl {name}");
+        let _ = writeln!(mtl, "Kd 1.0 1.0 1.0");
+        let _ = writeln!(mtl, "d {opacity}");
+        let _ = writeln!(mtl, "map_Kd {}", file_name(&atlas_path));
+        let _ = writeln!(mtl, "map_d {}", file_name(&atlas_path));
+        let _ = writeln!(mtl);
+    }
+
+    fs::write(path, obj)?;
+    fs::write(&mtl_path, mtl)?;
+    atlas.image.save(&atlas_path)?;
+    Ok(())
+}
+
+fn write_object(obj: &mut String, name: &str, buffers: &MeshBuffers, first_vertex: usize) {
+    let _ = writeln!(obj, "o {name}");
+    let _ = writeln!(obj, "usemtl {name}");
+
+    for (p, ao) in buffers.positions.iter().zip(&buffers.ao) {
+        let _ = writeln!(obj, "v {} {} {} {ao} {ao} {ao}", p[0], p[1], p[2]);
+    }
+    for uv in &buffers.uvs {
+        let _ = writeln!(obj, "vt {} {}", uv[0], 1.0 - uv[1]);
+    }
+    for n in &buffers.normals {
+        let _ = writeln!(obj, "vn {} {} {}", n[0], n[1], n[2]);
+    }
+    for triangle in buffers.indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| triangle[i] as usize + first_vertex);
+        let _ = writeln!(obj, "f {a}/{a}/{a} {b}/{b}/{b} {c}/{c}/{c}");
+    }
+}
@@ -2,14 +2,22 @@ pub mod app_lifecycle;
 pub mod asset_management;
 pub mod biome;
 pub mod block;
+pub mod block_updates;
 pub mod chunk;
+pub mod console;
+pub mod edit_history;
+pub mod falling_block;
+pub mod fluid;
 pub mod input;
+pub mod mesh_export;
 pub mod player;
 pub mod scheduling;
 pub mod showcase;
 pub mod terrain;
 pub mod time;
 pub mod user_interface;
+pub mod world_edit;
+pub mod world_save;
 
 pub use scheduling::{
     FixedUpdateSet, OnEnter, OnExit, SimulationSchedule, SimulationSet, StartupSet,
@@ -27,16 +35,26 @@ use crate::simulation_world::{
     asset_management::{AssetManagementPlugin, MeshAsset},
     biome::BiomePlugin,
     block::BlockPlugin,
+    block_updates::BlockUpdatePlugin,
     chunk::ChunkLoadingPlugin,
-    input::{InputModulePlugin, WindowSizeResource},
-    player::PlayerPlugin,
+    console::ConsolePlugin,
+    edit_history::EditHistoryPlugin,
+    falling_block::FallingBlockPlugin,
+    fluid::FluidPlugin,
+    input::{ActionStateResource, InputModulePlugin, InputRecordingPlugin, WindowSizeResource},
+    mesh_export::MeshExportPlugin,
+    player::{HeadlessCameraPlugin, PlayerPlugin},
     showcase::ShowcasePlugin,
     terrain::TerrainGenerationPlugin,
     time::TimeControlPlugin,
     user_interface::UiPlugin,
+    world_edit::WorldEditPlugin,
 };
 use crate::{
-    ecs_core::{worlds::SimulationWorldMarker, CommonEcsInterface, EcsBuilder, PluginGroup},
+    ecs_core::{
+        async_loading::OnLoadComplete, state_machine::GameState, worlds::SimulationWorldMarker,
+        CommonEcsInterface, EcsBuilder, PluginGroup,
+    },
     simulation_world::app_lifecycle::AppLifecyclePlugin,
 };
 use bevy_ecs::prelude::*;
@@ -76,6 +94,49 @@ impl SimulationWorldInterface {
             .add_resource(texture_registry_resource);
 
         // configure schedule sets before adding plugins
+        Self::configure_schedule_sets(&mut builder);
+
+        // now add plugins, which can safely use the configured sets
+        builder
+            .add_plugins(SharedPlugins)
+            .add_plugins(ClientOnlyPlugins);
+
+        return Self::build_simulation_world(builder);
+    }
+
+    /// Creates a simulation world that runs without a window, input or renderer.
+    ///
+    /// The active camera has to be moved by whoever drives the world.
+    pub fn new_headless(texture_registry_resource: TextureRegistryResource) -> Self {
+        Self::new_headless_with(texture_registry_resource, |_| {})
+    }
+
+    /// Like `new_headless`, but lets the caller add resources before any plugin is built.
+    ///
+    /// Plugins only initialize resources (eg the block and biome registries) that are
+    /// not there yet, so this is how tests swap in their own.
+    pub fn new_headless_with(
+        texture_registry_resource: TextureRegistryResource,
+        configure: impl FnOnce(&mut EcsBuilder),
+    ) -> Self {
+        let mut builder = EcsBuilder::new();
+
+        builder
+            .add_resource(texture_registry_resource)
+            // some shared systems are gated on actions, they just never happen here
+            .init_resource::<ActionStateResource>();
+
+        configure(&mut builder);
+        Self::configure_schedule_sets(&mut builder);
+
+        builder
+            .add_plugins(SharedPlugins)
+            .add_plugins(HeadlessOnlyPlugins);
+
+        Self::build_simulation_world(builder)
+    }
+
+    fn configure_schedule_sets(builder: &mut EcsBuilder) {
         builder
             .schedules
             .entry(SimulationSchedule::Startup)
@@ -100,13 +161,6 @@ impl SimulationWorldInterface {
                 )
                     .chain(),
             );
-
-        // now add plugins, which can safely use the configured sets
-        builder
-            .add_plugins(SharedPlugins)
-            .add_plugins(ClientOnlyPlugins);
-
-        return Self::build_simulation_world(builder);
     }
 
     fn build_simulation_world(mut builder: EcsBuilder) -> SimulationWorldInterface {
@@ -141,8 +195,16 @@ impl PluginGroup for SharedPlugins {
             .add_plugin(BlockPlugin)
             .add_plugin(BiomePlugin)
             .add_plugin(ChunkLoadingPlugin)
+            .add_plugin(ConsolePlugin)
+            .add_plugin(EditHistoryPlugin)
+            .add_plugin(FluidPlugin)
+            .add_plugin(InputRecordingPlugin)
+            .add_plugin(BlockUpdatePlugin)
+            .add_plugin(FallingBlockPlugin)
+            .add_plugin(MeshExportPlugin)
             .add_plugin(TerrainGenerationPlugin)
-            .add_plugin(TimeControlPlugin);
+            .add_plugin(TimeControlPlugin)
+            .add_plugin(WorldEditPlugin);
     }
 }
 
@@ -150,10 +212,23 @@ impl PluginGroup for SharedPlugins {
 struct ClientOnlyPlugins;
 impl PluginGroup for ClientOnlyPlugins {
     fn build(self, builder: &mut EcsBuilder) {
+        // the client waits in the main menu instead of dropping straight into the world
         builder
+            .add_resource(OnLoadComplete::new(GameState::MainMenu))
             .add_plugin(PlayerPlugin)
             .add_plugin(UiPlugin)
             .add_plugin(InputModulePlugin)
             .add_plugin(ShowcasePlugin);
     }
 }
+
+/// Plugins that stand in for the client ones when running headless (no window or GPU)
+struct HeadlessOnlyPlugins;
+impl PluginGroup for HeadlessOnlyPlugins {
+    fn build(self, builder: &mut EcsBuilder) {
+        // there is no menu to click through, so go straight to playing
+        builder
+            .add_resource(OnLoadComplete::new(GameState::Playing))
+            .add_plugin(HeadlessCameraPlugin);
+    }
+}
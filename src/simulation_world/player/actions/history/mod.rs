@@ -0,0 +1,3 @@
+pub mod step_edit_history;
+
+pub use step_edit_history::step_edit_history_system;
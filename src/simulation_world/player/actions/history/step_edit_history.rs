@@ -0,0 +1,16 @@
+use crate::simulation_world::{edit_history::EditHistoryEvent, input::ActionStateResource};
+use crate::SimulationAction;
+use bevy_ecs::prelude::{MessageWriter, Res};
+
+/// Fires an `EditHistoryEvent` when undo or redo was just pressed.
+pub fn step_edit_history_system(
+    action_state: Res<ActionStateResource>,
+    mut history_writer: MessageWriter<EditHistoryEvent>,
+) {
+    if action_state.just_happened(SimulationAction::UndoEdit) {
+        history_writer.write(EditHistoryEvent::Undo);
+    }
+    if action_state.just_happened(SimulationAction::RedoEdit) {
+        history_writer.write(EditHistoryEvent::Redo);
+    }
+}
@@ -1,6 +1,10 @@
+pub mod history;
 pub mod voxel;
+pub mod world_edit;
 
+pub use history::*;
 pub use voxel::*;
+pub use world_edit::*;
 
 // INFO: -------------------------------
 //         actions module plugin
@@ -9,11 +13,13 @@ pub use voxel::*;
 use crate::{
     ecs_core::{EcsBuilder, Plugin},
     simulation_world::{
+        console::ConsoleCommand,
         input::ActionStateResource,
         player::{
             break_targeted_voxel::{handle_break_voxel_events_system, BreakVoxelEvent},
             place_voxel_at_target::{
-                handle_place_voxel_events_system, place_targeted_voxel_system, PlaceVoxelEvent,
+                give_command, handle_place_voxel_events_system, place_targeted_voxel_system,
+                HeldBlock, PlaceVoxelEvent,
             },
         },
         SimulationSchedule,
@@ -57,6 +63,13 @@ impl Plugin for ActionPlugin {
         // add voxel on right click
         builder
             .init_resource::<Messages<PlaceVoxelEvent>>()
+            .init_resource::<HeldBlock>()
+            .add_console_command(ConsoleCommand::new(
+                "give",
+                "<block>",
+                "Picks the block that placing puts down",
+                give_command,
+            ))
             .schedule_entry(SimulationSchedule::Main)
             .add_systems((
                 handle_place_voxel_events_system,
@@ -66,5 +79,19 @@ impl Plugin for ActionPlugin {
                         action_state.just_happened(SimulationAction::PlaceVoxel)
                     }),
             ));
+
+        // undo/redo voxel edits
+        builder
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(step_edit_history_system.in_set(SimulationSet::Update));
+
+        // world edit selection and operations
+        builder
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(
+                (select_corners_system, edit_selection_system)
+                    .chain()
+                    .in_set(SimulationSet::Update),
+            );
     }
 }
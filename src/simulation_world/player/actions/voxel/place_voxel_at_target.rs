@@ -1,15 +1,21 @@
 use crate::prelude::*;
-use crate::simulation_world::block::TargetedBlock;
-use crate::simulation_world::chunk::ChunkStateManager;
-use crate::simulation_world::{
-    block::block_registry::SOLID_BLOCK_ID,
-    chunk::{
-        components::{ChunkBlocksComponent, ChunkCoord, ChunkMeshDirty},
-        CHUNK_SIDE_LENGTH,
-    },
-};
-use bevy_ecs::prelude::{Commands, Message, MessageReader, Query};
-use bevy_ecs::prelude::{MessageWriter, Res};
+use crate::simulation_world::block::{BlockId, BlockRegistryResource, TargetedBlock};
+use crate::simulation_world::console::{ConsoleArgs, ConsoleResult};
+use crate::simulation_world::edit_history::{EditJournal, EditTransaction};
+use crate::simulation_world::world_edit::commands::block_arg;
+use crate::simulation_world::{block::block_registry::SOLID_BLOCK_ID, chunk::WorldVoxels};
+use bevy_ecs::prelude::{Message, MessageReader, Resource, World};
+use bevy_ecs::prelude::{MessageWriter, Res, ResMut};
+
+/// The block that placing puts down.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeldBlock(pub BlockId);
+
+impl Default for HeldBlock {
+    fn default() -> Self {
+        Self(SOLID_BLOCK_ID)
+    }
+}
 
 /// An event that is sent when a voxel should be placed.
 #[derive(Message, Clone)]
@@ -34,59 +40,27 @@ pub fn place_targeted_voxel_system(
 pub fn handle_place_voxel_events_system(
     // input
     mut events: MessageReader<PlaceVoxelEvent>,
-    chunk_manager: Res<ChunkStateManager>,
+
+    held_block: Res<HeldBlock>,
 
     // output
-    mut chunks: Query<&mut ChunkBlocksComponent>,
-    mut commands: Commands,
+    mut voxels: WorldVoxels,
+    mut journal: ResMut<EditJournal>,
 ) {
     for event in events.read() {
-        let new_block_pos = event.target_pos;
-        let chunk_pos = ChunkCoord::world_to_chunk_pos(new_block_pos.as_vec3());
-
-        if let Some(entity) = chunk_manager.get_entity(chunk_pos) {
-            if let Ok(mut chunk_blocks) = chunks.get_mut(entity) {
-                let local_pos = new_block_pos - (chunk_pos * CHUNK_SIDE_LENGTH as i32);
-
-                let mut writer = chunk_blocks.get_writer();
-                writer.set_data(
-                    local_pos.x as usize,
-                    local_pos.y as usize,
-                    local_pos.z as usize,
-                    SOLID_BLOCK_ID,
-                );
-
-                // mark primary chunk as dirty
-                commands.entity(entity).insert(ChunkMeshDirty);
-
-                // mark any neighbors as dirty if relevant
-                let max_idx = (CHUNK_SIDE_LENGTH - 1) as i32;
-                let mut neighbor_coords_to_dirty = Vec::with_capacity(3);
-
-                if local_pos.x == 0 {
-                    neighbor_coords_to_dirty.push(chunk_pos - IVec3::X);
-                } else if local_pos.x == max_idx {
-                    neighbor_coords_to_dirty.push(chunk_pos + IVec3::X);
-                }
-
-                if local_pos.y == 0 {
-                    neighbor_coords_to_dirty.push(chunk_pos - IVec3::Y);
-                } else if local_pos.y == max_idx {
-                    neighbor_coords_to_dirty.push(chunk_pos + IVec3::Y);
-                }
-
-                if local_pos.z == 0 {
-                    neighbor_coords_to_dirty.push(chunk_pos - IVec3::Z);
-                } else if local_pos.z == max_idx {
-                    neighbor_coords_to_dirty.push(chunk_pos + IVec3::Z);
-                }
-
-                for neighbor_coord in neighbor_coords_to_dirty {
-                    if let Some(neighbor_entity) = chunk_manager.get_entity(neighbor_coord) {
-                        commands.entity(neighbor_entity).insert(ChunkMeshDirty);
-                    }
-                }
-            }
+        if let Some(old_block) = voxels.set_block(event.target_pos, held_block.0) {
+            let mut transaction = EditTransaction::new();
+            transaction.record(event.target_pos, old_block, held_block.0);
+            journal.commit(transaction);
         }
     }
 }
+
+/// `/give <block>`, makes placing put down `block`.
+pub fn give_command(world: &mut World, args: &ConsoleArgs) -> ConsoleResult {
+    let block = block_arg(world, args, 0)?;
+    world.insert_resource(HeldBlock(block));
+
+    let name = &world.resource::<BlockRegistryResource>().block_names()[block as usize];
+    Ok(format!("Now placing {name}"))
+}
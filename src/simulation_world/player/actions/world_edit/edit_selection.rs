@@ -0,0 +1,96 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    block::{TargetedBlock, AIR_BLOCK_ID},
+    chunk::VoxelReader,
+    input::ActionStateResource,
+    world_edit::{WorldEditEvent, WorldEditOperation, WorldEditSelection},
+};
+use crate::SimulationAction;
+use bevy_ecs::prelude::{MessageWriter, Res};
+
+/// Fires a `WorldEditEvent` for any world edit action that was just pressed.
+///
+/// The block under the crosshair acts as the brush. Replace swaps every block
+/// matching the first corner's block for the brush.
+pub fn edit_selection_system(
+    // input
+    action_state: Res<ActionStateResource>,
+    targeted_block: Res<TargetedBlock>,
+    selection: Res<WorldEditSelection>,
+    voxels: VoxelReader,
+
+    // output
+    mut world_edit_writer: MessageWriter<WorldEditEvent>,
+) {
+    let brush = targeted_block
+        .position
+        .and_then(|pos| voxels.get_block(pos))
+        .unwrap_or(AIR_BLOCK_ID);
+
+    let mut send = |operation| {
+        world_edit_writer.write(WorldEditEvent { operation });
+    };
+
+    // operations that don't need a selection
+    if action_state.just_happened(SimulationAction::WorldEditRotate) {
+        send(WorldEditOperation::RotateClipboard { quarter_turns: 1 });
+    }
+    if action_state.just_happened(SimulationAction::WorldEditPaste) {
+        if let (Some(pos), Some(normal)) = (targeted_block.position, targeted_block.normal) {
+            send(WorldEditOperation::Paste {
+                origin: pos + normal,
+                skip_air: true,
+            });
+        }
+    }
+
+    let region_actions = [
+        SimulationAction::WorldEditFill,
+        SimulationAction::WorldEditReplace,
+        SimulationAction::WorldEditHollow,
+        SimulationAction::WorldEditWalls,
+        SimulationAction::WorldEditCopy,
+    ];
+    if !region_actions
+        .iter()
+        .any(|&a| action_state.just_happened(a))
+    {
+        return;
+    }
+
+    let Some(region) = selection.region() else {
+        warn!(target: "world_edit", "Select two corners before editing a region.");
+        return;
+    };
+
+    if action_state.just_happened(SimulationAction::WorldEditFill) {
+        send(WorldEditOperation::Fill {
+            region,
+            block: brush,
+        });
+    }
+    if action_state.just_happened(SimulationAction::WorldEditReplace) {
+        if let Some(from) = selection.first_corner.and_then(|pos| voxels.get_block(pos)) {
+            send(WorldEditOperation::Replace {
+                region,
+                from,
+                to: brush,
+            });
+        }
+    }
+    if action_state.just_happened(SimulationAction::WorldEditHollow) {
+        send(WorldEditOperation::Hollow {
+            region,
+            block: brush,
+        });
+    }
+    if action_state.just_happened(SimulationAction::WorldEditWalls) {
+        send(WorldEditOperation::Walls {
+            region,
+            block: brush,
+        });
+    }
+    if action_state.just_happened(SimulationAction::WorldEditCopy) {
+        send(WorldEditOperation::Copy { region });
+    }
+}
@@ -0,0 +1,5 @@
+pub mod edit_selection;
+pub mod select_corners;
+
+pub use edit_selection::edit_selection_system;
+pub use select_corners::select_corners_system;
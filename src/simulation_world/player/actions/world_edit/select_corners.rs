@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    block::TargetedBlock, input::ActionStateResource, world_edit::WorldEditSelection,
+};
+use crate::SimulationAction;
+use bevy_ecs::prelude::{Res, ResMut};
+
+/// Sets a corner of the world edit selection to the currently targeted block.
+pub fn select_corners_system(
+    // input
+    action_state: Res<ActionStateResource>,
+    targeted_block: Res<TargetedBlock>,
+
+    // output
+    mut selection: ResMut<WorldEditSelection>,
+) {
+    let Some(target_pos) = targeted_block.position else {
+        return;
+    };
+
+    if action_state.just_happened(SimulationAction::SelectFirstCorner) {
+        selection.first_corner = Some(target_pos);
+        info!(target: "world_edit", "First corner set to {}", target_pos);
+    }
+    if action_state.just_happened(SimulationAction::SelectSecondCorner) {
+        selection.second_corner = Some(target_pos);
+        info!(target: "world_edit", "Second corner set to {}", target_pos);
+    }
+
+    if let Some(region) = selection.region() {
+        debug!(target: "world_edit", "Selection is {} blocks ({} total)", region.size(), region.volume());
+    }
+}
@@ -0,0 +1,35 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    console::{ConsoleArgs, ConsoleCommand, ConsoleError, ConsoleResult},
+    player::{ActiveCamera, CameraComponent},
+};
+use bevy_ecs::prelude::*;
+
+/// `/tp <x> <y> <z>`, shared by the client and headless cameras.
+pub fn teleport_console_command() -> ConsoleCommand {
+    ConsoleCommand::new(
+        "tp",
+        "<x> <y> <z>",
+        "Teleports the camera, or prints where it is",
+        teleport_command,
+    )
+}
+
+fn teleport_command(world: &mut World, args: &ConsoleArgs) -> ConsoleResult {
+    let camera_entity = world.resource::<ActiveCamera>().0;
+    let target = if args.is_empty() {
+        None
+    } else {
+        Some(args.vec3(0)?)
+    };
+
+    let mut camera = world
+        .get_mut::<CameraComponent>(camera_entity)
+        .ok_or_else(|| ConsoleError::Failed("There is no active camera".to_string()))?;
+
+    if let Some(target) = target {
+        camera.position = target;
+    }
+    let Vec3 { x, y, z } = camera.position;
+    Ok(format!("Camera at {x:.1} {y:.1} {z:.1}"))
+}
@@ -1,3 +1,4 @@
+use crate::ecs_core::config::DEFAULT_FOV;
 use bevy_ecs::component::Component;
 use glam::{Mat4, Vec3};
 
@@ -32,7 +33,7 @@ impl Default for CameraComponent {
 
             yaw: -90.0,
             pitch: 0.0,
-            zoom: 45.0,
+            zoom: DEFAULT_FOV,
 
             view_matrix: Mat4::IDENTITY,
             projection_matrix: Mat4::IDENTITY,
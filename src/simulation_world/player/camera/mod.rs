@@ -1,7 +1,9 @@
+pub mod commands;
 pub mod component;
 pub mod movement;
 pub mod resource;
 
+pub use commands::teleport_console_command;
 pub use component::*;
 pub use movement::*;
 pub use resource::*;
@@ -12,23 +14,64 @@ pub use resource::*;
 
 use crate::{
     ecs_core::{
-        state_machine::{utils::in_state, AppState},
-        EcsBuilder, Plugin,
+        state_machine::{utils::in_state, AppState, GameState},
+        AppConfig, EcsBuilder, Plugin,
+    },
+    simulation_world::{
+        input::{InputReplay, WindowSizeResource},
+        SimulationSchedule, SimulationSet,
     },
-    simulation_world::{SimulationSchedule, SimulationSet},
 };
 use bevy_ecs::prelude::*;
+use winit::dpi::PhysicalSize;
+
+/// The window size a headless camera pretends to have, only used for its aspect ratio.
+const HEADLESS_WINDOW_SIZE: PhysicalSize<u32> = PhysicalSize::new(1280, 720);
 
 pub struct CameraPlugin;
 
 impl Plugin for CameraPlugin {
     fn build(&self, builder: &mut EcsBuilder) {
-        builder.init_resource::<ActiveCamera>();
+        builder
+            .init_resource::<ActiveCamera>()
+            .add_console_command(teleport_console_command());
+
+        builder
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(
+                (
+                    apply_fov_setting_system.run_if(resource_exists_and_changed::<AppConfig>),
+                    camera_movement_system.run_if(in_state(GameState::Playing)),
+                    update_camera_chunk_chord_system,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Running))
+                    .in_set(SimulationSet::Update),
+            );
+    }
+}
+
+/// A camera without any live input handling for running without a window.
+///
+/// Whoever drives the world moves the `CameraComponent` directly, unless an `InputReplay`
+/// is playing, in which case it moves just like the client camera would.
+pub struct HeadlessCameraPlugin;
+
+impl Plugin for HeadlessCameraPlugin {
+    fn build(&self, builder: &mut EcsBuilder) {
+        builder
+            .init_resource::<ActiveCamera>()
+            .add_resource(WindowSizeResource::new(HEADLESS_WINDOW_SIZE))
+            .add_console_command(teleport_console_command());
 
         builder
             .schedule_entry(SimulationSchedule::Main)
             .add_systems(
-                (camera_movement_system, update_camera_chunk_chord_system)
+                (
+                    apply_fov_setting_system.run_if(resource_exists_and_changed::<AppConfig>),
+                    camera_movement_system.run_if(resource_exists::<InputReplay>),
+                    update_camera_chunk_chord_system,
+                )
                     .chain()
                     .run_if(in_state(AppState::Running))
                     .in_set(SimulationSet::Update),
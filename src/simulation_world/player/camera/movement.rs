@@ -1,12 +1,13 @@
 use crate::prelude::*;
 use crate::{
+    ecs_core::{config::DEFAULT_FOV, AppConfig},
     simulation_world::chunk::ChunkCoord,
     simulation_world::input::resources::WindowSizeResource,
     simulation_world::input::{
-        resources::CursorMovement, types::simulation_action::SimulationAction, ActionStateResource,
+        types::{simulation_action::SimulationAction, simulation_axis::SimulationAxis},
+        ActionStateResource,
     },
     simulation_world::player::{ActiveCamera, CameraComponent},
-    simulation_world::time::FrameClock,
 };
 use bevy_ecs::prelude::*;
 use glam::{Mat4, Vec3};
@@ -15,17 +16,16 @@ use tracing::{instrument, warn};
 /// The distance the near plane is set to for the camera frustum.
 pub const CAMERA_NEAR_PLANE: f32 = 1.0;
 const MOVEMENT_SPEED: f32 = 16.0;
-const MOUSE_SENSITIVITY: f32 = 0.1;
 
-/// A system that updates the active camera's position and orientation based on user input.
+/// A system that updates the active camera's position and orientation from the movement,
+/// look and zoom axes.
 #[instrument(skip_all)]
 pub fn camera_movement_system(
     // Input
-    movement: Res<CursorMovement>,
     action_state: Res<ActionStateResource>,
-    time: Res<FrameClock>,
     window: Res<WindowSizeResource>,
     active_camera: Res<ActiveCamera>,
+    config: Option<Res<AppConfig>>,
 
     // Output
     mut camera_query: Query<&mut CameraComponent>,
@@ -38,39 +38,19 @@ pub fn camera_movement_system(
         return;
     };
 
-    // update position based on input
-    let velocity = MOVEMENT_SPEED * time.delta.as_secs_f32();
-    let front = cam.front;
-    let mut multiplier = 1.0;
-
+    // update position based on input, the axes are already scaled by the frame time
+    let mut speed = MOVEMENT_SPEED;
     if action_state.is_ongoing(SimulationAction::MoveFaster) {
-        multiplier = 2.5;
-    }
-    if action_state.is_ongoing(SimulationAction::MoveForward) {
-        cam.position += front * velocity * multiplier;
-    }
-    let front = cam.front;
-    if action_state.is_ongoing(SimulationAction::MoveBackward) {
-        cam.position -= front * velocity * multiplier;
-    }
-    let right = cam.right;
-    if action_state.is_ongoing(SimulationAction::MoveLeft) {
-        cam.position -= right * velocity * multiplier;
+        speed *= 2.5;
     }
-    let right = cam.right;
-    if action_state.is_ongoing(SimulationAction::MoveRight) {
-        cam.position += right * velocity * multiplier;
-    }
-
-    // update rotation
-    let mut xoffset = movement.get_mouse_delta().x as f32;
-    let mut yoffset = movement.get_mouse_delta().y as f32;
-
-    xoffset *= MOUSE_SENSITIVITY;
-    yoffset *= MOUSE_SENSITIVITY;
+    let forward = action_state.axis(SimulationAxis::MoveForward);
+    let right = action_state.axis(SimulationAxis::MoveRight);
+    let (front_dir, right_dir) = (cam.front, cam.right);
+    cam.position += (front_dir * forward + right_dir * right) * speed;
 
-    cam.yaw += xoffset;
-    cam.pitch -= yoffset;
+    // update rotation, in degrees
+    cam.yaw += action_state.axis(SimulationAxis::LookRight);
+    cam.pitch += action_state.axis(SimulationAxis::LookUp);
 
     if cam.pitch > 89.0 {
         cam.pitch = 89.0;
@@ -91,17 +71,18 @@ pub fn camera_movement_system(
     cam.right = cam.front.cross(cam.world_up).normalize();
     cam.up = cam.right.cross(cam.front).normalize();
 
-    // handle zoom
-    let yoffset_scroll = movement.get_scroll_delta().y;
+    // handle zoom, which can't go wider than the configured field of view
+    let max_zoom = config.map_or(DEFAULT_FOV, |config| config.fov);
+    let zoom_in = action_state.axis(SimulationAxis::ZoomIn);
     let mut zoom_changed = false;
 
-    if yoffset_scroll != 0.0 {
-        cam.zoom -= yoffset_scroll;
+    if zoom_in != 0.0 {
+        cam.zoom -= zoom_in;
         if cam.zoom < 1.0 {
             cam.zoom = 1.0;
         }
-        if cam.zoom > 45.0 {
-            cam.zoom = 45.0;
+        if cam.zoom > max_zoom {
+            cam.zoom = max_zoom;
         }
         zoom_changed = true;
     }
@@ -109,14 +90,39 @@ pub fn camera_movement_system(
     // updated matrices
     cam.view_matrix = Mat4::look_at_rh(cam.position, cam.position + cam.front, cam.up);
     if zoom_changed || window.is_changed() {
-        cam.projection_matrix = Mat4::perspective_infinite_reverse_rh(
-            cam.zoom.to_radians(),
-            window.aspect_ratio(),
-            CAMERA_NEAR_PLANE,
-        );
+        update_projection(&mut cam, &window);
     }
 }
 
+/// A system that resets the active camera's zoom to the field of view from the settings.
+#[instrument(skip_all)]
+pub fn apply_fov_setting_system(
+    // Input
+    config: Res<AppConfig>,
+    window: Res<WindowSizeResource>,
+    active_camera: Res<ActiveCamera>,
+
+    // Output
+    mut camera_query: Query<&mut CameraComponent>,
+) {
+    let Ok(mut cam) = camera_query.get_mut(active_camera.0) else {
+        return;
+    };
+
+    if cam.zoom != config.fov {
+        cam.zoom = config.fov;
+        update_projection(&mut cam, &window);
+    }
+}
+
+fn update_projection(cam: &mut CameraComponent, window: &WindowSizeResource) {
+    cam.projection_matrix = Mat4::perspective_infinite_reverse_rh(
+        cam.zoom.to_radians(),
+        window.aspect_ratio(),
+        CAMERA_NEAR_PLANE,
+    );
+}
+
 /// A system to that updates the active camera's chunk chord based on its position.
 #[instrument(skip_all)]
 pub fn update_camera_chunk_chord_system(
@@ -0,0 +1,72 @@
+use crate::simulation_world::{
+    console::{ConsoleArgs, ConsoleError, ConsoleResult},
+    terrain::{ActiveTerrainGenerator, TerrainGeneratorLibrary, WorldSeed},
+};
+use bevy_ecs::prelude::*;
+
+/// `/gen [name]`, switches the terrain shaper by (part of) its name.
+///
+/// Only chunks generated afterwards use it.
+pub fn generator_command(world: &mut World, args: &ConsoleArgs) -> ConsoleResult {
+    let library = world.resource::<TerrainGeneratorLibrary>();
+    let names = || {
+        library
+            .generators
+            .iter()
+            .map(|shaper| shaper.name())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let Some(query) = args.get(0) else {
+        let active = world.resource::<ActiveTerrainGenerator>().0.name();
+        return Ok(format!("Generating with {active} (available: {})", names()));
+    };
+
+    // an exact name wins, otherwise it has to be the only one containing the query
+    let query = query.to_lowercase();
+    let exact = library
+        .generators
+        .iter()
+        .find(|shaper| shaper.name().to_lowercase() == query);
+    let mut partial = library
+        .generators
+        .iter()
+        .filter(|shaper| shaper.name().to_lowercase().contains(&query));
+    let shaper = match (exact, partial.next(), partial.next()) {
+        (Some(shaper), _, _) | (None, Some(shaper), None) => shaper.clone(),
+        (None, None, _) => {
+            return Err(ConsoleError::InvalidArgument(format!(
+                "No generator matches '{query}' (available: {})",
+                names()
+            )))
+        }
+        (None, Some(_), Some(_)) => {
+            return Err(ConsoleError::InvalidArgument(format!(
+                "'{query}' matches more than one generator (available: {})",
+                names()
+            )))
+        }
+    };
+
+    let message = format!("Switched to {}", shaper.name());
+    world.insert_resource(ActiveTerrainGenerator(shaper));
+    Ok(message)
+}
+
+/// `/seed [seed]`, prints the seed or rebuilds the climate noise with a new one.
+///
+/// Only chunks generated afterwards use it.
+pub fn seed_command(world: &mut World, args: &ConsoleArgs) -> ConsoleResult {
+    if args.is_empty() {
+        let seed = world
+            .get_resource::<WorldSeed>()
+            .copied()
+            .unwrap_or_default();
+        return Ok(format!("Seed: {}", seed.0));
+    }
+
+    let seed: u32 = args.parse(0)?;
+    WorldSeed(seed).install(world);
+    Ok(format!("Seed set to {seed}"))
+}
@@ -1,9 +1,11 @@
 pub mod biome;
 pub mod climate;
 pub mod painting;
+pub mod pipeline;
 pub mod shaping;
 
 pub use biome::{BasicBiomeGenerator, BiomeGenerator, BiomeResultBuilder};
 pub use climate::{ClimateGenerator, ClimateNoiseGenerator};
 pub use painting::{PaintResultBuilder, SimpleSurfacePainter, TerrainPainter};
+pub use pipeline::{ActiveTerrainPipeline, TerrainPipeline};
 pub use shaping::{NoisyShaper, ShapeResultBuilder, SinwaveShaper, SuperflatShaper, TerrainShaper};
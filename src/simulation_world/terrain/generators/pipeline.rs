@@ -0,0 +1,124 @@
+use crate::simulation_world::{
+    biome::BiomeRegistryResource,
+    block::BlockRegistryResource,
+    chunk::{ChunkBlocksComponent, ChunkCoord, ChunkLod, GeneratedChunkComponentBundle},
+    terrain::{
+        generators::shaping::ChunkUniformity, ActiveBiomeGenerator, ActiveClimateGenerator,
+        ActiveTerrainGenerator, ActiveTerrainPainter, BiomeGenerator, BiomeMapComponent,
+        BiomeResultBuilder, ClimateGenerator, PaintResultBuilder, ShapeResultBuilder,
+        TerrainPainter, TerrainShaper,
+    },
+};
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemParam;
+use std::sync::Arc;
+
+/// The full set of generators that turn a chunk coordinate into chunk data.
+///
+/// Cheap to clone, so it can be handed to worker threads.
+#[derive(Clone)]
+pub struct TerrainPipeline {
+    pub climate: Arc<dyn ClimateGenerator + Send + Sync>,
+    pub biome: Arc<dyn BiomeGenerator + Send + Sync>,
+    pub shaper: Arc<dyn TerrainShaper + Send + Sync>,
+    pub painter: Arc<dyn TerrainPainter + Send + Sync>,
+}
+
+/// The active generator resources, for systems that build a `TerrainPipeline`.
+#[derive(SystemParam)]
+pub struct ActiveTerrainPipeline<'w> {
+    climate: Res<'w, ActiveClimateGenerator>,
+    biome: Res<'w, ActiveBiomeGenerator>,
+    shaper: Res<'w, ActiveTerrainGenerator>,
+    painter: Res<'w, ActiveTerrainPainter>,
+}
+
+impl ActiveTerrainPipeline<'_> {
+    /// Builds a pipeline from the currently active generators.
+    pub fn get(&self) -> TerrainPipeline {
+        TerrainPipeline::from_active(&self.climate, &self.biome, &self.shaper, &self.painter)
+    }
+}
+
+impl TerrainPipeline {
+    /// Builds a pipeline from the currently active generator resources.
+    pub fn from_active(
+        climate: &ActiveClimateGenerator,
+        biome: &ActiveBiomeGenerator,
+        shaper: &ActiveTerrainGenerator,
+        painter: &ActiveTerrainPainter,
+    ) -> Self {
+        Self {
+            climate: climate.0.clone(),
+            biome: biome.0.clone(),
+            shaper: shaper.0.clone(),
+            painter: painter.0.clone(),
+        }
+    }
+
+    /// Generates a chunk from start to finish, returning `None` if it is empty.
+    ///
+    /// Uniform chunks skip the expensive passes the same way the chunk loader does.
+    pub fn generate_chunk(
+        &self,
+        coord: ChunkCoord,
+        lod: ChunkLod,
+        block_registry: &BlockRegistryResource,
+        biome_registry: &BiomeRegistryResource,
+    ) -> Option<GeneratedChunkComponentBundle> {
+        match self.shaper.determine_chunk_uniformity(coord.pos) {
+            ChunkUniformity::Empty => None,
+            ChunkUniformity::Solid => Some(Self::solid_chunk(lod)),
+            _ => Some(self.generate_mixed_chunk(coord, lod, block_registry, biome_registry)),
+        }
+    }
+
+    /// The data for a chunk the shaper reports as completely solid.
+    pub fn solid_chunk(lod: ChunkLod) -> GeneratedChunkComponentBundle {
+        GeneratedChunkComponentBundle {
+            chunk_blocks: Some(ChunkBlocksComponent::new_uniform_solid(lod)),
+            chunk_metadata: None,
+            biome_map: BiomeMapComponent::new_empty(lod),
+        }
+    }
+
+    /// Runs every generation pass (climate -> biome -> shape -> paint) for a chunk.
+    pub fn generate_mixed_chunk(
+        &self,
+        coord: ChunkCoord,
+        lod: ChunkLod,
+        block_registry: &BlockRegistryResource,
+        biome_registry: &BiomeRegistryResource,
+    ) -> GeneratedChunkComponentBundle {
+        // INFO: biome gen
+        let climate_map = self.climate.generate(coord.clone());
+        let biome_map = BiomeMapComponent::new_empty(lod);
+        let biome_builder = BiomeResultBuilder::new(biome_map, coord.clone());
+        let biome_map = self
+            .biome
+            .generate_biome_chunk(biome_builder, &climate_map, biome_registry)
+            .finish();
+
+        // INFO: shaping
+        let chunk_blocks = ChunkBlocksComponent::new_uniform_empty(lod);
+        let shaper = ShapeResultBuilder::new(chunk_blocks, coord.clone());
+        let shaped_chunk_blocks = self
+            .shaper
+            .shape_terrain_chunk(&climate_map, shaper)
+            .finish();
+
+        // INFO: painting
+        let painter_builder =
+            PaintResultBuilder::new(shaped_chunk_blocks, coord, block_registry.clone());
+        let (painted_chunk_blocks, chunk_metadata) = self
+            .painter
+            .paint_terrain_chunk(painter_builder, &biome_map, block_registry, biome_registry)
+            .finish();
+
+        GeneratedChunkComponentBundle {
+            chunk_blocks: Some(painted_chunk_blocks),
+            chunk_metadata: Some(chunk_metadata),
+            biome_map,
+        }
+    }
+}
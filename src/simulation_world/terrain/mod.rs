@@ -1,3 +1,4 @@
+pub mod commands;
 pub mod components;
 pub mod generators;
 pub mod public;
@@ -14,7 +15,11 @@ pub use public::*;
 use crate::prelude::*;
 use crate::{
     ecs_core::{EcsBuilder, Plugin},
-    simulation_world::input::ActionStateResource,
+    simulation_world::{
+        console::ConsoleCommand,
+        input::ActionStateResource,
+        terrain::commands::{generator_command, seed_command},
+    },
 };
 use bevy_ecs::prelude::{IntoScheduleConfigs, Res};
 pub use systems::{cycle_active_generator, TerrainGeneratorLibrary};
@@ -26,11 +31,30 @@ impl Plugin for TerrainGenerationPlugin {
         builder
             .add_resource(ClimateNoiseGenerator::new(0)) // hardcode seed 0 for presentation reproducibility
             .add_resource(ActiveClimateGenerator::default())
+            .add_resource(WorldSeed::default())
             .add_resource(ActiveBiomeGenerator::default())
             .add_resource(ActiveTerrainGenerator::default())
             .add_resource(ActiveTerrainPainter::default())
             .init_resource::<TerrainGeneratorLibrary>();
 
+        // INFO: ---------------------------------
+        //         console-based actions
+        // ---------------------------------------
+
+        builder
+            .add_console_command(ConsoleCommand::new(
+                "gen",
+                "[name]",
+                "Switches the terrain generator for new chunks",
+                generator_command,
+            ))
+            .add_console_command(ConsoleCommand::new(
+                "seed",
+                "[seed]",
+                "Prints the world seed, or sets it for new chunks",
+                seed_command,
+            ));
+
         // INFO: -------------------------------
         //         keybind-based actions
         // -------------------------------------
@@ -1,7 +1,7 @@
 use crate::simulation_world::terrain::generators::climate::{
     climate_noise_gen::ClimateNoiseGenerator, lib::ClimateGenerator,
 };
-use bevy_ecs::prelude::Resource;
+use bevy_ecs::prelude::{Resource, World};
 use std::sync::Arc;
 
 #[derive(Resource, Clone)]
@@ -12,3 +12,17 @@ impl Default for ActiveClimateGenerator {
         Self(Arc::new(ClimateNoiseGenerator::new(0)))
     }
 }
+
+/// The seed the active climate generator was built with.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WorldSeed(pub u32);
+
+impl WorldSeed {
+    /// Rebuilds the climate noise from this seed. Only chunks generated afterwards use it.
+    pub fn install(self, world: &mut World) {
+        world.insert_resource(ActiveClimateGenerator(Arc::new(
+            ClimateNoiseGenerator::new(self.0),
+        )));
+        world.insert_resource(self);
+    }
+}
@@ -6,7 +6,6 @@ use crate::simulation_world::terrain::{
 use bevy_ecs::{
     resource::Resource,
     system::{Local, Res, ResMut},
-    world::{FromWorld, World},
 };
 use std::sync::Arc;
 
@@ -15,8 +14,8 @@ pub struct TerrainGeneratorLibrary {
     pub generators: Vec<Arc<dyn TerrainShaper + Send + Sync>>,
 }
 
-impl FromWorld for TerrainGeneratorLibrary {
-    fn from_world(_world: &mut World) -> Self {
+impl Default for TerrainGeneratorLibrary {
+    fn default() -> Self {
         Self {
             generators: vec![
                 Arc::new(SuperflatShaper::new()),
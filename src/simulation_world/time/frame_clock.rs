@@ -32,6 +32,10 @@ pub struct FrameClock {
 
     /// The smoothed frames per second (FPS) value.
     pub smoothed_fps: f32,
+
+    /// When set, every update advances the clock by exactly this much instead of
+    /// measuring wall time. Used by headless runs to get one tick per frame.
+    pub fixed_step: Option<Duration>,
 }
 
 impl Default for FrameClock {
@@ -45,6 +49,7 @@ impl Default for FrameClock {
             accumulator: Duration::ZERO,
             smoothed_fps: 69.0,
             alpha: 0.0,
+            fixed_step: None,
         }
     }
 }
@@ -52,7 +57,10 @@ impl Default for FrameClock {
 impl FrameClock {
     /// Updates all timing information based on the current instant.
     pub fn update_all(&mut self) {
-        let now = Instant::now();
+        let now = match self.fixed_step {
+            Some(step) => self.last_update + step,
+            None => Instant::now(),
+        };
         let delta = now.duration_since(self.last_update);
 
         self.last_update = now;
@@ -95,3 +103,11 @@ impl FrameClock {
 pub fn update_frame_clock_system(mut clock: ResMut<FrameClock>) {
     clock.update_all();
 }
+
+/// Drops the time that piled up for fixed updates while paused, so resuming doesn't
+/// catch up on every tick that was skipped.
+#[instrument(skip_all)]
+pub fn discard_paused_time_system(mut clock: ResMut<FrameClock>) {
+    clock.accumulator = Duration::ZERO;
+    clock.alpha = 0.0;
+}
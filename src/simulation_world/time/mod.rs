@@ -4,14 +4,19 @@ pub mod world_clock;
 
 pub use frame_clock::FrameClock;
 pub use world_clock::WorldClockResource;
-use world_clock::{jump_world_clock_backwards_system, jump_world_clock_forward_system};
+use world_clock::{
+    jump_world_clock_backwards_system, jump_world_clock_forward_system, time_command,
+};
 
 // INFO: ---------------------
 //         Time plugin
 // ---------------------------
 
-use crate::ecs_core::state_machine::AppState;
-use crate::simulation_world::time::frame_clock::update_frame_clock_system;
+use crate::ecs_core::state_machine::{AppState, GameState};
+use crate::simulation_world::console::ConsoleCommand;
+use crate::simulation_world::time::frame_clock::{
+    discard_paused_time_system, update_frame_clock_system,
+};
 use crate::simulation_world::time::simulation_tick::{run_fixed_update_schedule, SimulationTick};
 use crate::simulation_world::time::world_clock::update_world_clock_system;
 use crate::SimulationAction;
@@ -34,19 +39,23 @@ impl Plugin for TimeControlPlugin {
             // systems
             .schedule_entry(SimulationSchedule::Main)
             .add_systems(
-                (update_frame_clock_system)
+                (
+                    update_frame_clock_system,
+                    discard_paused_time_system.run_if(in_state(GameState::Paused)),
+                )
+                    .chain()
                     .in_set(SimulationSet::PreUpdate)
                     .run_if(in_state(AppState::Running)),
             );
 
-        // Trigger the simulation ticks when appropriate
+        // Trigger the simulation ticks when appropriate, nothing ticks while paused
         builder
             .add_resource(SimulationTick::default())
             .schedule_entry(SimulationSchedule::Main)
             .add_systems(
                 run_fixed_update_schedule
                     .in_set(SimulationSet::Update)
-                    .run_if(in_state(AppState::Running)),
+                    .run_if(in_state(AppState::Running).and(not(in_state(GameState::Paused)))),
             );
 
         // Maintain world clock that depends on ticks rather that frames
@@ -57,16 +66,27 @@ impl Plugin for TimeControlPlugin {
         // controls for world clock
         builder
             .add_resource(WorldClockResource::default())
+            .add_console_command(ConsoleCommand::new(
+                "time",
+                "[set <0..1>]",
+                "Prints or sets the time of day, 0 being midnight",
+                time_command,
+            ))
             .schedule_entry(SimulationSchedule::Main)
-            .add_systems((
-                jump_world_clock_backwards_system.run_if(
-                    |action_state: Res<ActionStateResource>| {
-                        action_state.just_happened(SimulationAction::JumpGameTimeBackward)
-                    },
-                ),
-                jump_world_clock_forward_system.run_if(|action_state: Res<ActionStateResource>| {
-                    action_state.just_happened(SimulationAction::JumpGameTimeForward)
-                }),
-            ));
+            .add_systems(
+                (
+                    jump_world_clock_backwards_system.run_if(
+                        |action_state: Res<ActionStateResource>| {
+                            action_state.just_happened(SimulationAction::JumpGameTimeBackward)
+                        },
+                    ),
+                    jump_world_clock_forward_system.run_if(
+                        |action_state: Res<ActionStateResource>| {
+                            action_state.just_happened(SimulationAction::JumpGameTimeForward)
+                        },
+                    ),
+                )
+                    .run_if(not(in_state(GameState::Paused))),
+            );
     }
 }
@@ -1,5 +1,8 @@
 use crate::prelude::*;
-use crate::simulation_world::time::simulation_tick::SimulationTick;
+use crate::simulation_world::{
+    console::{ConsoleArgs, ConsoleError, ConsoleResult},
+    time::simulation_tick::SimulationTick,
+};
 use bevy_ecs::prelude::*;
 use std::time::Duration;
 
@@ -98,3 +101,38 @@ pub fn jump_world_clock_backwards_system(
         }
     }
 }
+
+// INFO: -------------------------
+//         console command
+// -------------------------------
+
+/// `/time [set <fraction>]`, where the fraction of the day goes from 0 (midnight) to 1.
+pub fn time_command(world: &mut World, args: &ConsoleArgs) -> ConsoleResult {
+    let mut world_clock = world.resource_mut::<WorldClockResource>();
+
+    match args.get(0) {
+        None => {}
+        Some("set") => {
+            let fraction: f32 = args.parse(1)?;
+            if !(0.0..=1.0).contains(&fraction) {
+                return Err(ConsoleError::InvalidArgument(format!(
+                    "{fraction} is not between 0 and 1"
+                )));
+            }
+            // 1 is the next midnight, which is 0 of the same cycle
+            let day_duration = world_clock.day_duration;
+            world_clock.time_of_day = day_duration.mul_f32(fraction % 1.0);
+        }
+        Some(other) => {
+            return Err(ConsoleError::InvalidArgument(format!(
+                "Unknown subcommand '{other}'"
+            )))
+        }
+    }
+
+    Ok(format!(
+        "Day {}, time {:.3}",
+        world_clock.total_days,
+        world_clock.day_night_cycle_value()
+    ))
+}
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::simulation_world::asset_management::{Handle, ImageAsset};
 use bevy_ecs::prelude::Component;
 
 // INFO: ----------------------
@@ -20,8 +21,9 @@ pub struct Node; // marker for any entity in the ui tree
 
 /// The current interaction state of a UI node.
 ///
-/// This is updated by an interaction system and can be used
-/// by styling systems to visually change the UI element.
+/// Only nodes spawned with one take part in hit-testing. It is updated by
+/// `update_interaction_system` and can be used by styling systems to visually change
+/// the UI element, while clicks arrive as `UiClickMessage`s.
 #[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum Interaction {
     #[default]
@@ -41,31 +43,175 @@ pub enum Size {
     Auto,
 }
 
-#[derive(Component)]
+/// A length on each side of a node, used for its margin, padding, border and insets.
+///
+/// `Size::Auto` only means something for margins and insets, elsewhere it is zero.
+#[derive(Clone, Copy, Debug)]
+pub struct UiRect {
+    pub left: Size,
+    pub right: Size,
+    pub top: Size,
+    pub bottom: Size,
+}
+
+impl UiRect {
+    pub const ZERO: UiRect = UiRect::all(Size::Px(0.0));
+    pub const AUTO: UiRect = UiRect::all(Size::Auto);
+
+    /// The same length on every side.
+    pub const fn all(value: Size) -> Self {
+        Self {
+            left: value,
+            right: value,
+            top: value,
+            bottom: value,
+        }
+    }
+
+    /// The same length in pixels on every side.
+    pub const fn px(value: f32) -> Self {
+        Self::all(Size::Px(value))
+    }
+
+    /// One length for the left and right sides and another for the top and bottom.
+    pub const fn axes(horizontal: Size, vertical: Size) -> Self {
+        Self {
+            left: horizontal,
+            right: horizontal,
+            top: vertical,
+            bottom: vertical,
+        }
+    }
+}
+
+/// The radius in pixels of each of a node's corners.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CornerRadius {
+    pub top_left: f32,
+    pub top_right: f32,
+    pub bottom_right: f32,
+    pub bottom_left: f32,
+}
+
+impl CornerRadius {
+    pub const ZERO: CornerRadius = CornerRadius::all(0.0);
+
+    /// The same radius on every corner.
+    pub const fn all(radius: f32) -> Self {
+        Self {
+            top_left: radius,
+            top_right: radius,
+            bottom_right: radius,
+            bottom_left: radius,
+        }
+    }
+}
+
+/// The size of a single row or column in a grid.
+#[derive(Clone, Copy, Debug)]
+pub enum GridTrack {
+    Px(f32),
+    Percent(f32),
+    /// A share of the space left over once the other tracks are sized.
+    Fr(f32),
+    Auto,
+    MinContent,
+    MaxContent,
+}
+
+/// Where a node is placed along one axis of its parent's grid.
+///
+/// Grid lines are numbered from 1, and negative lines count back from the end.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum GridPlacement {
+    /// The next free cell.
+    #[default]
+    Auto,
+    /// Starting at a grid line and spanning a single track.
+    Line(i16),
+    /// The next free cell, spanning a number of tracks.
+    Span(u16),
+    /// Starting at a grid line and spanning a number of tracks.
+    LineSpan(i16, u16),
+}
+
+#[derive(Component, Clone)]
 pub struct Style {
     // basic
+    pub display: taffy::style::Display,
     pub width: Size,
     pub height: Size,
-    pub padding: f32,
+    pub min_width: Size,
+    pub min_height: Size,
+    pub max_width: Size,
+    pub max_height: Size,
+    pub margin: UiRect,
+    pub padding: UiRect,
+    /// The width of the border on each side, drawn in `border_color`.
+    pub border: UiRect,
     pub position: taffy::style::Position,
+    /// Offsets from the parent's edges, for absolutely positioned nodes.
+    pub inset: UiRect,
 
     // flex
     pub flex_direction: taffy::style::FlexDirection,
+    pub flex_wrap: taffy::style::FlexWrap,
+    pub flex_grow: f32,
+    pub flex_shrink: f32,
+    pub flex_basis: Size,
     pub justify_content: Option<taffy::style::JustifyContent>,
     pub align_items: Option<taffy::style::AlignItems>,
+    pub align_self: Option<taffy::style::AlignSelf>,
+    pub align_content: Option<taffy::style::AlignContent>,
+    /// The space between rows of children, for flex wrapping and grids.
+    pub row_gap: Size,
+    /// The space between columns of children, or between flex children in a row.
+    pub column_gap: Size,
+
+    // grid
+    pub grid_template_rows: Vec<GridTrack>,
+    pub grid_template_columns: Vec<GridTrack>,
+    pub grid_row: GridPlacement,
+    pub grid_column: GridPlacement,
+
+    // visual
+    pub border_color: [f32; 4],
+    pub corner_radius: CornerRadius,
 }
 
 // Now, implement Default for your main Style component
 impl Default for Style {
     fn default() -> Self {
         Self {
+            display: taffy::style::Display::Flex,
             width: Size::Auto,
             height: Size::Auto,
-            padding: 0.0,
+            min_width: Size::Auto,
+            min_height: Size::Auto,
+            max_width: Size::Auto,
+            max_height: Size::Auto,
+            margin: UiRect::ZERO,
+            padding: UiRect::ZERO,
+            border: UiRect::ZERO,
             position: taffy::style::Position::default(),
+            inset: UiRect::AUTO,
             justify_content: None,
             align_items: None,
+            align_self: None,
+            align_content: None,
             flex_direction: taffy::FlexDirection::Row,
+            flex_wrap: taffy::FlexWrap::NoWrap,
+            flex_grow: 0.0,
+            flex_shrink: 1.0,
+            flex_basis: Size::Auto,
+            row_gap: Size::Px(0.0),
+            column_gap: Size::Px(0.0),
+            grid_template_rows: Vec::new(),
+            grid_template_columns: Vec::new(),
+            grid_row: GridPlacement::Auto,
+            grid_column: GridPlacement::Auto,
+            border_color: [0.0, 0.0, 0.0, 1.0],
+            corner_radius: CornerRadius::ZERO,
         }
     }
 }
@@ -76,14 +222,88 @@ impl Default for Style {
 
 #[derive(Component, Clone, Debug)]
 pub enum UiBackground {
-    SolidColor { color: [f32; 4] },
-    // TODO: image support
-    Image { color: [f32; 4] },
-    // Image {
-    //     texture: Handle<Image>,
-    //     /// A color to tint the texture. Use white `[1.0, 1.0, 1.0, 1.0]` for no tint.
-    //     tint: [f32; 4],
-    // },
+    SolidColor {
+        color: [f32; 4],
+    },
+    Image {
+        image: Handle<ImageAsset>,
+        /// A color to tint the image. Use white `[1.0, 1.0, 1.0, 1.0]` for no tint.
+        tint: [f32; 4],
+        /// The part of the image to draw.
+        uv_rect: UvRect,
+        /// If set, only the middle of the image stretches to fit the node.
+        nine_slice: Option<NineSlice>,
+    },
+}
+
+impl UiBackground {
+    /// The whole of `image`, untinted and stretched to fit the node.
+    pub fn image(image: Handle<ImageAsset>) -> Self {
+        Self::Image {
+            image,
+            tint: [1.0, 1.0, 1.0, 1.0],
+            uv_rect: UvRect::FULL,
+            nine_slice: None,
+        }
+    }
+
+    /// The color of a solid background, or the tint of an image.
+    pub fn color(&self) -> [f32; 4] {
+        match self {
+            UiBackground::SolidColor { color } => *color,
+            UiBackground::Image { tint, .. } => *tint,
+        }
+    }
+
+    pub fn set_color(&mut self, new_color: [f32; 4]) {
+        match self {
+            UiBackground::SolidColor { color } => *color = new_color,
+            UiBackground::Image { tint, .. } => *tint = new_color,
+        }
+    }
+}
+
+/// A sub-rectangle of an image in UV coordinates, where (0, 0) is its top left corner.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UvRect {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl UvRect {
+    pub const FULL: UvRect = UvRect {
+        min: Vec2::ZERO,
+        max: Vec2::ONE,
+    };
+
+    pub fn size(&self) -> Vec2 {
+        self.max - self.min
+    }
+}
+
+/// The borders, in image pixels, that a nine-slice image keeps unstretched.
+///
+/// Corners are drawn at their pixel size, edges stretch along their length and the
+/// middle stretches to fill whatever is left. Borders are measured within the image's
+/// `uv_rect`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NineSlice {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl NineSlice {
+    /// The same border on every side.
+    pub const fn uniform(border: f32) -> Self {
+        Self {
+            left: border,
+            right: border,
+            top: border,
+            bottom: border,
+        }
+    }
 }
 
 #[derive(Component, Clone)]
@@ -114,4 +334,14 @@ pub struct CalculatedLayout {
     pub position: Vec2,
     /// The absolute screen-space size (Width, Height) of the node.
     pub size: Vec2,
+    /// The width in pixels of the node's border on each side.
+    pub border: taffy::geometry::Rect<f32>,
+}
+
+impl CalculatedLayout {
+    /// Whether a screen-space point falls within the node.
+    pub fn contains(&self, point: Vec2) -> bool {
+        let max = self.position + self.size;
+        point.cmpge(self.position).all() && point.cmplt(max).all()
+    }
 }
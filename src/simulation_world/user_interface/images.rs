@@ -0,0 +1,51 @@
+use crate::prelude::*;
+use crate::simulation_world::asset_management::{AssetStorageResource, Handle, ImageAsset};
+use bevy_ecs::prelude::*;
+use std::path::Path;
+
+const UI_IMAGES_DIR: &str = "assets/ui";
+
+/// Handles to the images the built in screens are drawn with.
+///
+/// An image that failed to load is `None`, and whatever uses it falls back to solid colors.
+#[derive(Resource, Default, Debug)]
+pub struct UiImages {
+    pub crosshair: Option<Handle<ImageAsset>>,
+    /// A bevelled box meant to be nine-sliced, see `UI_PANEL_BORDER`.
+    pub panel: Option<Handle<ImageAsset>>,
+}
+
+/// The border, in pixels, of the panel image that stays unstretched.
+pub const UI_PANEL_BORDER: f32 = 4.0;
+
+/// A startup system that loads every image in `UI_IMAGES_DIR` into the image storage.
+#[instrument(skip_all)]
+pub fn load_ui_images_system(
+    // Input
+    images: Res<AssetStorageResource<ImageAsset>>,
+
+    // Output
+    mut commands: Commands,
+) {
+    let glob_path = Path::new(UI_IMAGES_DIR).join("*.png");
+    let mut paths: Vec<_> = glob::glob(glob_path.to_str().unwrap())
+        .expect("Failed to read glob pattern")
+        .filter_map(|e| e.ok())
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        match ImageAsset::load(&path) {
+            Ok(image) => {
+                debug!(target: "asset_management", "Loaded UI image {}", path.display());
+                images.add(image);
+            }
+            Err(e) => error!("Failed to load UI image {}: {}", path.display(), e),
+        }
+    }
+
+    commands.insert_resource(UiImages {
+        crosshair: images.get_by_name("crosshair"),
+        panel: images.get_by_name("panel"),
+    });
+}
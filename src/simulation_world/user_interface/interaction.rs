@@ -0,0 +1,79 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    input::resources::{Buttons, CursorPosition, DesiredCursorState, InputCapture},
+    user_interface::{
+        components::{CalculatedLayout, Interaction},
+        layout::UiDepth,
+    },
+};
+use bevy_ecs::prelude::*;
+use winit::{event::MouseButton, window::CursorGrabMode};
+
+/// Sent when a node with an `Interaction` is pressed and released with the left mouse
+/// button while under the cursor.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiClickMessage {
+    pub entity: Entity,
+}
+
+/// Hit-tests the cursor against every node with an `Interaction`, updating their state,
+/// sending click messages and taking mouse buttons away from the world while the cursor
+/// is over one.
+///
+/// Only the deepest node under the cursor is hovered. Nothing is while the cursor is
+/// locked for looking around.
+#[instrument(skip_all)]
+pub fn update_interaction_system(
+    // Input
+    cursor_position: Res<CursorPosition>,
+    cursor_state: Res<DesiredCursorState>,
+    mouse_input: Res<Buttons<MouseButton>>,
+
+    // Output (interaction state, clicks and consumed buttons)
+    mut nodes: Query<(
+        Entity,
+        &CalculatedLayout,
+        Option<&UiDepth>,
+        &mut Interaction,
+    )>,
+    mut clicks: MessageWriter<UiClickMessage>,
+    mut capture: ResMut<InputCapture>,
+) {
+    let cursor = cursor_position
+        .0
+        .filter(|_| cursor_state.grab_mode == CursorGrabMode::None);
+
+    let hovered = cursor.and_then(|cursor| {
+        nodes
+            .iter()
+            .filter(|(_, layout, _, _)| layout.contains(cursor))
+            .max_by(|(_, _, a, _), (_, _, b, _)| {
+                let depth = |depth: &Option<&UiDepth>| depth.map_or(0.0, |depth| depth.0);
+                depth(a).total_cmp(&depth(b))
+            })
+            .map(|(entity, ..)| entity)
+    });
+
+    for (entity, _, _, mut interaction) in nodes.iter_mut() {
+        let next = if hovered == Some(entity) {
+            let was_pressed = *interaction == Interaction::Pressed;
+            if was_pressed && mouse_input.was_released(MouseButton::Left) {
+                clicks.write(UiClickMessage { entity });
+            }
+
+            // a press has to start on the node, not be dragged onto it
+            if mouse_input.was_pressed(MouseButton::Left)
+                || (was_pressed && mouse_input.is_down(MouseButton::Left))
+            {
+                Interaction::Pressed
+            } else {
+                Interaction::Hovered
+            }
+        } else {
+            Interaction::Normal
+        };
+        interaction.set_if_neq(next);
+    }
+
+    capture.pointer = hovered.is_some();
+}
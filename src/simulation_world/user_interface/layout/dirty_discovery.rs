@@ -1,4 +1,5 @@
 use crate::{
+    ecs_core::AppConfig,
     prelude::*,
     simulation_world::{
         input::resources::WindowSizeResource,
@@ -164,9 +165,12 @@ pub fn update_changed_styles_system(
     }
 }
 
+/// Marks the layout dirty when the window is resized or the settings (and so the UI scale)
+/// change.
 #[instrument(skip_all)]
 pub fn handle_window_resize_system(
     window_size: Res<WindowSizeResource>,
+    config: Option<Res<AppConfig>>,
     mut is_dirty: ResMut<IsLayoutDirty>,
 ) {
     if window_size.is_changed() {
@@ -176,4 +180,12 @@ pub fn handle_window_resize_system(
         );
         is_dirty.0 = true; // ui needs recalc
     }
+
+    if config.is_some_and(|config| config.is_changed()) {
+        debug!(
+            target: "ui_efficiency",
+            "Settings changed. Marking layout as dirty.",
+        );
+        is_dirty.0 = true;
+    }
 }
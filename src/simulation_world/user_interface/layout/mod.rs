@@ -2,7 +2,7 @@ pub mod compute_depth;
 pub mod dirty_discovery;
 pub mod perform_layout;
 
-pub use compute_depth::compute_ui_depth_system;
+pub use compute_depth::{compute_ui_depth_system, UiDepth};
 pub use dirty_discovery::{
     handle_hierarchy_changes_system, handle_structural_changes_system, handle_window_resize_system,
     update_changed_styles_system, IsLayoutDirty,
@@ -1,4 +1,5 @@
 use crate::{
+    ecs_core::AppConfig,
     prelude::*,
     simulation_world::{
         input::resources::WindowSizeResource,
@@ -13,7 +14,11 @@ use crate::{
 use bevy_ecs::prelude::*;
 use derive_more::{Deref, DerefMut};
 use std::collections::HashMap;
-use taffy::{self, TaffyTree};
+use taffy::{
+    self,
+    style_helpers::{auto, fr, length, line, max_content, min_content, percent, span},
+    TaffyTree,
+};
 
 // INFO: -------------------
 //         Resources
@@ -49,18 +54,24 @@ pub fn compute_and_apply_layout_system(world: &mut World) {
     debug!(target: "ui_efficiency", "Recomputing the UI layout because it is dirty...");
 
     // Get the viewport size and root node
-    let (root_entity, root_node, viewport_size) = {
+    //
+    // Layout happens in unscaled units on a viewport shrunk by the UI scale, and the
+    // results are scaled back up to the window's pixels afterwards.
+    let (root_entity, root_node, viewport_size, ui_scale) = {
         let root_entity = world.get_resource::<UiRootNodeResource>().unwrap().0;
         let entity_to_node = world.resource::<EntityToNodeMap>();
         let window_size = world.resource::<WindowSizeResource>();
+        let ui_scale = world
+            .get_resource::<AppConfig>()
+            .map_or(1.0, |config| config.ui_scale);
 
         let root_node = entity_to_node[&root_entity];
         let viewport_size = taffy::Size {
-            width: taffy::AvailableSpace::Definite(window_size.width as f32),
-            height: taffy::AvailableSpace::Definite(window_size.height as f32),
+            width: taffy::AvailableSpace::Definite(window_size.width as f32 / ui_scale),
+            height: taffy::AvailableSpace::Definite(window_size.height as f32 / ui_scale),
         };
 
-        (root_entity, root_node, viewport_size)
+        (root_entity, root_node, viewport_size, ui_scale)
     };
 
     // Compute the layout (borrowing taffy tree mutably)
@@ -109,7 +120,11 @@ pub fn compute_and_apply_layout_system(world: &mut World) {
     };
 
     // And apply those to the world
-    for (entity, calculated_layout) in layouts_to_apply {
+    for (entity, mut calculated_layout) in layouts_to_apply {
+        calculated_layout.position *= ui_scale;
+        calculated_layout.size *= ui_scale;
+        calculated_layout.border = calculated_layout.border.map(|width| width * ui_scale);
+
         let absolute_pos = calculated_layout.position;
         let size = calculated_layout.size;
 
@@ -174,6 +189,7 @@ fn collect_layouts_recursively(
     let calculated_layout = CalculatedLayout {
         position: absolute_pos,
         size: Vec2::new(layout.size.width, layout.size.height),
+        border: layout.border,
     };
 
     // Add the result to our list instead of inserting into the world.
@@ -212,22 +228,97 @@ impl From<&simulation::Style> for taffy::Style {
                 simulation::Size::Auto => taffy::Dimension::auto(),
             }
         };
+        // padding, borders and gaps can't be auto, so it falls back to zero there
+        let to_length = |size: simulation::Size| -> taffy::LengthPercentage {
+            match size {
+                simulation::Size::Px(px) => taffy::LengthPercentage::length(px),
+                simulation::Size::Percent(percent) => {
+                    taffy::LengthPercentage::percent(percent / 100.0)
+                }
+                simulation::Size::Auto => taffy::LengthPercentage::length(0.0),
+            }
+        };
+        let to_length_auto = |size: simulation::Size| -> taffy::LengthPercentageAuto {
+            match size {
+                simulation::Size::Px(px) => taffy::LengthPercentageAuto::length(px),
+                simulation::Size::Percent(percent) => {
+                    taffy::LengthPercentageAuto::percent(percent / 100.0)
+                }
+                simulation::Size::Auto => taffy::LengthPercentageAuto::auto(),
+            }
+        };
+        fn to_rect<T>(
+            rect: simulation::UiRect,
+            convert: impl Fn(simulation::Size) -> T,
+        ) -> taffy::Rect<T> {
+            taffy::geometry::Rect {
+                left: convert(rect.left),
+                right: convert(rect.right),
+                top: convert(rect.top),
+                bottom: convert(rect.bottom),
+            }
+        }
+
+        let to_track = |track: &simulation::GridTrack| -> taffy::GridTemplateComponent<String> {
+            let sizing: taffy::TrackSizingFunction = match *track {
+                simulation::GridTrack::Px(px) => length(px),
+                simulation::GridTrack::Percent(value) => percent(value / 100.0),
+                simulation::GridTrack::Fr(fraction) => fr(fraction),
+                simulation::GridTrack::Auto => auto(),
+                simulation::GridTrack::MinContent => min_content(),
+                simulation::GridTrack::MaxContent => max_content(),
+            };
+            taffy::GridTemplateComponent::Single(sizing)
+        };
+        let to_placement =
+            |placement: simulation::GridPlacement| -> taffy::Line<taffy::GridPlacement> {
+                match placement {
+                    simulation::GridPlacement::Auto => auto(),
+                    simulation::GridPlacement::Line(start) => line(start),
+                    simulation::GridPlacement::Span(tracks) => span(tracks),
+                    simulation::GridPlacement::LineSpan(start, tracks) => taffy::Line {
+                        start: line(start),
+                        end: span(tracks),
+                    },
+                }
+            };
 
         taffy::style::Style {
+            display: value.display,
             size: taffy::Size {
                 width: to_dim(value.width),
                 height: to_dim(value.height),
             },
-            padding: taffy::geometry::Rect {
-                left: taffy::LengthPercentage::length(value.padding),
-                right: taffy::LengthPercentage::length(value.padding),
-                top: taffy::LengthPercentage::length(value.padding),
-                bottom: taffy::LengthPercentage::length(value.padding),
+            min_size: taffy::Size {
+                width: to_dim(value.min_width),
+                height: to_dim(value.min_height),
             },
+            max_size: taffy::Size {
+                width: to_dim(value.max_width),
+                height: to_dim(value.max_height),
+            },
+            margin: to_rect(value.margin, to_length_auto),
+            padding: to_rect(value.padding, to_length),
+            border: to_rect(value.border, to_length),
             position: value.position,
+            inset: to_rect(value.inset, to_length_auto),
+            flex_direction: value.flex_direction,
+            flex_wrap: value.flex_wrap,
+            flex_grow: value.flex_grow,
+            flex_shrink: value.flex_shrink,
+            flex_basis: to_dim(value.flex_basis),
             justify_content: value.justify_content,
             align_items: value.align_items,
-            flex_direction: value.flex_direction,
+            align_self: value.align_self,
+            align_content: value.align_content,
+            gap: taffy::Size {
+                width: to_length(value.column_gap),
+                height: to_length(value.row_gap),
+            },
+            grid_template_rows: value.grid_template_rows.iter().map(to_track).collect(),
+            grid_template_columns: value.grid_template_columns.iter().map(to_track).collect(),
+            grid_row: to_placement(value.grid_row),
+            grid_column: to_placement(value.grid_column),
             ..Default::default()
         }
     }
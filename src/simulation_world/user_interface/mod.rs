@@ -1,22 +1,33 @@
 pub mod components;
+pub mod images;
+pub mod interaction;
 pub mod layout;
 pub mod screens;
 pub mod text;
+pub mod widgets;
 
 // INFO: ----------------
 //         Plugin
 // ----------------------
 
+use self::images::{load_ui_images_system, UiImages};
+use self::interaction::{update_interaction_system, UiClickMessage};
 use self::layout::handle_window_resize_system;
+use crate::simulation_world::input::InputSystemSet;
 use crate::simulation_world::scheduling::StartupSet;
 use crate::simulation_world::user_interface::screens::{
-    DebugScreenPlugin, GameScreenPlugin, LoadingScreenPlugin,
+    ConsoleScreenPlugin, DebugScreenPlugin, GameScreenPlugin, LoadingScreenPlugin,
+    MenuScreensPlugin,
+};
+use crate::simulation_world::user_interface::widgets::{
+    skin_new_buttons_system, text_input_system, update_button_background_system,
+    update_text_input_display_system, TextInputSubmitted,
 };
 use crate::{
     ecs_core::{EcsBuilder, Plugin},
     simulation_world::{SimulationSchedule, SimulationSet},
 };
-use bevy_ecs::prelude::*;
+use bevy_ecs::{message::Messages, prelude::*};
 use {
     layout::{
         compute_and_apply_layout_system, compute_ui_depth_system, handle_hierarchy_changes_system,
@@ -38,7 +49,10 @@ impl Plugin for UiPlugin {
         builder.world.init_non_send_resource::<UiLayoutTree>();
         builder
             .add_resource(EntityToNodeMap::default())
-            .add_resource(IsLayoutDirty::default());
+            .add_resource(IsLayoutDirty::default())
+            .init_resource::<UiImages>()
+            .init_resource::<Messages<TextInputSubmitted>>()
+            .init_resource::<Messages<UiClickMessage>>();
 
         // INFO: -----------------
         //         Plugins
@@ -47,7 +61,9 @@ impl Plugin for UiPlugin {
         builder
             .add_plugin(LoadingScreenPlugin)
             .add_plugin(DebugScreenPlugin)
-            .add_plugin(GameScreenPlugin);
+            .add_plugin(GameScreenPlugin)
+            .add_plugin(ConsoleScreenPlugin)
+            .add_plugin(MenuScreensPlugin);
 
         // INFO: -----------------
         //         Systems
@@ -56,7 +72,11 @@ impl Plugin for UiPlugin {
         builder
             .schedule_entry(SimulationSchedule::Startup)
             .add_systems(
-                (setup_font_system, spawn_ui_root_system)
+                (
+                    setup_font_system,
+                    load_ui_images_system,
+                    spawn_ui_root_system,
+                )
                     .in_set(StartupSet::ResourceInitialization)
                     .chain(),
             );
@@ -64,8 +84,18 @@ impl Plugin for UiPlugin {
         builder
             .schedule_entry(SimulationSchedule::Main)
             .add_systems((
-                (handle_window_resize_system,).in_set(SimulationSet::Update),
+                update_interaction_system
+                    .after(InputSystemSet::WindowEvents)
+                    .before(InputSystemSet::Actions)
+                    .in_set(SimulationSet::Input),
+                (
+                    handle_window_resize_system,
+                    text_input_system,
+                    (skin_new_buttons_system, update_button_background_system).chain(),
+                )
+                    .in_set(SimulationSet::Update),
                 (
+                    update_text_input_display_system,
                     handle_structural_changes_system,
                     handle_hierarchy_changes_system,
                     update_changed_styles_system,
@@ -0,0 +1,289 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    console::{ConsoleCommandMessage, ConsoleLineKind, ConsoleLog},
+    input::{messages::KeyboardInputMessage, InputCapture},
+    user_interface::{
+        components::{Node, Size, Style, TextAlign, UiBackground, UiRect, UiText},
+        screens::spawn_root::UiRootNodeResource,
+        widgets::{TextInput, TextInputSubmitted},
+    },
+};
+use bevy_ecs::prelude::*;
+use winit::{
+    event::ElementState,
+    keyboard::{KeyCode, PhysicalKey},
+};
+
+/// How many log lines the console shows above the input.
+pub const CONSOLE_VISIBLE_LINES: usize = 12;
+
+const CONSOLE_FONT_SIZE: f32 = 24.0;
+
+// INFO: -------------------------
+//         Marker elements
+// -------------------------------
+
+/// A marker component for the root of the console screen.
+#[derive(Component)]
+pub struct RootConsoleScreenMarker;
+
+/// A marker component for a log line, counting up from the oldest visible line.
+#[derive(Component)]
+pub struct ConsoleLogLineMarker(pub usize);
+
+/// A marker component for the console's text input.
+#[derive(Component)]
+pub struct ConsoleInputMarker;
+
+/// Commands entered into the console, oldest first, for browsing with the arrow keys.
+#[derive(Resource, Debug, Default)]
+pub struct ConsoleHistory {
+    pub entries: Vec<String>,
+    /// The entry currently shown in the input, if browsing.
+    browsing: Option<usize>,
+}
+
+impl ConsoleHistory {
+    pub fn push(&mut self, line: &str) {
+        self.browsing = None;
+        if !line.is_empty() && self.entries.last().map(String::as_str) != Some(line) {
+            self.entries.push(line.to_string());
+        }
+    }
+
+    /// Steps back to an older entry.
+    pub fn older(&mut self) -> Option<&str> {
+        let index = match self.browsing {
+            Some(index) => index.saturating_sub(1),
+            None => self.entries.len().checked_sub(1)?,
+        };
+        self.browsing = Some(index);
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Steps forward to a newer entry, or back to an empty input past the newest.
+    pub fn newer(&mut self) -> Option<&str> {
+        let index = self.browsing? + 1;
+        self.browsing = (index < self.entries.len()).then_some(index);
+        Some(self.browsing.map_or("", |index| &self.entries[index]))
+    }
+}
+
+// INFO: -------------------------------------
+//         Toggling and creation logic
+// -------------------------------------------
+
+/// A run condition that returns true if the console is open.
+pub fn console_is_open(query: Query<(), With<RootConsoleScreenMarker>>) -> bool {
+    !query.is_empty()
+}
+
+/// Opens the console and captures input for it.
+#[instrument(skip_all)]
+pub fn open_console_system(
+    // Input
+    root_node: Res<UiRootNodeResource>,
+    log: Res<ConsoleLog>,
+
+    // Output (spawned screen and captured input)
+    mut capture: ResMut<InputCapture>,
+    mut commands: Commands,
+) {
+    info!("Opening console...");
+    capture.captured = true;
+
+    let console_ui_container = commands
+        .spawn((
+            RootConsoleScreenMarker,
+            Node,
+            Style {
+                position: taffy::style::Position::Absolute,
+                width: Size::Percent(100.0),
+                height: Size::Percent(100.0),
+                flex_direction: taffy::style::FlexDirection::Column,
+                justify_content: Some(taffy::JustifyContent::Start),
+                align_items: Some(taffy::AlignItems::Start),
+                ..Default::default()
+            },
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node,
+                    Style {
+                        width: Size::Percent(100.0),
+                        padding: UiRect::px(8.0),
+                        flex_direction: taffy::style::FlexDirection::Column,
+                        align_items: Some(taffy::AlignItems::Start),
+                        ..Default::default()
+                    },
+                    UiBackground::SolidColor {
+                        color: [0.0, 0.0, 0.0, 0.66],
+                    },
+                ))
+                .with_children(|panel| {
+                    let mut lines = log.tail(CONSOLE_VISIBLE_LINES);
+                    for index in 0..CONSOLE_VISIBLE_LINES {
+                        let (content, color) =
+                            lines.next().map_or((String::new(), [0.0; 4]), |line| {
+                                (line.text.clone(), line_color(line.kind))
+                            });
+                        panel.spawn((
+                            ConsoleLogLineMarker(index),
+                            Node,
+                            Style::default(),
+                            UiText {
+                                content,
+                                font_size: CONSOLE_FONT_SIZE,
+                                color,
+                                align: TextAlign::Start,
+                            },
+                        ));
+                    }
+
+                    panel
+                        .spawn((
+                            Node,
+                            Style {
+                                flex_direction: taffy::style::FlexDirection::Row,
+                                ..Default::default()
+                            },
+                        ))
+                        .with_children(|line| {
+                            line.spawn((
+                                Node,
+                                Style::default(),
+                                UiText {
+                                    content: "> ".to_string(),
+                                    font_size: CONSOLE_FONT_SIZE,
+                                    color: [0.7, 0.7, 0.7, 1.0],
+                                    align: TextAlign::Start,
+                                },
+                            ));
+                            line.spawn((
+                                ConsoleInputMarker,
+                                TextInput::focused(),
+                                Node,
+                                Style::default(),
+                                UiText {
+                                    content: String::new(),
+                                    font_size: CONSOLE_FONT_SIZE,
+                                    color: [1.0, 1.0, 1.0, 1.0],
+                                    align: TextAlign::Start,
+                                },
+                            ));
+                        });
+                });
+        })
+        .id();
+
+    commands.entity(root_node.0).add_child(console_ui_container);
+}
+
+/// Closes the console on escape or the console key, and browses history with up and down.
+#[instrument(skip_all)]
+pub fn console_keys_system(
+    // Input
+    mut keyboard_messages: MessageReader<KeyboardInputMessage>,
+    screen: Query<Entity, With<RootConsoleScreenMarker>>,
+
+    // Output (closed screen or browsed input)
+    mut inputs: Query<&mut TextInput, With<ConsoleInputMarker>>,
+    mut history: ResMut<ConsoleHistory>,
+    mut capture: ResMut<InputCapture>,
+    mut commands: Commands,
+) {
+    let pressed = keyboard_messages
+        .read()
+        .filter(|message| message.state == ElementState::Pressed)
+        .map(|message| message.key_code);
+
+    for key in pressed {
+        match key {
+            PhysicalKey::Code(KeyCode::Escape | KeyCode::Backquote) => {
+                info!("Closing console...");
+                for entity in screen.iter() {
+                    commands.entity(entity).despawn();
+                }
+                // unfocus now so nothing else typed this frame lands in the closed input
+                for mut input in inputs.iter_mut() {
+                    input.focused = false;
+                }
+                capture.captured = false;
+                return;
+            }
+            PhysicalKey::Code(KeyCode::ArrowUp) => {
+                if let Some(entry) = history.older() {
+                    inputs
+                        .iter_mut()
+                        .for_each(|mut input| input.set_value(entry));
+                }
+            }
+            PhysicalKey::Code(KeyCode::ArrowDown) => {
+                if let Some(entry) = history.newer() {
+                    inputs
+                        .iter_mut()
+                        .for_each(|mut input| input.set_value(entry));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Sends whatever was entered into the console off to be run.
+#[instrument(skip_all)]
+pub fn submit_console_input_system(
+    // Input
+    mut submitted: MessageReader<TextInputSubmitted>,
+
+    // Output (cleared input and commands to run)
+    mut inputs: Query<&mut TextInput, With<ConsoleInputMarker>>,
+    mut history: ResMut<ConsoleHistory>,
+    mut console_commands: MessageWriter<ConsoleCommandMessage>,
+) {
+    for message in submitted.read() {
+        let Ok(mut input) = inputs.get_mut(message.entity) else {
+            continue;
+        };
+        input.set_value("");
+
+        let line = message.value.trim();
+        if line.is_empty() {
+            continue;
+        }
+        history.push(line);
+        console_commands.write(ConsoleCommandMessage {
+            line: line.to_string(),
+        });
+    }
+}
+
+/// Shows the newest lines of the console log.
+#[instrument(skip_all)]
+pub fn update_console_log_text_system(
+    // Input
+    log: Res<ConsoleLog>,
+
+    // Output (updated lines)
+    mut query: Query<(&ConsoleLogLineMarker, &mut UiText)>,
+) {
+    let lines: Vec<_> = log.tail(CONSOLE_VISIBLE_LINES).collect();
+    for (ConsoleLogLineMarker(index), mut text) in query.iter_mut() {
+        match lines.get(*index) {
+            Some(line) => {
+                text.content.clone_from(&line.text);
+                text.color = line_color(line.kind);
+            }
+            None => text.content.clear(),
+        }
+    }
+}
+
+fn line_color(kind: ConsoleLineKind) -> [f32; 4] {
+    match kind {
+        ConsoleLineKind::Input => [0.7, 0.7, 0.7, 1.0],
+        ConsoleLineKind::Output => [1.0, 1.0, 1.0, 1.0],
+        ConsoleLineKind::Error => [1.0, 0.4, 0.4, 1.0],
+    }
+}
@@ -0,0 +1,44 @@
+pub mod console_screen;
+
+pub use console_screen::{
+    console_is_open, console_keys_system, open_console_system, submit_console_input_system,
+    update_console_log_text_system, ConsoleHistory,
+};
+
+// INFO: ----------------
+//         plugin
+// ----------------------
+
+use crate::prelude::*;
+use crate::simulation_world::{
+    console::ConsoleLog, input::ActionStateResource, user_interface::widgets::text_input_system,
+};
+use bevy_ecs::prelude::*;
+
+pub struct ConsoleScreenPlugin;
+
+impl Plugin for ConsoleScreenPlugin {
+    fn build(&self, builder: &mut EcsBuilder) {
+        builder.init_resource::<ConsoleHistory>();
+
+        builder
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(
+                (
+                    (console_keys_system, submit_console_input_system)
+                        .run_if(console_is_open)
+                        .after(text_input_system),
+                    open_console_system.run_if(
+                        (|action_state: Res<ActionStateResource>| {
+                            action_state.just_happened(SimulationAction::ToggleConsole)
+                        })
+                        .and(not(console_is_open)),
+                    ),
+                    update_console_log_text_system
+                        .run_if(console_is_open.and(resource_changed::<ConsoleLog>)),
+                )
+                    .chain()
+                    .in_set(SimulationSet::Update),
+            );
+    }
+}
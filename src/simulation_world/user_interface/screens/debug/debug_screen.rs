@@ -2,12 +2,12 @@ use crate::prelude::*;
 use crate::simulation_world::player::CameraComponent;
 use crate::simulation_world::terrain::ActiveTerrainGenerator;
 use crate::simulation_world::{
-    chunk::ChunkCoord,
+    chunk::{thread_buffer_pool::face_buffer_pool_stats, ChunkCoord},
     time::FrameClock,
     user_interface::{
-        components::{Node, Size, Style, TextAlign, UiBackground, UiText},
+        components::{Node, Size, Style, TextAlign, UiBackground, UiRect, UiText},
         screens::spawn_root::UiRootNodeResource,
-        screens::MeshCounterResource,
+        screens::{elements::face_pool::face_pool_stats_string, MeshCounterResource},
     },
 };
 use bevy_ecs::{prelude::*, relationship::RelatedSpawnerCommands};
@@ -26,6 +26,7 @@ pub enum StatMarker {
     // performance
     Fps(FpsCounterTextElementMarker),
     Memory(MemoryCounterTextElementMarker),
+    FacePool(FacePoolTextMarker),
     MeshCount(MeshCountTextMarker),
     FaceCount(FaceCountTextMarker),
 }
@@ -58,6 +59,10 @@ pub struct FpsCounterTextElementMarker;
 #[derive(Component)]
 pub struct MemoryCounterTextElementMarker;
 
+/// A marker component for the face buffer pool text element.
+#[derive(Component)]
+pub struct FacePoolTextMarker;
+
 /// A marker component for the total mesh count text element.
 #[derive(Component)]
 pub struct MeshCountTextMarker;
@@ -229,6 +234,15 @@ fn spawn_diagnostic_ui(
                     }];
                     spawn_stats_line(parent, memory_line_elements, font_size, align);
 
+                    // face buffer pool line
+                    let face_pool_line_elements = vec![StatLineElement {
+                        prefix: "Face pool: ".to_string(),
+                        content: face_pool_stats_string(&face_buffer_pool_stats()),
+                        color: [1.0, 1.0, 1.0, 1.0],
+                        marker: StatMarker::FacePool(FacePoolTextMarker),
+                    }];
+                    spawn_stats_line(parent, face_pool_line_elements, font_size, align);
+
                     // mesh line
                     let mesh_line_elements = vec![
                         StatLineElement {
@@ -277,7 +291,7 @@ fn spawn_stats_line(
         .spawn((
             Node,
             Style {
-                padding: 8.0,
+                padding: UiRect::px(8.0),
                 flex_direction: taffy::style::FlexDirection::Row,
                 align_items: Some(taffy::style::AlignItems::Center),
                 ..Default::default()
@@ -322,6 +336,7 @@ fn spawn_stats_line(
                     StatMarker::ActiveGen(marker) => text_entity.insert(marker),
                     StatMarker::Fps(marker) => text_entity.insert(marker),
                     StatMarker::Memory(marker) => text_entity.insert(marker),
+                    StatMarker::FacePool(marker) => text_entity.insert(marker),
                     StatMarker::MeshCount(marker) => text_entity.insert(marker),
                     StatMarker::FaceCount(marker) => text_entity.insert(marker),
                 };
@@ -0,0 +1,36 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    chunk::thread_buffer_pool::{face_buffer_pool_stats, FaceBufferPoolStats},
+    user_interface::{components::UiText, screens::debug_screen::FacePoolTextMarker},
+};
+use bevy_ecs::prelude::*;
+
+/// Formats the face buffer pool stats for the debug screen.
+pub fn face_pool_stats_string(stats: &FaceBufferPoolStats) -> String {
+    let handed_out = stats.allocated + stats.reused;
+    let reuse_percent = if handed_out == 0 {
+        0.0
+    } else {
+        stats.reused as f32 / handed_out as f32 * 100.0
+    };
+
+    format!(
+        "{:.0}% reused, {} idle ({:.2} MB)",
+        reuse_percent,
+        stats.idle,
+        stats.idle_bytes as f32 / 1024.0 / 1024.0
+    )
+}
+
+/// Updates the content of the face buffer pool text element.
+#[instrument(skip_all)]
+pub fn update_face_pool_screen_text(
+    // Output (updated component)
+    mut query: Query<&mut UiText, With<FacePoolTextMarker>>,
+) {
+    if let Ok(mut ui_text) = query.single_mut() {
+        ui_text.content = face_pool_stats_string(&face_buffer_pool_stats());
+    } else {
+        warn!("Failed to get single UiText with FacePoolTextMarker");
+    }
+}
@@ -28,10 +28,12 @@ pub fn mesh_remove_observer(
     let entity = trigger.entity;
 
     if let Ok(mesh_component) = mesh_query.get(entity) {
-        if let Some(mesh) = asset_storage.get(mesh_component.mesh_handle) {
+        if let Some(face_count) =
+            asset_storage.with(mesh_component.mesh_handle, |mesh| mesh.faces.len())
+        {
             // use saturating_sub to prevent panicking
             mesh_count.total_meshes = mesh_count.total_meshes.saturating_sub(1);
-            mesh_count.total_faces = mesh_count.total_faces.saturating_sub(mesh.faces.len());
+            mesh_count.total_faces = mesh_count.total_faces.saturating_sub(face_count);
         } else {
             warn!(
                 "MeshComponentRemovedMessage received for an invalid handle: {:?}",
@@ -56,9 +58,11 @@ pub fn mesh_add_observer(
     let entity = trigger.entity;
 
     if let Ok(mesh_component) = mesh_query.get(entity) {
-        if let Some(mesh) = asset_storage.get(mesh_component.mesh_handle) {
+        if let Some(face_count) =
+            asset_storage.with(mesh_component.mesh_handle, |mesh| mesh.faces.len())
+        {
             mesh_count.total_meshes += 1;
-            mesh_count.total_faces += mesh.faces.len();
+            mesh_count.total_faces += face_count;
         } else {
             warn!(
                 "MeshComponent added with an invalid handle: {:?}",
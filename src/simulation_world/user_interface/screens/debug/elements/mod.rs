@@ -2,6 +2,7 @@ pub mod active_gen_text;
 pub mod camera_coords;
 pub mod camera_xyz_coords;
 pub mod current_biome;
+pub mod face_pool;
 pub mod fps_counter;
 pub mod memory_counter;
 pub mod mesh_counter;
@@ -9,6 +10,7 @@ pub mod mesh_counter;
 pub use camera_coords::update_camera_chunk_coord_screen_text;
 pub use camera_xyz_coords::update_camera_xyz_coord_screen_text;
 pub use current_biome::update_current_biome_text_system;
+pub use face_pool::update_face_pool_screen_text;
 pub use fps_counter::update_fps_counter_screen_text_system;
 pub use memory_counter::{update_memory_counter_screen_text, SystemInfoResource};
 pub use mesh_counter::{
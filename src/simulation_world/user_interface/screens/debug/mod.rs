@@ -18,7 +18,7 @@ use crate::simulation_world::user_interface::screens::elements::mesh_counter::{
 };
 use crate::simulation_world::user_interface::screens::elements::{
     update_camera_chunk_coord_screen_text, update_camera_xyz_coord_screen_text,
-    update_memory_counter_screen_text,
+    update_face_pool_screen_text, update_memory_counter_screen_text,
 };
 use crate::{
     ecs_core::{EcsBuilder, Plugin},
@@ -63,6 +63,7 @@ impl Plugin for DebugScreenPlugin {
                     update_current_biome_text_system,
                     update_fps_counter_screen_text_system,
                     update_memory_counter_screen_text,
+                    update_face_pool_screen_text,
                     update_active_gen_text_system,
                 )
                     .run_if(diagnostic_ui_is_visible)
@@ -1,15 +1,26 @@
 use crate::prelude::*;
 use crate::simulation_world::user_interface::{
     components::{Node, Size, Style, UiBackground},
+    images::UiImages,
     screens::spawn_root::UiRootNodeResource,
 };
 use bevy_ecs::prelude::*;
 
-pub fn spawn_crosshair(mut commands: Commands, root_node: Res<UiRootNodeResource>) {
+/// A marker component for the crosshair, which is only there while playing.
+#[derive(Component)]
+pub struct CrosshairMarker;
+
+/// Spawns the crosshair image, or a plus of two solid bars if it didn't load.
+pub fn spawn_crosshair(
+    mut commands: Commands,
+    root_node: Res<UiRootNodeResource>,
+    images: Res<UiImages>,
+) {
     info!("Spawning crosshair element");
 
     let crosshair_entity = commands
         .spawn((
+            CrosshairMarker,
             Node,
             Style {
                 width: Size::Auto,
@@ -21,6 +32,19 @@ pub fn spawn_crosshair(mut commands: Commands, root_node: Res<UiRootNodeResource
             },
         ))
         .with_children(|parent| {
+            if let Some(crosshair) = images.crosshair {
+                parent.spawn((
+                    Node,
+                    Style {
+                        width: Size::Px(32.0),
+                        height: Size::Px(32.0),
+                        ..Style::default()
+                    },
+                    UiBackground::image(crosshair),
+                ));
+                return;
+            }
+
             // horizontal hair
             parent.spawn((
                 Node,
@@ -51,3 +75,9 @@ pub fn spawn_crosshair(mut commands: Commands, root_node: Res<UiRootNodeResource
 
     commands.entity(root_node.0).add_child(crosshair_entity);
 }
+
+pub fn despawn_crosshair(mut commands: Commands, query: Query<Entity, With<CrosshairMarker>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
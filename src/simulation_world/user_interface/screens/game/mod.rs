@@ -6,7 +6,9 @@ pub mod crosshair;
 
 use crate::ecs_core::{EcsBuilder, Plugin};
 use crate::prelude::*;
-use crate::simulation_world::user_interface::screens::game::crosshair::spawn_crosshair;
+use crate::simulation_world::user_interface::screens::game::crosshair::{
+    despawn_crosshair, spawn_crosshair,
+};
 
 pub struct GameScreenPlugin;
 
@@ -15,5 +17,8 @@ impl Plugin for GameScreenPlugin {
         builder
             .schedule_entry(OnEnter(GameState::Playing))
             .add_systems(spawn_crosshair);
+        builder
+            .schedule_entry(OnExit(GameState::Playing))
+            .add_systems(despawn_crosshair);
     }
 }
@@ -0,0 +1,82 @@
+use crate::ecs_core::AppConfig;
+use crate::prelude::*;
+use crate::simulation_world::{
+    user_interface::screens::{
+        menu::{
+            menu_screen::{spawn_menu_button, spawn_menu_screen, spawn_menu_text},
+            settings_menu::spawn_settings_page,
+            MenuButton, MenuPage, MenuStatus,
+        },
+        spawn_root::UiRootNodeResource,
+    },
+    world_save::{WorldSave, WORLD_SAVES_DIR},
+};
+use bevy_ecs::prelude::*;
+use std::path::Path;
+
+/// A marker component for the root of the main menu.
+#[derive(Component)]
+pub struct MainMenuMarker;
+
+/// Spawns the current page of the main menu.
+#[instrument(skip_all)]
+pub fn spawn_main_menu_system(
+    // Input
+    root_node: Res<UiRootNodeResource>,
+    page: Res<MenuPage>,
+    status: Res<MenuStatus>,
+    config: Res<AppConfig>,
+
+    // Output (spawned menu)
+    mut commands: Commands,
+) {
+    info!("Spawning main menu ({:?})...", *page);
+
+    let title = match *page {
+        MenuPage::Settings => "Settings",
+        _ => "b",
+    };
+    spawn_menu_screen(&mut commands, &root_node, MainMenuMarker, title, |menu| {
+        match *page {
+            MenuPage::Main => {
+                spawn_menu_button(menu, "New world", MenuButton::NewWorld);
+                spawn_menu_button(menu, "Load world", MenuButton::LoadWorld);
+                spawn_menu_button(menu, "Settings", MenuButton::Settings);
+                spawn_menu_button(menu, "Quit", MenuButton::Quit);
+            }
+            MenuPage::LoadWorld => {
+                let saves = WorldSave::list(Path::new(WORLD_SAVES_DIR));
+                if saves.is_empty() {
+                    spawn_menu_text(
+                        menu,
+                        format!("No saves in {WORLD_SAVES_DIR}/"),
+                        [0.7, 0.7, 0.7, 1.0],
+                    );
+                }
+                for save in saves {
+                    let name = save.file_name().map_or_else(
+                        || save.display().to_string(),
+                        |name| name.to_string_lossy().into_owned(),
+                    );
+                    spawn_menu_button(menu, name, MenuButton::LoadSave(save));
+                }
+                spawn_menu_button(menu, "Back", MenuButton::Back);
+            }
+            MenuPage::Settings => spawn_settings_page(menu, &config),
+        }
+
+        if !status.0.is_empty() {
+            spawn_menu_text(menu, status.0.clone(), [1.0, 0.4, 0.4, 1.0]);
+        }
+    });
+}
+
+#[instrument(skip_all)]
+pub fn despawn_main_menu_system(
+    mut commands: Commands,
+    query: Query<Entity, With<MainMenuMarker>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,103 @@
+use crate::ecs_core::{state_machine::NextState, AppConfig, Setting};
+use crate::prelude::*;
+use crate::simulation_world::{
+    chunk::unload_all_chunks,
+    terrain::WorldSeed,
+    user_interface::{
+        interaction::UiClickMessage,
+        screens::menu::{MenuPage, MenuStatus},
+    },
+    world_save::{WorldSave, WorldSaveResource},
+};
+use bevy_ecs::prelude::*;
+use std::{
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// What a menu button does when clicked.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub enum MenuButton {
+    /// Regenerates the world with a fresh seed and starts playing.
+    NewWorld,
+    /// Shows the saves that can be loaded.
+    LoadWorld,
+    LoadSave(PathBuf),
+    /// Back to the first page of the menu.
+    Back,
+    Settings,
+    /// Moves a setting by some steps, or flips it if it is a toggle.
+    AdjustSetting(Setting, i32),
+    Resume,
+    QuitToMenu,
+    Quit,
+}
+
+/// Does whatever the clicked menu buttons do.
+#[instrument(skip_all)]
+pub fn handle_menu_clicks_system(
+    // Input
+    mut clicks: MessageReader<UiClickMessage>,
+    buttons: Query<&MenuButton>,
+
+    // Output (menu pages, settings, state changes and world changes)
+    mut page: ResMut<MenuPage>,
+    mut config: Option<ResMut<AppConfig>>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut commands: Commands,
+) {
+    for click in clicks.read() {
+        let Ok(button) = buttons.get(click.entity) else {
+            continue;
+        };
+
+        match button {
+            MenuButton::NewWorld => {
+                commands.queue(start_new_world);
+            }
+            MenuButton::LoadWorld => *page = MenuPage::LoadWorld,
+            MenuButton::LoadSave(path) => {
+                let path = path.clone();
+                commands.queue(move |world: &mut World| load_saved_world(world, &path));
+            }
+            MenuButton::Back => *page = MenuPage::Main,
+            MenuButton::Settings => *page = MenuPage::Settings,
+            MenuButton::AdjustSetting(setting, steps) => {
+                if let Some(config) = config.as_mut() {
+                    config.adjust(*setting, *steps);
+                }
+            }
+            MenuButton::Resume => next_game_state.val = Some(GameState::Playing),
+            MenuButton::QuitToMenu => next_game_state.val = Some(GameState::MainMenu),
+            MenuButton::Quit => next_app_state.val = Some(AppState::ShuttingDown),
+        }
+    }
+}
+
+/// Drops any loaded save, regenerates every chunk from a fresh seed and starts playing.
+pub fn start_new_world(world: &mut World) {
+    let seed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_nanos() as u32);
+    info!("Starting a new world with seed {seed}...");
+
+    world.remove_resource::<WorldSaveResource>();
+    WorldSeed(seed).install(world);
+    unload_all_chunks(world);
+    world.resource_mut::<NextState<GameState>>().val = Some(GameState::Playing);
+}
+
+/// Loads the save in `dir` and starts playing, or shows why it couldn't.
+pub fn load_saved_world(world: &mut World, dir: &Path) {
+    match WorldSave::load_into(dir, world) {
+        Ok(()) => {
+            unload_all_chunks(world);
+            world.resource_mut::<NextState<GameState>>().val = Some(GameState::Playing);
+        }
+        Err(e) => {
+            error!("Failed to load world save {:?}: {}", dir, e);
+            world.resource_mut::<MenuStatus>().0 = format!("Couldn't load {}: {e}", dir.display());
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::simulation_world::user_interface::{
+    components::{Interaction, Node, Size, Style, TextAlign, UiBackground, UiRect, UiText},
+    screens::{menu::MenuButton, spawn_root::UiRootNodeResource},
+    widgets::spawn_button,
+};
+use bevy_ecs::{prelude::*, relationship::RelatedSpawnerCommands};
+
+const MENU_BUTTON_WIDTH: f32 = 360.0;
+/// The space between a menu's lines and buttons.
+const MENU_ROW_GAP: f32 = 12.0;
+
+/// Spawns a full screen menu with a title above whatever `build` adds.
+///
+/// The backdrop has an `Interaction` so clicks that miss the buttons don't reach the
+/// world behind it.
+pub fn spawn_menu_screen(
+    commands: &mut Commands,
+    root_node: &UiRootNodeResource,
+    marker: impl Bundle,
+    title: &str,
+    build: impl FnOnce(&mut RelatedSpawnerCommands<ChildOf>),
+) {
+    let menu_entity = commands
+        .spawn((
+            marker,
+            Node,
+            Interaction::default(),
+            Style {
+                position: taffy::style::Position::Absolute,
+                width: Size::Percent(100.0),
+                height: Size::Percent(100.0),
+                flex_direction: taffy::style::FlexDirection::Column,
+                justify_content: Some(taffy::JustifyContent::Center),
+                align_items: Some(taffy::AlignItems::Center),
+                row_gap: Size::Px(MENU_ROW_GAP),
+                ..Default::default()
+            },
+            UiBackground::SolidColor {
+                color: [0.0, 0.0, 0.0, 0.5],
+            },
+        ))
+        .with_children(|menu| {
+            menu.spawn((
+                Node,
+                Style {
+                    margin: UiRect::axes(Size::Px(0.0), Size::Px(MENU_ROW_GAP)),
+                    ..Default::default()
+                },
+                UiText {
+                    content: title.to_string(),
+                    font_size: 64.0,
+                    color: [1.0, 1.0, 1.0, 1.0],
+                    align: TextAlign::Center,
+                },
+            ));
+            build(menu);
+        })
+        .id();
+
+    commands.entity(root_node.0).add_child(menu_entity);
+}
+
+/// Spawns a menu button.
+pub fn spawn_menu_button(
+    menu: &mut RelatedSpawnerCommands<ChildOf>,
+    label: impl Into<String>,
+    action: MenuButton,
+) {
+    spawn_button(menu, label, Size::Px(MENU_BUTTON_WIDTH)).insert(action);
+}
+
+/// Spawns a line of plain text in a menu.
+pub fn spawn_menu_text(
+    menu: &mut RelatedSpawnerCommands<ChildOf>,
+    content: impl Into<String>,
+    color: [f32; 4],
+) {
+    menu.spawn((
+        Node,
+        Style::default(),
+        UiText {
+            content: content.into(),
+            font_size: 28.0,
+            color,
+            align: TextAlign::Center,
+        },
+    ));
+}
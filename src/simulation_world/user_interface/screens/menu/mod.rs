@@ -0,0 +1,144 @@
+pub mod main_menu;
+pub mod menu_actions;
+pub mod menu_screen;
+pub mod pause_menu;
+pub mod settings_menu;
+
+pub use main_menu::{despawn_main_menu_system, spawn_main_menu_system};
+pub use menu_actions::{handle_menu_clicks_system, MenuButton};
+pub use pause_menu::{despawn_pause_menu_system, spawn_pause_menu_system, toggle_pause_system};
+pub use settings_menu::save_config_system;
+
+// INFO: ----------------
+//         plugin
+// ----------------------
+
+use crate::ecs_core::AppConfig;
+use crate::prelude::*;
+use crate::simulation_world::input::ActionStateResource;
+use bevy_ecs::prelude::*;
+
+/// A message shown at the bottom of the main menu, like why a save didn't load.
+#[derive(Resource, Debug, Default)]
+pub struct MenuStatus(pub String);
+
+/// Which page of the main or pause menu is showing.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MenuPage {
+    #[default]
+    Main,
+    /// The list of saves in `WORLD_SAVES_DIR`, only in the main menu.
+    LoadWorld,
+    Settings,
+}
+
+/// Goes back to the first page of a menu whenever it is entered.
+pub fn reset_menu_page_system(mut page: ResMut<MenuPage>, mut status: ResMut<MenuStatus>) {
+    page.set_if_neq(MenuPage::Main);
+    if !status.0.is_empty() {
+        status.0.clear();
+    }
+}
+
+/// The main menu shown once loading finishes and the pause menu, each only present in
+/// its `GameState`.
+pub struct MenuScreensPlugin;
+
+impl Plugin for MenuScreensPlugin {
+    fn build(&self, builder: &mut EcsBuilder) {
+        builder
+            .init_resource::<MenuPage>()
+            .init_resource::<MenuStatus>();
+
+        // INFO: -------------------
+        //         Main menu
+        // -------------------------
+
+        builder
+            .schedule_entry(OnExit(AppState::StartingUp))
+            .add_systems(spawn_main_menu_system.run_if(in_state(GameState::MainMenu)));
+        builder
+            .schedule_entry(OnEnter(GameState::MainMenu))
+            .add_systems((reset_menu_page_system, spawn_main_menu_system).chain());
+        builder
+            .schedule_entry(OnExit(GameState::MainMenu))
+            .add_systems(despawn_main_menu_system);
+
+        builder
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(
+                (despawn_main_menu_system, spawn_main_menu_system)
+                    .chain()
+                    .run_if(
+                        in_state(AppState::Running)
+                            .and(in_state(GameState::MainMenu))
+                            .and(menu_changed),
+                    )
+                    .in_set(SimulationSet::PostUpdate),
+            );
+
+        // INFO: --------------------
+        //         Pause menu
+        // --------------------------
+
+        builder
+            .schedule_entry(OnEnter(GameState::Paused))
+            .add_systems((reset_menu_page_system, spawn_pause_menu_system).chain());
+        builder
+            .schedule_entry(OnExit(GameState::Paused))
+            .add_systems(despawn_pause_menu_system);
+
+        builder
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(
+                (despawn_pause_menu_system, spawn_pause_menu_system)
+                    .chain()
+                    .run_if(
+                        in_state(AppState::Running)
+                            .and(in_state(GameState::Paused))
+                            .and(menu_changed),
+                    )
+                    .in_set(SimulationSet::PostUpdate),
+            );
+
+        // INFO: ------------------
+        //         Settings
+        // ------------------------
+
+        builder
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(
+                save_config_system
+                    .run_if(
+                        resource_exists_and_changed::<AppConfig>
+                            .and(not(resource_added::<AppConfig>)),
+                    )
+                    .in_set(SimulationSet::PostUpdate),
+            );
+
+        builder
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(
+                (
+                    toggle_pause_system.run_if(
+                        (|action_state: Res<ActionStateResource>| {
+                            action_state.just_happened(SimulationAction::TogglePause)
+                        })
+                        .and(in_state(AppState::Running)),
+                    ),
+                    handle_menu_clicks_system,
+                )
+                    .in_set(SimulationSet::Update),
+            );
+    }
+}
+
+/// Whether the open menu needs to be rebuilt, because its page, status or the settings it
+/// shows changed.
+fn menu_changed(
+    page: Res<MenuPage>,
+    status: Res<MenuStatus>,
+    config: Option<Res<AppConfig>>,
+) -> bool {
+    page.is_changed() || status.is_changed() || config.is_some_and(|config| config.is_changed())
+}
@@ -0,0 +1,78 @@
+use crate::ecs_core::{
+    state_machine::{CurrentState, NextState},
+    AppConfig,
+};
+use crate::prelude::*;
+use crate::simulation_world::user_interface::screens::{
+    menu::{
+        menu_screen::{spawn_menu_button, spawn_menu_screen},
+        settings_menu::spawn_settings_page,
+        MenuButton, MenuPage,
+    },
+    spawn_root::UiRootNodeResource,
+};
+use bevy_ecs::prelude::*;
+
+/// A marker component for the root of the pause menu.
+#[derive(Component)]
+pub struct PauseMenuMarker;
+
+/// Pauses while playing and resumes while paused.
+#[instrument(skip_all)]
+pub fn toggle_pause_system(
+    // Input
+    current_state: Res<CurrentState<GameState>>,
+
+    // Output (requested state)
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    next_state.val = match current_state.val {
+        GameState::Playing => Some(GameState::Paused),
+        GameState::Paused => Some(GameState::Playing),
+        GameState::MainMenu => return,
+    };
+}
+
+/// Spawns the current page of the pause menu.
+#[instrument(skip_all)]
+pub fn spawn_pause_menu_system(
+    // Input
+    root_node: Res<UiRootNodeResource>,
+    page: Res<MenuPage>,
+    config: Res<AppConfig>,
+
+    // Output (spawned menu)
+    mut commands: Commands,
+) {
+    info!("Spawning pause menu ({:?})...", *page);
+
+    let title = match *page {
+        MenuPage::Settings => "Settings",
+        _ => "Paused",
+    };
+    spawn_menu_screen(
+        &mut commands,
+        &root_node,
+        PauseMenuMarker,
+        title,
+        |menu| match *page {
+            MenuPage::Settings => spawn_settings_page(menu, &config),
+            _ => {
+                spawn_menu_button(menu, "Resume", MenuButton::Resume);
+                spawn_menu_button(menu, "Settings", MenuButton::Settings);
+                spawn_menu_button(menu, "Main menu", MenuButton::QuitToMenu);
+                spawn_menu_button(menu, "Quit", MenuButton::Quit);
+            }
+        },
+    );
+}
+
+#[instrument(skip_all)]
+pub fn despawn_pause_menu_system(
+    mut commands: Commands,
+    query: Query<Entity, With<PauseMenuMarker>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
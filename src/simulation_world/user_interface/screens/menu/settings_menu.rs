@@ -0,0 +1,86 @@
+use crate::ecs_core::{AppConfig, Setting};
+use crate::prelude::*;
+use crate::simulation_world::user_interface::{
+    components::{Node, Size, Style, TextAlign, UiText},
+    screens::menu::{menu_screen::spawn_menu_button, MenuButton},
+    widgets::spawn_button,
+};
+use bevy_ecs::{prelude::*, relationship::RelatedSpawnerCommands};
+
+const STEP_BUTTON_WIDTH: f32 = 64.0;
+const STEP_BUTTON_GAP: f32 = 12.0;
+const SETTING_LABEL_WIDTH: f32 = 336.0;
+
+/// Spawns a row for every setting in `config` and a back button.
+///
+/// Settings that step up and down get `-` and `+` buttons around their value, toggles are
+/// a single button that flips them.
+pub fn spawn_settings_page(menu: &mut RelatedSpawnerCommands<ChildOf>, config: &AppConfig) {
+    for setting in Setting::ALL {
+        let label = setting_label(config, setting);
+        if setting.is_toggle() {
+            spawn_menu_button(menu, label, MenuButton::AdjustSetting(setting, 1));
+            continue;
+        }
+
+        menu.spawn((
+            Node,
+            Style {
+                flex_direction: taffy::style::FlexDirection::Row,
+                align_items: Some(taffy::AlignItems::Center),
+                column_gap: Size::Px(STEP_BUTTON_GAP),
+                ..Default::default()
+            },
+        ))
+        .with_children(|row| {
+            spawn_button(row, "-", Size::Px(STEP_BUTTON_WIDTH))
+                .insert(MenuButton::AdjustSetting(setting, -1));
+            row.spawn((
+                Node,
+                Style {
+                    width: Size::Px(SETTING_LABEL_WIDTH),
+                    ..Default::default()
+                },
+                UiText {
+                    content: label,
+                    font_size: 28.0,
+                    color: [1.0, 1.0, 1.0, 1.0],
+                    align: TextAlign::Center,
+                },
+            ));
+            spawn_button(row, "+", Size::Px(STEP_BUTTON_WIDTH))
+                .insert(MenuButton::AdjustSetting(setting, 1));
+        });
+    }
+
+    spawn_menu_button(menu, "Back", MenuButton::Back);
+}
+
+/// The setting's name and current value, as shown in the settings page.
+pub fn setting_label(config: &AppConfig, setting: Setting) -> String {
+    let on_off = |on: bool| if on { "On" } else { "Off" };
+    match setting {
+        Setting::RenderDistance => format!("Render distance: {}", config.render_distance),
+        Setting::Fov => format!("FOV: {:.0}", config.fov),
+        Setting::MouseSensitivity => format!("Mouse sensitivity: {:.1}", config.mouse_sensitivity),
+        Setting::Vsync => format!("Vsync: {}", on_off(config.vsync)),
+        Setting::UiScale => format!("UI scale: {:.2}", config.ui_scale),
+        Setting::Shadows => format!("Shadows: {}", on_off(config.shadows)),
+    }
+}
+
+/// Writes the settings back to their file after they change at runtime.
+#[instrument(skip_all)]
+pub fn save_config_system(
+    // Input
+    config: Res<AppConfig>,
+) {
+    let Some(path) = config.save_path() else {
+        return;
+    };
+
+    match config.save(path) {
+        Ok(()) => info!("Saved settings to {}", path.display()),
+        Err(e) => error!("Failed to save settings to {}: {}", path.display(), e),
+    }
+}
@@ -1,9 +1,13 @@
+pub mod console;
 pub mod debug;
 pub mod game;
 pub mod loading;
+pub mod menu;
 pub mod spawn_root;
 
+pub use console::ConsoleScreenPlugin;
 pub use debug::*;
 pub use game::*;
 pub use loading::*;
+pub use menu::MenuScreensPlugin;
 pub use spawn_root::spawn_ui_root_system;
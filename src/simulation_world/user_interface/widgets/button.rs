@@ -0,0 +1,114 @@
+use crate::prelude::*;
+use crate::simulation_world::user_interface::{
+    components::{
+        Interaction, NineSlice, Node, Size, Style, TextAlign, UiBackground, UiRect, UiText, UvRect,
+    },
+    images::{UiImages, UI_PANEL_BORDER},
+};
+use bevy_ecs::{prelude::*, relationship::RelatedSpawnerCommands};
+
+const BUTTON_FONT_SIZE: f32 = 32.0;
+const BUTTON_TEXT_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+/// A clickable box with a label, recolored as it is hovered and pressed.
+///
+/// Clicks arrive as `UiClickMessage`s for the button entity.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Button {
+    pub normal: [f32; 4],
+    pub hovered: [f32; 4],
+    pub pressed: [f32; 4],
+}
+
+impl Default for Button {
+    fn default() -> Self {
+        Self {
+            normal: [0.15, 0.15, 0.15, 0.85],
+            hovered: [0.25, 0.25, 0.25, 0.9],
+            pressed: [0.1, 0.3, 0.1, 0.9],
+        }
+    }
+}
+
+impl Button {
+    pub fn color(&self, interaction: Interaction) -> [f32; 4] {
+        match interaction {
+            Interaction::Normal => self.normal,
+            Interaction::Hovered => self.hovered,
+            Interaction::Pressed => self.pressed,
+        }
+    }
+}
+
+/// Spawns a default button with a centered label, returning its entity so callers can
+/// add whatever tells their clicks apart.
+pub fn spawn_button<'a>(
+    parent: &'a mut RelatedSpawnerCommands<ChildOf>,
+    label: impl Into<String>,
+    width: Size,
+) -> EntityCommands<'a> {
+    let button = Button::default();
+    let mut entity = parent.spawn((
+        button,
+        Interaction::default(),
+        Node,
+        Style {
+            width,
+            padding: UiRect::px(12.0),
+            justify_content: Some(taffy::JustifyContent::Center),
+            align_items: Some(taffy::AlignItems::Center),
+            ..Default::default()
+        },
+        UiBackground::SolidColor {
+            color: button.normal,
+        },
+    ));
+    entity.with_children(|button| {
+        button.spawn((
+            Node,
+            Style::default(),
+            UiText {
+                content: label.into(),
+                font_size: BUTTON_FONT_SIZE,
+                color: BUTTON_TEXT_COLOR,
+                align: TextAlign::Center,
+            },
+        ));
+    });
+    entity
+}
+
+/// Draws newly spawned buttons with the nine-sliced panel image, tinted by their colors.
+///
+/// Buttons stay solid colors if the panel image didn't load.
+#[instrument(skip_all)]
+pub fn skin_new_buttons_system(
+    // Input
+    images: Res<UiImages>,
+
+    // Output
+    mut query: Query<&mut UiBackground, Added<Button>>,
+) {
+    let Some(panel) = images.panel else {
+        return;
+    };
+
+    for mut background in query.iter_mut() {
+        *background = UiBackground::Image {
+            image: panel,
+            tint: background.color(),
+            uv_rect: UvRect::FULL,
+            nine_slice: Some(NineSlice::uniform(UI_PANEL_BORDER)),
+        };
+    }
+}
+
+/// Recolors buttons whose interaction state changed.
+#[instrument(skip_all)]
+pub fn update_button_background_system(
+    mut query: Query<(&Button, &Interaction, &mut UiBackground), Changed<Interaction>>,
+) {
+    for (button, interaction, mut background) in query.iter_mut() {
+        background.set_color(button.color(*interaction));
+    }
+}
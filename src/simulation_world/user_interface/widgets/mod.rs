@@ -0,0 +1,7 @@
+pub mod button;
+pub mod text_input;
+
+pub use button::{skin_new_buttons_system, spawn_button, update_button_background_system, Button};
+pub use text_input::{
+    text_input_system, update_text_input_display_system, TextInput, TextInputSubmitted,
+};
@@ -0,0 +1,136 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    input::messages::KeyboardInputMessage, user_interface::components::UiText,
+};
+use bevy_ecs::prelude::*;
+use winit::{
+    event::ElementState,
+    keyboard::{KeyCode, PhysicalKey},
+};
+
+/// The caret drawn into the text of a focused input.
+const CARET: char = '|';
+
+// INFO: ---------------------------
+//         Widget components
+// ---------------------------------
+
+/// A single line of editable text.
+///
+/// Spawn it next to a `UiText`, which gets rewritten to show the value. Only a focused
+/// input takes keystrokes; whoever spawns it is responsible for capturing input while
+/// it's focused.
+#[derive(Component, Debug, Default, Clone)]
+pub struct TextInput {
+    pub value: String,
+    /// The caret position as a char index into `value`.
+    pub cursor: usize,
+    pub focused: bool,
+}
+
+impl TextInput {
+    pub fn focused() -> Self {
+        Self {
+            focused: true,
+            ..Default::default()
+        }
+    }
+
+    /// Replaces the value and moves the caret to its end.
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = value.into();
+        self.cursor = self.value.chars().count();
+    }
+
+    /// The byte offset of the char at `index`.
+    fn byte_offset(&self, index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(index)
+            .map_or(self.value.len(), |(offset, _)| offset)
+    }
+}
+
+/// Sent when enter is pressed in a focused `TextInput`.
+#[derive(Message, Debug, Clone)]
+pub struct TextInputSubmitted {
+    pub entity: Entity,
+    pub value: String,
+}
+
+// INFO: -----------------
+//         Systems
+// -----------------------
+
+/// Edits focused text inputs from keyboard presses.
+#[instrument(skip_all)]
+pub fn text_input_system(
+    // Input
+    mut keyboard_messages: MessageReader<KeyboardInputMessage>,
+
+    // Output (edited inputs and submissions)
+    mut inputs: Query<(Entity, &mut TextInput)>,
+    mut submitted: MessageWriter<TextInputSubmitted>,
+) {
+    for message in keyboard_messages.read() {
+        if message.state != ElementState::Pressed {
+            continue;
+        }
+
+        for (entity, mut input) in inputs.iter_mut().filter(|(_, input)| input.focused) {
+            let len = input.value.chars().count();
+            match message.key_code {
+                PhysicalKey::Code(KeyCode::Enter | KeyCode::NumpadEnter) => {
+                    submitted.write(TextInputSubmitted {
+                        entity,
+                        value: input.value.clone(),
+                    });
+                }
+                PhysicalKey::Code(KeyCode::Backspace) if input.cursor > 0 => {
+                    input.cursor -= 1;
+                    let offset = input.byte_offset(input.cursor);
+                    input.value.remove(offset);
+                }
+                PhysicalKey::Code(KeyCode::Delete) if input.cursor < len => {
+                    let offset = input.byte_offset(input.cursor);
+                    input.value.remove(offset);
+                }
+                PhysicalKey::Code(KeyCode::ArrowLeft) => {
+                    input.cursor = input.cursor.saturating_sub(1);
+                }
+                PhysicalKey::Code(KeyCode::ArrowRight) => {
+                    input.cursor = (input.cursor + 1).min(len);
+                }
+                PhysicalKey::Code(KeyCode::Home) => input.cursor = 0,
+                PhysicalKey::Code(KeyCode::End) => input.cursor = len,
+                _ => {
+                    let Some(text) = &message.text else {
+                        continue;
+                    };
+                    let printable: String = text.chars().filter(|c| !c.is_control()).collect();
+                    if printable.is_empty() {
+                        continue;
+                    }
+
+                    let offset = input.byte_offset(input.cursor);
+                    input.value.insert_str(offset, &printable);
+                    input.cursor += printable.chars().count();
+                }
+            }
+        }
+    }
+}
+
+/// Shows the value of changed text inputs, with a caret when focused.
+#[instrument(skip_all)]
+pub fn update_text_input_display_system(
+    mut query: Query<(&TextInput, &mut UiText), Changed<TextInput>>,
+) {
+    for (input, mut text) in query.iter_mut() {
+        let mut content = input.value.clone();
+        if input.focused {
+            content.insert(input.byte_offset(input.cursor), CARET);
+        }
+        text.content = content;
+    }
+}
@@ -0,0 +1,67 @@
+use crate::prelude::*;
+use crate::simulation_world::block::BlockId;
+use bevy_ecs::prelude::*;
+
+/// A copied box of blocks, stored relative to its minimum corner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardRegion {
+    size: IVec3,
+    /// Blocks in x-major order, `None` for voxels that were not loaded when copied.
+    blocks: Vec<Option<BlockId>>,
+}
+
+impl ClipboardRegion {
+    /// Builds a clipboard by reading every voxel in a box of `size` blocks.
+    pub fn from_fn(size: IVec3, mut get_block: impl FnMut(IVec3) -> Option<BlockId>) -> Self {
+        let mut blocks = Vec::with_capacity((size.x * size.y * size.z) as usize);
+        for x in 0..size.x {
+            for y in 0..size.y {
+                for z in 0..size.z {
+                    blocks.push(get_block(IVec3::new(x, y, z)));
+                }
+            }
+        }
+        Self { size, blocks }
+    }
+
+    pub fn size(&self) -> IVec3 {
+        self.size
+    }
+
+    /// Gets the block at a position relative to the minimum corner.
+    pub fn get(&self, local_pos: IVec3) -> Option<BlockId> {
+        self.blocks[self.index(local_pos)]
+    }
+
+    /// Iterates every stored block along with its position relative to the minimum corner.
+    pub fn iter(&self) -> impl Iterator<Item = (IVec3, BlockId)> + '_ {
+        let size = self.size;
+        (0..size.x)
+            .flat_map(move |x| {
+                (0..size.y).flat_map(move |y| (0..size.z).map(move |z| IVec3::new(x, y, z)))
+            })
+            .filter_map(|pos| Some((pos, self.get(pos)?)))
+    }
+
+    /// Returns a copy rotated clockwise around the Y axis (seen from above)
+    /// by `quarter_turns` steps of 90 degrees.
+    pub fn rotated_y(&self, quarter_turns: i32) -> Self {
+        let mut rotated = self.clone();
+        for _ in 0..quarter_turns.rem_euclid(4) {
+            let size = rotated.size;
+            rotated = Self::from_fn(IVec3::new(size.z, size.y, size.x), |pos| {
+                // the new x axis runs along the old z axis, the new z along the flipped old x
+                rotated.get(IVec3::new(pos.z, pos.y, size.z - 1 - pos.x))
+            });
+        }
+        rotated
+    }
+
+    fn index(&self, local_pos: IVec3) -> usize {
+        ((local_pos.x * self.size.y + local_pos.y) * self.size.z + local_pos.z) as usize
+    }
+}
+
+/// The most recently copied region, if any.
+#[derive(Resource, Debug, Default)]
+pub struct WorldEditClipboard(pub Option<ClipboardRegion>);
@@ -0,0 +1,92 @@
+use crate::simulation_world::{
+    block::{BlockId, BlockRegistryResource},
+    console::{ConsoleArgs, ConsoleError, ConsoleResult},
+    world_edit::{
+        EditRegion, StructureFileEvent, StructureFormat, WorldEditClipboard, WorldEditEvent,
+        WorldEditOperation, WorldEditSelection, MAX_EDIT_VOLUME,
+    },
+};
+use bevy_ecs::prelude::*;
+use std::path::PathBuf;
+
+/// Looks up the block named by the argument at `index`.
+pub fn block_arg(world: &World, args: &ConsoleArgs, index: usize) -> Result<BlockId, ConsoleError> {
+    let name = args.str(index)?;
+    world
+        .resource::<BlockRegistryResource>()
+        .get_block_id_by_name(name)
+        .ok_or_else(|| ConsoleError::InvalidArgument(format!("Unknown block '{name}'")))
+}
+
+/// `/fill [<x1> <y1> <z1> <x2> <y2> <z2>] <block>`, fills the given corners or the
+/// current selection.
+pub fn fill_command(world: &mut World, args: &ConsoleArgs) -> ConsoleResult {
+    let (region, block) = match args.len() {
+        1 => {
+            let region = world
+                .resource::<WorldEditSelection>()
+                .region()
+                .ok_or_else(|| {
+                    ConsoleError::Failed("Select two corners or pass them in".to_string())
+                })?;
+            (region, block_arg(world, args, 0)?)
+        }
+        7 => (
+            EditRegion::from_corners(args.ivec3(0)?, args.ivec3(3)?),
+            block_arg(world, args, 6)?,
+        ),
+        _ => {
+            return Err(ConsoleError::InvalidArgument(
+                "Expected a block, optionally after two corners".to_string(),
+            ))
+        }
+    };
+
+    if region.volume() > MAX_EDIT_VOLUME {
+        return Err(ConsoleError::Failed(format!(
+            "{} blocks is more than the limit of {MAX_EDIT_VOLUME}",
+            region.volume()
+        )));
+    }
+
+    world.write_message(WorldEditEvent {
+        operation: WorldEditOperation::Fill { region, block },
+    });
+    Ok(format!(
+        "Filling {} blocks with {}",
+        region.volume(),
+        args.str(args.len() - 1)?
+    ))
+}
+
+/// `/structure <load|save> <path>`, moves the clipboard to or from a `.vox` or `.bschem` file.
+pub fn structure_command(world: &mut World, args: &ConsoleArgs) -> ConsoleResult {
+    let path = PathBuf::from(args.str(1)?);
+    StructureFormat::from_path(&path).map_err(|e| ConsoleError::InvalidArgument(e.to_string()))?;
+
+    let (event, output) = match args.str(0)? {
+        "load" => (
+            StructureFileEvent::LoadClipboard { path: path.clone() },
+            format!("Loading {} into the clipboard", path.display()),
+        ),
+        "save" => {
+            if world.resource::<WorldEditClipboard>().0.is_none() {
+                return Err(ConsoleError::Failed(
+                    "Nothing in the clipboard to save".to_string(),
+                ));
+            }
+            (
+                StructureFileEvent::SaveClipboard { path: path.clone() },
+                format!("Saving the clipboard to {}", path.display()),
+            )
+        }
+        other => {
+            return Err(ConsoleError::InvalidArgument(format!(
+                "Unknown subcommand '{other}'"
+            )))
+        }
+    };
+
+    world.write_message(event);
+    Ok(output)
+}
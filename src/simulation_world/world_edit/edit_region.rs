@@ -0,0 +1,47 @@
+use crate::prelude::*;
+
+/// An axis aligned box of voxels, inclusive on both ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EditRegion {
+    pub min: IVec3,
+    pub max: IVec3,
+}
+
+impl EditRegion {
+    /// Creates the region spanned by two opposite corners (in any order).
+    pub fn from_corners(a: IVec3, b: IVec3) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// The number of voxels along each axis.
+    pub fn size(&self) -> IVec3 {
+        self.max - self.min + IVec3::ONE
+    }
+
+    /// The total number of voxels in the region.
+    pub fn volume(&self) -> u64 {
+        let size = self.size().as_u64vec3();
+        size.x * size.y * size.z
+    }
+
+    /// Whether a position lies on the outer shell of the region.
+    pub fn is_on_shell(&self, pos: IVec3) -> bool {
+        self.is_on_walls(pos) || pos.y == self.min.y || pos.y == self.max.y
+    }
+
+    /// Whether a position lies on one of the 4 vertical sides of the region.
+    pub fn is_on_walls(&self, pos: IVec3) -> bool {
+        pos.x == self.min.x || pos.x == self.max.x || pos.z == self.min.z || pos.z == self.max.z
+    }
+
+    /// Iterates every position in the region.
+    pub fn iter(&self) -> impl Iterator<Item = IVec3> {
+        let (min, max) = (self.min, self.max);
+        (min.x..=max.x).flat_map(move |x| {
+            (min.y..=max.y).flat_map(move |y| (min.z..=max.z).map(move |z| IVec3::new(x, y, z)))
+        })
+    }
+}
@@ -0,0 +1,60 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    block::BlockRegistryResource,
+    world_edit::{load_structure, save_structure, VoxPaletteMapping, WorldEditClipboard},
+};
+use bevy_ecs::prelude::*;
+use std::path::PathBuf;
+
+/// Moves structures between the world edit clipboard and files on disk.
+///
+/// The format (`.vox` or `.bschem`) follows the file extension. Loaded structures
+/// are pasted like any other clipboard content.
+#[derive(Message, Clone, Debug, PartialEq, Eq)]
+pub enum StructureFileEvent {
+    /// Replaces the clipboard with the structure in a file.
+    LoadClipboard { path: PathBuf },
+    /// Writes the clipboard to a file.
+    SaveClipboard { path: PathBuf },
+}
+
+/// A system that handles every pending `StructureFileEvent`.
+#[instrument(skip_all)]
+pub fn handle_structure_file_events_system(
+    // Input
+    mut events: MessageReader<StructureFileEvent>,
+    block_registry: Res<BlockRegistryResource>,
+    mapping: Res<VoxPaletteMapping>,
+
+    // Output
+    mut clipboard: ResMut<WorldEditClipboard>,
+) {
+    for event in events.read() {
+        match event {
+            StructureFileEvent::LoadClipboard { path } => {
+                match load_structure(path, &mapping, &block_registry) {
+                    Ok(structure) => {
+                        info!(
+                            target: "world_edit",
+                            "Loaded {} block structure from {:?} into the clipboard",
+                            structure.size(),
+                            path
+                        );
+                        clipboard.0 = Some(structure);
+                    }
+                    Err(e) => error!(target: "world_edit", "Failed to load {:?}: {}", path, e),
+                }
+            }
+            StructureFileEvent::SaveClipboard { path } => {
+                let Some(structure) = clipboard.0.as_ref() else {
+                    warn!(target: "world_edit", "Nothing in the clipboard to save.");
+                    continue;
+                };
+                match save_structure(path, structure, &mapping, &block_registry) {
+                    Ok(()) => info!(target: "world_edit", "Saved clipboard to {:?}", path),
+                    Err(e) => error!(target: "world_edit", "Failed to save {:?}: {}", path, e),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+pub mod clipboard;
+pub mod commands;
+pub mod edit_region;
+pub mod file_operations;
+pub mod operations;
+pub mod selection;
+pub mod structure_files;
+
+pub use clipboard::{ClipboardRegion, WorldEditClipboard};
+pub use edit_region::EditRegion;
+pub use file_operations::{handle_structure_file_events_system, StructureFileEvent};
+pub use operations::{
+    apply_world_edit, handle_world_edit_events_system, WorldEditEvent, WorldEditOperation,
+    MAX_EDIT_VOLUME,
+};
+pub use selection::WorldEditSelection;
+pub use structure_files::*;
+
+// INFO: --------------------------
+//         World edit plugin
+// --------------------------------
+
+use crate::prelude::*;
+use crate::simulation_world::console::ConsoleCommand;
+use bevy_ecs::message::Messages;
+use bevy_ecs::prelude::*;
+
+pub struct WorldEditPlugin;
+
+impl Plugin for WorldEditPlugin {
+    fn build(&self, builder: &mut EcsBuilder) {
+        builder
+            .init_resource::<WorldEditSelection>()
+            .init_resource::<WorldEditClipboard>()
+            .init_resource::<VoxPaletteMapping>()
+            .init_resource::<Messages<WorldEditEvent>>()
+            .init_resource::<Messages<StructureFileEvent>>()
+            .add_console_command(ConsoleCommand::new(
+                "fill",
+                "[<x1> <y1> <z1> <x2> <y2> <z2>] <block>",
+                "Fills the selection or the given corners with a block",
+                commands::fill_command,
+            ))
+            .add_console_command(ConsoleCommand::new(
+                "structure",
+                "<load|save> <path>",
+                "Loads a .vox or .bschem file into the clipboard, or saves the clipboard to one",
+                commands::structure_command,
+            ))
+            .schedule_entry(SimulationSchedule::Main)
+            .add_systems(
+                (
+                    handle_structure_file_events_system,
+                    handle_world_edit_events_system,
+                )
+                    .chain()
+                    .in_set(SimulationSet::Update),
+            );
+    }
+}
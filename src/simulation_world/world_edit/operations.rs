@@ -0,0 +1,173 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    block::{BlockId, AIR_BLOCK_ID},
+    chunk::{VoxelBatch, WorldVoxels},
+    edit_history::{EditJournal, EditTransaction},
+    world_edit::{ClipboardRegion, EditRegion, WorldEditClipboard},
+};
+use bevy_ecs::prelude::*;
+
+/// Regions bigger than this many voxels are refused to avoid stalling the frame.
+pub const MAX_EDIT_VOLUME: u64 = 1 << 21;
+
+/// A bulk edit to apply to the world.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorldEditOperation {
+    /// Sets every voxel in the region to `block`.
+    Fill { region: EditRegion, block: BlockId },
+    /// Swaps every `from` block in the region for `to`.
+    Replace {
+        region: EditRegion,
+        from: BlockId,
+        to: BlockId,
+    },
+    /// Builds a hollow box, `block` on the outer shell and air inside.
+    Hollow { region: EditRegion, block: BlockId },
+    /// Sets the 4 vertical sides of the region to `block`, leaving the rest untouched.
+    Walls { region: EditRegion, block: BlockId },
+    /// Copies the region into the clipboard.
+    Copy { region: EditRegion },
+    /// Rotates the clipboard around the Y axis by 90 degree steps.
+    RotateClipboard { quarter_turns: i32 },
+    /// Writes the clipboard with its minimum corner at `origin`.
+    Paste { origin: IVec3, skip_air: bool },
+}
+
+/// An event that is sent when a world edit operation should be applied.
+#[derive(Message, Clone, Debug)]
+pub struct WorldEditEvent {
+    pub operation: WorldEditOperation,
+}
+
+/// A system that applies every pending `WorldEditEvent`.
+#[instrument(skip_all)]
+pub fn handle_world_edit_events_system(
+    // Input
+    mut events: MessageReader<WorldEditEvent>,
+
+    // Output
+    mut voxels: WorldVoxels,
+    mut clipboard: ResMut<WorldEditClipboard>,
+    mut journal: ResMut<EditJournal>,
+) {
+    for event in events.read() {
+        let mut transaction = EditTransaction::new();
+        apply_world_edit(
+            &event.operation,
+            &mut voxels,
+            &mut clipboard,
+            &mut transaction,
+        );
+        journal.commit(transaction);
+    }
+}
+
+/// Applies a single world edit operation.
+///
+/// Voxel writes are batched, so each touched chunk is only re-meshed once. Every
+/// change is recorded into `transaction` so the whole operation can be undone.
+pub fn apply_world_edit(
+    operation: &WorldEditOperation,
+    voxels: &mut WorldVoxels,
+    clipboard: &mut WorldEditClipboard,
+    transaction: &mut EditTransaction,
+) {
+    match *operation {
+        WorldEditOperation::Fill { region, block } => {
+            edit_region(region, voxels, transaction, |_, _| Some(block));
+        }
+        WorldEditOperation::Replace { region, from, to } => {
+            edit_region(region, voxels, transaction, |_, current| {
+                (current == from).then_some(to)
+            });
+        }
+        WorldEditOperation::Hollow { region, block } => {
+            edit_region(region, voxels, transaction, |pos, _| {
+                Some(if region.is_on_shell(pos) {
+                    block
+                } else {
+                    AIR_BLOCK_ID
+                })
+            });
+        }
+        WorldEditOperation::Walls { region, block } => {
+            edit_region(region, voxels, transaction, |pos, _| {
+                region.is_on_walls(pos).then_some(block)
+            });
+        }
+        WorldEditOperation::Copy { region } => {
+            if !check_volume(region.volume()) {
+                return;
+            }
+
+            let copied = ClipboardRegion::from_fn(region.size(), |local_pos| {
+                voxels.get_block(region.min + local_pos)
+            });
+            info!(target: "world_edit", "Copied {} block region to clipboard", region.size());
+            clipboard.0 = Some(copied);
+        }
+        WorldEditOperation::RotateClipboard { quarter_turns } => match clipboard.0.as_mut() {
+            Some(copied) => *copied = copied.rotated_y(quarter_turns),
+            None => warn!(target: "world_edit", "Nothing in the clipboard to rotate."),
+        },
+        WorldEditOperation::Paste { origin, skip_air } => {
+            let Some(copied) = clipboard.0.as_ref() else {
+                warn!(target: "world_edit", "Nothing in the clipboard to paste.");
+                return;
+            };
+
+            let mut batch = voxels.batch();
+            for (local_pos, block) in copied.iter() {
+                if skip_air && block == AIR_BLOCK_ID {
+                    continue;
+                }
+                let pos = origin + local_pos;
+                if let Some(old_block) = batch.set_block(pos, block) {
+                    transaction.record(pos, old_block, block);
+                }
+            }
+            finish_batch("Paste", batch);
+        }
+    }
+}
+
+/// Runs `edit` for every loaded voxel in the region, writing the block it returns (if any).
+fn edit_region(
+    region: EditRegion,
+    voxels: &mut WorldVoxels,
+    transaction: &mut EditTransaction,
+    mut edit: impl FnMut(IVec3, BlockId) -> Option<BlockId>,
+) {
+    if !check_volume(region.volume()) {
+        return;
+    }
+
+    let mut batch = voxels.batch();
+    for pos in region.iter() {
+        let Some(current) = batch.get_block(pos) else {
+            continue;
+        };
+        if let Some(new_block) = edit(pos, current) {
+            if let Some(old_block) = batch.set_block(pos, new_block) {
+                transaction.record(pos, old_block, new_block);
+            }
+        }
+    }
+    finish_batch("Region edit", batch);
+}
+
+fn finish_batch(name: &str, batch: VoxelBatch) {
+    let changed = batch.finish();
+    info!(target: "world_edit", "{} changed {} blocks", name, changed);
+}
+
+fn check_volume(volume: u64) -> bool {
+    if volume > MAX_EDIT_VOLUME {
+        warn!(
+            target: "world_edit",
+            "Refusing to edit {} voxels (max is {}).", volume, MAX_EDIT_VOLUME
+        );
+        return false;
+    }
+    true
+}
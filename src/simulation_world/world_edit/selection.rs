@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use crate::simulation_world::world_edit::EditRegion;
+use bevy_ecs::prelude::*;
+
+/// The two corners of the current world edit selection.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct WorldEditSelection {
+    pub first_corner: Option<IVec3>,
+    pub second_corner: Option<IVec3>,
+}
+
+impl WorldEditSelection {
+    /// The selected region, once both corners are set.
+    pub fn region(&self) -> Option<EditRegion> {
+        Some(EditRegion::from_corners(
+            self.first_corner?,
+            self.second_corner?,
+        ))
+    }
+}
@@ -0,0 +1,32 @@
+use std::{fmt, io::Error};
+
+#[derive(Debug)]
+pub enum StructureFileError {
+    IoError(Error),
+    /// The file is not a valid structure file of its format.
+    InvalidFormat(String),
+    /// The structure can't be stored in the requested format.
+    Unsupported(String),
+}
+
+impl From<Error> for StructureFileError {
+    fn from(err: Error) -> Self {
+        StructureFileError::IoError(err)
+    }
+}
+
+impl fmt::Display for StructureFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StructureFileError::IoError(err) => write!(f, "Structure file IO error: {err}"),
+            StructureFileError::InvalidFormat(what) => {
+                write!(f, "Invalid structure file: {what}")
+            }
+            StructureFileError::Unsupported(what) => {
+                write!(f, "Unsupported structure: {what}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StructureFileError {}
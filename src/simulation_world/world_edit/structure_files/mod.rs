@@ -0,0 +1,68 @@
+pub mod error;
+pub mod schematic;
+pub mod vox;
+pub mod vox_palette;
+
+pub use error::StructureFileError;
+pub use schematic::{read_schematic, write_schematic};
+pub use vox::{export_vox, import_vox};
+pub use vox_palette::{VoxPaletteEntry, VoxPaletteMapping, VOX_PALETTE_PATH};
+
+use crate::simulation_world::{block::BlockRegistryResource, world_edit::ClipboardRegion};
+use std::{fs, path::Path};
+
+/// File extension of the native schematic format.
+pub const SCHEMATIC_EXTENSION: &str = "bschem";
+
+/// The structure file formats, picked by file extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructureFormat {
+    /// MagicaVoxel `.vox`.
+    Vox,
+    /// The native `.bschem` schematic format.
+    Schematic,
+}
+
+impl StructureFormat {
+    pub fn from_path(path: &Path) -> Result<Self, StructureFileError> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("vox") => Ok(StructureFormat::Vox),
+            Some(SCHEMATIC_EXTENSION) => Ok(StructureFormat::Schematic),
+            _ => Err(StructureFileError::Unsupported(format!(
+                "{path:?} is neither a .vox nor a .{SCHEMATIC_EXTENSION} file"
+            ))),
+        }
+    }
+}
+
+/// Loads a structure file in either format.
+pub fn load_structure(
+    path: &Path,
+    mapping: &VoxPaletteMapping,
+    block_registry: &BlockRegistryResource,
+) -> Result<ClipboardRegion, StructureFileError> {
+    let format = StructureFormat::from_path(path)?;
+    let bytes = fs::read(path)?;
+    match format {
+        StructureFormat::Vox => import_vox(&bytes, mapping, block_registry),
+        StructureFormat::Schematic => read_schematic(&bytes, block_registry),
+    }
+}
+
+/// Saves a structure in the format matching the path's extension.
+pub fn save_structure(
+    path: &Path,
+    region: &ClipboardRegion,
+    mapping: &VoxPaletteMapping,
+    block_registry: &BlockRegistryResource,
+) -> Result<(), StructureFileError> {
+    let bytes = match StructureFormat::from_path(path)? {
+        StructureFormat::Vox => export_vox(region, mapping, block_registry)?,
+        StructureFormat::Schematic => write_schematic(region, block_registry)?,
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, bytes)?;
+    Ok(())
+}
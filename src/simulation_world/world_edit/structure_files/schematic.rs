@@ -0,0 +1,165 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    block::{BlockId, BlockRegistryResource, AIR_BLOCK_ID},
+    world_edit::{ClipboardRegion, StructureFileError, MAX_EDIT_VOLUME},
+};
+use std::collections::HashMap;
+
+const SCHEMATIC_MAGIC: &[u8; 4] = b"BSCH";
+const SCHEMATIC_VERSION: u16 = 1;
+/// Palette index of voxels that weren't loaded when the structure was copied.
+const UNSET_INDEX: u16 = u16::MAX;
+
+/// Writes a clipboard region in the native schematic format.
+///
+/// Layout: magic `BSCH`, version `u16`, size as 3 `u32`s, a palette of block names
+/// (`u16` count, then `u8` length + UTF-8 per name) and the voxels in clipboard order
+/// as `(run: u16, palette index: u16)` pairs. Everything is little endian.
+pub fn write_schematic(
+    region: &ClipboardRegion,
+    block_registry: &BlockRegistryResource,
+) -> Result<Vec<u8>, StructureFileError> {
+    let names = block_registry.block_names();
+    let size = region.size();
+
+    let mut palette: Vec<&str> = Vec::new();
+    let mut palette_index: HashMap<BlockId, u16> = HashMap::new();
+    let mut runs: Vec<(u16, u16)> = Vec::new();
+
+    for x in 0..size.x {
+        for y in 0..size.y {
+            for z in 0..size.z {
+                let index = match region.get(IVec3::new(x, y, z)) {
+                    Some(block) => *palette_index.entry(block).or_insert_with(|| {
+                        palette.push(&names[block as usize]);
+                        (palette.len() - 1) as u16
+                    }),
+                    None => UNSET_INDEX,
+                };
+
+                match runs.last_mut() {
+                    Some((run, last)) if *last == index && *run < u16::MAX => *run += 1,
+                    _ => runs.push((1, index)),
+                }
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    out.extend_from_slice(SCHEMATIC_MAGIC);
+    out.extend(SCHEMATIC_VERSION.to_le_bytes());
+    for axis in size.to_array() {
+        out.extend((axis as u32).to_le_bytes());
+    }
+
+    out.extend((palette.len() as u16).to_le_bytes());
+    for name in palette {
+        let len = u8::try_from(name.len()).map_err(|_| {
+            StructureFileError::Unsupported(format!("block name '{name}' is too long"))
+        })?;
+        out.push(len);
+        out.extend_from_slice(name.as_bytes());
+    }
+
+    for (run, index) in runs {
+        out.extend(run.to_le_bytes());
+        out.extend(index.to_le_bytes());
+    }
+    Ok(out)
+}
+
+/// Reads a structure written by `write_schematic`.
+///
+/// Blocks that are no longer registered are loaded as air.
+pub fn read_schematic(
+    bytes: &[u8],
+    block_registry: &BlockRegistryResource,
+) -> Result<ClipboardRegion, StructureFileError> {
+    let invalid = |what: String| StructureFileError::InvalidFormat(format!("schematic {what}"));
+    let mut reader = SchematicReader { bytes, pos: 0 };
+
+    if reader.take(4)? != SCHEMATIC_MAGIC {
+        return Err(invalid("has no 'BSCH' header".to_string()));
+    }
+    let version = reader.read_u16()?;
+    if version != SCHEMATIC_VERSION {
+        return Err(invalid(format!("version {version} is not supported")));
+    }
+
+    let size = IVec3::new(
+        reader.read_u32()? as i32,
+        reader.read_u32()? as i32,
+        reader.read_u32()? as i32,
+    );
+    if size.min_element() < 0 {
+        return Err(invalid(format!("has an invalid size {size}")));
+    }
+    let volume = size.as_u64vec3().element_product();
+    if volume > MAX_EDIT_VOLUME {
+        return Err(StructureFileError::Unsupported(format!(
+            "{volume} voxels is more than the {MAX_EDIT_VOLUME} voxel edit limit"
+        )));
+    }
+    let volume = volume as usize;
+
+    let palette_len = reader.read_u16()? as usize;
+    let mut palette = Vec::with_capacity(palette_len);
+    for _ in 0..palette_len {
+        let len = reader.take(1)?[0] as usize;
+        let name = std::str::from_utf8(reader.take(len)?)
+            .map_err(|_| invalid("has a block name that isn't UTF-8".to_string()))?;
+        palette.push(block_registry.get_block_id_by_name(name).unwrap_or_else(|| {
+            warn!(target: "world_edit", "Schematic block '{}' is not registered, loading it as air.", name);
+            AIR_BLOCK_ID
+        }));
+    }
+
+    let mut blocks: Vec<Option<BlockId>> = Vec::with_capacity(volume);
+    while blocks.len() < volume {
+        let run = reader.read_u16()? as usize;
+        let index = reader.read_u16()?;
+        let block = match index {
+            UNSET_INDEX => None,
+            index => Some(
+                *palette
+                    .get(index as usize)
+                    .ok_or_else(|| invalid(format!("palette index {index} is out of range")))?,
+            ),
+        };
+        if run == 0 || blocks.len() + run > volume {
+            return Err(invalid(
+                "has a run past the end of the structure".to_string(),
+            ));
+        }
+        blocks.resize(blocks.len() + run, block);
+    }
+
+    Ok(ClipboardRegion::from_fn(size, |pos| {
+        blocks[((pos.x * size.y + pos.y) * size.z + pos.z) as usize]
+    }))
+}
+
+struct SchematicReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> SchematicReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], StructureFileError> {
+        let slice = self.bytes.get(self.pos..self.pos + len).ok_or_else(|| {
+            StructureFileError::InvalidFormat("schematic file ended early".to_string())
+        })?;
+        self.pos += len;
+        Ok(slice)
+    }
+
+    fn read_u16(&mut self) -> Result<u16, StructureFileError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u32(&mut self) -> Result<u32, StructureFileError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
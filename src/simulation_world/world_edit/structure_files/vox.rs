@@ -0,0 +1,237 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    block::{BlockId, BlockRegistryResource, AIR_BLOCK_ID},
+    world_edit::{ClipboardRegion, StructureFileError, VoxPaletteMapping},
+};
+use std::collections::BTreeSet;
+
+const VOX_MAGIC: &[u8; 4] = b"VOX ";
+const VOX_VERSION: i32 = 150;
+/// MagicaVoxel models can't be larger than this along any axis.
+const MAX_VOX_SIZE: i32 = 256;
+
+// INFO: -------------------
+//         importing
+// -------------------------
+
+/// Reads the first model of a MagicaVoxel `.vox` file into a clipboard region.
+///
+/// MagicaVoxel is Z-up, so its axes are converted to Y-up (keeping handedness).
+/// Empty voxels become air and palette indices are mapped to blocks with `mapping`.
+pub fn import_vox(
+    bytes: &[u8],
+    mapping: &VoxPaletteMapping,
+    block_registry: &BlockRegistryResource,
+) -> Result<ClipboardRegion, StructureFileError> {
+    let invalid = |what: &str| StructureFileError::InvalidFormat(format!("vox {what}"));
+
+    let mut reader = VoxReader { bytes, pos: 0 };
+    if reader.take(4)? != VOX_MAGIC {
+        return Err(invalid("file has no 'VOX ' header"));
+    }
+    let _version = reader.read_i32()?;
+
+    let main = reader.read_chunk()?;
+    if main.id != b"MAIN" || !main.content.is_empty() {
+        return Err(invalid("file has no MAIN chunk"));
+    }
+
+    let mut size = None;
+    let mut voxels = None;
+    let mut model_count = 0;
+
+    let mut children = VoxReader {
+        bytes: main.children,
+        pos: 0,
+    };
+    while children.pos < children.bytes.len() {
+        let chunk = children.read_chunk()?;
+        let mut content = VoxReader {
+            bytes: chunk.content,
+            pos: 0,
+        };
+        match chunk.id {
+            b"SIZE" => {
+                model_count += 1;
+                if size.is_none() {
+                    size = Some(IVec3::new(
+                        content.read_i32()?,
+                        content.read_i32()?,
+                        content.read_i32()?,
+                    ));
+                }
+            }
+            b"XYZI" if voxels.is_none() => {
+                let count = content.read_i32()?.max(0) as usize;
+                let data = content.take(count * 4)?;
+                voxels = Some(
+                    data.chunks_exact(4)
+                        .map(|v| [v[0], v[1], v[2], v[3]])
+                        .collect::<Vec<_>>(),
+                );
+            }
+            // colors only matter when exporting, transforms and materials are ignored
+            _ => {}
+        }
+    }
+
+    let (Some(vox_size), Some(voxels)) = (size, voxels) else {
+        return Err(invalid("file has no model"));
+    };
+    if model_count > 1 {
+        warn!(target: "world_edit", "Vox file has {} models, only importing the first.", model_count);
+    }
+    if vox_size.min_element() <= 0 || vox_size.max_element() > MAX_VOX_SIZE {
+        return Err(invalid(&format!("model has an invalid size {vox_size}")));
+    }
+
+    let (lookup, unmapped) = mapping.resolve_indices(block_registry);
+
+    // Z-up (x, y, z) -> Y-up (x, z, size_y - 1 - y)
+    let size = IVec3::new(vox_size.x, vox_size.z, vox_size.y);
+    let mut blocks = vec![AIR_BLOCK_ID; (size.x * size.y * size.z) as usize];
+    let mut used_unmapped = BTreeSet::new();
+
+    for [x, y, z, color_index] in voxels {
+        let pos = IVec3::new(x as i32, z as i32, vox_size.y - 1 - y as i32);
+        if pos.cmplt(IVec3::ZERO).any() || pos.cmpge(size).any() {
+            continue;
+        }
+        if unmapped.contains(&color_index) {
+            used_unmapped.insert(color_index);
+        }
+        blocks[((pos.x * size.y + pos.y) * size.z + pos.z) as usize] = lookup[color_index as usize];
+    }
+
+    if !used_unmapped.is_empty() {
+        warn!(
+            target: "world_edit",
+            "Vox palette indices {:?} have no block mapping, using the fallback block.", used_unmapped
+        );
+    }
+
+    Ok(ClipboardRegion::from_fn(size, |pos| {
+        Some(blocks[((pos.x * size.y + pos.y) * size.z + pos.z) as usize])
+    }))
+}
+
+// INFO: -------------------
+//         exporting
+// -------------------------
+
+/// Writes a clipboard region as a single model MagicaVoxel `.vox` file.
+///
+/// Air and voxels that weren't loaded when copied are left empty.
+pub fn export_vox(
+    region: &ClipboardRegion,
+    mapping: &VoxPaletteMapping,
+    block_registry: &BlockRegistryResource,
+) -> Result<Vec<u8>, StructureFileError> {
+    let size = region.size();
+    if size.max_element() > MAX_VOX_SIZE {
+        return Err(StructureFileError::Unsupported(format!(
+            "{size} is larger than the {MAX_VOX_SIZE} voxel vox limit"
+        )));
+    }
+
+    let solid: Vec<(IVec3, BlockId)> = region
+        .iter()
+        .filter(|&(_, block)| block != AIR_BLOCK_ID)
+        .collect();
+
+    let assigned = mapping.assign_indices(solid.iter().map(|&(_, block)| block), block_registry);
+    let mut palette = [[0u8; 4]; 256];
+    for &(index, [r, g, b]) in assigned.values().flatten() {
+        palette[index as usize - 1] = [r, g, b, 255];
+    }
+
+    let mut xyzi = Vec::with_capacity(4 + solid.len() * 4);
+    xyzi.extend(0i32.to_le_bytes());
+    let mut count = 0i32;
+    let mut skipped = 0;
+    for (pos, block) in solid {
+        let Some((index, _)) = assigned[&block] else {
+            skipped += 1;
+            continue;
+        };
+        // Y-up (x, y, z) -> Z-up (x, size_z - 1 - z, y)
+        xyzi.extend([pos.x as u8, (size.z - 1 - pos.z) as u8, pos.y as u8, index]);
+        count += 1;
+    }
+    xyzi[0..4].copy_from_slice(&count.to_le_bytes());
+    if skipped > 0 {
+        warn!(
+            target: "world_edit",
+            "Skipped {} voxels whose blocks didn't fit in the vox palette.", skipped
+        );
+    }
+
+    let mut size_content = Vec::with_capacity(12);
+    for axis in [size.x, size.z, size.y] {
+        size_content.extend(axis.to_le_bytes());
+    }
+
+    let mut children = Vec::new();
+    write_chunk(&mut children, b"SIZE", &size_content);
+    write_chunk(&mut children, b"XYZI", &xyzi);
+    write_chunk(&mut children, b"RGBA", palette.as_flattened());
+
+    let mut out = Vec::with_capacity(8 + 12 + children.len());
+    out.extend_from_slice(VOX_MAGIC);
+    out.extend(VOX_VERSION.to_le_bytes());
+    out.extend_from_slice(b"MAIN");
+    out.extend(0i32.to_le_bytes());
+    out.extend((children.len() as i32).to_le_bytes());
+    out.extend(children);
+    Ok(out)
+}
+
+fn write_chunk(out: &mut Vec<u8>, id: &[u8; 4], content: &[u8]) {
+    out.extend_from_slice(id);
+    out.extend((content.len() as i32).to_le_bytes());
+    out.extend(0i32.to_le_bytes());
+    out.extend_from_slice(content);
+}
+
+// INFO: ---------------
+//         helpers
+// ---------------------
+
+struct VoxChunk<'a> {
+    id: &'a [u8],
+    content: &'a [u8],
+    children: &'a [u8],
+}
+
+struct VoxReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> VoxReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], StructureFileError> {
+        let slice = self
+            .bytes
+            .get(self.pos..self.pos + len)
+            .ok_or_else(|| StructureFileError::InvalidFormat("vox file ended early".to_string()))?;
+        self.pos += len;
+        Ok(slice)
+    }
+
+    fn read_i32(&mut self) -> Result<i32, StructureFileError> {
+        let bytes = self.take(4)?;
+        Ok(i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Reads a chunk along with its content and children.
+    fn read_chunk(&mut self) -> Result<VoxChunk<'a>, StructureFileError> {
+        let id = self.take(4)?;
+        let content_len = self.read_i32()?.max(0) as usize;
+        let children_len = self.read_i32()?.max(0) as usize;
+        Ok(VoxChunk {
+            id,
+            content: self.take(content_len)?,
+            children: self.take(children_len)?,
+        })
+    }
+}
@@ -0,0 +1,134 @@
+use crate::prelude::*;
+use crate::simulation_world::block::{BlockId, BlockRegistryResource, SOLID_BLOCK_ID};
+use bevy_ecs::prelude::*;
+use serde::Deserialize;
+use std::{collections::HashMap, fs};
+
+/// Where the palette mapping is loaded from.
+pub const VOX_PALETTE_PATH: &str = "assets/vox_palette.ron";
+
+/// Color written for blocks that have no palette entry.
+const UNMAPPED_COLOR: [u8; 3] = [128, 128, 128];
+
+/// One MagicaVoxel palette slot and the block it stands for.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct VoxPaletteEntry {
+    /// The palette index (1 to 255) as shown in MagicaVoxel.
+    pub index: u8,
+    /// The block name, as in `assets/blocks`.
+    pub block: String,
+    /// The color written to exported files.
+    #[serde(default)]
+    pub color: Option<[u8; 3]>,
+}
+
+/// Maps MagicaVoxel palette indices to blocks, loaded from `assets/vox_palette.ron`.
+#[derive(Resource, Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct VoxPaletteMapping {
+    pub palette: Vec<VoxPaletteEntry>,
+    /// The block used for palette indices without an entry, stone if not set.
+    #[serde(default)]
+    pub fallback: Option<String>,
+}
+
+impl VoxPaletteMapping {
+    /// A mapping without entries, every palette index imports as stone.
+    pub fn empty() -> Self {
+        Self {
+            palette: Vec::new(),
+            fallback: None,
+        }
+    }
+
+    pub fn from_ron_str(ron_string: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(ron_string)
+    }
+
+    /// Resolves every palette index (0 to 255) to a block ID.
+    ///
+    /// Returns the lookup table along with the indices that fell back, for reporting.
+    pub fn resolve_indices(
+        &self,
+        block_registry: &BlockRegistryResource,
+    ) -> ([BlockId; 256], Vec<u8>) {
+        let fallback = self
+            .fallback
+            .as_deref()
+            .and_then(|name| block_registry.get_block_id_by_name(name))
+            .unwrap_or(SOLID_BLOCK_ID);
+
+        let mut lookup = [fallback; 256];
+        let mut mapped = [false; 256];
+        for entry in &self.palette {
+            match block_registry.get_block_id_by_name(&entry.block) {
+                Some(block_id) => {
+                    lookup[entry.index as usize] = block_id;
+                    mapped[entry.index as usize] = true;
+                }
+                None => warn!(
+                    target: "world_edit",
+                    "Vox palette index {} maps to unknown block '{}'.", entry.index, entry.block
+                ),
+            }
+        }
+
+        let unmapped = (1..=255u8).filter(|&i| !mapped[i as usize]).collect();
+        (lookup, unmapped)
+    }
+
+    /// Assigns a palette index and color to every block in `blocks`.
+    ///
+    /// Blocks with an entry keep their index, the rest take the lowest free indices.
+    /// Returns `None` for blocks that didn't fit in the 255 slots.
+    pub fn assign_indices(
+        &self,
+        blocks: impl IntoIterator<Item = BlockId>,
+        block_registry: &BlockRegistryResource,
+    ) -> HashMap<BlockId, Option<(u8, [u8; 3])>> {
+        let mut used = [false; 256];
+        used[0] = true;
+
+        let mut by_block: HashMap<BlockId, (u8, [u8; 3])> = HashMap::new();
+        for entry in &self.palette {
+            let Some(block_id) = block_registry.get_block_id_by_name(&entry.block) else {
+                continue;
+            };
+            used[entry.index as usize] = true;
+            by_block
+                .entry(block_id)
+                .or_insert((entry.index, entry.color.unwrap_or(UNMAPPED_COLOR)));
+        }
+
+        let mut free = (1..=255u8).filter(|&i| !used[i as usize]);
+        let mut assigned = HashMap::new();
+        for block_id in blocks {
+            assigned.entry(block_id).or_insert_with(|| {
+                by_block
+                    .get(&block_id)
+                    .copied()
+                    .or_else(|| free.next().map(|index| (index, UNMAPPED_COLOR)))
+            });
+        }
+        assigned
+    }
+}
+
+impl FromWorld for VoxPaletteMapping {
+    fn from_world(_world: &mut World) -> Self {
+        let ron_string = match fs::read_to_string(VOX_PALETTE_PATH) {
+            Ok(ron_string) => ron_string,
+            Err(e) => {
+                warn!(
+                    "Failed to read {}: {}. Vox files will import as stone.",
+                    VOX_PALETTE_PATH, e
+                );
+                return Self::empty();
+            }
+        };
+
+        Self::from_ron_str(&ron_string).unwrap_or_else(|e| {
+            error!("Failed to parse {}: {}", VOX_PALETTE_PATH, e);
+            Self::empty()
+        })
+    }
+}
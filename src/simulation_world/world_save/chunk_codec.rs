@@ -0,0 +1,220 @@
+use crate::simulation_world::{
+    chunk::{
+        ChunkBlocksComponent, ChunkLod, ChunkView, ChunkVolumeData, GeneratedChunkComponentBundle,
+        CHUNK_SIDE_LENGTH,
+    },
+    terrain::BiomeMapComponent,
+    world_save::{RegionSlot, WorldSaveError},
+};
+
+/// Marks a volume stored as a single value.
+const TAG_UNIFORM: u8 = 0;
+/// Marks a volume stored as run-length encoded values.
+const TAG_RUN_LENGTH: u8 = 1;
+
+/// The generated data of a single saved chunk.
+#[derive(Clone)]
+pub enum SavedChunk {
+    /// The chunk was generated but is all air, so no data is stored.
+    Empty,
+    Generated {
+        chunk_blocks: ChunkBlocksComponent,
+        biome_map: BiomeMapComponent,
+    },
+}
+
+impl SavedChunk {
+    /// Converts the saved chunk into the bundle the chunk loader expects from generation.
+    pub fn into_bundle(self, lod: ChunkLod) -> GeneratedChunkComponentBundle {
+        match self {
+            SavedChunk::Empty => GeneratedChunkComponentBundle {
+                chunk_blocks: None,
+                chunk_metadata: None,
+                biome_map: BiomeMapComponent::new_empty(lod),
+            },
+            SavedChunk::Generated {
+                chunk_blocks,
+                biome_map,
+            } => GeneratedChunkComponentBundle {
+                chunk_blocks: Some(chunk_blocks),
+                chunk_metadata: None,
+                biome_map,
+            },
+        }
+    }
+}
+
+// INFO: ----------------
+//         encoding
+// ----------------------
+
+/// Encodes a chunk's blocks and biomes into a compact byte payload.
+///
+/// Layout: `lod: u8`, then the block volume, then the biome volume. Each volume is
+/// either `TAG_UNIFORM, value` or `TAG_RUN_LENGTH` followed by `(run: u16 LE, value)`
+/// pairs in memory order.
+pub fn encode_chunk(chunk_blocks: &ChunkBlocksComponent, biome_map: &BiomeMapComponent) -> Vec<u8> {
+    let lod = chunk_blocks.lod();
+    let mut out = vec![*lod];
+
+    match chunk_blocks.get_view() {
+        ChunkView::Uniform(block_id) => out.extend([TAG_UNIFORM, block_id]),
+        ChunkView::Dense(view) => {
+            let size = chunk_blocks.size();
+            write_volume(
+                &mut out,
+                volume_values(size, |x, y, z| view.get_data(x, y, z)),
+            );
+        }
+    }
+
+    let size = biome_map.size();
+    write_volume(
+        &mut out,
+        volume_values(size, |x, y, z| biome_map.get_data_unchecked(x, y, z)),
+    );
+
+    out
+}
+
+/// Encodes the result of `TerrainPipeline::generate_chunk` into a region slot.
+pub fn encode_generated(generated: Option<&GeneratedChunkComponentBundle>) -> RegionSlot {
+    match generated {
+        Some(GeneratedChunkComponentBundle {
+            chunk_blocks: Some(chunk_blocks),
+            biome_map,
+            ..
+        }) => RegionSlot::Data(encode_chunk(chunk_blocks, biome_map)),
+        _ => RegionSlot::Empty,
+    }
+}
+
+/// Decodes a payload written by `encode_chunk`.
+///
+/// `remap_block` and `remap_biome` translate the IDs stored in the save to the IDs
+/// of the running registries.
+pub fn decode_chunk(
+    bytes: &[u8],
+    remap_block: impl Fn(u8) -> u8,
+    remap_biome: impl Fn(u8) -> u8,
+) -> Result<SavedChunk, WorldSaveError> {
+    let mut reader = ByteReader { bytes, pos: 0 };
+
+    let lod = ChunkLod(reader.read_u8()?);
+    if *lod as u32 > CHUNK_SIDE_LENGTH.trailing_zeros() {
+        return Err(WorldSaveError::CorruptData(format!(
+            "invalid chunk lod {lod}"
+        )));
+    }
+    let volume_len = lod.sidelength_pow3();
+
+    let chunk_blocks = match read_volume(&mut reader, volume_len)? {
+        Volume::Uniform(block_id) => ChunkBlocksComponent::new_uniform(lod, remap_block(block_id)),
+        Volume::Dense(values) => {
+            ChunkBlocksComponent::from_vec(lod, values.into_iter().map(&remap_block).collect())
+        }
+    };
+
+    let biome_map = match read_volume(&mut reader, volume_len)? {
+        Volume::Uniform(biome_id) => {
+            BiomeMapComponent(ChunkVolumeData::new_filled(lod, remap_biome(biome_id)))
+        }
+        Volume::Dense(values) => BiomeMapComponent(ChunkVolumeData::from_vec(
+            lod,
+            values.into_iter().map(&remap_biome).collect(),
+        )),
+    };
+
+    Ok(SavedChunk::Generated {
+        chunk_blocks,
+        biome_map,
+    })
+}
+
+// INFO: ---------------
+//         helpers
+// ---------------------
+
+/// Collects every value of a volume in memory order (x, then z, then y).
+fn volume_values(size: usize, get: impl Fn(usize, usize, usize) -> u8) -> Vec<u8> {
+    let mut values = Vec::with_capacity(size * size * size);
+    for x in 0..size {
+        for z in 0..size {
+            for y in 0..size {
+                values.push(get(x, y, z));
+            }
+        }
+    }
+    values
+}
+
+fn write_volume(out: &mut Vec<u8>, values: Vec<u8>) {
+    let mut values = values.into_iter();
+    let Some(first) = values.next() else {
+        out.extend([TAG_UNIFORM, 0]);
+        return;
+    };
+
+    let mut runs: Vec<(u16, u8)> = vec![(1, first)];
+    for value in values {
+        match runs.last_mut() {
+            Some((run, last)) if *last == value && *run < u16::MAX => *run += 1,
+            _ => runs.push((1, value)),
+        }
+    }
+
+    if let [(_, value)] = runs[..] {
+        out.extend([TAG_UNIFORM, value]);
+        return;
+    }
+
+    out.push(TAG_RUN_LENGTH);
+    for (run, value) in runs {
+        out.extend(run.to_le_bytes());
+        out.push(value);
+    }
+}
+
+enum Volume {
+    Uniform(u8),
+    Dense(Vec<u8>),
+}
+
+fn read_volume(reader: &mut ByteReader, len: usize) -> Result<Volume, WorldSaveError> {
+    match reader.read_u8()? {
+        TAG_UNIFORM => Ok(Volume::Uniform(reader.read_u8()?)),
+        TAG_RUN_LENGTH => {
+            let mut values = Vec::with_capacity(len);
+            while values.len() < len {
+                let run = u16::from_le_bytes([reader.read_u8()?, reader.read_u8()?]) as usize;
+                let value = reader.read_u8()?;
+                if run == 0 || values.len() + run > len {
+                    return Err(WorldSaveError::CorruptData(format!(
+                        "run of {run} overflows a volume of {len} values"
+                    )));
+                }
+                values.resize(values.len() + run, value);
+            }
+            Ok(Volume::Dense(values))
+        }
+        tag => Err(WorldSaveError::CorruptData(format!(
+            "unknown volume tag {tag}"
+        ))),
+    }
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl ByteReader<'_> {
+    fn read_u8(&mut self) -> Result<u8, WorldSaveError> {
+        let byte =
+            self.bytes.get(self.pos).copied().ok_or_else(|| {
+                WorldSaveError::CorruptData("chunk payload ended early".to_string())
+            })?;
+        self.pos += 1;
+        Ok(byte)
+    }
+}
@@ -0,0 +1,35 @@
+use std::{fmt, io::Error};
+
+#[derive(Debug)]
+pub enum WorldSaveError {
+    IoError(Error),
+    /// The world metadata could not be parsed or written.
+    MetadataError(String),
+    /// A region file or chunk payload is malformed.
+    CorruptData(String),
+    /// The save was written by a newer or unknown format version.
+    UnsupportedVersion(u16),
+}
+
+impl From<Error> for WorldSaveError {
+    fn from(err: Error) -> Self {
+        WorldSaveError::IoError(err)
+    }
+}
+
+impl fmt::Display for WorldSaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorldSaveError::IoError(err) => write!(f, "World save IO error: {err}"),
+            WorldSaveError::MetadataError(err) => {
+                write!(f, "Invalid world save metadata: {err}")
+            }
+            WorldSaveError::CorruptData(what) => write!(f, "Corrupt world save data: {what}"),
+            WorldSaveError::UnsupportedVersion(version) => {
+                write!(f, "Unsupported world save format version {version}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WorldSaveError {}
@@ -0,0 +1,9 @@
+pub mod chunk_codec;
+pub mod error;
+pub mod region;
+pub mod save;
+
+pub use chunk_codec::{decode_chunk, encode_chunk, encode_generated, SavedChunk};
+pub use error::WorldSaveError;
+pub use region::{Region, RegionCoord, RegionSlot, REGION_SIDE_CHUNKS};
+pub use save::{WorldSave, WorldSaveMeta, WorldSaveResource, WORLD_SAVES_DIR, WORLD_SAVE_VERSION};
@@ -0,0 +1,209 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    chunk::{WORLD_MAX_Y_CHUNK, WORLD_MIN_Y_CHUNK},
+    world_save::WorldSaveError,
+};
+use std::{fs, path::Path};
+
+/// The number of chunk columns along each horizontal axis of a region.
+pub const REGION_SIDE_CHUNKS: i32 = 16;
+/// The number of chunks in a single column of the world.
+pub const REGION_HEIGHT_CHUNKS: i32 = WORLD_MAX_Y_CHUNK - WORLD_MIN_Y_CHUNK + 1;
+/// The number of chunk slots stored in a region file.
+pub const REGION_SLOT_COUNT: usize =
+    (REGION_SIDE_CHUNKS * REGION_SIDE_CHUNKS * REGION_HEIGHT_CHUNKS) as usize;
+
+const REGION_MAGIC: &[u8; 4] = b"BREG";
+const REGION_VERSION: u16 = 1;
+/// Magic + version + slot count.
+const HEADER_LEN: usize = 4 + 2 + 4;
+/// Offset + length, both `u32` LE.
+const TABLE_ENTRY_LEN: usize = 8;
+/// Table offset marking a chunk that was generated but is all air.
+const EMPTY_SLOT_OFFSET: u32 = u32::MAX;
+
+/// The coordinate of a region, in regions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegionCoord {
+    pub x: i32,
+    pub z: i32,
+}
+
+impl RegionCoord {
+    /// The region containing a chunk.
+    pub fn of_chunk(chunk_pos: IVec3) -> Self {
+        Self {
+            x: chunk_pos.x.div_euclid(REGION_SIDE_CHUNKS),
+            z: chunk_pos.z.div_euclid(REGION_SIDE_CHUNKS),
+        }
+    }
+
+    /// The file name this region is stored under.
+    pub fn file_name(&self) -> String {
+        format!("r.{}.{}.bin", self.x, self.z)
+    }
+
+    /// The position of the region's lowest chunk column, in chunks.
+    pub fn min_chunk(&self) -> IVec3 {
+        IVec3::new(
+            self.x * REGION_SIDE_CHUNKS,
+            WORLD_MIN_Y_CHUNK,
+            self.z * REGION_SIDE_CHUNKS,
+        )
+    }
+
+    /// The index of a chunk's slot within this region, `None` if it lies outside.
+    pub fn slot_index(&self, chunk_pos: IVec3) -> Option<usize> {
+        let local = chunk_pos - self.min_chunk();
+        let in_bounds = (0..REGION_SIDE_CHUNKS).contains(&local.x)
+            && (0..REGION_SIDE_CHUNKS).contains(&local.z)
+            && (0..REGION_HEIGHT_CHUNKS).contains(&local.y);
+
+        in_bounds.then(|| {
+            ((local.x * REGION_SIDE_CHUNKS + local.z) * REGION_HEIGHT_CHUNKS + local.y) as usize
+        })
+    }
+
+    /// Iterates over every chunk position covered by this region.
+    pub fn chunk_positions(&self) -> impl Iterator<Item = IVec3> {
+        let min = self.min_chunk();
+        (0..REGION_SIDE_CHUNKS).flat_map(move |x| {
+            (0..REGION_SIDE_CHUNKS).flat_map(move |z| {
+                (0..REGION_HEIGHT_CHUNKS).map(move |y| min + IVec3::new(x, y, z))
+            })
+        })
+    }
+}
+
+/// What a region stores for a single chunk.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum RegionSlot {
+    /// The chunk was never written.
+    #[default]
+    Missing,
+    /// The chunk was generated and is all air.
+    Empty,
+    /// An encoded chunk payload, see `chunk_codec`.
+    Data(Vec<u8>),
+}
+
+/// The contents of one region file.
+///
+/// Layout: magic `BREG`, version `u16`, slot count `u32`, then a table of
+/// `(offset: u32, len: u32)` per slot followed by the chunk payloads. An entry of
+/// `(0, 0)` is a missing chunk and `(u32::MAX, 0)` an empty one.
+#[derive(Debug, Clone)]
+pub struct Region {
+    pub slots: Vec<RegionSlot>,
+}
+
+impl Default for Region {
+    fn default() -> Self {
+        Self {
+            slots: vec![RegionSlot::Missing; REGION_SLOT_COUNT],
+        }
+    }
+}
+
+impl Region {
+    /// Serializes the region to its on-disk format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let table_len = self.slots.len() * TABLE_ENTRY_LEN;
+        let payload_len: usize = self
+            .slots
+            .iter()
+            .map(|slot| match slot {
+                RegionSlot::Data(data) => data.len(),
+                _ => 0,
+            })
+            .sum();
+
+        let mut out = Vec::with_capacity(HEADER_LEN + table_len + payload_len);
+        out.extend_from_slice(REGION_MAGIC);
+        out.extend(REGION_VERSION.to_le_bytes());
+        out.extend((self.slots.len() as u32).to_le_bytes());
+
+        let mut offset = (HEADER_LEN + table_len) as u32;
+        for slot in &self.slots {
+            let (entry_offset, len) = match slot {
+                RegionSlot::Missing => (0, 0),
+                RegionSlot::Empty => (EMPTY_SLOT_OFFSET, 0),
+                RegionSlot::Data(data) => (offset, data.len() as u32),
+            };
+            out.extend(entry_offset.to_le_bytes());
+            out.extend(len.to_le_bytes());
+            offset += len;
+        }
+
+        for slot in &self.slots {
+            if let RegionSlot::Data(data) = slot {
+                out.extend_from_slice(data);
+            }
+        }
+
+        out
+    }
+
+    /// Parses a region from its on-disk format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, WorldSaveError> {
+        let corrupt = |what: &str| WorldSaveError::CorruptData(format!("region {what}"));
+
+        if bytes.len() < HEADER_LEN || &bytes[0..4] != REGION_MAGIC {
+            return Err(corrupt("has no valid header"));
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != REGION_VERSION {
+            return Err(WorldSaveError::UnsupportedVersion(version));
+        }
+        let slot_count = read_u32(bytes, 6) as usize;
+        if slot_count != REGION_SLOT_COUNT {
+            return Err(corrupt(&format!(
+                "has {slot_count} slots, expected {REGION_SLOT_COUNT}"
+            )));
+        }
+        if bytes.len() < HEADER_LEN + slot_count * TABLE_ENTRY_LEN {
+            return Err(corrupt("table is truncated"));
+        }
+
+        let mut slots = Vec::with_capacity(slot_count);
+        for i in 0..slot_count {
+            let entry = HEADER_LEN + i * TABLE_ENTRY_LEN;
+            let offset = read_u32(bytes, entry);
+            let len = read_u32(bytes, entry + 4) as usize;
+
+            slots.push(match (offset, len) {
+                (0, 0) => RegionSlot::Missing,
+                (EMPTY_SLOT_OFFSET, 0) => RegionSlot::Empty,
+                (offset, len) => {
+                    let start = offset as usize;
+                    let data = bytes
+                        .get(start..start + len)
+                        .ok_or_else(|| corrupt("payload is out of bounds"))?;
+                    RegionSlot::Data(data.to_vec())
+                }
+            });
+        }
+
+        Ok(Self { slots })
+    }
+
+    /// Reads a region file.
+    pub fn read(path: &Path) -> Result<Self, WorldSaveError> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+
+    /// Writes a region file, replacing any existing one atomically.
+    ///
+    /// Returns the size of the written file in bytes.
+    pub fn write(&self, path: &Path) -> Result<usize, WorldSaveError> {
+        let tmp_path = path.with_extension("bin.tmp");
+        let bytes = self.to_bytes();
+        fs::write(&tmp_path, &bytes)?;
+        fs::rename(&tmp_path, path)?;
+        Ok(bytes.len())
+    }
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
@@ -0,0 +1,315 @@
+use crate::prelude::*;
+use crate::simulation_world::{
+    biome::{BiomeId, BiomeRegistryResource},
+    block::{BlockId, BlockRegistryResource, AIR_BLOCK_ID},
+    terrain::{ActiveTerrainGenerator, TerrainGeneratorLibrary, WorldSeed},
+    world_save::{decode_chunk, Region, RegionCoord, RegionSlot, SavedChunk, WorldSaveError},
+};
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+/// The current version of the world save format.
+pub const WORLD_SAVE_VERSION: u16 = 1;
+
+/// Where the game looks for world saves to load, one directory each.
+pub const WORLD_SAVES_DIR: &str = "saves";
+
+const META_FILE_NAME: &str = "world.ron";
+const REGIONS_DIR_NAME: &str = "regions";
+
+/// How many read regions are kept in memory before the least recently used is dropped.
+const MAX_CACHED_REGIONS: usize = 64;
+
+/// Describes how a saved world was generated, stored as `world.ron` in the save directory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorldSaveMeta {
+    pub version: u16,
+    /// The seed of the climate noise.
+    pub seed: u32,
+    /// The name of the terrain shaper, see `TerrainShaper::name`.
+    pub generator: String,
+    /// The block names the saved block IDs refer to, indexed by ID.
+    pub block_palette: Vec<String>,
+    /// The biome names the saved biome IDs refer to, indexed by ID.
+    pub biome_palette: Vec<String>,
+}
+
+/// A world save directory holding the metadata and region files.
+///
+/// Block and biome IDs are stored by palette, so a save stays readable when the
+/// registries gain or reorder entries.
+pub struct WorldSave {
+    dir: PathBuf,
+    pub meta: WorldSaveMeta,
+    /// Maps saved block IDs to the IDs of the running registry.
+    block_remap: Vec<BlockId>,
+    /// Maps saved biome IDs to the IDs of the running registry.
+    biome_remap: Vec<BiomeId>,
+    /// Recently read regions, `None` if the region has no file.
+    region_cache: Mutex<RegionCache>,
+}
+
+/// A small LRU of read regions, so neighboring chunk loads don't reread the same file.
+#[derive(Default)]
+struct RegionCache {
+    regions: HashMap<RegionCoord, Arc<Option<Region>>>,
+    /// Least recently used first.
+    order: VecDeque<RegionCoord>,
+}
+
+impl RegionCache {
+    fn get(&mut self, coord: RegionCoord) -> Option<Arc<Option<Region>>> {
+        let region = self.regions.get(&coord)?.clone();
+        self.touch(coord);
+        Some(region)
+    }
+
+    fn insert(&mut self, coord: RegionCoord, region: Arc<Option<Region>>) {
+        if self.regions.insert(coord, region).is_some() {
+            self.touch(coord);
+            return;
+        }
+
+        self.order.push_back(coord);
+        while self.order.len() > MAX_CACHED_REGIONS {
+            if let Some(evicted) = self.order.pop_front() {
+                self.regions.remove(&evicted);
+            }
+        }
+    }
+
+    fn remove(&mut self, coord: RegionCoord) {
+        if self.regions.remove(&coord).is_some() {
+            self.order.retain(|&c| c != coord);
+        }
+    }
+
+    fn touch(&mut self, coord: RegionCoord) {
+        if let Some(index) = self.order.iter().position(|&c| c == coord) {
+            self.order.remove(index);
+            self.order.push_back(coord);
+        }
+    }
+}
+
+impl WorldSave {
+    /// Creates a new save directory for a world generated with the current registries.
+    pub fn create(
+        dir: impl Into<PathBuf>,
+        seed: u32,
+        generator: &str,
+        block_registry: &BlockRegistryResource,
+        biome_registry: &BiomeRegistryResource,
+    ) -> Result<Self, WorldSaveError> {
+        let dir = dir.into();
+        let meta = WorldSaveMeta {
+            version: WORLD_SAVE_VERSION,
+            seed,
+            generator: generator.to_string(),
+            block_palette: block_registry.block_names(),
+            biome_palette: biome_registry.biome_names(),
+        };
+
+        fs::create_dir_all(dir.join(REGIONS_DIR_NAME))?;
+        let meta_ron = ron::ser::to_string_pretty(&meta, ron::ser::PrettyConfig::default())
+            .map_err(|e| WorldSaveError::MetadataError(e.to_string()))?;
+        fs::write(dir.join(META_FILE_NAME), meta_ron)?;
+
+        Ok(Self::with_meta(dir, meta, block_registry, biome_registry))
+    }
+
+    /// Opens an existing save directory, remapping its palettes to the current registries.
+    pub fn open(
+        dir: impl Into<PathBuf>,
+        block_registry: &BlockRegistryResource,
+        biome_registry: &BiomeRegistryResource,
+    ) -> Result<Self, WorldSaveError> {
+        let dir = dir.into();
+        let meta_ron = fs::read_to_string(dir.join(META_FILE_NAME))?;
+        let meta: WorldSaveMeta =
+            ron::from_str(&meta_ron).map_err(|e| WorldSaveError::MetadataError(e.to_string()))?;
+
+        if meta.version != WORLD_SAVE_VERSION {
+            return Err(WorldSaveError::UnsupportedVersion(meta.version));
+        }
+
+        Ok(Self::with_meta(dir, meta, block_registry, biome_registry))
+    }
+
+    /// Opens the save in `dir` against a world's registries and installs it there.
+    pub fn load_into(dir: impl Into<PathBuf>, world: &mut World) -> Result<(), WorldSaveError> {
+        let save = Self::open(
+            dir,
+            world.resource::<BlockRegistryResource>(),
+            world.resource::<BiomeRegistryResource>(),
+        )?;
+        save.install(world)
+    }
+
+    /// The saves directly inside `dir`, sorted by name. A missing directory has none.
+    pub fn list(dir: &Path) -> Vec<PathBuf> {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        let mut saves: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.join(META_FILE_NAME).is_file())
+            .collect();
+        saves.sort();
+        saves
+    }
+
+    fn with_meta(
+        dir: PathBuf,
+        meta: WorldSaveMeta,
+        block_registry: &BlockRegistryResource,
+        biome_registry: &BiomeRegistryResource,
+    ) -> Self {
+        let block_remap = meta
+            .block_palette
+            .iter()
+            .map(|name| {
+                block_registry
+                    .get_block_id_by_name(name)
+                    .unwrap_or_else(|| {
+                        warn!(
+                            "Saved block '{}' is not registered, loading it as air",
+                            name
+                        );
+                        AIR_BLOCK_ID
+                    })
+            })
+            .collect();
+
+        let biome_remap = meta
+            .biome_palette
+            .iter()
+            .map(|name| {
+                biome_registry.get_id_by_name(name).unwrap_or_else(|| {
+                    warn!(
+                        "Saved biome '{}' is not registered, loading it as ID 0",
+                        name
+                    );
+                    0
+                })
+            })
+            .collect();
+
+        Self {
+            dir,
+            meta,
+            block_remap,
+            biome_remap,
+            region_cache: Mutex::new(RegionCache::default()),
+        }
+    }
+
+    /// The directory the save lives in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The path of a region's file.
+    pub fn region_path(&self, region: RegionCoord) -> PathBuf {
+        self.dir.join(REGIONS_DIR_NAME).join(region.file_name())
+    }
+
+    /// Writes a whole region to disk, replacing any previous version of it.
+    ///
+    /// The region isn't kept in memory, so writing a large world doesn't hold all of it.
+    /// Returns the size of the written file in bytes.
+    pub fn write_region(
+        &self,
+        coord: RegionCoord,
+        region: &Region,
+    ) -> Result<usize, WorldSaveError> {
+        let bytes = region.write(&self.region_path(coord))?;
+        self.region_cache.lock().unwrap().remove(coord);
+        Ok(bytes)
+    }
+
+    /// Reads a single chunk from the save.
+    ///
+    /// Returns `Ok(None)` if the chunk was never saved.
+    pub fn read_chunk(&self, chunk_pos: IVec3) -> Result<Option<SavedChunk>, WorldSaveError> {
+        let coord = RegionCoord::of_chunk(chunk_pos);
+        let Some(slot_index) = coord.slot_index(chunk_pos) else {
+            return Ok(None);
+        };
+
+        let region = self.load_region(coord)?;
+        let Some(region) = region.as_ref() else {
+            return Ok(None);
+        };
+
+        match &region.slots[slot_index] {
+            RegionSlot::Missing => Ok(None),
+            RegionSlot::Empty => Ok(Some(SavedChunk::Empty)),
+            RegionSlot::Data(bytes) => decode_chunk(
+                bytes,
+                |id| {
+                    self.block_remap
+                        .get(id as usize)
+                        .copied()
+                        .unwrap_or(AIR_BLOCK_ID)
+                },
+                |id| self.biome_remap.get(id as usize).copied().unwrap_or(0),
+            )
+            .map(Some),
+        }
+    }
+
+    fn load_region(&self, coord: RegionCoord) -> Result<Arc<Option<Region>>, WorldSaveError> {
+        let mut cache = self.region_cache.lock().unwrap();
+        if let Some(region) = cache.get(coord) {
+            return Ok(region);
+        }
+
+        let path = self.region_path(coord);
+        let region = if path.exists() {
+            Arc::new(Some(Region::read(&path)?))
+        } else {
+            Arc::new(None)
+        };
+
+        cache.insert(coord, region.clone());
+        Ok(region)
+    }
+
+    /// Makes a simulation world load chunks from this save and generate any missing
+    /// ones with the generator the save was created with.
+    pub fn install(self, world: &mut World) -> Result<(), WorldSaveError> {
+        let shaper = world
+            .resource::<TerrainGeneratorLibrary>()
+            .generators
+            .iter()
+            .find(|shaper| shaper.name() == self.meta.generator)
+            .cloned()
+            .ok_or_else(|| {
+                WorldSaveError::MetadataError(format!(
+                    "unknown terrain generator '{}'",
+                    self.meta.generator
+                ))
+            })?;
+
+        info!(
+            "Loading world save {:?} (generator '{}', seed {})",
+            self.dir, self.meta.generator, self.meta.seed
+        );
+
+        WorldSeed(self.meta.seed).install(world);
+        world.insert_resource(ActiveTerrainGenerator(shaper));
+        world.insert_resource(WorldSaveResource(Arc::new(self)));
+        Ok(())
+    }
+}
+
+/// The world save chunks are loaded from, if any.
+#[derive(Resource, Clone)]
+pub struct WorldSaveResource(pub Arc<WorldSave>);
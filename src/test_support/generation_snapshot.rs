@@ -0,0 +1,270 @@
+use crate::{
+    prelude::*,
+    simulation_world::{
+        biome::BiomeRegistryResource,
+        block::BlockRegistryResource,
+        chunk::{ChunkBlocksComponent, ChunkCoord, ChunkLod, ChunkView},
+        terrain::{
+            biome::MultiNoiseBiomeGenerator, BasicBiomeGenerator, BiomeGenerator,
+            BiomeMapComponent, ClimateNoiseGenerator, SimpleSurfacePainter,
+            TerrainGeneratorLibrary, TerrainPainter, TerrainPipeline,
+        },
+    },
+    test_support::missing_only_texture_registry,
+};
+use bevy_ecs::world::World;
+use rayon::prelude::*;
+use std::{fmt, fs, path::Path, sync::Arc};
+
+/// The climate noise seed every snapshot is generated with.
+pub const SNAPSHOT_SEED: u32 = 1337;
+
+/// The chunks generated for every snapshot.
+///
+/// A mix of deep, surface and sky chunks spread out so the climate (and with it
+/// the biomes) differ between them.
+pub const SNAPSHOT_COORDS: &[IVec3] = &[
+    IVec3::new(0, 0, 0),
+    IVec3::new(0, 1, 0),
+    IVec3::new(0, 2, 0),
+    IVec3::new(0, 3, 0),
+    IVec3::new(5, 2, -3),
+    IVec3::new(-7, 1, 4),
+    IVec3::new(12, 2, 12),
+    IVec3::new(-40, 2, -25),
+    IVec3::new(64, 4, 3),
+];
+
+/// A named pipeline stage, so snapshots can tell which combination produced them.
+pub type NamedStage<T> = (&'static str, Arc<T>);
+
+/// Every biome generator the snapshots cover, the default first.
+pub fn snapshot_biome_generators() -> Vec<NamedStage<dyn BiomeGenerator + Send + Sync>> {
+    vec![
+        ("MultiNoise", Arc::new(MultiNoiseBiomeGenerator)),
+        ("Basic", Arc::new(BasicBiomeGenerator)),
+    ]
+}
+
+/// Every terrain painter the snapshots cover, the default first.
+pub fn snapshot_terrain_painters() -> Vec<NamedStage<dyn TerrainPainter + Send + Sync>> {
+    vec![("SimpleSurface", Arc::new(SimpleSurfacePainter::new()))]
+}
+
+/// Set this environment variable to rewrite golden files instead of comparing against them.
+pub const BLESS_ENV_VAR: &str = "BLESS";
+
+/// The generated data of a single chunk, boiled down to hashes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkSnapshot {
+    /// The shaper, biome generator and painter, as `shaper/biome/painter`.
+    pub generator: String,
+    pub coord: IVec3,
+    /// Hashes of the block and biome data, `None` if the chunk generated empty.
+    pub hashes: Option<(u64, u64)>,
+}
+
+impl fmt::Display for ChunkSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let coord = format!("({}, {}, {})", self.coord.x, self.coord.y, self.coord.z);
+        match self.hashes {
+            Some((blocks, biomes)) => write!(
+                f,
+                "{:<36} {:<14} blocks={blocks:016x} biomes={biomes:016x}",
+                self.generator, coord
+            ),
+            None => write!(f, "{:<36} {:<14} empty", self.generator, coord),
+        }
+    }
+}
+
+// INFO: -------------------------
+//         taking snapshots
+// -------------------------------
+
+/// Loads the block and biome registries from `assets/` without needing any textures.
+pub fn load_snapshot_registries() -> (BlockRegistryResource, BiomeRegistryResource) {
+    let mut world = World::new();
+    world.insert_resource(missing_only_texture_registry());
+    world.init_resource::<BlockRegistryResource>();
+    world.init_resource::<BiomeRegistryResource>();
+
+    (
+        world.resource::<BlockRegistryResource>().clone(),
+        world.resource::<BiomeRegistryResource>().clone(),
+    )
+}
+
+/// Generates `SNAPSHOT_COORDS` with every combination of a shaper in the
+/// `TerrainGeneratorLibrary`, a snapshot biome generator and a snapshot painter.
+///
+/// Climate is seeded with `SNAPSHOT_SEED`. Snapshots are ordered by shaper (library
+/// index), biome generator and painter, then by coordinate.
+pub fn snapshot_generator_library(
+    block_registry: &BlockRegistryResource,
+    biome_registry: &BiomeRegistryResource,
+) -> Vec<ChunkSnapshot> {
+    let library = TerrainGeneratorLibrary::default();
+    let climate = Arc::new(ClimateNoiseGenerator::new(SNAPSHOT_SEED));
+    let biome_generators = snapshot_biome_generators();
+    let painters = snapshot_terrain_painters();
+
+    let mut pipelines = Vec::new();
+    for shaper in &library.generators {
+        for (biome_name, biome) in &biome_generators {
+            for (painter_name, painter) in &painters {
+                let name = format!("{}/{biome_name}/{painter_name}", shaper.name());
+                let pipeline = TerrainPipeline {
+                    climate: climate.clone(),
+                    biome: biome.clone(),
+                    shaper: shaper.clone(),
+                    painter: painter.clone(),
+                };
+                pipelines.push((name, pipeline));
+            }
+        }
+    }
+
+    let jobs: Vec<_> = pipelines
+        .iter()
+        .flat_map(|(name, pipeline)| {
+            SNAPSHOT_COORDS
+                .iter()
+                .map(move |&coord| (name.clone(), pipeline.clone(), coord))
+        })
+        .collect();
+
+    jobs.into_par_iter()
+        .map(|(name, pipeline, coord)| {
+            let generated = pipeline.generate_chunk(
+                ChunkCoord { pos: coord },
+                ChunkLod(0),
+                block_registry,
+                biome_registry,
+            );
+
+            ChunkSnapshot {
+                generator: name,
+                coord,
+                hashes: generated.and_then(|bundle| {
+                    let blocks = hash_chunk_blocks(bundle.chunk_blocks.as_ref()?);
+                    Some((blocks, hash_biome_map(&bundle.biome_map)))
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Hashes every voxel of a chunk, so uniform and dense chunks with equal blocks match.
+pub fn hash_chunk_blocks(chunk_blocks: &ChunkBlocksComponent) -> u64 {
+    let size = chunk_blocks.size();
+    let mut hasher = Fnv1a::new();
+    hasher.write(&[*chunk_blocks.lod()]);
+
+    match chunk_blocks.get_view() {
+        ChunkView::Uniform(block_id) => {
+            for _ in 0..size.pow(3) {
+                hasher.write(&[block_id]);
+            }
+        }
+        ChunkView::Dense(view) => {
+            for_each_voxel(size, |x, y, z| hasher.write(&[view.get_data(x, y, z)]));
+        }
+    }
+
+    hasher.finish()
+}
+
+/// Hashes every voxel of a biome map.
+pub fn hash_biome_map(biome_map: &BiomeMapComponent) -> u64 {
+    let mut hasher = Fnv1a::new();
+    hasher.write(&[*biome_map.lod()]);
+    for_each_voxel(biome_map.size(), |x, y, z| {
+        hasher.write(&[biome_map.get_data_unchecked(x, y, z)]);
+    });
+    hasher.finish()
+}
+
+fn for_each_voxel(size: usize, mut f: impl FnMut(usize, usize, usize)) {
+    for x in 0..size {
+        for z in 0..size {
+            for y in 0..size {
+                f(x, y, z);
+            }
+        }
+    }
+}
+
+/// 64 bit FNV-1a, used over `std`'s hasher since its output may change between releases.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+// INFO: ---------------------
+//         golden files
+// ---------------------------
+
+/// Compares snapshots against a checked-in golden file.
+///
+/// If the `BLESS` environment variable is set the golden file is (re)written instead.
+/// Returns a readable list of every mismatch on failure.
+pub fn check_golden_file(path: &Path, snapshots: &[ChunkSnapshot]) -> Result<(), String> {
+    let actual: String = snapshots.iter().map(|s| format!("{s}\n")).collect();
+
+    if std::env::var_os(BLESS_ENV_VAR).is_some() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create {parent:?}: {e}"))?;
+        }
+        fs::write(path, actual).map_err(|e| format!("Failed to write {path:?}: {e}"))?;
+        info!("Blessed golden file {:?}", path);
+        return Ok(());
+    }
+
+    let expected = fs::read_to_string(path).map_err(|e| {
+        format!(
+            "Failed to read golden file {path:?} ({e}). Run with {BLESS_ENV_VAR}=1 to create it."
+        )
+    })?;
+
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+
+    let mut mismatches = Vec::new();
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        let expected_line = expected_lines.get(i).copied().unwrap_or("<missing>");
+        let actual_line = actual_lines.get(i).copied().unwrap_or("<missing>");
+        if expected_line != actual_line {
+            mismatches.push(format!(
+                "  expected: {expected_line}\n  actual:   {actual_line}"
+            ));
+        }
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} snapshot(s) in {path:?} changed:\n{}\nIf the change is intended, rerun with {BLESS_ENV_VAR}=1.",
+            mismatches.len(),
+            mismatches.join("\n")
+        ))
+    }
+}
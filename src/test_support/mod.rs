@@ -0,0 +1,27 @@
+pub mod generation_snapshot;
+pub mod test_world;
+
+pub use generation_snapshot::*;
+pub use test_world::*;
+
+use crate::render_world::textures::TextureRegistryResource;
+use crate::simulation_world::block::BlockRegistryResource;
+use std::collections::HashMap;
+
+/// A texture registry with only the "missing" texture, so every block resolves to it.
+///
+/// Tests never render, they only need the registries to build.
+pub fn missing_only_texture_registry() -> TextureRegistryResource {
+    TextureRegistryResource::new(HashMap::from([("missing".to_string(), 0)]))
+        .expect("Failed to build test texture registry")
+}
+
+/// A block registry built from `(name, ron)` block definitions, like `DEFAULT_TEST_BLOCKS`.
+pub fn test_block_registry(blocks: &[(&str, &str)]) -> BlockRegistryResource {
+    BlockRegistryResource::from_ron_sources(
+        blocks
+            .iter()
+            .map(|(name, ron)| (name.to_string(), ron.to_string())),
+        &missing_only_texture_registry(),
+    )
+}
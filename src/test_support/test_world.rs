@@ -0,0 +1,335 @@
+use crate::{
+    headless::{ChunkCounts, HeadlessApp},
+    prelude::*,
+    render_world::types::UnpackedFace,
+    simulation_world::{
+        asset_management::{AssetStorageResource, MeshAsset},
+        biome::load_biome_defs_from_sources,
+        block::{BlockId, BlockRegistryResource},
+        chunk::{
+            ActiveChunkMesher, ChunkCoord, ChunkMesher, ChunkState, ChunkStateManager,
+            OpaqueMeshComponent, RenderDistance, TransparentMeshComponent, VoxelReader,
+            WorldVoxels,
+        },
+        player::{ActiveCamera, CameraComponent},
+        terrain::{ActiveTerrainGenerator, SuperflatShaper, TerrainShaper},
+        world_edit::VoxPaletteMapping,
+        SimulationWorldInterface,
+    },
+    test_support::missing_only_texture_registry,
+};
+use bevy_ecs::{prelude::*, system::RunSystemOnce};
+use std::{sync::Arc, time::Duration};
+
+/// How long a test world may take to start up or settle its chunks before the test fails.
+const TEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Blocks every test world has unless overridden.
+///
+/// Kept free of behaviors and gravity so nothing changes unless a test changes it.
+pub const DEFAULT_TEST_BLOCKS: &[(&str, &str)] = &[
+    (
+        "stone",
+        r#"(display_name: "Stone", textures: (fallback: "stone"), is_transparent: false)"#,
+    ),
+    (
+        "dirt",
+        r#"(display_name: "Dirt", textures: (fallback: "dirt"), is_transparent: false)"#,
+    ),
+    (
+        "sand",
+        r#"(display_name: "Sand", textures: (fallback: "sand"), is_transparent: false)"#,
+    ),
+    (
+        "glass",
+        r#"(display_name: "Glass", textures: (fallback: "glass"), is_transparent: true)"#,
+    ),
+    (
+        "water",
+        r#"(display_name: "Water", textures: (fallback: "water"), is_transparent: true, fluid: Some(()))"#,
+    ),
+];
+
+/// Biomes every test world has unless overridden. "ocean" has to exist as the default biome.
+pub const DEFAULT_TEST_BIOMES: &[(&str, &str)] = &[(
+    "ocean",
+    r#"(name: "Ocean", terrain: (surface_material: "sand", subsurface_material: "dirt"))"#,
+)];
+
+// INFO: -------------------
+//         The builder
+// -------------------------
+
+/// Builds a headless simulation world for tests, without touching `assets/`.
+///
+/// Defaults to a superflat world (a sand surface at y = 64 over stone) with a
+/// render distance of 1 chunk around a camera at the origin chunk.
+pub struct TestWorldBuilder {
+    blocks: Vec<(String, String)>,
+    biomes: Vec<(String, String)>,
+    shaper: Arc<dyn TerrainShaper + Send + Sync>,
+    camera_position: Vec3,
+    render_distance: RenderDistance,
+    vox_palette: VoxPaletteMapping,
+    mesher: ChunkMesher,
+}
+
+impl Default for TestWorldBuilder {
+    fn default() -> Self {
+        let to_owned = |sources: &[(&str, &str)]| {
+            sources
+                .iter()
+                .map(|(name, ron)| (name.to_string(), ron.to_string()))
+                .collect()
+        };
+
+        Self {
+            blocks: to_owned(DEFAULT_TEST_BLOCKS),
+            biomes: to_owned(DEFAULT_TEST_BIOMES),
+            shaper: Arc::new(SuperflatShaper::new()),
+            camera_position: Vec3::new(16.0, 80.0, 16.0),
+            render_distance: RenderDistance(1),
+            vox_palette: VoxPaletteMapping::empty(),
+            mesher: ChunkMesher::default(),
+        }
+    }
+}
+
+impl TestWorldBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a block from a RON string, replacing any block with the same name.
+    pub fn with_block(mut self, name: &str, ron: &str) -> Self {
+        replace_source(&mut self.blocks, name, ron);
+        self
+    }
+
+    /// Adds a biome from a RON string, replacing any biome with the same name.
+    pub fn with_biome(mut self, name: &str, ron: &str) -> Self {
+        replace_source(&mut self.biomes, name, ron);
+        self
+    }
+
+    /// Sets the terrain shaper used to generate chunks.
+    pub fn with_shaper(mut self, shaper: impl TerrainShaper + 'static) -> Self {
+        self.shaper = Arc::new(shaper);
+        self
+    }
+
+    /// Sets where the (fake) active camera sits, which decides which chunks get loaded.
+    pub fn with_camera_position(mut self, position: Vec3) -> Self {
+        self.camera_position = position;
+        self
+    }
+
+    /// Sets the render distance in chunks. Keep it small, every ring adds a lot of chunks.
+    pub fn with_render_distance(mut self, render_distance: i32) -> Self {
+        self.render_distance = RenderDistance(render_distance);
+        self
+    }
+
+    /// Sets the palette mapping used to import and export `.vox` files.
+    pub fn with_vox_palette(mut self, vox_palette: VoxPaletteMapping) -> Self {
+        self.vox_palette = vox_palette;
+        self
+    }
+
+    /// Sets which mesher builds chunk meshes.
+    pub fn with_mesher(mut self, mesher: ChunkMesher) -> Self {
+        self.mesher = mesher;
+        self
+    }
+
+    /// Builds the world and steps it until startup loading is done.
+    ///
+    /// Panics if anything goes wrong, since this is only meant for tests.
+    pub fn build(self) -> TestWorld {
+        let texture_registry = missing_only_texture_registry();
+
+        let block_registry =
+            BlockRegistryResource::from_ron_sources(self.blocks, &texture_registry);
+        let biome_registry = load_biome_defs_from_sources(self.biomes);
+        let camera_position = self.camera_position;
+        let render_distance = self.render_distance;
+        let vox_palette = self.vox_palette;
+        let mesher = ActiveChunkMesher(self.mesher);
+
+        let mut simulation_world =
+            SimulationWorldInterface::new_headless_with(texture_registry, |builder| {
+                builder
+                    .add_resource(block_registry)
+                    .add_resource(biome_registry)
+                    .add_resource(render_distance)
+                    .add_resource(vox_palette)
+                    .add_resource(mesher);
+
+                let camera_entity = builder
+                    .world
+                    .spawn((
+                        CameraComponent {
+                            position: camera_position,
+                            ..Default::default()
+                        },
+                        ChunkCoord {
+                            pos: ChunkCoord::world_to_chunk_pos(camera_position),
+                        },
+                    ))
+                    .id();
+                builder.add_resource(ActiveCamera(camera_entity));
+            });
+
+        // the terrain plugin always inserts its default shaper, so swap it after building
+        simulation_world.add_resource(ActiveTerrainGenerator(self.shaper));
+
+        let mut app = HeadlessApp::from_simulation_world(simulation_world);
+        app.wait_until_running(TEST_TIMEOUT)
+            .unwrap_or_else(|e| panic!("Test world failed to start: {e}"));
+
+        TestWorld { app }
+    }
+}
+
+fn replace_source(sources: &mut Vec<(String, String)>, name: &str, ron: &str) {
+    sources.retain(|(existing, _)| existing != name);
+    sources.push((name.to_string(), ron.to_string()));
+}
+
+// INFO: -----------------------
+//         The test world
+// -----------------------------
+
+/// The number of faces in a chunk's meshes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MeshFaceCounts {
+    pub opaque: usize,
+    pub transparent: usize,
+}
+
+impl MeshFaceCounts {
+    pub fn total(&self) -> usize {
+        self.opaque + self.transparent
+    }
+}
+
+/// A running headless simulation world with helpers for asserting on it.
+pub struct TestWorld {
+    pub app: HeadlessApp,
+}
+
+impl TestWorld {
+    pub fn world(&mut self) -> &mut World {
+        &mut self.app.simulation_world.world
+    }
+
+    /// Runs a single frame (one fixed tick).
+    pub fn step(&mut self) {
+        self.app.step();
+    }
+
+    /// Runs `ticks` frames (one fixed tick each).
+    pub fn step_ticks(&mut self, ticks: u64) {
+        for _ in 0..ticks {
+            self.app.step();
+        }
+    }
+
+    /// Steps until every chunk around the camera is either `Loaded` or (in the outer
+    /// load ring) `DataReady`.
+    ///
+    /// Always runs at least one tick first so pending edits get picked up.
+    pub fn settle_chunks(&mut self) {
+        self.step();
+        self.app
+            .settle(TEST_TIMEOUT)
+            .unwrap_or_else(|e| panic!("Chunks did not settle: {e}"));
+    }
+
+    /// Moves the camera, which loads and unloads chunks on the following ticks.
+    pub fn set_camera_position(&mut self, position: Vec3) {
+        self.app.set_camera_position(position);
+    }
+
+    pub fn chunk_counts(&self) -> ChunkCounts {
+        self.app.chunk_counts()
+    }
+
+    pub fn chunk_state(&self, chunk_coord: IVec3) -> Option<ChunkState> {
+        self.app
+            .simulation_world
+            .get_resource::<ChunkStateManager>()?
+            .get_state(chunk_coord)
+    }
+
+    /// Looks up a block ID by name, panicking if the block is not registered.
+    pub fn block_id(&self, name: &str) -> BlockId {
+        self.app
+            .simulation_world
+            .get_resource::<BlockRegistryResource>()
+            .and_then(|registry| registry.get_block_id_by_name(name))
+            .unwrap_or_else(|| panic!("Block '{name}' is not registered"))
+    }
+
+    /// Gets the block at a world position, if its chunk is loaded at full detail.
+    pub fn get_block(&mut self, world_pos: IVec3) -> Option<BlockId> {
+        self.world()
+            .run_system_once(move |voxels: VoxelReader| voxels.get_block(world_pos))
+            .expect("Failed to read voxel")
+    }
+
+    /// Sets the block at a world position the same way gameplay edits do, returning
+    /// the block that was replaced.
+    pub fn set_block(&mut self, world_pos: IVec3, block_id: BlockId) -> Option<BlockId> {
+        self.world()
+            .run_system_once(move |mut voxels: WorldVoxels| voxels.set_block(world_pos, block_id))
+            .expect("Failed to write voxel")
+    }
+
+    /// Counts the faces in a chunk's current meshes.
+    ///
+    /// Returns `None` if the chunk is not tracked, and zero counts if it has no mesh.
+    pub fn mesh_face_counts(&mut self, chunk_coord: IVec3) -> Option<MeshFaceCounts> {
+        let entity = match self.chunk_state(chunk_coord)? {
+            ChunkState::Loaded { entity: None } => return Some(MeshFaceCounts::default()),
+            state => state.entity()?,
+        };
+
+        let world = self.world();
+        let meshes = world.resource::<AssetStorageResource<MeshAsset>>();
+        let face_count = |handle| meshes.with(handle, |mesh| mesh.faces.len()).unwrap_or(0);
+
+        Some(MeshFaceCounts {
+            opaque: world
+                .get::<OpaqueMeshComponent>(entity)
+                .map_or(0, |mesh| face_count(mesh.mesh_handle)),
+            transparent: world
+                .get::<TransparentMeshComponent>(entity)
+                .map_or(0, |mesh| face_count(mesh.mesh_handle)),
+        })
+    }
+
+    /// Unpacks the faces in a chunk's current transparent mesh.
+    ///
+    /// Transparent faces carry their surface drop, which is how fluids render at partial
+    /// heights. Empty if the chunk is not tracked or has no transparent mesh.
+    pub fn transparent_faces(&mut self, chunk_coord: IVec3) -> Vec<UnpackedFace> {
+        let Some(entity) = self
+            .chunk_state(chunk_coord)
+            .and_then(|state| state.entity())
+        else {
+            return Vec::new();
+        };
+
+        let world = self.world();
+        let Some(mesh) = world.get::<TransparentMeshComponent>(entity) else {
+            return Vec::new();
+        };
+        world
+            .resource::<AssetStorageResource<MeshAsset>>()
+            .with(mesh.mesh_handle, |mesh| {
+                mesh.faces.iter().map(|face| face.unpack()).collect()
+            })
+            .unwrap_or_default()
+    }
+}
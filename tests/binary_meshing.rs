@@ -0,0 +1,92 @@
+use b::render_world::types::PackedFace;
+use b::simulation_world::{
+    asset_management::MeshAsset,
+    block::BlockId,
+    chunk::{
+        build_chunk_mesh, thread_buffer_pool::acquire_buffer, ChunkBlocksComponent,
+        ChunkDataOption, ChunkLod, ChunkMesher, NeighborLODs, OpaqueMeshData, PaddedChunk,
+        TransparentMeshData,
+    },
+};
+use b::test_support::{test_block_registry, DEFAULT_TEST_BLOCKS};
+
+/// A chunk filled with a deterministic mix of the given blocks.
+fn noisy_chunk(seed: u32, palette: &[BlockId]) -> ChunkBlocksComponent {
+    let mut chunk = ChunkBlocksComponent::new_uniform_empty(ChunkLod(0));
+    let size = chunk.size();
+    let mut state = seed.wrapping_mul(0x9E37_79B9) | 1;
+    let mut writer = chunk.get_writer();
+    for x in 0..size {
+        for y in 0..size {
+            for z in 0..size {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                writer.set_data(x, y, z, palette[state as usize % palette.len()]);
+            }
+        }
+    }
+    chunk
+}
+
+/// The packed opaque and transparent faces of a chunk.
+type SortedFaces = (Vec<u32>, Vec<u32>);
+
+/// Every face of both meshes, sorted so meshers that emit in different orders compare equal.
+fn sorted_faces(meshes: (Option<OpaqueMeshData>, Option<TransparentMeshData>)) -> SortedFaces {
+    let sorted = |mesh: Option<MeshAsset>| {
+        let faces: &[PackedFace] = mesh.as_ref().map_or(&[], |mesh| &mesh.faces);
+        let mut faces: Vec<u32> = faces.iter().map(|face| face.data).collect();
+        faces.sort_unstable();
+        faces
+    };
+    (sorted(meshes.0), sorted(meshes.1))
+}
+
+#[test]
+fn binary_mesher_matches_the_dense_mesher() {
+    let registry = test_block_registry(DEFAULT_TEST_BLOCKS);
+    let id = |name: &str| registry.get_block_id_by_name(name).unwrap();
+    let water = id("water");
+    let flowing = registry.get_fluid_variant(water, 2).unwrap();
+
+    let palettes: [&[BlockId]; 4] = [
+        &[0, id("stone")],
+        &[0, 0, 0, id("stone"), id("dirt"), id("glass")],
+        &[0, water, water, flowing, id("stone")],
+        &[0, water, flowing, id("glass"), id("sand"), id("stone")],
+    ];
+
+    for (seed, palette) in palettes.iter().enumerate() {
+        let mut chunks: [[[ChunkDataOption; 3]; 3]; 3] = Default::default();
+        for (x, plane) in chunks.iter_mut().enumerate() {
+            for (y, row) in plane.iter_mut().enumerate() {
+                for (z, chunk) in row.iter_mut().enumerate() {
+                    let seed = (seed * 27 + x * 9 + y * 3 + z) as u32 + 1;
+                    *chunk = ChunkDataOption::Generated(noisy_chunk(seed, palette));
+                }
+            }
+        }
+        // a missing neighbor reads as air
+        chunks[1][2][1] = ChunkDataOption::Empty;
+
+        let mesh_with = |mesher| {
+            let padded = PaddedChunk::new(
+                &chunks,
+                ChunkLod(0),
+                NeighborLODs::default(),
+                acquire_buffer(),
+            );
+            sorted_faces(build_chunk_mesh("test", &padded, &registry, mesher))
+        };
+
+        let dense = mesh_with(ChunkMesher::Dense);
+        let binary = mesh_with(ChunkMesher::Binary);
+        assert!(!dense.0.is_empty() || !dense.1.is_empty());
+        assert_eq!(binary.0, dense.0, "opaque faces differ for palette {seed}");
+        assert_eq!(
+            binary.1, dense.1,
+            "transparent faces differ for palette {seed}"
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use b::prelude::*;
+use b::simulation_world::block_updates::{RandomTickSpeed, TickQueue};
+use b::simulation_world::chunk::CHUNK_SIZE;
+use b::test_support::{TestWorld, TestWorldBuilder};
+
+/// Random ticks every voxel of a chunk about once per tick, so behaviors that would take
+/// minutes in game settle within a test.
+const EVERY_VOXEL: RandomTickSpeed = RandomTickSpeed(CHUNK_SIZE as u32);
+
+/// Upper bound on how long grass gets to spread, far more than it needs in practice.
+const SPREAD_TICK_LIMIT: u64 = 1000;
+
+const SURFACE: IVec3 = IVec3::new(5, 64, 5);
+
+const GRASS: &str = r#"(display_name: "Grass", textures: (fallback: "grass"), is_transparent: false, behavior: Some(SpreadOnto(target: "dirt")))"#;
+const SNOW: &str = r#"(display_name: "Snow", textures: (fallback: "snow"), is_transparent: false, behavior: Some(Melt(into: "air", min_temperature: -0.2)))"#;
+
+/// A superflat world with a dirt surface in a biome of the given temperature.
+///
+/// Only the chunk around the camera is loaded, since random ticks sample every loaded chunk.
+fn world_at_temperature(temperature: f32) -> TestWorld {
+    let ocean = format!(
+        r#"(name: "Ocean", terrain: (surface_material: "dirt", subsurface_material: "dirt"), temperature: {temperature})"#
+    );
+    let mut world = TestWorldBuilder::new()
+        .with_block("grass", GRASS)
+        .with_block("snow", SNOW)
+        .with_biome("ocean", &ocean)
+        .with_render_distance(0)
+        .build();
+    world.settle_chunks();
+    world
+}
+
+#[test]
+fn grass_spreads_onto_nearby_dirt() {
+    let mut world = world_at_temperature(0.0);
+    world.world().insert_resource(EVERY_VOXEL);
+
+    let grass = world.block_id("grass");
+    world.set_block(SURFACE, grass);
+    world.settle_chunks();
+
+    // the chunk skips random ticks while it re-meshes after each spread, so give it
+    // plenty of ticks and stop as soon as a neighbor turns
+    let spread_to_neighbor = |world: &mut TestWorld| {
+        [IVec3::X, IVec3::NEG_X, IVec3::Z, IVec3::NEG_Z]
+            .into_iter()
+            .any(|offset| world.get_block(SURFACE + offset) == Some(grass))
+    };
+    for _ in 0..SPREAD_TICK_LIMIT {
+        if spread_to_neighbor(&mut world) {
+            break;
+        }
+        world.step();
+    }
+
+    assert!(
+        spread_to_neighbor(&mut world),
+        "grass never spread onto the dirt around it"
+    );
+}
+
+#[test]
+fn snow_melts_in_a_warm_biome() {
+    let mut world = world_at_temperature(0.8);
+    world.world().insert_resource(EVERY_VOXEL);
+
+    let snow = world.block_id("snow");
+    world.set_block(SURFACE + IVec3::Y, snow);
+    world.settle_chunks();
+    world.step_ticks(40);
+
+    assert_eq!(
+        world.get_block(SURFACE + IVec3::Y),
+        Some(world.block_id("air"))
+    );
+}
+
+#[test]
+fn snow_stays_in_a_cold_biome() {
+    let mut world = world_at_temperature(-0.6);
+    world.world().insert_resource(EVERY_VOXEL);
+
+    let snow = world.block_id("snow");
+    world.set_block(SURFACE + IVec3::Y, snow);
+    world.settle_chunks();
+    world.step_ticks(40);
+
+    assert_eq!(world.get_block(SURFACE + IVec3::Y), Some(snow));
+}
+
+#[test]
+fn tick_queue_fires_after_its_delay() {
+    let mut queue = TickQueue::default();
+    let pos = IVec3::new(1, 2, 3);
+    queue.schedule(pos, "first", 10);
+
+    // a position that is already waiting can't be queued again
+    queue.schedule(pos, "second", 5);
+    assert_eq!(queue.len(), 1);
+
+    for tick in 0..10 {
+        assert!(
+            queue.pop_due(tick, usize::MAX).is_empty(),
+            "fired on {tick}"
+        );
+    }
+    assert_eq!(queue.pop_due(10, usize::MAX), vec![(pos, "first")]);
+    assert!(queue.is_empty());
+    assert!(!queue.is_scheduled(pos));
+}
+
+#[test]
+fn tick_queue_carries_over_past_its_budget() {
+    let mut queue = TickQueue::default();
+    for x in 0..5 {
+        queue.schedule(IVec3::new(x, 0, 0), (), 1);
+    }
+
+    assert_eq!(queue.pop_due(1, 3).len(), 3);
+    assert_eq!(queue.pop_due(2, 3).len(), 2);
+    assert!(queue.is_empty());
+}
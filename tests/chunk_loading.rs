@@ -0,0 +1,66 @@
+use b::prelude::*;
+use b::simulation_world::chunk::{ChunkState, CHUNK_AREA, WORLD_MAX_Y_CHUNK, WORLD_MIN_Y_CHUNK};
+use b::test_support::TestWorldBuilder;
+
+#[test]
+fn chunks_around_camera_reach_loaded() {
+    let mut world = TestWorldBuilder::new().with_render_distance(1).build();
+    world.settle_chunks();
+
+    for y in WORLD_MIN_Y_CHUNK..=WORLD_MAX_Y_CHUNK {
+        for z in -1..=1 {
+            for x in -1..=1 {
+                let coord = IVec3::new(x, y, z);
+                assert!(
+                    matches!(world.chunk_state(coord), Some(ChunkState::Loaded { .. })),
+                    "chunk {coord} is {:?}",
+                    world.chunk_state(coord)
+                );
+            }
+        }
+    }
+
+    // the extra load ring only holds data
+    assert!(matches!(
+        world.chunk_state(IVec3::new(2, 0, 0)),
+        Some(ChunkState::DataReady { .. })
+    ));
+
+    let counts = world.chunk_counts();
+    assert_eq!(counts.pending(), 0);
+}
+
+#[test]
+fn superflat_layers_are_painted() {
+    let mut world = TestWorldBuilder::new().build();
+    world.settle_chunks();
+
+    let sand = world.block_id("sand");
+    let stone = world.block_id("stone");
+    let air = world.block_id("air");
+
+    assert_eq!(world.get_block(IVec3::new(3, 65, 7)), Some(air));
+    assert_eq!(world.get_block(IVec3::new(3, 64, 7)), Some(sand));
+    assert_eq!(world.get_block(IVec3::new(3, 60, 7)), Some(stone));
+    assert_eq!(world.get_block(IVec3::new(3, 5, 7)), Some(stone));
+}
+
+#[test]
+fn only_surface_chunks_have_faces() {
+    let mut world = TestWorldBuilder::new().build();
+    world.settle_chunks();
+
+    // y = 64 is the bottom layer of chunk y = 2, so it holds one full layer of top faces
+    let surface = world.mesh_face_counts(IVec3::new(0, 2, 0)).unwrap();
+    assert_eq!(surface.opaque, CHUNK_AREA);
+
+    // the solid chunk below and the empty one above have nothing exposed
+    assert_eq!(
+        world.mesh_face_counts(IVec3::new(0, 1, 0)).unwrap().total(),
+        0
+    );
+    assert_eq!(
+        world.mesh_face_counts(IVec3::new(0, 3, 0)).unwrap().total(),
+        0
+    );
+}
@@ -0,0 +1,59 @@
+use b::ecs_core::{
+    config::{load_config_from, AppConfig, DEFAULT_FOV},
+    Setting,
+};
+
+#[test]
+fn missing_fields_take_their_defaults() {
+    let config =
+        AppConfig::from_ron_str(r#"AppConfig(texture_pack: "other", shadows: false)"#).unwrap();
+
+    assert_eq!(config.texture_pack, "other");
+    assert!(!config.shadows);
+    assert_eq!(config.fov, DEFAULT_FOV);
+    assert_eq!(config.render_distance, AppConfig::default().render_distance);
+
+    // the shipped config parses too
+    AppConfig::from_ron_str(&std::fs::read_to_string("config.ron").unwrap()).unwrap();
+}
+
+#[test]
+fn a_malformed_file_falls_back_to_the_defaults() {
+    let path = std::env::temp_dir().join(format!("b_config_bad_{}.ron", std::process::id()));
+    std::fs::write(&path, "AppConfig(render_distance: \"far\"").unwrap();
+
+    assert!(AppConfig::load(&path).is_err());
+    let config = load_config_from(&path);
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(config, AppConfig::default().with_save_path(&path));
+}
+
+#[test]
+fn settings_roundtrip_and_save_atomically() {
+    let mut config = AppConfig::default();
+    config.adjust(Setting::RenderDistance, 3);
+    config.adjust(Setting::MouseSensitivity, 4);
+    config.adjust(Setting::Vsync, 1);
+
+    let path = std::env::temp_dir().join(format!("b_config_{}.ron", std::process::id()));
+    config.save(&path).unwrap();
+    assert!(!path.with_extension("ron.tmp").exists());
+
+    let reloaded = AppConfig::load(&path);
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(reloaded.unwrap(), config);
+}
+
+#[test]
+fn adjusting_a_setting_stays_in_range() {
+    let mut config = AppConfig::default();
+    config.adjust(Setting::UiScale, 100);
+    assert_eq!(config.ui_scale, 2.0);
+    config.adjust(Setting::RenderDistance, -100);
+    assert_eq!(config.render_distance, 2);
+
+    // out of range values from the file are pulled back in too
+    let config = AppConfig::from_ron_str("AppConfig(fov: 500.0)").unwrap();
+    assert_eq!(config.fov, 110.0);
+}
@@ -0,0 +1,135 @@
+use b::prelude::*;
+use b::simulation_world::console::{
+    ConsoleCommandMessage, ConsoleError, ConsoleLineKind, ConsoleLog,
+};
+use b::simulation_world::player::{ActiveCamera, CameraComponent};
+use b::simulation_world::terrain::{ActiveTerrainGenerator, WorldSeed};
+use b::simulation_world::time::WorldClockResource;
+use b::simulation_world::world_edit::{
+    EditRegion, WorldEditClipboard, WorldEditEvent, WorldEditOperation,
+};
+use b::test_support::TestWorldBuilder;
+
+#[test]
+fn tp_moves_the_camera() {
+    let mut world = TestWorldBuilder::new().build();
+
+    world.app.run_command("/tp 10 80.5 -3").unwrap();
+
+    let world = world.world();
+    let camera_entity = world.resource::<ActiveCamera>().0;
+    let camera = world.get::<CameraComponent>(camera_entity).unwrap();
+    assert_eq!(camera.position, Vec3::new(10.0, 80.5, -3.0));
+}
+
+#[test]
+fn bad_arguments_come_back_with_the_usage() {
+    let mut world = TestWorldBuilder::new().build();
+
+    let err = world.app.run_command("tp 10 up 3").unwrap_err();
+    assert!(matches!(err, ConsoleError::Usage { .. }));
+    assert!(err.to_string().contains("/tp <x> <y> <z>"), "{err}");
+
+    let err = world.app.run_command("/nope").unwrap_err();
+    assert!(matches!(err, ConsoleError::UnknownCommand(name) if name == "nope"));
+}
+
+#[test]
+fn time_set_moves_the_day_cycle() {
+    let mut world = TestWorldBuilder::new().build();
+
+    world.app.run_command("/time set 0.5").unwrap();
+
+    let clock = world.world().resource::<WorldClockResource>();
+    let fraction = clock.time_of_day.as_secs_f32() / clock.day_duration.as_secs_f32();
+    assert!((fraction - 0.5).abs() < 1e-4, "{fraction}");
+    assert!(world.app.run_command("/time set 2").is_err());
+}
+
+#[test]
+fn gen_and_seed_switch_generation_settings() {
+    let mut world = TestWorldBuilder::new().build();
+
+    world.app.run_command("/gen realistic").unwrap();
+    let active = world.world().resource::<ActiveTerrainGenerator>().0.name();
+    assert_eq!(active, "ClimateRealistic");
+
+    world.app.run_command("/seed 1234").unwrap();
+    assert_eq!(world.world().resource::<WorldSeed>().0, 1234);
+    assert_eq!(world.app.run_command("/seed").unwrap(), "Seed: 1234");
+}
+
+#[test]
+fn fill_edits_the_world() {
+    let mut world = TestWorldBuilder::new().build();
+    world.settle_chunks();
+
+    world.app.run_command("/fill 0 70 0 1 71 1 glass").unwrap();
+    world.step();
+
+    let glass = world.block_id("glass");
+    assert_eq!(world.get_block(IVec3::new(0, 70, 0)), Some(glass));
+    assert_eq!(world.get_block(IVec3::new(1, 71, 1)), Some(glass));
+    assert!(world.app.run_command("/fill 0 70 0 1 71 1 cheese").is_err());
+}
+
+#[test]
+fn structure_saves_and_loads_the_clipboard() {
+    let mut world = TestWorldBuilder::new().build();
+    world.settle_chunks();
+
+    let path = std::env::temp_dir().join(format!("b_console_{}.bschem", std::process::id()));
+    let save = format!("/structure save {}", path.display());
+    let load = format!("/structure load {}", path.display());
+
+    assert!(
+        world.app.run_command(&save).is_err(),
+        "saved an empty clipboard"
+    );
+    assert!(world.app.run_command("/structure load house.txt").is_err());
+    assert!(world.app.run_command("/structure paste house.vox").is_err());
+
+    world.world().write_message(WorldEditEvent {
+        operation: WorldEditOperation::Copy {
+            region: EditRegion::from_corners(IVec3::new(0, 64, 0), IVec3::new(2, 65, 1)),
+        },
+    });
+    world.step();
+    let copied = world.world().resource::<WorldEditClipboard>().0.clone();
+
+    world.app.run_command(&save).unwrap();
+    world.step();
+    world.world().resource_mut::<WorldEditClipboard>().0 = None;
+
+    world.app.run_command(&load).unwrap();
+    world.step();
+    assert_eq!(world.world().resource::<WorldEditClipboard>().0, copied);
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn command_messages_run_and_land_in_the_log() {
+    let mut world = TestWorldBuilder::new().build();
+
+    world.world().write_message(ConsoleCommandMessage {
+        line: "/help time".to_string(),
+    });
+    world.world().write_message(ConsoleCommandMessage {
+        line: "/bogus".to_string(),
+    });
+    world.step();
+
+    let log = world.world().resource::<ConsoleLog>();
+    let kinds: Vec<_> = log.tail(10).map(|line| line.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            ConsoleLineKind::Input,
+            ConsoleLineKind::Output,
+            ConsoleLineKind::Input,
+            ConsoleLineKind::Error,
+        ]
+    );
+    assert!(log.tail(10).nth(1).unwrap().text.starts_with("/time"));
+}
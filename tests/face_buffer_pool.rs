@@ -0,0 +1,69 @@
+use b::prelude::*;
+use b::render_world::types::PackedFace;
+use b::simulation_world::{
+    asset_management::MeshAsset,
+    chunk::{
+        meshing::common::{AoLevel, FaceSide},
+        thread_buffer_pool::{
+            acquire_face_buffer, face_buffer_pool_stats, release_face_buffer,
+            DEFAULT_FACE_POOL_IDLE_BYTES,
+        },
+        RenderDistance, RENDER_DISTANCE,
+    },
+};
+use b::test_support::TestWorldBuilder;
+
+// the pool is shared by the whole process, so this is a single test to keep the stats predictable
+#[test]
+fn freed_meshes_return_their_face_buffers_to_the_pool() {
+    // INFO: size classes
+    let mut faces = acquire_face_buffer(100);
+    assert_eq!(faces.capacity(), 128);
+    faces.extend((0..100).map(|i| PackedFace::new(i, 0, 0, FaceSide::Top, [AoLevel::None; 4], 1)));
+
+    let before = face_buffer_pool_stats();
+    drop(MeshAsset {
+        name: "pooled".to_string(),
+        faces,
+        extents: Vec::new(),
+    });
+    let after_drop = face_buffer_pool_stats();
+    assert_eq!(after_drop.idle, before.idle + 1);
+    assert_eq!(
+        after_drop.idle_bytes,
+        before.idle_bytes + 128 * size_of::<PackedFace>()
+    );
+
+    let reused = acquire_face_buffer(70);
+    assert_eq!(reused.capacity(), 128);
+    assert!(reused.is_empty());
+    assert_eq!(face_buffer_pool_stats().reused, after_drop.reused + 1);
+    release_face_buffer(reused);
+
+    // buffers that aren't a size class are freed instead
+    let idle = face_buffer_pool_stats().idle;
+    release_face_buffer(Vec::with_capacity(100));
+    assert_eq!(face_buffer_pool_stats().idle, idle);
+
+    // INFO: chunk meshes
+    let mut world = TestWorldBuilder::new().build();
+    world.settle_chunks();
+    let loaded = face_buffer_pool_stats();
+
+    // unloading frees the meshes, and the chunks loaded in their place reuse the buffers
+    world.set_camera_position(Vec3::new(16.0 + 32.0 * 8.0, 80.0, 16.0));
+    world.step_ticks(3);
+    world.settle_chunks();
+    let moved = face_buffer_pool_stats();
+    assert!(moved.reused > loaded.reused, "{moved:?} vs {loaded:?}");
+
+    // INFO: idle budget
+    // a smaller render distance trims the pool and caps what it keeps from then on
+    world.world().insert_resource(RenderDistance(0));
+    world.step();
+    let budget = DEFAULT_FACE_POOL_IDLE_BYTES / (2 * RENDER_DISTANCE as usize + 1).pow(2);
+    assert!(face_buffer_pool_stats().idle_bytes <= budget);
+
+    release_face_buffer(acquire_face_buffer(1 << 17));
+    assert!(face_buffer_pool_stats().idle_bytes <= budget);
+}
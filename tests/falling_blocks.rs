@@ -0,0 +1,58 @@
+use b::prelude::*;
+use b::simulation_world::chunk::CHUNK_SIDE_LENGTH;
+use b::test_support::{TestWorld, TestWorldBuilder};
+
+const GRAVEL: &str = r#"(display_name: "Gravel", textures: (fallback: "gravel"), is_transparent: false, has_gravity: true)"#;
+
+/// The first y of the chunk holding the surface, so the chunk below ends at `CHUNK_BOUNDARY - 1`.
+const CHUNK_BOUNDARY: i32 = 2 * CHUNK_SIDE_LENGTH as i32;
+
+/// Enough ticks for a short column to fall and land at the default tick rate.
+const FALL_TICKS: u64 = 80;
+
+fn gravel_world() -> TestWorld {
+    let mut world = TestWorldBuilder::new().with_block("gravel", GRAVEL).build();
+    world.settle_chunks();
+    world
+}
+
+#[test]
+fn gravel_column_falls_across_a_chunk_boundary() {
+    let mut world = gravel_world();
+    let (air, gravel) = (world.block_id("air"), world.block_id("gravel"));
+
+    // a shaft through the surface down into the chunk below, with the column straddling
+    // the chunk boundary above it
+    let column_x_z = IVec3::new(5, 0, 5);
+    let floor = CHUNK_BOUNDARY - 10;
+    let column = (CHUNK_BOUNDARY - 2)..(CHUNK_BOUNDARY + 3);
+    for y in (floor + 1)..column.start {
+        world.set_block(column_x_z.with_y(y), air);
+    }
+    for y in column.clone() {
+        world.set_block(column_x_z.with_y(y), gravel);
+    }
+    world.step_ticks(FALL_TICKS);
+
+    let height = column.len() as i32;
+    for y in (floor + 1)..column.end {
+        let expected = if y <= floor + height { gravel } else { air };
+        assert_eq!(
+            world.get_block(column_x_z.with_y(y)),
+            Some(expected),
+            "at y = {y}"
+        );
+    }
+}
+
+#[test]
+fn gravel_rests_on_solid_ground() {
+    let mut world = gravel_world();
+    let gravel = world.block_id("gravel");
+
+    let on_surface = IVec3::new(5, CHUNK_BOUNDARY + 1, 5);
+    world.set_block(on_surface, gravel);
+    world.step_ticks(FALL_TICKS);
+
+    assert_eq!(world.get_block(on_surface), Some(gravel));
+}
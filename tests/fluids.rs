@@ -0,0 +1,104 @@
+use b::prelude::*;
+use b::simulation_world::chunk::{meshing::common::FaceSide, CHUNK_SIDE_LENGTH};
+use b::test_support::{TestWorld, TestWorldBuilder};
+
+const SURFACE_CHUNK: IVec3 = IVec3::new(0, 2, 0);
+
+/// The sand layer at y = 64, which water dug into is held in by.
+const SOURCE: IVec3 = IVec3::new(5, 64, 5);
+
+/// Enough ticks for water to flow its full distance at the default tick delay.
+const FLOW_TICKS: u64 = 60;
+
+/// A world with a water source sunk into the surface, surrounded by sand.
+fn world_with_source() -> TestWorld {
+    let mut world = TestWorldBuilder::new().build();
+    world.settle_chunks();
+
+    let water = world.block_id("water");
+    world.set_block(SOURCE, water);
+    world.step_ticks(FLOW_TICKS);
+    world
+}
+
+/// The surface drop of the top face at a world position in the surface chunk.
+fn top_surface_drop(world: &mut TestWorld, world_pos: IVec3) -> Option<u8> {
+    let local = (world_pos - SURFACE_CHUNK * CHUNK_SIDE_LENGTH as i32).as_uvec3();
+    world
+        .transparent_faces(SURFACE_CHUNK)
+        .into_iter()
+        .find(|face| face.normal == FaceSide::Top && face.position == local)
+        .map(|face| face.surface_drop)
+}
+
+#[test]
+fn water_stops_at_solid_blocks() {
+    let mut world = world_with_source();
+
+    let sand = world.block_id("sand");
+    for offset in [IVec3::X, IVec3::NEG_X, IVec3::Z, IVec3::NEG_Z] {
+        assert_eq!(world.get_block(SOURCE + offset), Some(sand));
+    }
+
+    // nothing to rest on beside it on the surface, so it doesn't climb out either
+    let air = world.block_id("air");
+    assert_eq!(world.get_block(SOURCE + IVec3::new(1, 1, 0)), Some(air));
+}
+
+#[test]
+fn water_spreads_into_a_dug_out_neighbor() {
+    let mut world = world_with_source();
+
+    let air = world.block_id("air");
+    for x in 1..=3 {
+        world.set_block(SOURCE + IVec3::new(x, 0, 0), air);
+    }
+    world.step_ticks(FLOW_TICKS);
+
+    for distance in 1..=3 {
+        let flowing = world.block_id(&format!("water_flowing_{distance}"));
+        assert_eq!(
+            world.get_block(SOURCE + IVec3::new(distance, 0, 0)),
+            Some(flowing)
+        );
+    }
+}
+
+#[test]
+fn flowing_water_dries_up_without_its_source() {
+    let mut world = world_with_source();
+
+    let (air, sand) = (world.block_id("air"), world.block_id("sand"));
+    for x in 1..=3 {
+        world.set_block(SOURCE + IVec3::new(x, 0, 0), air);
+    }
+    world.step_ticks(FLOW_TICKS);
+
+    world.set_block(SOURCE, sand);
+    world.step_ticks(FLOW_TICKS);
+
+    for x in 1..=3 {
+        assert_eq!(world.get_block(SOURCE + IVec3::new(x, 0, 0)), Some(air));
+    }
+}
+
+#[test]
+fn flowing_water_surface_drops_with_distance() {
+    let mut world = world_with_source();
+
+    let air = world.block_id("air");
+    for x in 1..=3 {
+        world.set_block(SOURCE + IVec3::new(x, 0, 0), air);
+    }
+    world.step_ticks(FLOW_TICKS);
+    world.settle_chunks();
+
+    // sources are full height, each flow level sits lower than the last
+    let drops: Vec<_> = (0..=3)
+        .map(|x| top_surface_drop(&mut world, SOURCE + IVec3::new(x, 0, 0)))
+        .collect();
+    assert_eq!(drops[0], Some(0));
+    for pair in drops.windows(2) {
+        assert!(pair[0].unwrap() < pair[1].unwrap(), "{drops:?}");
+    }
+}
@@ -0,0 +1,91 @@
+use b::simulation_world::input::{
+    resources::{
+        GamepadAxes, GamepadBackendResource, GamepadEvent, Input, KeyChord, MockGamepad, Modifiers,
+    },
+    systems::{gamepad_events_system, update_action_state_system},
+    ActionStateResource, AxisDirection, Buttons, GamepadAxis, GamepadButton, GamepadStick,
+    InputActionMapResource, SimulationAction,
+};
+use bevy_ecs::{system::RunSystemOnce, world::World};
+use glam::Vec2;
+use winit::{event::MouseButton, keyboard::PhysicalKey};
+
+/// A world with everything the gamepad and action systems need, driven by a mock pad.
+fn gamepad_world(map: InputActionMapResource) -> (World, MockGamepad) {
+    let pad = MockGamepad::default();
+    let mut world = World::new();
+    world.insert_resource(map);
+    world.insert_resource(ActionStateResource::default());
+    world.insert_resource(Buttons::<PhysicalKey>::default());
+    world.insert_resource(Buttons::<MouseButton>::default());
+    world.insert_resource(Buttons::<GamepadButton>::default());
+    world.insert_resource(Buttons::<(GamepadAxis, AxisDirection)>::default());
+    world.insert_resource(GamepadAxes::default());
+    world.insert_resource(GamepadBackendResource::new(pad.clone()));
+    (world, pad)
+}
+
+fn frame(world: &mut World) {
+    world.run_system_once(gamepad_events_system).unwrap();
+    world.run_system_once(update_action_state_system).unwrap();
+}
+
+#[test]
+fn gamepad_buttons_and_axes_fire_bound_actions() {
+    let mut map = InputActionMapResource::builtin();
+    let trigger = Input::GamepadAxis(GamepadAxis::RightZ, AxisDirection::Positive);
+    map.bind(
+        KeyChord::new(trigger, Modifiers::NONE),
+        SimulationAction::ToggleChunkBorders,
+    );
+    let (mut world, pad) = gamepad_world(map);
+
+    pad.press(GamepadButton::RightTrigger2);
+    pad.move_axis(GamepadAxis::RightZ, 0.9);
+    frame(&mut world);
+    let actions = world.resource::<ActionStateResource>();
+    assert!(actions.just_happened(SimulationAction::BreakVoxel));
+    assert!(actions.just_happened(SimulationAction::ToggleChunkBorders));
+
+    // a trigger resting just under the threshold lets go of its action
+    pad.release(GamepadButton::RightTrigger2);
+    pad.move_axis(GamepadAxis::RightZ, 0.3);
+    frame(&mut world);
+    let actions = world.resource::<ActionStateResource>();
+    assert!(actions.just_ended(SimulationAction::BreakVoxel));
+    assert!(actions.just_ended(SimulationAction::ToggleChunkBorders));
+
+    // unplugging the pad releases whatever it held
+    pad.press(GamepadButton::Start);
+    frame(&mut world);
+    assert!(world
+        .resource::<ActionStateResource>()
+        .is_ongoing(SimulationAction::TogglePause));
+    pad.push(GamepadEvent::Disconnected);
+    frame(&mut world);
+    assert!(world
+        .resource::<ActionStateResource>()
+        .just_ended(SimulationAction::TogglePause));
+}
+
+#[test]
+fn sticks_use_a_radial_deadzone() {
+    let mut axes = GamepadAxes::default();
+
+    axes.set(GamepadAxis::LeftStickX, 0.1);
+    axes.set(GamepadAxis::LeftStickY, -0.1);
+    assert_eq!(axes.stick(GamepadStick::Left), Vec2::ZERO);
+
+    // diagonals keep their direction and full deflection reaches 1
+    axes.set(GamepadAxis::LeftStickX, 1.0);
+    axes.set(GamepadAxis::LeftStickY, 1.0);
+    let stick = axes.stick(GamepadStick::Left);
+    assert!((stick.length() - 1.0).abs() < 1e-5);
+    assert!((stick.x - stick.y).abs() < 1e-5);
+
+    // single axes rescale from the deadzone edge
+    axes.set(GamepadAxis::RightZ, axes.deadzone);
+    assert_eq!(axes.get(GamepadAxis::RightZ), 0.0);
+    axes.set(GamepadAxis::RightZ, -1.0);
+    assert_eq!(axes.get(GamepadAxis::RightZ), -1.0);
+}
@@ -0,0 +1,33 @@
+//! Golden-hash tests for terrain generation.
+//!
+//! Every shaper in the `TerrainGeneratorLibrary`, paired with every biome generator and
+//! painter, generates a fixed set of chunks, which are hashed and compared against
+//! `tests/golden/terrain_generation.txt`. When a change to generation is intended,
+//! re-bless the hashes with:
+//!
+//! ```sh
+//! BLESS=1 cargo test --test generation_golden
+//! ```
+
+use b::test_support::{check_golden_file, load_snapshot_registries, snapshot_generator_library};
+use std::path::Path;
+
+#[test]
+fn terrain_generators_match_golden_hashes() {
+    let (block_registry, biome_registry) = load_snapshot_registries();
+    let snapshots = snapshot_generator_library(&block_registry, &biome_registry);
+
+    let golden = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden/terrain_generation.txt");
+    if let Err(e) = check_golden_file(&golden, &snapshots) {
+        panic!("{e}");
+    }
+}
+
+#[test]
+fn terrain_generation_is_deterministic() {
+    let (block_registry, biome_registry) = load_snapshot_registries();
+
+    let first = snapshot_generator_library(&block_registry, &biome_registry);
+    let second = snapshot_generator_library(&block_registry, &biome_registry);
+    assert_eq!(first, second);
+}
@@ -0,0 +1,102 @@
+use b::prelude::*;
+use b::render_world::types::{FaceExtent, PackedFace};
+use b::simulation_world::chunk::{
+    cycle_active_chunk_mesher_system,
+    meshing::{
+        common::{AoLevel, FaceSide},
+        greedy::merge_coplanar_faces,
+    },
+    ActiveChunkMesher, ChunkMesher,
+};
+use b::test_support::TestWorldBuilder;
+use bevy_ecs::system::RunSystemOnce;
+
+const SAND_SURFACE_CHUNK: IVec3 = IVec3::new(0, 2, 0);
+
+#[test]
+fn coplanar_faces_merge_into_stretched_quads() {
+    let flat = [AoLevel::None; 4];
+    let mut faces = Vec::new();
+    for x in 0..32 {
+        for z in 0..32 {
+            faces.push(PackedFace::new(x, 5, z, FaceSide::Top, flat, 7));
+        }
+    }
+    // a shaded face and a different texture break up the layer above
+    faces.push(PackedFace::new(
+        0,
+        6,
+        0,
+        FaceSide::Top,
+        [AoLevel::High, AoLevel::None, AoLevel::None, AoLevel::None],
+        7,
+    ));
+    faces.push(PackedFace::new(1, 6, 0, FaceSide::Top, flat, 8));
+    faces.push(PackedFace::new(2, 6, 0, FaceSide::Top, flat, 7));
+
+    let (merged, extents) = merge_coplanar_faces(&faces, false);
+    assert_eq!(merged.len(), 4);
+    assert_eq!(extents.len(), merged.len());
+
+    let full = merged
+        .iter()
+        .zip(&extents)
+        .map(|(face, &extent)| face.unpack_with_extent(extent))
+        .find(|face| face.position.y == 5)
+        .unwrap();
+    assert_eq!((full.width, full.height), (32, 32));
+    assert_eq!(full.position.to_array(), [0, 5, 0]);
+
+    let corners = full.corners(false);
+    let min = corners.iter().fold(Vec3::MAX, |acc, c| acc.min(c.position));
+    let max = corners.iter().fold(Vec3::MIN, |acc, c| acc.max(c.position));
+    assert_eq!(min, Vec3::new(0.0, 6.0, 0.0));
+    assert_eq!(max, Vec3::new(32.0, 6.0, 32.0));
+    assert!(corners.iter().any(|c| c.uv == Vec2::new(32.0, 32.0)));
+
+    // merging never changes the covered area
+    let area: u32 = extents
+        .iter()
+        .map(|extent| extent.width() * extent.height())
+        .sum();
+    assert_eq!(area as usize, faces.len());
+
+    // plain faces stay one word, merged ones only add a half word extent
+    assert_eq!(size_of::<PackedFace>(), 4);
+    assert_eq!(size_of::<FaceExtent>(), 2);
+}
+
+#[test]
+fn greedy_meshing_shrinks_flat_terrain_and_can_be_switched_live() {
+    let mut dense_world = TestWorldBuilder::new().build();
+    dense_world.settle_chunks();
+    let dense = dense_world.mesh_face_counts(SAND_SURFACE_CHUNK).unwrap();
+
+    let mut greedy_world = TestWorldBuilder::new()
+        .with_mesher(ChunkMesher::Greedy)
+        .build();
+    greedy_world.settle_chunks();
+    let greedy = greedy_world.mesh_face_counts(SAND_SURFACE_CHUNK).unwrap();
+
+    assert!(dense.opaque >= 32 * 32, "{dense:?}");
+    assert!(greedy.opaque > 0);
+    assert!(
+        greedy.opaque * 100 < dense.opaque,
+        "{greedy:?} vs {dense:?}"
+    );
+
+    // switching back remeshes the loaded chunks with the dense mesher
+    greedy_world
+        .world()
+        .run_system_once(cycle_active_chunk_mesher_system)
+        .unwrap();
+    assert_eq!(
+        *greedy_world.world().resource::<ActiveChunkMesher>(),
+        ActiveChunkMesher(ChunkMesher::Dense)
+    );
+    greedy_world.settle_chunks();
+    assert_eq!(
+        greedy_world.mesh_face_counts(SAND_SURFACE_CHUNK).unwrap(),
+        dense
+    );
+}
@@ -0,0 +1,98 @@
+use b::headless::{CameraPath, HeadlessApp, HeadlessConfig};
+use b::simulation_world::chunk::ChunkMesher;
+use std::path::PathBuf;
+
+fn parse(args: &[&str]) -> Result<HeadlessConfig, String> {
+    HeadlessConfig::from_args(args.iter().map(|arg| arg.to_string()))
+}
+
+#[test]
+fn from_args_reads_every_flag() {
+    let config = parse(&[
+        "--ticks",
+        "42",
+        "--path",
+        "circle",
+        "--world",
+        "saves/test",
+        "--export-mesh",
+        "out.glb",
+        "--mesher",
+        "greedy",
+        "--replay",
+        "run.ron",
+        "--command",
+        "/tp 0 80 0",
+        "--command",
+        "/time",
+        "--no-settle",
+    ])
+    .unwrap();
+
+    assert_eq!(config.ticks, 42);
+    assert!(matches!(config.camera_path, CameraPath::Circle { .. }));
+    assert_eq!(config.world, Some(PathBuf::from("saves/test")));
+    assert_eq!(config.export_mesh, Some(PathBuf::from("out.glb")));
+    assert_eq!(config.mesher, ChunkMesher::Greedy);
+    assert_eq!(config.replay, Some(PathBuf::from("run.ron")));
+    assert_eq!(config.commands, ["/tp 0 80 0", "/time"]);
+    assert!(!config.settle);
+}
+
+#[test]
+fn from_args_without_flags_uses_the_defaults() {
+    let config = parse(&[]).unwrap();
+    let default = HeadlessConfig::default();
+
+    assert_eq!(config.ticks, default.ticks);
+    assert_eq!(config.camera_path, default.camera_path);
+    assert_eq!(config.settle, default.settle);
+    assert!(config.world.is_none());
+    assert!(config.commands.is_empty());
+}
+
+#[test]
+fn from_args_ignores_unrelated_args() {
+    let config = parse(&["target/debug/b", "--headless", "--ticks", "7", "extra"]).unwrap();
+    assert_eq!(config.ticks, 7);
+    assert!(config.export_mesh.is_none());
+}
+
+#[test]
+fn from_args_rejects_missing_values() {
+    for flag in [
+        "--ticks",
+        "--path",
+        "--world",
+        "--export-mesh",
+        "--mesher",
+        "--replay",
+        "--command",
+    ] {
+        assert!(parse(&[flag]).is_err(), "{flag} without a value");
+    }
+}
+
+#[test]
+fn from_args_rejects_bad_values() {
+    assert!(parse(&["--ticks", "lots"]).is_err());
+    assert!(parse(&["--path", "zigzag"]).is_err());
+    assert!(parse(&["--mesher", "marching"]).is_err());
+}
+
+#[test]
+fn short_run_reports_its_chunks() {
+    let config = HeadlessConfig {
+        ticks: 10,
+        settle: false,
+        ..Default::default()
+    };
+
+    let report = HeadlessApp::run(&config).unwrap();
+
+    assert_eq!(report.ticks, 10);
+    assert!(report.chunks.total() > 0, "{:?}", report.chunks);
+    // without settling most chunks are still in flight, but the empty ones load right away
+    let loaded = report.chunks.loaded + report.chunks.loaded_empty;
+    assert!(loaded > 0, "{:?}", report.chunks);
+}
@@ -0,0 +1,110 @@
+use b::simulation_world::{
+    input::{
+        resources::InputRecording, ActionStateResource, CursorMovement, InputRecorder, InputReplay,
+        SimulationAction, SimulationAxis,
+    },
+    player::{ActiveCamera, CameraComponent},
+    time::FrameClock,
+};
+use b::test_support::{TestWorld, TestWorldBuilder};
+use glam::{DVec2, Vec3};
+use std::time::Duration;
+
+/// The active camera's position and yaw.
+fn camera(world: &mut TestWorld) -> (Vec3, f32) {
+    let world = world.world();
+    let entity = world.resource::<ActiveCamera>().0;
+    let camera = world.get::<CameraComponent>(entity).unwrap();
+    (camera.position, camera.yaw)
+}
+
+#[test]
+fn replaying_a_recording_reproduces_it_exactly() {
+    let mut recorded = TestWorldBuilder::new().build();
+    recorded.world().insert_resource(InputRecorder::default());
+    recorded.world().resource_mut::<FrameClock>().fixed_step = Some(Duration::from_millis(10));
+
+    // hold sprint and forward for a few frames while looking around, then let go
+    for frame in 0..6 {
+        let world = recorded.world();
+        let mut actions = world.resource_mut::<ActionStateResource>();
+        actions.clear();
+        match frame {
+            0 => {
+                actions.press(SimulationAction::MoveFaster);
+                actions.hold(SimulationAction::MoveFaster);
+            }
+            4 => actions.release(SimulationAction::MoveFaster),
+            _ => {}
+        }
+        if frame < 4 {
+            actions.set_axis(SimulationAxis::MoveForward, 0.01);
+        }
+        actions.set_axis(SimulationAxis::LookRight, frame as f32 * 0.5);
+        let mut movement = world.resource_mut::<CursorMovement>();
+        movement.reset_deltas();
+        movement.adjust_mouse_delta(DVec2::new(frame as f64 * 3.0, -1.0));
+
+        recorded.step();
+    }
+
+    let recording = recorded
+        .world()
+        .remove_resource::<InputRecorder>()
+        .unwrap()
+        .recording;
+    assert_eq!(recording.len(), 6);
+    assert_eq!(recording.frames[0].pressed, [SimulationAction::MoveFaster]);
+    assert_eq!(recording.frames[4].released, [SimulationAction::MoveFaster]);
+    assert_eq!(
+        recording.frames[3].axes,
+        [
+            (SimulationAxis::MoveForward, 0.01),
+            (SimulationAxis::LookRight, 1.5)
+        ]
+    );
+    assert!(recording
+        .frames
+        .iter()
+        .all(|frame| frame.delta == Duration::from_millis(10)));
+
+    // goes through a file like a real session would
+    let path = std::env::temp_dir().join(format!("b_input_{}.ron", std::process::id()));
+    recording.save(&path).unwrap();
+    let loaded = InputRecording::load(&path);
+    let _ = std::fs::remove_file(&path);
+    let loaded = loaded.unwrap();
+    assert_eq!(loaded, recording);
+
+    // the headless camera follows the replay, and re-recording it gives the same frames
+    let mut replayed = TestWorldBuilder::new().build();
+    let start = camera(&mut replayed);
+    replayed.world().insert_resource(InputReplay::new(loaded));
+    replayed.world().insert_resource(InputRecorder::default());
+    let tick_step = replayed.world().resource::<FrameClock>().fixed_step;
+
+    for _ in 0..recording.len() {
+        replayed.step();
+    }
+    let rerecorded = replayed
+        .world()
+        .resource::<InputRecorder>()
+        .recording
+        .clone();
+    assert_eq!(rerecorded, recording);
+
+    let moved = camera(&mut replayed);
+    assert!(moved.0.distance(start.0) > 0.0);
+    assert_ne!(moved.1, start.1);
+
+    // once the recording runs out, the replay hands the clock back
+    replayed.step();
+    assert!(!replayed.world().contains_resource::<InputReplay>());
+    assert_eq!(
+        replayed.world().resource::<FrameClock>().fixed_step,
+        tick_step
+    );
+    let actions = replayed.world().resource::<ActionStateResource>();
+    assert!(!actions.is_ongoing(SimulationAction::MoveFaster));
+    assert_eq!(actions.axis(SimulationAxis::MoveForward), 0.0);
+}
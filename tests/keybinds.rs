@@ -0,0 +1,209 @@
+use b::simulation_world::{
+    input::{
+        resources::{
+            AxisBinding, AxisSettings, AxisSource, GamepadAxes, Input, KeyChord, KeybindsError,
+            Modifier, Modifiers,
+        },
+        systems::{update_action_state_system, update_axis_state_system},
+        ActionStateResource, AxisDirection, Buttons, CursorMovement, GamepadAxis, GamepadButton,
+        InputActionMapResource, SimulationAction, SimulationAxis,
+    },
+    time::FrameClock,
+};
+use bevy_ecs::{system::RunSystemOnce, world::World};
+use glam::DVec2;
+use std::time::Duration;
+use winit::{
+    event::MouseButton,
+    keyboard::{KeyCode, PhysicalKey},
+};
+
+#[test]
+fn builtin_keybinds_roundtrip_through_ron() {
+    let builtin = InputActionMapResource::builtin();
+    let ron_string = builtin.to_ron_string().unwrap();
+    let reloaded = InputActionMapResource::from_ron_str(&ron_string).unwrap();
+
+    assert_eq!(
+        builtin.iter().collect::<Vec<_>>(),
+        reloaded.iter().collect::<Vec<_>>()
+    );
+
+    // saving goes through a temp file and leaves only the target behind
+    let path = std::env::temp_dir().join(format!("b_keybinds_{}.ron", std::process::id()));
+    let mut edited = reloaded;
+    edited.bind(KeyChord::key(KeyCode::KeyJ), SimulationAction::TogglePause);
+    edited.save(&path).unwrap();
+    assert!(!path.with_extension("ron.tmp").exists());
+
+    let saved = InputActionMapResource::from_ron_str(&std::fs::read_to_string(&path).unwrap());
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(
+        saved
+            .unwrap()
+            .actions_for(&KeyChord::key(KeyCode::KeyJ).input, Modifiers::NONE),
+        [SimulationAction::TogglePause]
+    );
+}
+
+#[test]
+fn chords_pick_the_most_specific_binding() {
+    let map = InputActionMapResource::builtin();
+    let z = KeyChord::key(KeyCode::KeyZ).input;
+    let ctrl = Modifiers::NONE.with(Modifier::Ctrl);
+
+    assert_eq!(
+        map.actions_for(&z, Modifiers::NONE),
+        [SimulationAction::SelectFirstCorner]
+    );
+    assert_eq!(map.actions_for(&z, ctrl), [SimulationAction::UndoEdit]);
+    assert_eq!(
+        map.actions_for(&z, ctrl.with(Modifier::Shift)),
+        [SimulationAction::RedoEdit]
+    );
+
+    // unrelated modifiers don't block plain bindings
+    let f = KeyChord::key(KeyCode::KeyF).input;
+    assert_eq!(
+        map.actions_for(&f, Modifiers::NONE.with(Modifier::Shift)),
+        [SimulationAction::WorldEditFill]
+    );
+}
+
+#[test]
+fn one_input_can_fire_several_actions() {
+    let mut map = InputActionMapResource::from_ron_str(
+        "(bindings: [(input: MouseButton(Middle), actions: [PauseGameTime, ToggleDiagnostics])])",
+    )
+    .unwrap();
+    let middle = KeyChord::mouse(MouseButton::Middle);
+
+    assert_eq!(
+        map.actions_for(&middle.input, Modifiers::NONE),
+        [
+            SimulationAction::PauseGameTime,
+            SimulationAction::ToggleDiagnostics
+        ]
+    );
+
+    assert!(map.unbind(middle, SimulationAction::PauseGameTime));
+    assert!(!map.unbind(middle, SimulationAction::PauseGameTime));
+    assert_eq!(
+        map.actions_for(&middle.input, Modifiers::NONE),
+        [SimulationAction::ToggleDiagnostics]
+    );
+}
+
+#[test]
+fn conflicting_chords_are_rejected_at_load_time() {
+    let result = InputActionMapResource::from_ron_str(
+        "(bindings: [
+            (input: Key(Code(KeyQ)), modifiers: [Ctrl], actions: [UndoEdit]),
+            (input: Key(Code(KeyQ)), actions: [SelectFirstCorner]),
+            (input: Key(Code(KeyQ)), modifiers: [Ctrl], actions: [RedoEdit]),
+        ])",
+    );
+
+    let Err(KeybindsError::Conflicts(conflicts)) = result else {
+        panic!("expected a conflict, got {result:?}");
+    };
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(
+        conflicts[0].chord,
+        KeyChord::key(KeyCode::KeyQ).with(Modifier::Ctrl)
+    );
+    assert_eq!(conflicts[0].first, [SimulationAction::UndoEdit]);
+    assert_eq!(conflicts[0].second, [SimulationAction::RedoEdit]);
+}
+
+#[test]
+fn action_mapper_switches_chords_when_modifiers_change() {
+    let mut world = World::new();
+    world.insert_resource(InputActionMapResource::builtin());
+    world.insert_resource(ActionStateResource::default());
+    world.insert_resource(Buttons::<MouseButton>::default());
+    world.insert_resource(Buttons::<GamepadButton>::default());
+    world.insert_resource(Buttons::<(GamepadAxis, AxisDirection)>::default());
+
+    let mut keys = Buttons::<PhysicalKey>::default();
+    keys.press(PhysicalKey::Code(KeyCode::ControlLeft));
+    keys.press(PhysicalKey::Code(KeyCode::KeyZ));
+    world.insert_resource(keys);
+
+    world.run_system_once(update_action_state_system).unwrap();
+    let actions = world.resource::<ActionStateResource>();
+    assert!(actions.just_happened(SimulationAction::UndoEdit));
+    assert!(!actions.is_ongoing(SimulationAction::SelectFirstCorner));
+
+    // letting go of ctrl while z is still down ends the chord
+    let mut keys = world.resource_mut::<Buttons<PhysicalKey>>();
+    keys.swap_previous();
+    keys.release(PhysicalKey::Code(KeyCode::ControlLeft));
+
+    world.run_system_once(update_action_state_system).unwrap();
+    let actions = world.resource::<ActionStateResource>();
+    assert!(actions.just_ended(SimulationAction::UndoEdit));
+    assert!(actions.is_ongoing(SimulationAction::SelectFirstCorner));
+    assert!(!actions.just_happened(SimulationAction::SelectFirstCorner));
+}
+
+#[test]
+fn axes_sum_their_bindings_and_apply_settings() {
+    let mut map = InputActionMapResource::builtin();
+    map.bind_axis(
+        AxisBinding::new(
+            SimulationAxis::ZoomIn,
+            AxisSource::Composite {
+                positive: Input::Key(PhysicalKey::Code(KeyCode::Equal)),
+                negative: Input::Key(PhysicalKey::Code(KeyCode::Minus)),
+            },
+        )
+        .scaled(10.0),
+    );
+    map.set_axis_settings(
+        SimulationAxis::LookUp,
+        AxisSettings {
+            sensitivity: 2.0,
+            invert: true,
+        },
+    );
+    let reloaded = InputActionMapResource::from_ron_str(&map.to_ron_string().unwrap()).unwrap();
+    assert_eq!(
+        reloaded.iter_axes().collect::<Vec<_>>(),
+        map.iter_axes().collect::<Vec<_>>()
+    );
+    assert!(reloaded.axis_settings(SimulationAxis::LookUp).invert);
+
+    let mut world = World::new();
+    world.insert_resource(reloaded);
+    world.insert_resource(ActionStateResource::default());
+    world.insert_resource(Buttons::<MouseButton>::default());
+    world.insert_resource(Buttons::<GamepadButton>::default());
+    world.insert_resource(Buttons::<(GamepadAxis, AxisDirection)>::default());
+    world.insert_resource(GamepadAxes::default());
+
+    let mut clock = FrameClock::default();
+    clock.delta = Duration::from_millis(500);
+    world.insert_resource(clock);
+
+    // holding both W and S cancels out, while held keys scale with the frame time
+    let mut keys = Buttons::<PhysicalKey>::default();
+    keys.press(PhysicalKey::Code(KeyCode::KeyW));
+    keys.press(PhysicalKey::Code(KeyCode::KeyS));
+    keys.press(PhysicalKey::Code(KeyCode::KeyD));
+    keys.press(PhysicalKey::Code(KeyCode::Minus));
+    world.insert_resource(keys);
+
+    // mouse motion is already per frame, so it isn't
+    let mut movement = CursorMovement::default();
+    movement.adjust_mouse_delta(DVec2::new(0.0, 10.0));
+    world.insert_resource(movement);
+
+    world.run_system_once(update_axis_state_system).unwrap();
+    let actions = world.resource::<ActionStateResource>();
+    assert_eq!(actions.axis(SimulationAxis::MoveForward), 0.0);
+    assert_eq!(actions.axis(SimulationAxis::MoveRight), 0.5);
+    assert_eq!(actions.axis(SimulationAxis::ZoomIn), -5.0);
+    // builtin mouse look is -0.1 degrees per pixel, then doubled and inverted
+    assert!((actions.axis(SimulationAxis::LookUp) - 2.0).abs() < 1e-5);
+}
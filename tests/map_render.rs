@@ -0,0 +1,73 @@
+use b::map_render::{BlockColorPalette, MapArea, MapChunkSource, MapLayer, SurfaceMap};
+use b::prelude::*;
+use b::simulation_world::terrain::{
+    ActiveBiomeGenerator, ActiveTerrainPainter, ClimateNoiseGenerator, SuperflatShaper,
+    TerrainPipeline,
+};
+use b::test_support::{load_snapshot_registries, SNAPSHOT_SEED};
+use image::{Rgba, RgbaImage};
+use std::sync::Arc;
+
+/// A superflat area crossing chunk borders on both axes, including negative coordinates.
+const AREA: MapArea = MapArea {
+    min: IVec2::new(-40, -8),
+    max: IVec2::new(39, 47),
+};
+
+fn superflat_surface() -> (SurfaceMap, BlockColorPalette) {
+    let (block_registry, biome_registry) = load_snapshot_registries();
+
+    // without textures every block uses the "missing" texture, so they all share a color
+    let images = vec![RgbaImage::from_pixel(2, 2, Rgba([100, 150, 200, 255]))];
+    let palette = BlockColorPalette::from_textures(&block_registry, &images);
+
+    let source = MapChunkSource {
+        pipeline: TerrainPipeline {
+            climate: Arc::new(ClimateNoiseGenerator::new(SNAPSHOT_SEED)),
+            biome: ActiveBiomeGenerator::default().0,
+            shaper: Arc::new(SuperflatShaper::new()),
+            painter: ActiveTerrainPainter::default().0,
+        },
+        block_registry,
+        biome_registry,
+        world_save: None,
+    };
+
+    (SurfaceMap::sample(AREA, &source), palette)
+}
+
+#[test]
+fn superflat_maps_to_a_flat_evenly_shaded_image() {
+    let (surface, palette) = superflat_surface();
+
+    let height = surface.columns[0]
+        .height
+        .expect("superflat column has no surface");
+    assert!(surface.columns.iter().all(|c| c.height == Some(height)));
+
+    let image = surface.render(MapLayer::Surface, &palette, 0.5);
+    assert_eq!(image.dimensions(), (AREA.width(), AREA.depth()));
+
+    let first = *image.get_pixel(0, 0);
+    assert!(image.pixels().all(|pixel| *pixel == first));
+}
+
+#[test]
+fn overlays_are_blended_over_the_surface() {
+    let (surface, palette) = superflat_surface();
+    let plain = surface.render(MapLayer::Surface, &palette, 0.5);
+
+    for layer in MapLayer::ALL {
+        assert_eq!(layer.name().parse::<MapLayer>(), Ok(layer));
+        if layer == MapLayer::Surface {
+            continue;
+        }
+
+        let overlaid = surface.render(layer, &palette, 0.5);
+        assert_ne!(
+            overlaid, plain,
+            "{layer} overlay left the surface unchanged"
+        );
+        assert_eq!(surface.render(layer, &palette, 0.0), plain);
+    }
+}
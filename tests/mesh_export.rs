@@ -0,0 +1,113 @@
+use b::prelude::*;
+use b::render_world::types::{PackedFace, QUAD_TRIANGLES};
+use b::simulation_world::{
+    chunk::{
+        meshing::common::{AoLevel, FaceSide},
+        WORLD_MAX_Y_CHUNK, WORLD_MIN_Y_CHUNK,
+    },
+    mesh_export::{atlas_path, ChunkRange, MeshExportEvent},
+};
+use b::test_support::TestWorldBuilder;
+use std::path::PathBuf;
+
+fn temp_export_path(name: &str) -> PathBuf {
+    std::env::temp_dir()
+        .join(format!("b_mesh_export_{}", std::process::id()))
+        .join(name)
+}
+
+#[test]
+fn packed_faces_decode_to_what_the_shader_draws() {
+    let ao = [AoLevel::None, AoLevel::Low, AoLevel::Medium, AoLevel::High];
+
+    for side in FaceSide::ALL {
+        let face = PackedFace::new(3, 7, 31, side, ao, 42).unpack();
+        assert_eq!(face.position.to_array(), [3, 7, 31]);
+        assert_eq!(face.normal, side);
+        assert_eq!(face.ao_levels, ao);
+        assert_eq!(face.texture_id, 42);
+
+        let normal = IVec3::from_array(side.to_vec3()).as_vec3();
+        let corners = face.corners(false);
+
+        // every corner sits on the face's side of the block
+        let plane = |p: Vec3| (p - Vec3::new(3.0, 7.0, 31.0)).dot(normal);
+        let expected_plane = if normal.max_element() > 0.0 { 1.0 } else { 0.0 };
+        for corner in &corners {
+            assert_eq!(plane(corner.position), expected_plane, "{side:?}");
+            assert_eq!(corner.normal, normal);
+        }
+
+        // both triangles wind counter-clockwise around the normal
+        for triangle in QUAD_TRIANGLES.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| corners[triangle[i]].position);
+            assert_eq!((b - a).cross(c - a).normalize(), normal, "{side:?}");
+        }
+
+        assert_eq!(corners.map(|c| c.ao), [1.0, 0.8, 0.5, 0.2]);
+    }
+
+    // transparent faces lower their top edge instead of using AO
+    let water = PackedFace::new_transparent(0, 5, 0, FaceSide::Front, 4, 1).unpack();
+    assert_eq!(water.surface_drop, 4);
+    let heights: Vec<f32> = water
+        .corners(true)
+        .iter()
+        .map(|corner| corner.position.y)
+        .collect();
+    assert_eq!(heights, [5.0, 5.0, 5.5, 5.5]);
+}
+
+#[test]
+fn loaded_chunks_export_as_obj_and_glb() {
+    let mut world = TestWorldBuilder::new().build();
+    world.settle_chunks();
+    let glass = world.block_id("glass");
+    world.set_block(IVec3::new(4, 65, 4), glass);
+    world.settle_chunks();
+
+    let column = ChunkRange::from_corners(
+        IVec3::new(0, WORLD_MIN_Y_CHUNK, 0),
+        IVec3::new(0, WORLD_MAX_Y_CHUNK, 0),
+    );
+    let expected_faces: usize = (WORLD_MIN_Y_CHUNK..=WORLD_MAX_Y_CHUNK)
+        .filter_map(|y| world.mesh_face_counts(IVec3::new(0, y, 0)))
+        .map(|counts| counts.total())
+        .sum();
+    assert!(expected_faces > 0);
+
+    // obj, through the headless app
+    let obj_path = temp_export_path("column.obj");
+    let summary = world.app.export_meshes(&obj_path, Some(column)).unwrap();
+    assert_eq!(summary.faces, expected_faces);
+
+    let obj = std::fs::read_to_string(&obj_path).unwrap();
+    let count = |prefix: &str| obj.lines().filter(|l| l.starts_with(prefix)).count();
+    assert_eq!(count("v "), expected_faces * 4);
+    assert_eq!(count("f "), expected_faces * 2);
+    assert!(obj.contains("usemtl transparent"));
+    assert!(obj_path.with_extension("mtl").exists());
+    assert!(atlas_path(&obj_path).exists());
+
+    // glb, through the message
+    let glb_path = temp_export_path("loaded.glb");
+    world.world().write_message(MeshExportEvent {
+        path: glb_path.clone(),
+        range: None,
+    });
+    world.step();
+
+    let glb = std::fs::read(&glb_path).unwrap();
+    assert_eq!(&glb[..4], b"glTF");
+    assert_eq!(
+        u32::from_le_bytes(glb[8..12].try_into().unwrap()) as usize,
+        glb.len()
+    );
+    let json_len = u32::from_le_bytes(glb[12..16].try_into().unwrap()) as usize;
+    let json = std::str::from_utf8(&glb[20..20 + json_len]).unwrap();
+    assert!(json.contains(r#""POSITION""#));
+    assert!(json.contains(r#""material":1"#));
+    assert!(json.contains(r#""mimeType":"image/png""#));
+
+    let _ = std::fs::remove_dir_all(obj_path.parent().unwrap());
+}
@@ -0,0 +1,71 @@
+use b::ecs_core::state_machine::{CurrentState, GameState, NextState};
+use b::simulation_world::{
+    chunk::ChunkStateManager,
+    terrain::WorldSeed,
+    time::{simulation_tick::SimulationTick, WorldClockResource},
+    user_interface::screens::menu::menu_actions::start_new_world,
+};
+use b::test_support::{TestWorld, TestWorldBuilder};
+
+fn set_game_state(world: &mut TestWorld, state: GameState) {
+    world.world().resource_mut::<NextState<GameState>>().val = Some(state);
+    world.step();
+    assert_eq!(
+        world.world().resource::<CurrentState<GameState>>().val,
+        state
+    );
+}
+
+fn tick(world: &mut TestWorld) -> u64 {
+    world.world().resource::<SimulationTick>().tick
+}
+
+#[test]
+fn nothing_ticks_while_paused() {
+    let mut world = TestWorldBuilder::new().build();
+    set_game_state(&mut world, GameState::Playing);
+
+    set_game_state(&mut world, GameState::Paused);
+    let paused_at = tick(&mut world);
+    let time_of_day = world.world().resource::<WorldClockResource>().time_of_day;
+
+    world.step_ticks(20);
+    assert_eq!(tick(&mut world), paused_at);
+    assert_eq!(
+        world.world().resource::<WorldClockResource>().time_of_day,
+        time_of_day
+    );
+
+    // resuming picks up where it left off instead of catching up on the paused time
+    set_game_state(&mut world, GameState::Playing);
+    world.step();
+    assert!(
+        tick(&mut world) - paused_at <= 2,
+        "{}",
+        tick(&mut world) - paused_at
+    );
+}
+
+#[test]
+fn a_new_world_regenerates_every_chunk() {
+    let mut world = TestWorldBuilder::new().build();
+    world.settle_chunks();
+    let before = world.chunk_counts().total();
+    assert!(before > 0);
+
+    start_new_world(world.world());
+    assert!(world
+        .world()
+        .resource::<ChunkStateManager>()
+        .chunk_states
+        .is_empty());
+    let seed = world.world().resource::<WorldSeed>().0;
+
+    world.settle_chunks();
+    assert_eq!(world.chunk_counts().total(), before);
+    assert_eq!(world.world().resource::<WorldSeed>().0, seed);
+    assert_eq!(
+        world.world().resource::<CurrentState<GameState>>().val,
+        GameState::Playing
+    );
+}
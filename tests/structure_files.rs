@@ -0,0 +1,183 @@
+use b::prelude::*;
+use b::simulation_world::{
+    block::{BlockId, BlockRegistryResource, AIR_BLOCK_ID},
+    world_edit::{
+        export_vox, import_vox, read_schematic, write_schematic, ClipboardRegion, EditRegion,
+        StructureFileEvent, VoxPaletteMapping, WorldEditClipboard, WorldEditEvent,
+        WorldEditOperation,
+    },
+};
+use b::test_support::{test_block_registry, TestWorldBuilder, DEFAULT_TEST_BLOCKS};
+
+const TEST_VOX_PALETTE: &str = r#"(
+    palette: [
+        (index: 1, block: "stone", color: Some((125, 125, 125))),
+        (index: 7, block: "sand", color: Some((219, 207, 163))),
+    ],
+    fallback: Some("dirt"),
+)"#;
+
+/// An asymmetric structure, so any axis mix-up changes it.
+fn test_structure(registry: &BlockRegistryResource) -> ClipboardRegion {
+    let stone = registry.get_block_id_by_name("stone").unwrap();
+    let sand = registry.get_block_id_by_name("sand").unwrap();
+    let glass = registry.get_block_id_by_name("glass").unwrap();
+
+    ClipboardRegion::from_fn(IVec3::new(3, 4, 2), |pos| {
+        Some(match (pos.x + 2 * pos.y + 3 * pos.z) % 4 {
+            0 => stone,
+            1 => sand,
+            2 => glass,
+            _ => AIR_BLOCK_ID,
+        })
+    })
+}
+
+/// A minimal single model vox file, in MagicaVoxel's Z-up axes.
+fn vox_file(size: [i32; 3], voxels: &[[u8; 4]]) -> Vec<u8> {
+    let mut size_chunk = b"SIZE".to_vec();
+    size_chunk.extend(12i32.to_le_bytes());
+    size_chunk.extend(0i32.to_le_bytes());
+    size.iter()
+        .for_each(|axis| size_chunk.extend(axis.to_le_bytes()));
+
+    let mut xyzi_chunk = b"XYZI".to_vec();
+    xyzi_chunk.extend((4 + voxels.len() as i32 * 4).to_le_bytes());
+    xyzi_chunk.extend(0i32.to_le_bytes());
+    xyzi_chunk.extend((voxels.len() as i32).to_le_bytes());
+    voxels.iter().for_each(|voxel| xyzi_chunk.extend(voxel));
+
+    let mut out = b"VOX ".to_vec();
+    out.extend(150i32.to_le_bytes());
+    out.extend(b"MAIN");
+    out.extend(0i32.to_le_bytes());
+    out.extend(((size_chunk.len() + xyzi_chunk.len()) as i32).to_le_bytes());
+    out.extend(size_chunk);
+    out.extend(xyzi_chunk);
+    out
+}
+
+#[test]
+fn vox_files_map_palette_indices_and_axes() {
+    let registry = test_block_registry(DEFAULT_TEST_BLOCKS);
+    let mapping = VoxPaletteMapping::from_ron_str(TEST_VOX_PALETTE).unwrap();
+    let block = |name| registry.get_block_id_by_name(name).unwrap();
+
+    // 1 wide, 2 deep and 3 tall in MagicaVoxel
+    let bytes = vox_file([1, 2, 3], &[[0, 0, 2, 7], [0, 1, 0, 1], [0, 1, 1, 42]]);
+    let imported = import_vox(&bytes, &mapping, &registry).unwrap();
+
+    assert_eq!(imported.size(), IVec3::new(1, 3, 2));
+    assert_eq!(imported.get(IVec3::new(0, 2, 1)), Some(block("sand")));
+    assert_eq!(imported.get(IVec3::new(0, 0, 0)), Some(block("stone")));
+    // index 42 has no entry, so it uses the fallback
+    assert_eq!(imported.get(IVec3::new(0, 1, 0)), Some(block("dirt")));
+    assert_eq!(imported.get(IVec3::new(0, 0, 1)), Some(AIR_BLOCK_ID));
+
+    // blocks with palette entries survive exporting and importing again
+    let structure = test_structure(&registry);
+    let structure = ClipboardRegion::from_fn(structure.size(), |pos| {
+        structure.get(pos).map(|id| {
+            if id == block("glass") {
+                block("stone")
+            } else {
+                id
+            }
+        })
+    });
+    let exported = export_vox(&structure, &mapping, &registry).unwrap();
+    assert_eq!(
+        import_vox(&exported, &mapping, &registry).unwrap(),
+        structure
+    );
+
+    assert!(import_vox(b"not a vox file", &mapping, &registry).is_err());
+}
+
+#[test]
+fn schematics_roundtrip_by_block_name() {
+    let registry = test_block_registry(DEFAULT_TEST_BLOCKS);
+    let structure = test_structure(&registry);
+    let bytes = write_schematic(&structure, &registry).unwrap();
+    assert_eq!(read_schematic(&bytes, &registry).unwrap(), structure);
+
+    // unloaded voxels stay unset
+    let with_holes = ClipboardRegion::from_fn(IVec3::new(2, 2, 2), |pos| {
+        (pos.y == 0).then(|| registry.get_block_id_by_name("stone").unwrap())
+    });
+    let bytes = write_schematic(&with_holes, &registry).unwrap();
+    assert_eq!(read_schematic(&bytes, &registry).unwrap(), with_holes);
+
+    // blocks are stored by name, so a reordered registry still reads them correctly
+    // and blocks it doesn't know become air
+    let reordered: Vec<_> = DEFAULT_TEST_BLOCKS
+        .iter()
+        .rev()
+        .filter(|(name, _)| *name != "glass")
+        .copied()
+        .collect();
+    let other_registry = test_block_registry(&reordered);
+    let read = read_schematic(
+        &write_schematic(&structure, &registry).unwrap(),
+        &other_registry,
+    )
+    .unwrap();
+
+    for (pos, block) in structure.iter() {
+        let expected: BlockId = match block {
+            b if b == registry.get_block_id_by_name("glass").unwrap() => AIR_BLOCK_ID,
+            b if b == AIR_BLOCK_ID => AIR_BLOCK_ID,
+            b => {
+                let names = registry.block_names();
+                other_registry
+                    .get_block_id_by_name(&names[b as usize])
+                    .unwrap()
+            }
+        };
+        assert_eq!(read.get(pos), Some(expected), "block at {pos}");
+    }
+}
+
+#[test]
+fn saved_structures_can_be_loaded_and_pasted() {
+    let mut world = TestWorldBuilder::new().build();
+    world.settle_chunks();
+
+    let glass = world.block_id("glass");
+    let stone = world.block_id("stone");
+    world.set_block(IVec3::new(2, 65, 2), glass);
+    world.set_block(IVec3::new(3, 66, 2), stone);
+
+    let path = std::env::temp_dir().join(format!("b_structure_{}.bschem", std::process::id()));
+
+    // copy, save, forget the clipboard, then load it again and paste it elsewhere
+    world.world().write_message(WorldEditEvent {
+        operation: WorldEditOperation::Copy {
+            region: EditRegion::from_corners(IVec3::new(2, 65, 2), IVec3::new(3, 66, 2)),
+        },
+    });
+    world.step();
+    world
+        .world()
+        .write_message(StructureFileEvent::SaveClipboard { path: path.clone() });
+    world.step();
+    world.world().resource_mut::<WorldEditClipboard>().0 = None;
+
+    world
+        .world()
+        .write_message(StructureFileEvent::LoadClipboard { path: path.clone() });
+    world.step();
+    world.world().write_message(WorldEditEvent {
+        operation: WorldEditOperation::Paste {
+            origin: IVec3::new(10, 65, 10),
+            skip_air: true,
+        },
+    });
+    world.settle_chunks();
+
+    assert_eq!(world.get_block(IVec3::new(10, 65, 10)), Some(glass));
+    assert_eq!(world.get_block(IVec3::new(11, 66, 10)), Some(stone));
+    assert_eq!(world.get_block(IVec3::new(11, 65, 10)), Some(AIR_BLOCK_ID));
+
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,113 @@
+use b::render_world::passes::ui_pass::{extract::PanelShape, queue::panel_quads};
+use b::simulation_world::{
+    asset_management::ImageAsset,
+    user_interface::components::{NineSlice, UvRect},
+};
+use glam::{vec2, Vec2};
+use std::path::Path;
+
+#[test]
+fn nine_slice_keeps_borders_at_their_pixel_size() {
+    let quads = panel_quads(
+        vec2(10.0, 20.0),
+        vec2(100.0, 40.0),
+        UvRect::FULL,
+        Some(NineSlice::uniform(4.0)),
+        Some(vec2(16.0, 16.0)),
+    );
+    assert_eq!(quads.len(), 9);
+
+    // corners keep their size and uv, the middle stretches over what is left
+    let (top_left, middle, bottom_right) = (quads[0], quads[4], quads[8]);
+    assert_eq!(top_left.position, vec2(10.0, 20.0));
+    assert_eq!(top_left.size, vec2(4.0, 4.0));
+    assert_eq!(top_left.uv_rect.max, vec2(0.25, 0.25));
+    assert_eq!(middle.size, vec2(92.0, 32.0));
+    assert_eq!(middle.uv_rect.min, vec2(0.25, 0.25));
+    assert_eq!(middle.uv_rect.max, vec2(0.75, 0.75));
+    assert_eq!(bottom_right.position + bottom_right.size, vec2(110.0, 60.0));
+    assert_eq!(bottom_right.uv_rect.max, Vec2::ONE);
+
+    // a panel too small for its borders shrinks them instead of overlapping
+    let quads = panel_quads(
+        Vec2::ZERO,
+        vec2(4.0, 40.0),
+        UvRect::FULL,
+        Some(NineSlice::uniform(4.0)),
+        Some(vec2(16.0, 16.0)),
+    );
+    assert_eq!(quads[0].size.x, 2.0);
+    assert_eq!(quads[1].size.x, 0.0);
+}
+
+#[test]
+fn plain_images_are_one_quad_over_their_uv_rect() {
+    let uv_rect = UvRect {
+        min: vec2(0.5, 0.0),
+        max: vec2(1.0, 0.5),
+    };
+
+    let quads = panel_quads(
+        Vec2::ZERO,
+        vec2(8.0, 8.0),
+        uv_rect,
+        None,
+        Some(vec2(16.0, 16.0)),
+    );
+    assert_eq!(quads.len(), 1);
+    assert_eq!(quads[0].uv_rect, uv_rect);
+    assert_eq!(
+        quads[0]
+            .object_data(Vec2::ZERO, vec2(8.0, 8.0), &PanelShape::default())
+            .uv_rect,
+        [0.5, 0.0, 0.5, 0.5]
+    );
+
+    // without a texture size the borders can't be measured, so it just stretches
+    let quads = panel_quads(
+        Vec2::ZERO,
+        vec2(8.0, 8.0),
+        uv_rect,
+        Some(NineSlice::uniform(4.0)),
+        None,
+    );
+    assert_eq!(quads.len(), 1);
+}
+
+#[test]
+fn builtin_ui_images_load() {
+    for name in ["crosshair", "panel"] {
+        let image = ImageAsset::load(&Path::new("assets/ui").join(format!("{name}.png"))).unwrap();
+        assert_eq!(image.name, name);
+        assert_eq!(
+            image.pixels.len(),
+            (image.size.x * image.size.y * 4) as usize
+        );
+    }
+}
+
+#[test]
+fn every_nine_slice_quad_is_shaped_against_the_whole_panel() {
+    let (position, size) = (vec2(10.0, 20.0), vec2(100.0, 40.0));
+    let shape = PanelShape {
+        border: [2.0, 2.0, 1.0, 1.0],
+        border_color: [1.0, 0.0, 0.0, 1.0],
+        corner_radius: [6.0; 4],
+    };
+    let quads = panel_quads(
+        position,
+        size,
+        UvRect::FULL,
+        Some(NineSlice::uniform(4.0)),
+        Some(vec2(16.0, 16.0)),
+    );
+
+    // the border and corners are drawn across the panel, not within each slice
+    for quad in quads {
+        let object = quad.object_data(position, size, &shape);
+        assert_eq!(object.panel_rect, [10.0, 20.0, 100.0, 40.0]);
+        assert_eq!(object.border, shape.border);
+        assert_eq!(object.border_color, shape.border_color);
+        assert_eq!(object.corner_radius, shape.corner_radius);
+    }
+}
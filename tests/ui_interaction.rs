@@ -0,0 +1,149 @@
+use b::simulation_world::{
+    input::{
+        resources::{CursorPosition, DesiredCursorState, InputCapture},
+        systems::update_action_state_system,
+        ActionStateResource, AxisDirection, Buttons, GamepadAxis, GamepadButton,
+        InputActionMapResource, SimulationAction,
+    },
+    user_interface::{
+        components::{CalculatedLayout, Interaction},
+        interaction::{update_interaction_system, UiClickMessage},
+        layout::UiDepth,
+    },
+};
+use bevy_ecs::{entity::Entity, message::Messages, system::RunSystemOnce, world::World};
+use glam::Vec2;
+use winit::{event::MouseButton, keyboard::PhysicalKey, window::CursorGrabMode};
+
+/// A world with a big panel and a button drawn on top of it.
+fn world_with_button() -> (World, Entity, Entity) {
+    let mut world = World::new();
+    world.insert_resource(CursorPosition::default());
+    world.insert_resource(DesiredCursorState::default());
+    world.insert_resource(Buttons::<MouseButton>::default());
+    world.insert_resource(InputCapture::default());
+    world.init_resource::<Messages<UiClickMessage>>();
+
+    let panel = world
+        .spawn((
+            CalculatedLayout {
+                position: Vec2::ZERO,
+                size: Vec2::new(400.0, 400.0),
+                ..Default::default()
+            },
+            UiDepth(1.0),
+            Interaction::default(),
+        ))
+        .id();
+    let button = world
+        .spawn((
+            CalculatedLayout {
+                position: Vec2::new(100.0, 100.0),
+                size: Vec2::new(50.0, 20.0),
+                ..Default::default()
+            },
+            UiDepth(2.0),
+            Interaction::default(),
+        ))
+        .id();
+
+    (world, panel, button)
+}
+
+fn frame(world: &mut World, cursor: Vec2, left_down: bool) {
+    world.resource_mut::<CursorPosition>().0 = Some(cursor);
+    let mut mouse = world.resource_mut::<Buttons<MouseButton>>();
+    mouse.swap_previous();
+    if left_down {
+        mouse.press(MouseButton::Left);
+    } else {
+        mouse.release(MouseButton::Left);
+    }
+    world.run_system_once(update_interaction_system).unwrap();
+}
+
+fn clicks(world: &mut World) -> Vec<Entity> {
+    world
+        .resource_mut::<Messages<UiClickMessage>>()
+        .drain()
+        .map(|message| message.entity)
+        .collect()
+}
+
+#[test]
+fn only_the_deepest_node_under_the_cursor_is_hovered() {
+    let (mut world, panel, button) = world_with_button();
+
+    frame(&mut world, Vec2::new(110.0, 110.0), false);
+    assert_eq!(
+        world.get::<Interaction>(button),
+        Some(&Interaction::Hovered)
+    );
+    assert_eq!(world.get::<Interaction>(panel), Some(&Interaction::Normal));
+
+    frame(&mut world, Vec2::new(300.0, 300.0), false);
+    assert_eq!(world.get::<Interaction>(button), Some(&Interaction::Normal));
+    assert_eq!(world.get::<Interaction>(panel), Some(&Interaction::Hovered));
+
+    // a locked cursor is looking around, not pointing at anything
+    world.resource_mut::<DesiredCursorState>().grab_mode = CursorGrabMode::Locked;
+    frame(&mut world, Vec2::new(300.0, 300.0), false);
+    assert_eq!(world.get::<Interaction>(panel), Some(&Interaction::Normal));
+    assert!(!world.resource::<InputCapture>().pointer);
+}
+
+#[test]
+fn a_click_is_a_press_and_release_on_the_same_node() {
+    let (mut world, _, button) = world_with_button();
+    let on_button = Vec2::new(110.0, 110.0);
+
+    frame(&mut world, on_button, true);
+    assert_eq!(
+        world.get::<Interaction>(button),
+        Some(&Interaction::Pressed)
+    );
+    frame(&mut world, on_button, false);
+    assert_eq!(
+        world.get::<Interaction>(button),
+        Some(&Interaction::Hovered)
+    );
+    assert_eq!(clicks(&mut world), [button]);
+
+    // dragging off cancels the press
+    frame(&mut world, on_button, true);
+    frame(&mut world, Vec2::new(300.0, 300.0), true);
+    frame(&mut world, on_button, false);
+    assert!(clicks(&mut world).is_empty());
+
+    // and pressing elsewhere then dragging on isn't a click either
+    frame(&mut world, Vec2::new(300.0, 300.0), true);
+    frame(&mut world, on_button, true);
+    assert_eq!(
+        world.get::<Interaction>(button),
+        Some(&Interaction::Hovered)
+    );
+    frame(&mut world, on_button, false);
+    assert!(clicks(&mut world).is_empty());
+}
+
+#[test]
+fn clicking_the_ui_does_not_fire_world_actions() {
+    let (mut world, _, _) = world_with_button();
+    world.insert_resource(InputActionMapResource::builtin());
+    world.insert_resource(ActionStateResource::default());
+    world.insert_resource(Buttons::<PhysicalKey>::default());
+    world.insert_resource(Buttons::<GamepadButton>::default());
+    world.insert_resource(Buttons::<(GamepadAxis, AxisDirection)>::default());
+
+    frame(&mut world, Vec2::new(110.0, 110.0), true);
+    world.run_system_once(update_action_state_system).unwrap();
+    let actions = world.resource::<ActionStateResource>();
+    assert!(!actions.just_happened(SimulationAction::BreakVoxel));
+
+    // off the UI the same press breaks a block
+    frame(&mut world, Vec2::new(600.0, 600.0), false);
+    frame(&mut world, Vec2::new(600.0, 600.0), true);
+    world.run_system_once(update_action_state_system).unwrap();
+    let actions = world.resource::<ActionStateResource>();
+    assert!(actions.just_happened(SimulationAction::BreakVoxel));
+}
@@ -0,0 +1,116 @@
+use b::simulation_world::user_interface::components::{
+    GridPlacement, GridTrack, Size, Style, UiRect,
+};
+use taffy::{NodeId, TaffyTree};
+
+/// Lays out `children` in a `parent` node and returns each child's location and size.
+fn layout_children(parent: Style, children: &[Style]) -> Vec<([f32; 2], [f32; 2])> {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let child_nodes: Vec<NodeId> = children
+        .iter()
+        .map(|style| tree.new_leaf(style.into()).unwrap())
+        .collect();
+    let root = tree
+        .new_with_children((&parent).into(), &child_nodes)
+        .unwrap();
+    tree.compute_layout(root, taffy::Size::max_content())
+        .unwrap();
+
+    child_nodes
+        .iter()
+        .map(|&node| {
+            let layout = tree.layout(node).unwrap();
+            (
+                [layout.location.x, layout.location.y],
+                [layout.size.width, layout.size.height],
+            )
+        })
+        .collect()
+}
+
+fn fixed(width: f32, height: f32) -> Style {
+    Style {
+        width: Size::Px(width),
+        height: Size::Px(height),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn padding_margin_and_gap_space_out_children() {
+    let parent = Style {
+        flex_direction: taffy::FlexDirection::Column,
+        padding: UiRect {
+            left: Size::Px(10.0),
+            top: Size::Px(20.0),
+            ..UiRect::ZERO
+        },
+        row_gap: Size::Px(5.0),
+        ..Default::default()
+    };
+    let second = Style {
+        margin: UiRect {
+            left: Size::Px(3.0),
+            ..UiRect::ZERO
+        },
+        ..fixed(50.0, 10.0)
+    };
+
+    let layouts = layout_children(parent, &[fixed(50.0, 10.0), second]);
+    assert_eq!(layouts[0], ([10.0, 20.0], [50.0, 10.0]));
+    assert_eq!(layouts[1], ([13.0, 35.0], [50.0, 10.0]));
+}
+
+#[test]
+fn flex_grow_fills_the_row_up_to_max_width() {
+    let parent = fixed(300.0, 10.0);
+    let grow = |max_width| Style {
+        flex_grow: 1.0,
+        max_width,
+        ..Default::default()
+    };
+
+    let layouts = layout_children(parent, &[grow(Size::Px(50.0)), grow(Size::Auto)]);
+    assert_eq!(layouts[0].1[0], 50.0);
+    assert_eq!(layouts[1], ([50.0, 0.0], [250.0, 10.0]));
+}
+
+#[test]
+fn absolute_nodes_are_placed_by_their_insets() {
+    let badge = Style {
+        position: taffy::Position::Absolute,
+        inset: UiRect {
+            right: Size::Px(4.0),
+            bottom: Size::Percent(50.0),
+            ..UiRect::AUTO
+        },
+        ..fixed(10.0, 10.0)
+    };
+
+    let layouts = layout_children(fixed(100.0, 60.0), &[badge]);
+    assert_eq!(layouts[0], ([86.0, 20.0], [10.0, 10.0]));
+}
+
+#[test]
+fn grid_places_children_in_tracks() {
+    let parent = Style {
+        display: taffy::Display::Grid,
+        grid_template_columns: vec![GridTrack::Px(100.0), GridTrack::Fr(1.0)],
+        grid_template_rows: vec![GridTrack::Px(20.0), GridTrack::Px(30.0)],
+        column_gap: Size::Px(10.0),
+        ..fixed(310.0, 50.0)
+    };
+    let second_column = Style {
+        grid_column: GridPlacement::Line(2),
+        ..Default::default()
+    };
+    let spanning_row = Style {
+        grid_row: GridPlacement::Line(2),
+        grid_column: GridPlacement::Span(2),
+        ..Default::default()
+    };
+
+    let layouts = layout_children(parent, &[second_column, spanning_row]);
+    assert_eq!(layouts[0], ([110.0, 0.0], [200.0, 20.0]));
+    assert_eq!(layouts[1], ([0.0, 20.0], [310.0, 30.0]));
+}
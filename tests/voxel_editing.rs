@@ -0,0 +1,99 @@
+use b::prelude::*;
+use b::simulation_world::edit_history::{EditHistoryEvent, EditJournal, EditTransaction};
+use b::test_support::TestWorldBuilder;
+
+const SURFACE_CHUNK: IVec3 = IVec3::new(0, 2, 0);
+
+#[test]
+fn placing_a_block_adds_its_exposed_faces() {
+    let mut world = TestWorldBuilder::new().build();
+    world.settle_chunks();
+
+    let stone = world.block_id("stone");
+    let before = world.mesh_face_counts(SURFACE_CHUNK).unwrap();
+
+    world.set_block(IVec3::new(5, 65, 5), stone);
+    world.settle_chunks();
+
+    // 5 new faces, minus the top face of the block it sits on
+    let after = world.mesh_face_counts(SURFACE_CHUNK).unwrap();
+    assert_eq!(after.opaque, before.opaque + 4);
+    assert_eq!(world.get_block(IVec3::new(5, 65, 5)), Some(stone));
+}
+
+#[test]
+fn breaking_a_block_on_a_chunk_edge_remeshes_the_neighbor() {
+    let mut world = TestWorldBuilder::new().build();
+    world.settle_chunks();
+
+    let air = world.block_id("air");
+    let below_chunk = SURFACE_CHUNK - IVec3::Y;
+    let surface_before = world.mesh_face_counts(SURFACE_CHUNK).unwrap();
+    let below_before = world.mesh_face_counts(below_chunk).unwrap();
+
+    // y = 64 is the bottom layer of the surface chunk
+    let sand = world.set_block(IVec3::new(5, 64, 5), air);
+    assert_eq!(sand, Some(world.block_id("sand")));
+    world.settle_chunks();
+
+    // loses the top face, exposes the 4 sides around the hole
+    let surface_after = world.mesh_face_counts(SURFACE_CHUNK).unwrap();
+    assert_eq!(surface_after.opaque, surface_before.opaque + 3);
+
+    // the floor of the hole belongs to the chunk below
+    let below_after = world.mesh_face_counts(below_chunk).unwrap();
+    assert_eq!(below_after.opaque, below_before.opaque + 1);
+}
+
+#[test]
+fn transparent_blocks_go_in_the_transparent_mesh() {
+    let mut world = TestWorldBuilder::new().build();
+    world.settle_chunks();
+
+    let glass = world.block_id("glass");
+    let before = world.mesh_face_counts(SURFACE_CHUNK).unwrap();
+
+    world.set_block(IVec3::new(9, 65, 9), glass);
+    world.settle_chunks();
+
+    let after = world.mesh_face_counts(SURFACE_CHUNK).unwrap();
+    assert!(after.transparent > before.transparent);
+    assert_eq!(after.opaque, before.opaque);
+}
+
+#[test]
+fn undo_leaves_voxels_that_changed_since_the_edit() {
+    let mut world = TestWorldBuilder::new()
+        .with_block(
+            "gravel",
+            r#"(display_name: "Gravel", textures: (fallback: "gravel"), is_transparent: false, has_gravity: true)"#,
+        )
+        .build();
+    world.settle_chunks();
+
+    let (air, stone, gravel) = (
+        world.block_id("air"),
+        world.block_id("stone"),
+        world.block_id("gravel"),
+    );
+    let dug = IVec3::new(5, 64, 5);
+    world.set_block(dug, stone);
+    world.set_block(dug + IVec3::Y, gravel);
+    world.step_ticks(5);
+
+    // a recorded edit digs out the stone, then the gravel falls into the hole
+    let mut transaction = EditTransaction::new();
+    transaction.record(dug, world.set_block(dug, air).unwrap(), air);
+    world
+        .world()
+        .resource_mut::<EditJournal>()
+        .commit(transaction);
+    world.step_ticks(60);
+    assert_eq!(world.get_block(dug), Some(gravel));
+
+    // undoing would put the stone back over the gravel
+    world.world().write_message(EditHistoryEvent::Undo);
+    world.step();
+    assert_eq!(world.get_block(dug), Some(gravel));
+    assert_eq!(world.get_block(dug + IVec3::Y), Some(air));
+}
@@ -0,0 +1,197 @@
+use b::prelude::*;
+use b::simulation_world::chunk::ChunkMeshDirty;
+use b::simulation_world::world_edit::{
+    ClipboardRegion, EditRegion, WorldEditEvent, WorldEditOperation,
+};
+use b::test_support::{TestWorld, TestWorldBuilder};
+use bevy_ecs::prelude::*;
+use std::collections::HashMap;
+
+const SURFACE_CHUNK: IVec3 = IVec3::new(0, 2, 0);
+
+/// How often `ChunkMeshDirty` was inserted on each entity.
+#[derive(Resource, Default)]
+struct DirtyInserts(HashMap<Entity, usize>);
+
+fn settled_world() -> TestWorld {
+    let mut world = TestWorldBuilder::new().build();
+    world.settle_chunks();
+    world
+}
+
+fn apply(world: &mut TestWorld, operation: WorldEditOperation) {
+    world.world().write_message(WorldEditEvent { operation });
+    world.step();
+}
+
+/// A 3x3x3 box standing on the surface.
+fn box_on_surface() -> EditRegion {
+    EditRegion::from_corners(IVec3::new(2, 65, 2), IVec3::new(4, 67, 4))
+}
+
+#[test]
+fn replace_only_swaps_matching_blocks() {
+    let mut world = settled_world();
+    let (air, sand, stone) = (
+        world.block_id("air"),
+        world.block_id("sand"),
+        world.block_id("stone"),
+    );
+
+    // the region covers the sand surface and the air above it
+    let region = EditRegion::from_corners(IVec3::new(2, 64, 2), IVec3::new(4, 65, 4));
+    apply(
+        &mut world,
+        WorldEditOperation::Replace {
+            region,
+            from: sand,
+            to: stone,
+        },
+    );
+
+    for pos in region.iter() {
+        let expected = if pos.y == 64 { stone } else { air };
+        assert_eq!(world.get_block(pos), Some(expected), "at {pos}");
+    }
+    assert_eq!(world.get_block(IVec3::new(5, 64, 2)), Some(sand));
+}
+
+#[test]
+fn hollow_builds_a_shell_around_air() {
+    let mut world = settled_world();
+    let (air, glass, stone) = (
+        world.block_id("air"),
+        world.block_id("glass"),
+        world.block_id("stone"),
+    );
+
+    let region = box_on_surface();
+    let center = IVec3::new(3, 66, 3);
+    world.set_block(center, glass);
+    apply(
+        &mut world,
+        WorldEditOperation::Hollow {
+            region,
+            block: stone,
+        },
+    );
+
+    for pos in region.iter() {
+        let expected = if pos == center { air } else { stone };
+        assert_eq!(world.get_block(pos), Some(expected), "at {pos}");
+    }
+}
+
+#[test]
+fn walls_leave_the_top_bottom_and_inside_alone() {
+    let mut world = settled_world();
+    let (air, glass, stone) = (
+        world.block_id("air"),
+        world.block_id("glass"),
+        world.block_id("stone"),
+    );
+
+    let region = box_on_surface();
+    let center = IVec3::new(3, 66, 3);
+    world.set_block(center, glass);
+    apply(
+        &mut world,
+        WorldEditOperation::Walls {
+            region,
+            block: stone,
+        },
+    );
+
+    for pos in region.iter() {
+        let expected = match pos {
+            _ if pos == center => glass,
+            _ if pos.x == 3 && pos.z == 3 => air,
+            _ => stone,
+        };
+        assert_eq!(world.get_block(pos), Some(expected), "at {pos}");
+    }
+}
+
+#[test]
+fn copied_region_pastes_rotated() {
+    let mut world = settled_world();
+    let (air, glass, stone) = (
+        world.block_id("air"),
+        world.block_id("glass"),
+        world.block_id("stone"),
+    );
+
+    // a 3 long row running east, stone on its west end and glass on its east end
+    world.set_block(IVec3::new(2, 65, 2), stone);
+    world.set_block(IVec3::new(4, 65, 2), glass);
+    let row = EditRegion::from_corners(IVec3::new(2, 65, 2), IVec3::new(4, 65, 2));
+
+    apply(&mut world, WorldEditOperation::Copy { region: row });
+    apply(
+        &mut world,
+        WorldEditOperation::RotateClipboard { quarter_turns: 1 },
+    );
+    let origin = IVec3::new(10, 65, 10);
+    apply(
+        &mut world,
+        WorldEditOperation::Paste {
+            origin,
+            skip_air: false,
+        },
+    );
+
+    // now running south
+    assert_eq!(world.get_block(origin), Some(stone));
+    assert_eq!(world.get_block(origin + IVec3::Z), Some(air));
+    assert_eq!(world.get_block(origin + IVec3::Z * 2), Some(glass));
+    assert_eq!(world.get_block(origin + IVec3::X * 2), Some(air));
+}
+
+#[test]
+fn quarter_turn_takes_east_to_south() {
+    let size = IVec3::new(3, 1, 2);
+    let east_end = IVec3::new(2, 0, 0);
+    let marker = |pos| Some(if pos == east_end { 1 } else { 0 });
+    let clipboard = ClipboardRegion::from_fn(size, marker);
+
+    let rotated = clipboard.rotated_y(1);
+    assert_eq!(rotated.size(), IVec3::new(2, 1, 3));
+    // the east end now lies along the south edge, with north swung round to east
+    assert_eq!(rotated.get(IVec3::new(1, 0, 2)), Some(1));
+    assert_eq!(rotated.iter().filter(|&(_, block)| block == 1).count(), 1);
+
+    assert_eq!(clipboard.rotated_y(4), clipboard);
+    assert_eq!(clipboard.rotated_y(-1), clipboard.rotated_y(3));
+}
+
+#[test]
+fn edits_on_a_chunk_edge_dirty_the_neighbor_once() {
+    let mut world = settled_world();
+    let stone = world.block_id("stone");
+
+    world.world().init_resource::<DirtyInserts>();
+    world.world().add_observer(
+        |trigger: On<Insert, ChunkMeshDirty>, mut inserts: ResMut<DirtyInserts>| {
+            *inserts.0.entry(trigger.entity).or_default() += 1;
+        },
+    );
+
+    // y = 64 is the bottom layer of the surface chunk, so every voxel touches the chunk below
+    let region = EditRegion::from_corners(IVec3::new(2, 64, 2), IVec3::new(6, 64, 6));
+    apply(
+        &mut world,
+        WorldEditOperation::Fill {
+            region,
+            block: stone,
+        },
+    );
+
+    let entity = |world: &TestWorld, coord| world.chunk_state(coord).unwrap().entity().unwrap();
+    let surface = entity(&world, SURFACE_CHUNK);
+    let below = entity(&world, SURFACE_CHUNK - IVec3::Y);
+
+    let inserts = &world.world().resource::<DirtyInserts>().0;
+    assert_eq!(inserts.get(&surface), Some(&1));
+    assert_eq!(inserts.get(&below), Some(&1));
+    assert_eq!(inserts.len(), 2);
+}
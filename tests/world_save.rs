@@ -0,0 +1,128 @@
+use b::prelude::*;
+use b::simulation_world::{
+    biome::load_biome_defs_from_sources,
+    chunk::{ChunkBlocksComponent, ChunkCoord, ChunkLod},
+    terrain::{
+        shaping::RealisticShaper, ActiveBiomeGenerator, ActiveTerrainPainter, BiomeMapComponent,
+        ClimateNoiseGenerator, TerrainPipeline,
+    },
+    world_save::{
+        decode_chunk, encode_chunk, encode_generated, Region, RegionCoord, RegionSlot, SavedChunk,
+        WorldSave, WorldSaveError,
+    },
+};
+use b::test_support::{
+    hash_biome_map, hash_chunk_blocks, load_snapshot_registries, test_block_registry,
+    DEFAULT_TEST_BIOMES, DEFAULT_TEST_BLOCKS, SNAPSHOT_COORDS, SNAPSHOT_SEED,
+};
+use std::{path::PathBuf, sync::Arc};
+
+/// A fresh, per-test directory under the system temp dir.
+fn temp_save_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("b_world_save_{}_{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn generated_chunks_survive_an_encode_decode_roundtrip() {
+    let (blocks, biomes) = load_snapshot_registries();
+    let pipeline = TerrainPipeline {
+        climate: Arc::new(ClimateNoiseGenerator::new(SNAPSHOT_SEED)),
+        biome: ActiveBiomeGenerator::default().0,
+        shaper: Arc::new(RealisticShaper::new()),
+        painter: ActiveTerrainPainter::default().0,
+    };
+
+    for &pos in SNAPSHOT_COORDS {
+        let generated = pipeline.generate_chunk(ChunkCoord { pos }, ChunkLod(0), &blocks, &biomes);
+        let Some(chunk_blocks) = generated.as_ref().and_then(|g| g.chunk_blocks.as_ref()) else {
+            assert_eq!(encode_generated(generated.as_ref()), RegionSlot::Empty);
+            continue;
+        };
+        let biome_map = &generated.as_ref().unwrap().biome_map;
+
+        let bytes = encode_chunk(chunk_blocks, biome_map);
+        let SavedChunk::Generated {
+            chunk_blocks: decoded_blocks,
+            biome_map: decoded_biomes,
+        } = decode_chunk(&bytes, |id| id, |id| id).unwrap()
+        else {
+            panic!("chunk {pos} decoded as empty");
+        };
+
+        assert_eq!(
+            hash_chunk_blocks(&decoded_blocks),
+            hash_chunk_blocks(chunk_blocks)
+        );
+        assert_eq!(hash_biome_map(&decoded_biomes), hash_biome_map(biome_map));
+    }
+}
+
+#[test]
+fn regions_roundtrip_and_reject_corrupt_files() {
+    let coord = RegionCoord::of_chunk(IVec3::new(-1, 0, 20));
+    assert_eq!(coord, RegionCoord { x: -1, z: 1 });
+
+    let mut region = Region::default();
+    let empty_slot = coord.slot_index(IVec3::new(-1, 3, 20)).unwrap();
+    let data_slot = coord.slot_index(IVec3::new(-16, 0, 31)).unwrap();
+    region.slots[empty_slot] = RegionSlot::Empty;
+    region.slots[data_slot] = RegionSlot::Data(vec![1, 2, 3, 4]);
+
+    let bytes = region.to_bytes();
+    let read_back = Region::from_bytes(&bytes).unwrap();
+    assert_eq!(read_back.slots, region.slots);
+
+    assert_eq!(coord.slot_index(IVec3::new(0, 0, 20)), None);
+    assert!(matches!(
+        Region::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(WorldSaveError::CorruptData(_))
+    ));
+    assert!(matches!(
+        Region::from_bytes(b"nope"),
+        Err(WorldSaveError::CorruptData(_))
+    ));
+}
+
+#[test]
+fn saved_chunks_are_remapped_to_the_current_registries() {
+    let dir = temp_save_dir("remap");
+    let biomes = load_biome_defs_from_sources(
+        DEFAULT_TEST_BIOMES
+            .iter()
+            .map(|(name, ron)| (name.to_string(), ron.to_string())),
+    );
+
+    // write a chunk of sand with the default test blocks
+    let blocks = test_block_registry(DEFAULT_TEST_BLOCKS);
+    let sand = blocks.get_block_id_by_name("sand").unwrap();
+    let save = WorldSave::create(&dir, 3, "Superflat", &blocks, &biomes).unwrap();
+
+    let pos = IVec3::new(4, 1, -2);
+    let coord = RegionCoord::of_chunk(pos);
+    let mut region = Region::default();
+    region.slots[coord.slot_index(pos).unwrap()] = RegionSlot::Data(encode_chunk(
+        &ChunkBlocksComponent::new_uniform(ChunkLod(0), sand),
+        &BiomeMapComponent::new_empty(ChunkLod(0)),
+    ));
+    save.write_region(coord, &region).unwrap();
+
+    // reopen it with sand registered under a different ID
+    let reordered: Vec<_> = DEFAULT_TEST_BLOCKS.iter().rev().copied().collect();
+    let reordered_blocks = test_block_registry(&reordered);
+    let reordered_sand = reordered_blocks.get_block_id_by_name("sand").unwrap();
+    assert_ne!(sand, reordered_sand);
+
+    let save = WorldSave::open(&dir, &reordered_blocks, &biomes).unwrap();
+    assert_eq!(save.meta.seed, 3);
+    assert_eq!(save.meta.generator, "Superflat");
+
+    let Some(SavedChunk::Generated { chunk_blocks, .. }) = save.read_chunk(pos).unwrap() else {
+        panic!("saved chunk was not found");
+    };
+    assert_eq!(chunk_blocks.is_uniform(), Some(reordered_sand));
+    assert!(save.read_chunk(pos + IVec3::X).unwrap().is_none());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
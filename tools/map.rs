@@ -0,0 +1,228 @@
+//! Renders a top-down PNG map of a rectangle of the world, without a GPU.
+//!
+//! Usage:
+//!   b-map (--world <dir> | --seed <n> --generator <name>) (--radius <blocks> [--center <x>,<z>] | --box <x0>,<z0>,<x1>,<z1>)
+//!         [--layer <surface|biome|temperature|precipitation|continentalness|erosion|weirdness|all>]...
+//!         [--overlay-alpha <0..1>] [--out <file.png>]
+//!
+//! With more than one layer, every layer is written next to `--out` as `<stem>_<layer>.png`.
+
+use b::ecs_core::config;
+use b::map_render::{
+    BlockColorPalette, MapArea, MapChunkSource, MapLayer, SurfaceMap, DEFAULT_OVERLAY_ALPHA,
+};
+use b::prelude::*;
+use b::render_world::textures::load_voxel_texture_assets;
+use b::simulation_world::{
+    biome::BiomeRegistryResource,
+    block::BlockRegistryResource,
+    terrain::{
+        ActiveBiomeGenerator, ActiveTerrainPainter, ClimateNoiseGenerator, TerrainGeneratorLibrary,
+        TerrainPipeline,
+    },
+    world_save::WorldSave,
+};
+use b::utils;
+use bevy_ecs::world::World;
+use std::{error::Error, path::PathBuf, sync::Arc, time::Instant};
+
+struct MapArgs {
+    world: Option<PathBuf>,
+    seed: u32,
+    generator: Option<String>,
+    area: MapArea,
+    layers: Vec<MapLayer>,
+    overlay_alpha: f32,
+    out: PathBuf,
+}
+
+impl MapArgs {
+    fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut world = None;
+        let mut seed = 0;
+        let mut generator = None;
+        let mut radius = None;
+        let mut center = IVec2::ZERO;
+        let mut area = None;
+        let mut layers = Vec::new();
+        let mut overlay_alpha = DEFAULT_OVERLAY_ALPHA;
+        let mut out = PathBuf::from("map.png");
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--world" => world = Some(PathBuf::from(value()?)),
+                "--seed" => {
+                    let value = value()?;
+                    seed = value
+                        .parse()
+                        .map_err(|e| format!("Invalid seed '{value}': {e}"))?;
+                }
+                "--generator" => generator = Some(value()?),
+                "--radius" => {
+                    let value = value()?;
+                    radius = Some(
+                        value
+                            .parse::<i32>()
+                            .map_err(|e| format!("Invalid radius '{value}': {e}"))?,
+                    );
+                }
+                "--center" => {
+                    let [x, z] = parse_ints(&value()?)?;
+                    center = IVec2::new(x, z);
+                }
+                "--box" => {
+                    let [x0, z0, x1, z1] = parse_ints(&value()?)?;
+                    area = Some(MapArea::from_corners(
+                        IVec2::new(x0, z0),
+                        IVec2::new(x1, z1),
+                    ));
+                }
+                "--layer" => match value()?.as_str() {
+                    "all" => layers.extend(MapLayer::ALL),
+                    name => layers.push(name.parse()?),
+                },
+                "--overlay-alpha" => {
+                    let value = value()?;
+                    overlay_alpha = value
+                        .parse()
+                        .map_err(|e| format!("Invalid overlay alpha '{value}': {e}"))?;
+                }
+                "--out" => out = PathBuf::from(value()?),
+                other => return Err(format!("Unknown argument '{other}'")),
+            }
+        }
+
+        let area = match (area, radius) {
+            (Some(area), None) => area,
+            (None, Some(radius)) if radius >= 0 => {
+                MapArea::from_corners(center - IVec2::splat(radius), center + IVec2::splat(radius))
+            }
+            (None, Some(radius)) => return Err(format!("Invalid radius {radius}")),
+            _ => return Err("Expected exactly one of --radius or --box".to_string()),
+        };
+
+        if world.is_none() && generator.is_none() {
+            return Err("Either --world or --generator is required".to_string());
+        }
+        if layers.is_empty() {
+            layers.push(MapLayer::Surface);
+        }
+
+        Ok(Self {
+            world,
+            seed,
+            generator,
+            area,
+            layers,
+            overlay_alpha,
+            out,
+        })
+    }
+
+    /// Where a layer's image is written.
+    fn layer_path(&self, layer: MapLayer) -> PathBuf {
+        if self.layers.len() == 1 {
+            return self.out.clone();
+        }
+        let stem = self
+            .out
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "map".to_string());
+        self.out.with_file_name(format!("{stem}_{layer}.png"))
+    }
+}
+
+fn parse_ints<const N: usize>(value: &str) -> Result<[i32; N], String> {
+    let parsed: Vec<i32> = value
+        .split(',')
+        .map(|part| part.trim().parse::<i32>())
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Invalid coordinates '{value}': {e}"))?;
+
+    parsed
+        .try_into()
+        .map_err(|_| format!("Expected {N} comma separated values, got '{value}'"))
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    utils::logger::attach_logger();
+
+    let args = MapArgs::from_args(std::env::args().skip(1))?;
+
+    // INFO: registries and block colors
+    let app_config = config::load_config();
+    let (staging_images, texture_registry) = load_voxel_texture_assets(&app_config)?;
+
+    let mut world = World::new();
+    world.insert_resource(texture_registry);
+    world.init_resource::<BlockRegistryResource>();
+    world.init_resource::<BiomeRegistryResource>();
+    let block_registry = world.resource::<BlockRegistryResource>().clone();
+    let biome_registry = world.resource::<BiomeRegistryResource>().clone();
+
+    let palette = BlockColorPalette::from_textures(&block_registry, &staging_images.images);
+
+    // INFO: chunk source
+    let world_save = args
+        .world
+        .as_ref()
+        .map(|dir| WorldSave::open(dir, &block_registry, &biome_registry))
+        .transpose()?;
+
+    let (seed, generator_name) = match &world_save {
+        Some(save) => (save.meta.seed, save.meta.generator.clone()),
+        None => (args.seed, args.generator.clone().unwrap_or_default()),
+    };
+
+    let library = TerrainGeneratorLibrary::default();
+    let shaper = library
+        .generators
+        .iter()
+        .find(|shaper| shaper.name() == generator_name)
+        .cloned()
+        .ok_or_else(|| {
+            let names: Vec<&str> = library.generators.iter().map(|g| g.name()).collect();
+            format!(
+                "Unknown generator '{}' (expected one of: {})",
+                generator_name,
+                names.join(", ")
+            )
+        })?;
+
+    let source = MapChunkSource {
+        pipeline: TerrainPipeline {
+            climate: Arc::new(ClimateNoiseGenerator::new(seed)),
+            biome: ActiveBiomeGenerator::default().0,
+            shaper,
+            painter: ActiveTerrainPainter::default().0,
+        },
+        block_registry,
+        biome_registry,
+        world_save: world_save.map(Arc::new),
+    };
+
+    // INFO: sampling and rendering
+    println!(
+        "Sampling {}x{} columns with '{}' (seed {})...",
+        args.area.width(),
+        args.area.depth(),
+        generator_name,
+        seed
+    );
+    let start = Instant::now();
+    let surface = SurfaceMap::sample(args.area, &source);
+    println!("Sampled surface in {:.2?}", start.elapsed());
+
+    for &layer in &args.layers {
+        let path = args.layer_path(layer);
+        surface
+            .render(layer, &palette, args.overlay_alpha)
+            .save(&path)?;
+        println!("Wrote {layer} map to {}", path.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,264 @@
+//! Pre-generates a region of the world and writes it to a world save.
+//!
+//! Usage:
+//!   b-worldgen --seed <n> --generator <name> --out <dir> (--radius <chunks> [--center <x>,<z>] | --box <x0>,<z0>,<x1>,<z1>)
+//!
+//! The resulting directory can be loaded with `b --headless --world <dir>`.
+
+use b::ecs_core::config;
+use b::prelude::*;
+use b::render_world::textures::load_voxel_texture_assets;
+use b::simulation_world::{
+    biome::BiomeRegistryResource,
+    block::BlockRegistryResource,
+    chunk::{ChunkCoord, ChunkLod},
+    terrain::{
+        ActiveBiomeGenerator, ActiveTerrainPainter, ClimateNoiseGenerator, TerrainGeneratorLibrary,
+        TerrainPipeline,
+    },
+    world_save::{encode_generated, Region, RegionCoord, RegionSlot, WorldSave},
+};
+use b::utils;
+use bevy_ecs::world::World;
+use rayon::prelude::*;
+use std::{
+    error::Error,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+struct WorldgenArgs {
+    seed: u32,
+    generator: String,
+    out: PathBuf,
+    /// Inclusive chunk column bounds, as `(min, max)` over x and z.
+    bounds: (IVec2, IVec2),
+}
+
+impl WorldgenArgs {
+    fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut seed = None;
+        let mut generator = None;
+        let mut out = None;
+        let mut radius = None;
+        let mut center = IVec2::ZERO;
+        let mut bounds = None;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--seed" => {
+                    let value = value()?;
+                    seed = Some(
+                        value
+                            .parse()
+                            .map_err(|e| format!("Invalid seed '{value}': {e}"))?,
+                    );
+                }
+                "--generator" => generator = Some(value()?),
+                "--out" => out = Some(PathBuf::from(value()?)),
+                "--radius" => {
+                    let value = value()?;
+                    radius = Some(
+                        value
+                            .parse::<i32>()
+                            .map_err(|e| format!("Invalid radius '{value}': {e}"))?,
+                    );
+                }
+                "--center" => {
+                    let [x, z] = parse_ints(&value()?)?;
+                    center = IVec2::new(x, z);
+                }
+                "--box" => {
+                    let [x0, z0, x1, z1] = parse_ints(&value()?)?;
+                    bounds = Some((
+                        IVec2::new(x0.min(x1), z0.min(z1)),
+                        IVec2::new(x0.max(x1), z0.max(z1)),
+                    ));
+                }
+                other => return Err(format!("Unknown argument '{other}'")),
+            }
+        }
+
+        let bounds = match (bounds, radius) {
+            (Some(bounds), None) => bounds,
+            (None, Some(radius)) if radius >= 0 => {
+                (center - IVec2::splat(radius), center + IVec2::splat(radius))
+            }
+            (None, Some(radius)) => return Err(format!("Invalid radius {radius}")),
+            _ => return Err("Expected exactly one of --radius or --box".to_string()),
+        };
+
+        Ok(Self {
+            seed: seed.ok_or("--seed is required")?,
+            generator: generator.ok_or("--generator is required")?,
+            out: out.ok_or("--out is required")?,
+            bounds,
+        })
+    }
+
+    fn contains_column(&self, chunk_pos: IVec3) -> bool {
+        let (min, max) = self.bounds;
+        (min.x..=max.x).contains(&chunk_pos.x) && (min.y..=max.y).contains(&chunk_pos.z)
+    }
+
+    /// Every region overlapping the requested bounds.
+    fn regions(&self) -> Vec<RegionCoord> {
+        let (min, max) = self.bounds;
+        let min_region = RegionCoord::of_chunk(IVec3::new(min.x, 0, min.y));
+        let max_region = RegionCoord::of_chunk(IVec3::new(max.x, 0, max.y));
+
+        (min_region.x..=max_region.x)
+            .flat_map(|x| (min_region.z..=max_region.z).map(move |z| RegionCoord { x, z }))
+            .collect()
+    }
+}
+
+fn parse_ints<const N: usize>(value: &str) -> Result<[i32; N], String> {
+    let parsed: Vec<i32> = value
+        .split(',')
+        .map(|part| part.trim().parse::<i32>())
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Invalid coordinates '{value}': {e}"))?;
+
+    parsed
+        .try_into()
+        .map_err(|_| format!("Expected {N} comma separated values, got '{value}'"))
+}
+
+/// Loads the block and biome registries the same way the app does.
+fn load_registries() -> Result<(BlockRegistryResource, BiomeRegistryResource), Box<dyn Error>> {
+    let app_config = config::load_config();
+
+    // textures are only needed for their IDs, the images themselves are dropped
+    let (_, texture_registry) = load_voxel_texture_assets(&app_config)?;
+
+    let mut world = World::new();
+    world.insert_resource(texture_registry);
+    world.init_resource::<BlockRegistryResource>();
+    world.init_resource::<BiomeRegistryResource>();
+
+    Ok((
+        world.resource::<BlockRegistryResource>().clone(),
+        world.resource::<BiomeRegistryResource>().clone(),
+    ))
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    utils::logger::attach_logger();
+
+    let args = WorldgenArgs::from_args(std::env::args().skip(1))?;
+
+    let library = TerrainGeneratorLibrary::default();
+    let shaper = library
+        .generators
+        .iter()
+        .find(|shaper| shaper.name() == args.generator)
+        .cloned()
+        .ok_or_else(|| {
+            let names: Vec<&str> = library.generators.iter().map(|g| g.name()).collect();
+            format!(
+                "Unknown generator '{}' (expected one of: {})",
+                args.generator,
+                names.join(", ")
+            )
+        })?;
+
+    let (block_registry, biome_registry) = load_registries()?;
+    let save = WorldSave::create(
+        &args.out,
+        args.seed,
+        &args.generator,
+        &block_registry,
+        &biome_registry,
+    )?;
+
+    let pipeline = TerrainPipeline {
+        climate: Arc::new(ClimateNoiseGenerator::new(args.seed)),
+        biome: ActiveBiomeGenerator::default().0,
+        shaper,
+        painter: ActiveTerrainPainter::default().0,
+    };
+
+    let regions = args.regions();
+    let (min, max) = args.bounds;
+    let columns = ((max.x - min.x + 1) * (max.y - min.y + 1)) as usize;
+    println!(
+        "Generating {} chunk columns ({} regions) with '{}' (seed {}) into {:?}",
+        columns,
+        regions.len(),
+        args.generator,
+        args.seed,
+        args.out
+    );
+
+    let start = Instant::now();
+    let regions_done = AtomicUsize::new(0);
+    let chunks_done = AtomicUsize::new(0);
+
+    let bytes_written = regions
+        .par_iter()
+        .map(|&coord| -> Result<usize, String> {
+            let positions: Vec<IVec3> = coord
+                .chunk_positions()
+                .filter(|&pos| args.contains_column(pos))
+                .collect();
+
+            let generated: Vec<(IVec3, RegionSlot)> = positions
+                .into_par_iter()
+                .map(|pos| {
+                    let bundle = pipeline.generate_chunk(
+                        ChunkCoord { pos },
+                        ChunkLod(0),
+                        &block_registry,
+                        &biome_registry,
+                    );
+                    (pos, encode_generated(bundle.as_ref()))
+                })
+                .collect();
+
+            let mut region = Region::default();
+            let chunk_count = generated.len();
+            for (pos, slot) in generated {
+                if let Some(index) = coord.slot_index(pos) {
+                    region.slots[index] = slot;
+                }
+            }
+
+            let bytes = save
+                .write_region(coord, &region)
+                .map_err(|e| format!("Failed to write region {}: {e}", coord.file_name()))?;
+
+            let done = regions_done.fetch_add(1, Ordering::Relaxed) + 1;
+            let chunks = chunks_done.fetch_add(chunk_count, Ordering::Relaxed) + chunk_count;
+            let elapsed = start.elapsed().as_secs_f64();
+            println!(
+                "[{done}/{}] {} ({chunks} chunks, {:.0} chunks/s)",
+                regions.len(),
+                coord.file_name(),
+                chunks as f64 / elapsed.max(f64::EPSILON)
+            );
+
+            Ok(bytes)
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .sum::<usize>();
+
+    let elapsed = start.elapsed();
+    let chunks = chunks_done.load(Ordering::Relaxed);
+    println!(
+        "Generated {} chunks in {:.2?} ({:.0} chunks/s), wrote {:.1} MiB",
+        chunks,
+        elapsed,
+        chunks as f64 / elapsed.as_secs_f64().max(f64::EPSILON),
+        bytes_written as f64 / (1024.0 * 1024.0)
+    );
+
+    Ok(())
+}